@@ -180,10 +180,11 @@ impl App {
             .block(Block::bordered().title("World"))
             .marker(self.marker)
             .paint(|ctx| {
-                ctx.draw(&Map {
-                    color: Color::Green,
-                    resolution: MapResolution::High,
-                });
+                ctx.draw(
+                    &Map::default()
+                        .color(Color::Green)
+                        .resolution(MapResolution::High),
+                );
                 ctx.print(self.x, -self.y, "You are here".yellow());
             })
             .x_bounds([-180.0, 180.0])
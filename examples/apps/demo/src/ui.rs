@@ -308,10 +308,11 @@ fn draw_second_tab(frame: &mut Frame, app: &mut App, area: Rect) {
     let map = Canvas::default()
         .block(Block::bordered().title("World"))
         .paint(|ctx| {
-            ctx.draw(&Map {
-                color: Color::White,
-                resolution: MapResolution::High,
-            });
+            ctx.draw(
+                &Map::default()
+                    .color(Color::White)
+                    .resolution(MapResolution::High),
+            );
             ctx.layer();
             ctx.draw(&Rectangle {
                 x: 0.0,
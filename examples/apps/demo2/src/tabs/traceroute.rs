@@ -107,10 +107,9 @@ pub fn render_ping(progress: usize, area: Rect, buf: &mut Buffer) {
 fn render_map(selected_row: usize, area: Rect, buf: &mut Buffer) {
     let theme = THEME.traceroute.map;
     let path: Option<(&Hop, &Hop)> = HOPS.iter().tuple_windows().nth(selected_row);
-    let map = Map {
-        resolution: MapResolution::High,
-        color: theme.color,
-    };
+    let map = Map::default()
+        .resolution(MapResolution::High)
+        .color(theme.color);
     Canvas::default()
         .background_color(theme.background_color)
         .block(
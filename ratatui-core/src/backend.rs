@@ -9,7 +9,8 @@
 //! - [Termion]: enable the `termion` feature and use [`TermionBackend`]
 //! - [Termwiz]: enable the `termwiz` feature and use [`TermwizBackend`]
 //!
-//! Additionally, a [`TestBackend`] is provided for testing purposes.
+//! Additionally, a [`TestBackend`] is provided for testing purposes, and a [`NullBackend`] for
+//! running applications headless when no terminal is available.
 //!
 //! See the [Backend Comparison] section of the [Ratatui Website] for more details on the different
 //! backends.
@@ -90,6 +91,10 @@
 //! backend being used, and developers should consult the specific backend's documentation to
 //! understand how it implements mouse capture.
 //!
+//! Backends that support it allow choosing which mouse events are captured with
+//! [`Backend::set_mouse_capture`]. Capturing only clicks avoids flooding the application with
+//! motion events when it does not need them.
+//!
 //! [`CrosstermBackend`]: https://docs.rs/ratatui/latest/ratatui/backend/struct.CrosstermBackend.html
 //! [`TermionBackend`]: https://docs.rs/ratatui/latest/ratatui/backend/struct.TermionBackend.html
 //! [`TermwizBackend`]: https://docs.rs/ratatui/latest/ratatui/backend/struct.TermwizBackend.html
@@ -109,8 +114,13 @@ use crate::{
     layout::{Position, Size},
 };
 
+mod mouse;
+mod notification;
+mod null;
 mod test;
-pub use self::test::TestBackend;
+pub use self::{
+    mouse::MouseCapture, notification::NotificationProtocol, null::NullBackend, test::TestBackend,
+};
 
 /// Enum representing the different types of clearing operations that can be performed
 /// on the terminal screen.
@@ -313,6 +323,52 @@ pub trait Backend {
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> io::Result<()>;
 
+    /// Ring the terminal bell.
+    ///
+    /// Depending on the terminal configuration, this plays a sound, flashes the screen or marks
+    /// the window as requiring attention.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    fn bell(&mut self) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "bell not supported with this backend",
+        ))
+    }
+
+    /// Show a desktop notification with the given title and body using the given
+    /// [`NotificationProtocol`].
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    fn notify(
+        &mut self,
+        protocol: NotificationProtocol,
+        _title: &str,
+        _body: &str,
+    ) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("notification protocol [{protocol:?}] not supported with this backend"),
+        ))
+    }
+
+    /// Set which mouse events are reported by the terminal.
+    ///
+    /// See [`MouseCapture`] for the available modes. Pass [`MouseCapture::Disabled`] to stop
+    /// capturing mouse events.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`], which can be used
+    /// to detect whether the backend supports choosing the mode.
+    fn set_mouse_capture(&mut self, capture: MouseCapture) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("mouse capture [{capture:?}] not supported with this backend"),
+        ))
+    }
+
     /// Scroll a region of the screen upwards, where a region is specified by a (half-open) range
     /// of rows.
     ///
@@ -342,9 +398,23 @@ pub trait Backend {
     ///
     /// For examples of how this function is expected to work, refer to the tests for
     /// [`TestBackend::scroll_region_up`].
+    ///
+    /// The default implementation returns an error of kind [`io::ErrorKind::Unsupported`]. When
+    /// drawing, [`Terminal`] then falls back to redrawing the rows that moved instead of scrolling
+    /// them.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
     #[cfg(feature = "scrolling-regions")]
-    fn scroll_region_up(&mut self, region: std::ops::Range<u16>, line_count: u16)
-        -> io::Result<()>;
+    fn scroll_region_up(
+        &mut self,
+        _region: std::ops::Range<u16>,
+        _line_count: u16,
+    ) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "scrolling regions not supported with this backend",
+        ))
+    }
 
     /// Scroll a region of the screen downwards, where a region is specified by a (half-open) range
     /// of rows.
@@ -364,12 +434,20 @@ pub trait Backend {
     ///
     /// For examples of how this function is expected to work, refer to the tests for
     /// [`TestBackend::scroll_region_down`].
+    ///
+    /// The default implementation returns an error of kind [`io::ErrorKind::Unsupported`], like
+    /// [`Self::scroll_region_up`].
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_down(
         &mut self,
-        region: std::ops::Range<u16>,
-        line_count: u16,
-    ) -> io::Result<()>;
+        _region: std::ops::Range<u16>,
+        _line_count: u16,
+    ) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "scrolling regions not supported with this backend",
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,63 @@
+/// The mouse events that are reported by the terminal while mouse capture is enabled.
+///
+/// Terminals report mouse events when the application enables one of the xterm mouse tracking
+/// modes. The modes report increasingly more events, and each of them includes the events of the
+/// previous ones:
+///
+/// - [`MouseCapture::Click`] reports button presses and releases, and the mouse wheel.
+/// - [`MouseCapture::Drag`] also reports movement while a button is held down.
+/// - [`MouseCapture::AllMotion`] also reports any movement of the mouse.
+///
+/// Capturing more events than needed has a cost: all-motion capture sends an event for every cell
+/// the mouse crosses, which can flood the event loop of the application, and while any mode is
+/// enabled the terminal does not handle text selection itself (most terminals still allow
+/// selecting text while holding Shift).
+///
+/// Use [`Backend::set_mouse_capture`] to change the mode of a terminal.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::MouseCapture;
+///
+/// assert_eq!(
+///     MouseCapture::Drag.escape_sequence(),
+///     "\x1b[?1003l\x1b[?1000h\x1b[?1002h\x1b[?1015h\x1b[?1006h"
+/// );
+/// ```
+///
+/// [`Backend::set_mouse_capture`]: crate::backend::Backend::set_mouse_capture
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MouseCapture {
+    /// No mouse events are reported.
+    #[default]
+    Disabled,
+    /// Button presses, button releases and mouse wheel events are reported.
+    Click,
+    /// Like [`MouseCapture::Click`], and mouse movement while a button is held down is reported.
+    Drag,
+    /// Like [`MouseCapture::Drag`], and all mouse movement is reported.
+    AllMotion,
+}
+
+impl MouseCapture {
+    /// Returns whether any mouse events are reported.
+    pub const fn is_enabled(self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+
+    /// Returns the escape sequence that switches the terminal to this mode.
+    ///
+    /// The sequence disables the tracking modes that report more events than this mode, so it can
+    /// be used to switch between modes. When enabled, the coordinates are reported in the SGR
+    /// (`1006`) and urxvt (`1015`) extended formats, so that positions beyond column 223 are
+    /// reported correctly.
+    pub const fn escape_sequence(self) -> &'static str {
+        match self {
+            Self::Disabled => "\x1b[?1006l\x1b[?1015l\x1b[?1003l\x1b[?1002l\x1b[?1000l",
+            Self::Click => "\x1b[?1003l\x1b[?1002l\x1b[?1000h\x1b[?1015h\x1b[?1006h",
+            Self::Drag => "\x1b[?1003l\x1b[?1000h\x1b[?1002h\x1b[?1015h\x1b[?1006h",
+            Self::AllMotion => "\x1b[?1000h\x1b[?1002h\x1b[?1003h\x1b[?1015h\x1b[?1006h",
+        }
+    }
+}
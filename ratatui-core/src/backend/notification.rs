@@ -0,0 +1,133 @@
+use std::env;
+
+/// The escape sequence used to raise a desktop notification from the terminal.
+///
+/// There is no standard way to ask a terminal emulator to show a desktop notification. Two
+/// proprietary Operating System Command (OSC) sequences are widely implemented:
+///
+/// - `OSC 9` was introduced by iTerm2 and is also understood by kitty, Windows Terminal and many
+///   others. It only carries a message.
+/// - `OSC 777` was introduced by rxvt-unicode and is also understood by foot, Ghostty and VTE based
+///   terminals that enable it. It carries a title and a message.
+///
+/// Terminals that do not understand the sequence silently ignore it, but some terminals may print
+/// garbage instead. Use [`NotificationProtocol::detect`] to only send notifications to terminals
+/// that are known to support them.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::NotificationProtocol;
+///
+/// let sequence = NotificationProtocol::Osc777.escape_sequence("Build", "finished");
+/// assert_eq!(sequence, "\x1b]777;notify;Build;finished\x07");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum NotificationProtocol {
+    /// The iTerm2 `OSC 9` notification sequence.
+    Osc9,
+    /// The rxvt-unicode `OSC 777` notification sequence.
+    Osc777,
+}
+
+impl NotificationProtocol {
+    /// Detects the notification protocol supported by the current terminal.
+    ///
+    /// The detection is based on the `TERM_PROGRAM` and `TERM` environment variables. Returns
+    /// `None` when the terminal is not known to support desktop notifications.
+    pub fn detect() -> Option<Self> {
+        let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+        let term = env::var("TERM").unwrap_or_default();
+        Self::from_terminal(&term_program, &term)
+    }
+
+    /// Returns the notification protocol supported by a terminal identified by the values of the
+    /// `TERM_PROGRAM` and `TERM` environment variables.
+    fn from_terminal(term_program: &str, term: &str) -> Option<Self> {
+        match term_program {
+            "iTerm.app" | "WezTerm" => return Some(Self::Osc9),
+            "ghostty" => return Some(Self::Osc777),
+            _ => {}
+        }
+        if term.starts_with("xterm-kitty") {
+            Some(Self::Osc9)
+        } else if term.starts_with("rxvt-unicode") || term.starts_with("foot") {
+            Some(Self::Osc777)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the escape sequence that shows a notification with the given title and body.
+    ///
+    /// Control characters are removed from the title and body so that they cannot terminate the
+    /// sequence early. `OSC 9` does not support titles, so the title is prepended to the body.
+    pub fn escape_sequence(self, title: &str, body: &str) -> String {
+        let body = sanitize(body);
+        match self {
+            Self::Osc9 if title.is_empty() => format!("\x1b]9;{body}\x07"),
+            Self::Osc9 => format!("\x1b]9;{}: {body}\x07", sanitize(title)),
+            // the title is a `;` separated field, so it cannot contain any `;`
+            Self::Osc777 => format!(
+                "\x1b]777;notify;{};{body}\x07",
+                sanitize(title).replace(';', ",")
+            ),
+        }
+    }
+}
+
+fn sanitize(value: &str) -> String {
+    value.chars().filter(|c| !c.is_control()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::iterm("iTerm.app", "xterm-256color", Some(NotificationProtocol::Osc9))]
+    #[case::wezterm("WezTerm", "xterm-256color", Some(NotificationProtocol::Osc9))]
+    #[case::ghostty("ghostty", "xterm-ghostty", Some(NotificationProtocol::Osc777))]
+    #[case::kitty("", "xterm-kitty", Some(NotificationProtocol::Osc9))]
+    #[case::urxvt("", "rxvt-unicode-256color", Some(NotificationProtocol::Osc777))]
+    #[case::foot("", "foot", Some(NotificationProtocol::Osc777))]
+    #[case::xterm("", "xterm-256color", None)]
+    #[case::unknown("", "", None)]
+    fn from_terminal(
+        #[case] term_program: &str,
+        #[case] term: &str,
+        #[case] expected: Option<NotificationProtocol>,
+    ) {
+        assert_eq!(
+            NotificationProtocol::from_terminal(term_program, term),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::osc9(NotificationProtocol::Osc9, "", "done", "\x1b]9;done\x07")]
+    #[case::osc9_title(NotificationProtocol::Osc9, "Build", "done", "\x1b]9;Build: done\x07")]
+    #[case::osc777(
+        NotificationProtocol::Osc777,
+        "Build",
+        "done",
+        "\x1b]777;notify;Build;done\x07"
+    )]
+    #[case::osc777_title_separator(
+        NotificationProtocol::Osc777,
+        "a;b",
+        "c;d",
+        "\x1b]777;notify;a,b;c;d\x07"
+    )]
+    #[case::control_characters(NotificationProtocol::Osc9, "", "do\x07ne\x1b", "\x1b]9;done\x07")]
+    fn escape_sequence(
+        #[case] protocol: NotificationProtocol,
+        #[case] title: &str,
+        #[case] body: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(protocol.escape_sequence(title, body), expected);
+    }
+}
@@ -0,0 +1,211 @@
+//! This module provides the `NullBackend` implementation for the [`Backend`] trait.
+//! It is used to run applications headless, when no terminal is available.
+
+use std::io;
+
+use crate::{
+    backend::{Backend, ClearType, MouseCapture, WindowSize},
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect, Size},
+};
+
+/// A [`Backend`] implementation that accepts all operations and discards the output.
+///
+/// This backend allows applications with an optional UI to keep running when no terminal is
+/// available, for example under cron or in CI, instead of failing when querying the size of the
+/// terminal. It reports a fixed size, and drawing to it has no effect unless capture is enabled
+/// with [`NullBackend::capture`], in which case the drawn cells are kept so that the final frame
+/// can be inspected or logged with [`NullBackend::captured_frame`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::{backend::NullBackend, terminal::Terminal, text::Line};
+///
+/// let backend = NullBackend::new(10, 1).capture(true);
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget(Line::raw("done"), frame.area()))?;
+/// let frame = terminal.backend().captured_frame().unwrap();
+/// assert_eq!(frame[(0, 0)].symbol(), "d");
+/// # std::io::Result::Ok(())
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NullBackend {
+    size: Size,
+    cursor: bool,
+    pos: Position,
+    captured: Option<Buffer>,
+}
+
+impl Default for NullBackend {
+    /// Creates a `NullBackend` with the size of a standard 80x24 terminal.
+    fn default() -> Self {
+        Self::new(80, 24)
+    }
+}
+
+impl NullBackend {
+    /// Creates a new `NullBackend` that reports the specified width and height.
+    pub const fn new(width: u16, height: u16) -> Self {
+        Self {
+            size: Size::new(width, height),
+            cursor: false,
+            pos: Position::ORIGIN,
+            captured: None,
+        }
+    }
+
+    /// Sets whether the drawn cells are captured.
+    ///
+    /// When enabled, the backend keeps a buffer of its screen, which can be retrieved with
+    /// [`NullBackend::captured_frame`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn capture(mut self, capture: bool) -> Self {
+        self.captured = capture.then(|| Buffer::empty(Rect::from((Position::ORIGIN, self.size))));
+        self
+    }
+
+    /// Returns the screen as drawn so far, or `None` if capture is disabled.
+    pub const fn captured_frame(&self) -> Option<&Buffer> {
+        self.captured.as_ref()
+    }
+
+    /// Returns whether the cursor is visible.
+    pub const fn is_cursor_visible(&self) -> bool {
+        self.cursor
+    }
+
+    /// Resizes the `NullBackend` to the specified width and height.
+    ///
+    /// The captured frame, if any, is cleared.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.size = Size::new(width, height);
+        if let Some(captured) = &mut self.captured {
+            *captured = Buffer::empty(Rect::new(0, 0, width, height));
+        }
+    }
+}
+
+impl Backend for NullBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        if let Some(captured) = &mut self.captured {
+            for (x, y, cell) in content {
+                if let Some(target) = captured.cell_mut((x, y)) {
+                    *target = cell.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.cursor = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.cursor = true;
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        Ok(self.pos)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.pos = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        if let Some(captured) = &mut self.captured {
+            captured.reset();
+        }
+        Ok(())
+    }
+
+    fn clear_region(&mut self, _clear_type: ClearType) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::ZERO,
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn bell(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_mouse_capture(&mut self, _capture: MouseCapture) -> io::Result<()> {
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(&mut self, _region: std::ops::Range<u16>, _amount: u16) -> io::Result<()> {
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(
+        &mut self,
+        _region: std::ops::Range<u16>,
+        _amount: u16,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discards_output() {
+        let mut backend = NullBackend::new(4, 2);
+        let cell = Cell::new("x");
+        backend.draw(std::iter::once((0, 0, &cell))).unwrap();
+        assert_eq!(backend.captured_frame(), None);
+        assert_eq!(backend.size().unwrap(), Size::new(4, 2));
+    }
+
+    #[test]
+    fn captures_frame() {
+        let mut backend = NullBackend::new(4, 2).capture(true);
+        let cell = Cell::new("x");
+        backend
+            .draw([(1, 1, &cell), (9, 9, &cell)].into_iter())
+            .unwrap();
+        assert_eq!(
+            backend.captured_frame(),
+            Some(&Buffer::with_lines(["    ", " x  "]))
+        );
+        backend.clear().unwrap();
+        assert_eq!(
+            backend.captured_frame(),
+            Some(&Buffer::with_lines(["    ", "    "]))
+        );
+    }
+
+    #[test]
+    fn cursor() {
+        let mut backend = NullBackend::default();
+        backend.show_cursor().unwrap();
+        backend.set_cursor_position((3, 4)).unwrap();
+        assert!(backend.is_cursor_visible());
+        assert_eq!(backend.get_cursor_position().unwrap(), Position::new(3, 4));
+    }
+}
@@ -37,7 +37,7 @@ pub struct TestBackend {
     buffer: Buffer,
     scrollback: Buffer,
     cursor: bool,
-    pos: (u16, u16),
+    pos: Position,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -76,7 +76,7 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
             cursor: false,
-            pos: (0, 0),
+            pos: Position::ORIGIN,
         }
     }
 
@@ -98,7 +98,7 @@ impl TestBackend {
             buffer,
             scrollback,
             cursor: false,
-            pos: (0, 0),
+            pos: Position::ORIGIN,
         }
     }
 
@@ -256,11 +256,11 @@ impl Backend for TestBackend {
     }
 
     fn get_cursor_position(&mut self) -> io::Result<Position> {
-        Ok(self.pos.into())
+        Ok(self.pos)
     }
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
-        self.pos = position.into().into();
+        self.pos = position.into();
         Ok(())
     }
 
@@ -273,21 +273,21 @@ impl Backend for TestBackend {
         let region = match clear_type {
             ClearType::All => return self.clear(),
             ClearType::AfterCursor => {
-                let index = self.buffer.index_of(self.pos.0, self.pos.1) + 1;
+                let index = self.buffer.index_of(self.pos.x, self.pos.y) + 1;
                 &mut self.buffer.content[index..]
             }
             ClearType::BeforeCursor => {
-                let index = self.buffer.index_of(self.pos.0, self.pos.1);
+                let index = self.buffer.index_of(self.pos.x, self.pos.y);
                 &mut self.buffer.content[..index]
             }
             ClearType::CurrentLine => {
-                let line_start_index = self.buffer.index_of(0, self.pos.1);
-                let line_end_index = self.buffer.index_of(self.buffer.area.width - 1, self.pos.1);
+                let line_start_index = self.buffer.index_of(0, self.pos.y);
+                let line_end_index = self.buffer.index_of(self.buffer.area.width - 1, self.pos.y);
                 &mut self.buffer.content[line_start_index..=line_end_index]
             }
             ClearType::UntilNewLine => {
-                let index = self.buffer.index_of(self.pos.0, self.pos.1);
-                let line_end_index = self.buffer.index_of(self.buffer.area.width - 1, self.pos.1);
+                let index = self.buffer.index_of(self.pos.x, self.pos.y);
+                let line_end_index = self.buffer.index_of(self.buffer.area.width - 1, self.pos.y);
                 &mut self.buffer.content[index..=line_end_index]
             }
         };
@@ -467,7 +467,7 @@ mod tests {
                 buffer: Buffer::with_lines(["          "; 2]),
                 scrollback: Buffer::empty(Rect::new(0, 0, 10, 0)),
                 cursor: false,
-                pos: (0, 0),
+                pos: Position::ORIGIN,
             }
         );
     }
@@ -570,7 +570,7 @@ mod tests {
         backend
             .set_cursor_position(Position { x: 5, y: 5 })
             .unwrap();
-        assert_eq!(backend.pos, (5, 5));
+        assert_eq!(backend.pos, Position::new(5, 5));
     }
 
     #[test]
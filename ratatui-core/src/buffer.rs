@@ -1,9 +1,13 @@
 #![warn(missing_docs)]
-//! A module for the [`Buffer`] and [`Cell`] types.
+//! A module for the [`Buffer`], [`BufferMut`] and [`Cell`] types, and the [`Layer`]s of a [`Compositor`].
 
 mod assert;
 mod buffer;
+mod buffer_mut;
 mod cell;
+mod layer;
 
 pub use buffer::Buffer;
+pub use buffer_mut::BufferMut;
 pub use cell::Cell;
+pub use layer::{Compositor, Layer};
@@ -3,13 +3,12 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    buffer::Cell,
+    buffer::{BufferMut, Cell},
     layout::{Position, Rect},
-    style::Style,
+    style::{Color, Modifier, Style},
     text::{Line, Span},
 };
 
@@ -90,6 +89,47 @@ impl Buffer {
         Self { area, content }
     }
 
+    /// Returns a Buffer for the given area that uses the given cells as its storage.
+    ///
+    /// The cells are reset and the storage is resized to the size of the area. No memory is
+    /// allocated when the capacity of `cells` is large enough, so storage allocated once (e.g. at
+    /// startup on memory-constrained targets, or outside of a hot rendering loop) can be reused
+    /// for every frame, getting it back with [`Buffer::into_cells`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, style::Style};
+    ///
+    /// let area = Rect::new(0, 0, 10, 2);
+    /// let mut cells = Vec::with_capacity(area.area() as usize);
+    /// for frame in 0..3 {
+    ///     let mut buf = Buffer::from_cells(area, cells);
+    ///     buf.set_string(0, 0, format!("frame {frame}"), Style::new());
+    ///     cells = buf.into_cells();
+    /// }
+    /// assert_eq!(cells.capacity(), 20);
+    /// ```
+    #[must_use]
+    pub fn from_cells(area: Rect, mut cells: Vec<Cell>) -> Self {
+        let length = area.area() as usize;
+        cells.truncate(length);
+        for cell in &mut cells {
+            cell.reset();
+        }
+        cells.resize(length, Cell::EMPTY);
+        Self {
+            area,
+            content: cells,
+        }
+    }
+
+    /// Consumes the buffer and returns its cells, e.g. to reuse them with [`Buffer::from_cells`].
+    #[must_use]
+    pub fn into_cells(self) -> Vec<Cell> {
+        self.content
+    }
+
     /// Returns a Buffer containing the given lines
     #[must_use]
     pub fn with_lines<'a, Iter>(lines: Iter) -> Self
@@ -117,6 +157,19 @@ impl Buffer {
         &self.area
     }
 
+    /// Returns a [`BufferMut`] that borrows the cells of this buffer.
+    ///
+    /// This lets a [`SliceWidget`] render into a `Buffer`.
+    ///
+    /// [`SliceWidget`]: crate::widgets::SliceWidget
+    #[must_use]
+    pub fn view_mut(&mut self) -> BufferMut<'_> {
+        BufferMut {
+            area: self.area,
+            content: &mut self.content,
+        }
+    }
+
     /// Returns a reference to the [`Cell`] at the given coordinates
     ///
     /// Callers should use [`Buffer[]`](Self::index) or [`Buffer::cell`] instead of this method.
@@ -332,7 +385,7 @@ impl Buffer {
     /// Use [`Buffer::set_string`] when the maximum amount of characters can be printed.
     pub fn set_stringn<T, S>(
         &mut self,
-        mut x: u16,
+        x: u16,
         y: u16,
         string: T,
         max_width: usize,
@@ -342,55 +395,17 @@ impl Buffer {
         T: AsRef<str>,
         S: Into<Style>,
     {
-        let max_width = max_width.try_into().unwrap_or(u16::MAX);
-        let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
-        let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
-            .filter(|symbol| !symbol.contains(char::is_control))
-            .map(|symbol| (symbol, symbol.width() as u16))
-            .filter(|(_symbol, width)| *width > 0)
-            .map_while(|(symbol, width)| {
-                remaining_width = remaining_width.checked_sub(width)?;
-                Some((symbol, width))
-            });
-        let style = style.into();
-        for (symbol, width) in graphemes {
-            self[(x, y)].set_symbol(symbol).set_style(style);
-            let next_symbol = x + width;
-            x += 1;
-            // Reset following cells if multi-width (they would be hidden by the grapheme),
-            while x < next_symbol {
-                self[(x, y)].reset();
-                x += 1;
-            }
-        }
-        (x, y)
+        self.view_mut().set_stringn(x, y, string, max_width, style)
     }
 
     /// Print a line, starting at the position (x, y)
     pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
-        let mut remaining_width = max_width;
-        let mut x = x;
-        for span in line {
-            if remaining_width == 0 {
-                break;
-            }
-            let pos = self.set_stringn(
-                x,
-                y,
-                span.content.as_ref(),
-                remaining_width as usize,
-                line.style.patch(span.style),
-            );
-            let w = pos.0.saturating_sub(x);
-            x = pos.0;
-            remaining_width = remaining_width.saturating_sub(w);
-        }
-        (x, y)
+        self.view_mut().set_line(x, y, line, max_width)
     }
 
     /// Print a span, starting at the position (x, y)
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        self.view_mut().set_span(x, y, span, max_width)
     }
 
     /// Set the style of all cells in the given area.
@@ -400,12 +415,106 @@ impl Buffer {
     ///
     /// [`Color`]: crate::style::Color
     pub fn set_style<S: Into<Style>>(&mut self, area: Rect, style: S) {
-        let style = style.into();
+        self.view_mut().set_style(area, style);
+    }
+
+    /// Set whether all cells in the given area are skipped when copying (diffing) the buffer to
+    /// the screen.
+    ///
+    /// Skipped cells are left untouched on the terminal, which allows other programs (e.g. an image
+    /// drawn with sixels or the output of a subprocess) to draw in that area without being
+    /// overwritten. See [`Cell::skip`].
+    pub fn set_skip(&mut self, area: Rect, skip: bool) {
         let area = self.area.intersection(area);
-        for y in area.top()..area.bottom() {
-            for x in area.left()..area.right() {
-                self[(x, y)].set_style(style);
+        for position in area.positions() {
+            self[position].set_skip(skip);
+        }
+    }
+
+    /// Darken all cells in the given area, e.g. behind a popup or for a disabled widget.
+    ///
+    /// The colors of the cells are blended with black, where a `factor` of 0 leaves them
+    /// unchanged and a `factor` of 1 makes them black. The factor is clamped between 0 and 1.
+    ///
+    /// Blending is only approximated on terminals without true colors: indexed colors are
+    /// replaced with the closest indexed color and named colors with the closest named color.
+    /// Foreground colors that cannot be darkened this way (the default foreground color and the
+    /// named colors) get the [`Modifier::DIM`] modifier instead, and the default background color
+    /// is left unchanged.
+    ///
+    /// [`Modifier::DIM`]: crate::style::Modifier::DIM
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, style::Color};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+    /// buffer.set_style(
+    ///     buffer.area,
+    ///     (Color::Rgb(200, 200, 200), Color::Rgb(0, 0, 100)),
+    /// );
+    /// buffer.dim(Rect::new(2, 1, 6, 3), 0.5);
+    /// assert_eq!(buffer[(2, 1)].fg, Color::Rgb(100, 100, 100));
+    /// assert_eq!(buffer[(2, 1)].bg, Color::Rgb(0, 0, 50));
+    /// ```
+    pub fn dim(&mut self, area: Rect, factor: f64) {
+        // an RGB black keeps the blended colors as capable as the original ones
+        const BLACK: Color = Color::Rgb(0, 0, 0);
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            let cell = &mut self[position];
+            match cell.fg {
+                Color::Rgb(..) | Color::Indexed(16..=255) => {
+                    cell.fg = cell.fg.blend(BLACK, factor).unwrap_or(cell.fg);
+                }
+                _ if factor > 0.0 => cell.modifier.insert(Modifier::DIM),
+                _ => {}
             }
+            cell.bg = cell.bg.blend(BLACK, factor).unwrap_or(cell.bg);
+        }
+    }
+
+    /// Blend a background color over all cells in the given area, e.g. to tint the backdrop of a
+    /// popup.
+    ///
+    /// `alpha` is the opacity of `color`, clamped between 0 (the backgrounds are unchanged) and 1
+    /// (the backgrounds are replaced with `color`). The default background color is assumed to
+    /// be black.
+    ///
+    /// As with [`Buffer::dim`], blending is only approximated on terminals without true colors:
+    /// the result is the closest indexed color if either color is an indexed color and the
+    /// closest named color if either color is a named color. If `color` is [`Color::Reset`], the
+    /// backgrounds are only reset when `alpha` is at least 0.5.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     style::{Color, Style},
+    /// };
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+    /// buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(0, 0, 0)));
+    /// buffer.blend_bg(buffer.area, Color::Rgb(100, 0, 200), 0.5);
+    /// assert_eq!(buffer[(0, 0)].bg, Color::Rgb(50, 0, 100));
+    /// ```
+    pub fn blend_bg(&mut self, area: Rect, color: Color, alpha: f64) {
+        // the reset background is assumed to be black, as an RGB color so that it blends smoothly
+        const BLACK: Color = Color::Rgb(0, 0, 0);
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            let cell = &mut self[position];
+            let bg = if cell.bg == Color::Reset {
+                BLACK
+            } else {
+                cell.bg
+            };
+            cell.bg = bg
+                .blend(color, alpha)
+                .unwrap_or(if alpha >= 0.5 { color } else { cell.bg });
         }
     }
 
@@ -650,9 +759,10 @@ mod tests {
 
     use itertools::Itertools;
     use rstest::{fixture, rstest};
+    use unicode_segmentation::UnicodeSegmentation;
 
     use super::*;
-    use crate::style::{Color, Modifier, Stylize};
+    use crate::style::Stylize;
 
     #[test]
     fn debug_empty_buffer() {
@@ -1013,6 +1123,31 @@ mod tests {
         assert_eq!(buffer.area.height, 4);
     }
 
+    #[test]
+    fn from_cells_reuses_storage() {
+        let area = Rect::new(0, 0, 4, 2);
+        let mut cells = Buffer::with_lines(["abcdef".red(), "ghijkl".into()]).into_cells();
+        let capacity = cells.capacity();
+        let pointer = cells.as_ptr();
+
+        let mut buffer = Buffer::from_cells(area, cells);
+        assert_eq!(buffer, Buffer::empty(area));
+        buffer.set_string(0, 1, "xy", Style::new());
+        assert_eq!(buffer, Buffer::with_lines(["    ", "xy  "]));
+
+        cells = buffer.into_cells();
+        assert_eq!(cells.len(), 8);
+        assert_eq!(cells.capacity(), capacity);
+        assert_eq!(cells.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn from_cells_grows_storage() {
+        let area = Rect::new(0, 0, 3, 3);
+        let buffer = Buffer::from_cells(area, vec![Cell::new("x")]);
+        assert_eq!(buffer, Buffer::empty(area));
+    }
+
     #[test]
     fn diff_empty_empty() {
         let area = Rect::new(0, 0, 40, 40);
@@ -1121,6 +1256,75 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[test]
+    fn set_skip() {
+        let prev = Buffer::with_lines(["123", "456"]);
+        let mut next = Buffer::with_lines(["abc", "def"]);
+        next.set_skip(Rect::new(1, 0, 5, 1), true);
+
+        let diff = prev.diff(&next);
+        assert_eq!(
+            diff,
+            [
+                (0, 0, &Cell::new("a")),
+                (0, 1, &Cell::new("d")),
+                (1, 1, &Cell::new("e")),
+                (2, 1, &Cell::new("f")),
+            ]
+        );
+
+        next.set_skip(next.area, false);
+        assert!(next.content.iter().all(|cell| !cell.skip));
+    }
+
+    #[test]
+    fn dim() {
+        let mut buffer = Buffer::with_lines(["abcd"]);
+        buffer[(0, 0)].set_fg(Color::Rgb(100, 50, 0));
+        buffer[(1, 0)].set_style((Color::Indexed(231), Color::Indexed(231)));
+        buffer[(2, 0)].set_style((Color::Red, Color::White));
+        buffer.dim(Rect::new(0, 0, 3, 5), 0.5);
+
+        let mut expected = Buffer::with_lines(["abcd"]);
+        expected[(0, 0)].set_fg(Color::Rgb(50, 25, 0));
+        expected[(1, 0)].set_style((Color::Indexed(244), Color::Indexed(244)));
+        expected[(2, 0)].set_style(Style::new().red().on_dark_gray().dim());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn dim_reset_colors() {
+        let mut buffer = Buffer::with_lines(["a"]);
+        buffer.dim(buffer.area, 0.0);
+        assert_eq!(buffer, Buffer::with_lines(["a"]));
+        buffer.dim(buffer.area, 1.0);
+        assert_eq!(buffer, Buffer::with_lines(["a".dim()]));
+    }
+
+    #[test]
+    fn blend_bg() {
+        let mut buffer = Buffer::with_lines(["abc"]);
+        buffer[(1, 0)].set_bg(Color::Rgb(200, 200, 200));
+        buffer[(2, 0)].set_bg(Color::Indexed(16));
+        buffer.blend_bg(buffer.area, Color::Rgb(0, 0, 200), 0.5);
+
+        let mut expected = Buffer::with_lines(["abc"]);
+        expected[(0, 0)].set_bg(Color::Rgb(0, 0, 100));
+        expected[(1, 0)].set_bg(Color::Rgb(100, 100, 200));
+        expected[(2, 0)].set_bg(Color::Indexed(17));
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::transparent(0.25, Color::Rgb(1, 2, 3))]
+    #[case::opaque(0.75, Color::Reset)]
+    fn blend_bg_reset(#[case] alpha: f64, #[case] expected: Color) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer[(0, 0)].set_bg(Color::Rgb(1, 2, 3));
+        buffer.blend_bg(buffer.area, Color::Reset, alpha);
+        assert_eq!(buffer[(0, 0)].bg, expected);
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
@@ -0,0 +1,312 @@
+use std::ops::{Index, IndexMut};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect},
+    style::Style,
+    text::{Line, Span},
+};
+
+/// A mutable view of cells that are borrowed from the caller, mapped to an area of the terminal.
+///
+/// A [`Buffer`] owns its cells in a `Vec`, which is allocated on the heap. A `BufferMut` renders
+/// into a caller-provided `&mut [Cell]` instead, e.g. a fixed-size array on a memory-constrained
+/// target or storage reused across the iterations of a hot loop, so rendering never allocates.
+///
+/// Widgets that implement [`SliceWidget`] can be rendered into a `BufferMut`. A `BufferMut` that
+/// borrows the cells of a [`Buffer`] is returned by [`Buffer::view_mut`].
+///
+/// [`SliceWidget`]: crate::widgets::SliceWidget
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::{
+///     buffer::{BufferMut, Cell},
+///     layout::Rect,
+///     text::Line,
+///     widgets::SliceWidget,
+/// };
+///
+/// let mut cells = [Cell::EMPTY; 10];
+/// let mut buf = BufferMut::new(Rect::new(0, 0, 5, 2), &mut cells);
+/// Line::from("Hello").render_slice(buf.area, &mut buf);
+/// assert_eq!(buf[(4, 0)].symbol(), "o");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct BufferMut<'a> {
+    /// The area represented by this buffer
+    pub area: Rect,
+    /// The content of the buffer. The length of this slice is always equal to area.width *
+    /// area.height
+    pub content: &'a mut [Cell],
+}
+
+impl<'a> BufferMut<'a> {
+    /// Returns a `BufferMut` for the given area that renders into the given cells.
+    ///
+    /// The cells are not reset, call [`BufferMut::reset`] to clear them before rendering a new
+    /// frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `content` is not equal to the number of cells in the area.
+    #[track_caller]
+    pub fn new(area: Rect, content: &'a mut [Cell]) -> Self {
+        assert_eq!(
+            content.len(),
+            area.area() as usize,
+            "the number of cells does not match the area {area:?}",
+        );
+        Self { area, content }
+    }
+
+    /// Resets all the cells of the buffer.
+    pub fn reset(&mut self) {
+        for cell in self.content.iter_mut() {
+            cell.reset();
+        }
+    }
+
+    /// Returns a reference to the [`Cell`] at the given position or [`None`] if the position is
+    /// outside the area of the buffer.
+    #[must_use]
+    pub fn cell<P: Into<Position>>(&self, position: P) -> Option<&Cell> {
+        let index = self.index_of_opt(position.into())?;
+        self.content.get(index)
+    }
+
+    /// Returns a mutable reference to the [`Cell`] at the given position or [`None`] if the
+    /// position is outside the area of the buffer.
+    #[must_use]
+    pub fn cell_mut<P: Into<Position>>(&mut self, position: P) -> Option<&mut Cell> {
+        let index = self.index_of_opt(position.into())?;
+        self.content.get_mut(index)
+    }
+
+    /// Returns the index in the content for the given global (x, y) coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics when given a coordinate that is outside of the area of the buffer.
+    #[track_caller]
+    #[must_use]
+    pub fn index_of(&self, x: u16, y: u16) -> usize {
+        self.index_of_opt(Position { x, y }).unwrap_or_else(|| {
+            panic!(
+                "index outside of buffer: the area is {area:?} but index is ({x}, {y})",
+                area = self.area,
+            )
+        })
+    }
+
+    /// Returns the index in the content for the given global (x, y) coordinates, or `None` if
+    /// the coordinates are outside of the area of the buffer.
+    const fn index_of_opt(&self, position: Position) -> Option<usize> {
+        let area = self.area;
+        if !area.contains(position) {
+            return None;
+        }
+        // remove offset
+        let y = (position.y - self.area.y) as usize;
+        let x = (position.x - self.area.x) as usize;
+        let width = self.area.width as usize;
+        Some(y * width + x)
+    }
+
+    /// Print a string, starting at the position (x, y)
+    pub fn set_string<T, S>(&mut self, x: u16, y: u16, string: T, style: S)
+    where
+        T: AsRef<str>,
+        S: Into<Style>,
+    {
+        self.set_stringn(x, y, string, usize::MAX, style);
+    }
+
+    /// Print at most the first n characters of a string if enough space is available
+    /// until the end of the line. Skips zero-width graphemes and control characters.
+    ///
+    /// Use [`BufferMut::set_string`] when the maximum amount of characters can be printed.
+    pub fn set_stringn<T, S>(
+        &mut self,
+        mut x: u16,
+        y: u16,
+        string: T,
+        max_width: usize,
+        style: S,
+    ) -> (u16, u16)
+    where
+        T: AsRef<str>,
+        S: Into<Style>,
+    {
+        let max_width = max_width.try_into().unwrap_or(u16::MAX);
+        let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
+        let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
+            .filter(|symbol| !symbol.contains(char::is_control))
+            .map(|symbol| (symbol, symbol.width() as u16))
+            .filter(|(_symbol, width)| *width > 0)
+            .map_while(|(symbol, width)| {
+                remaining_width = remaining_width.checked_sub(width)?;
+                Some((symbol, width))
+            });
+        let style = style.into();
+        for (symbol, width) in graphemes {
+            self[(x, y)].set_symbol(symbol).set_style(style);
+            let next_symbol = x + width;
+            x += 1;
+            // Reset following cells if multi-width (they would be hidden by the grapheme),
+            while x < next_symbol {
+                self[(x, y)].reset();
+                x += 1;
+            }
+        }
+        (x, y)
+    }
+
+    /// Print a line, starting at the position (x, y)
+    pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
+        let mut remaining_width = max_width;
+        let mut x = x;
+        for span in line {
+            if remaining_width == 0 {
+                break;
+            }
+            let pos = self.set_stringn(
+                x,
+                y,
+                span.content.as_ref(),
+                remaining_width as usize,
+                line.style.patch(span.style),
+            );
+            let w = pos.0.saturating_sub(x);
+            x = pos.0;
+            remaining_width = remaining_width.saturating_sub(w);
+        }
+        (x, y)
+    }
+
+    /// Print a span, starting at the position (x, y)
+    pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
+        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+    }
+
+    /// Set the style of all cells in the given area.
+    pub fn set_style<S: Into<Style>>(&mut self, area: Rect, style: S) {
+        let style = style.into();
+        let area = self.area.intersection(area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self[(x, y)].set_style(style);
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a mut Buffer> for BufferMut<'a> {
+    fn from(buffer: &'a mut Buffer) -> Self {
+        buffer.view_mut()
+    }
+}
+
+impl<P: Into<Position>> Index<P> for BufferMut<'_> {
+    type Output = Cell;
+
+    /// Returns a reference to the [`Cell`] at the given position.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the given position is outside the buffer's area. For a method that returns
+    /// `None` instead of panicking, use [`BufferMut::cell`](Self::cell).
+    fn index(&self, position: P) -> &Self::Output {
+        let position = position.into();
+        let index = self.index_of(position.x, position.y);
+        &self.content[index]
+    }
+}
+
+impl<P: Into<Position>> IndexMut<P> for BufferMut<'_> {
+    /// Returns a mutable reference to the [`Cell`] at the given position.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the given position is outside the buffer's area. For a method that returns
+    /// `None` instead of panicking, use [`BufferMut::cell_mut`](Self::cell_mut).
+    fn index_mut(&mut self, position: P) -> &mut Self::Output {
+        let position = position.into();
+        let index = self.index_of(position.x, position.y);
+        &mut self.content[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        style::Stylize,
+        text::Text,
+        widgets::{SliceWidget, Widget},
+    };
+
+    #[test]
+    fn new() {
+        let mut cells = [Cell::EMPTY; 6];
+        let buf = BufferMut::new(Rect::new(1, 2, 3, 2), &mut cells);
+        assert_eq!(buf.area, Rect::new(1, 2, 3, 2));
+        assert_eq!(buf.index_of(2, 3), 4);
+        assert_eq!(buf.cell((0, 0)), None);
+    }
+
+    #[test]
+    #[should_panic = "the number of cells does not match the area"]
+    fn new_panics_on_length_mismatch() {
+        let mut cells = [Cell::EMPTY; 5];
+        let _ = BufferMut::new(Rect::new(0, 0, 3, 2), &mut cells);
+    }
+
+    #[test]
+    fn set_string_matches_buffer() {
+        let area = Rect::new(0, 0, 6, 2);
+        let mut cells = vec![Cell::EMPTY; 6 * 2];
+        let mut buf = BufferMut::new(area, &mut cells);
+        buf.set_string(1, 0, "コンピ", Style::new().red());
+        buf.set_line(0, 1, &Line::from(vec!["ab".blue(), "cdefgh".into()]), 4);
+        buf.set_style(Rect::new(0, 1, 1, 1), Style::new().on_green());
+
+        let mut expected = Buffer::empty(area);
+        expected.set_string(1, 0, "コンピ", Style::new().red());
+        expected.set_line(0, 1, &Line::from(vec!["ab".blue(), "cdefgh".into()]), 4);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().on_green());
+        assert_eq!(cells, expected.content);
+    }
+
+    #[test]
+    fn reset() {
+        let mut cells = vec![Cell::new("x"); 4];
+        BufferMut::new(Rect::new(0, 0, 2, 2), &mut cells).reset();
+        assert_eq!(cells, [Cell::EMPTY; 4]);
+    }
+
+    #[test]
+    fn render_into_preallocated_cells() {
+        let area = Rect::new(0, 0, 8, 2);
+        let text = Text::from(vec![Line::from("Hello").centered(), "wörld!".red().into()]);
+        let mut cells = Vec::with_capacity(area.area() as usize);
+        cells.resize(area.area() as usize, Cell::EMPTY);
+        let (pointer, capacity) = (cells.as_ptr(), cells.capacity());
+
+        for _ in 0..3 {
+            let mut buf = BufferMut::new(area, &mut cells);
+            buf.reset();
+            (&text).render_slice(area, &mut buf);
+        }
+
+        assert_eq!(cells.as_ptr(), pointer);
+        assert_eq!(cells.capacity(), capacity);
+        let mut expected = Buffer::empty(area);
+        text.render(area, &mut expected);
+        assert_eq!(cells, expected.content);
+    }
+}
@@ -0,0 +1,299 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect},
+    style::{Color, Modifier},
+    widgets::{StatefulWidget, Widget},
+};
+
+/// The cell a layer is filled with, which lets the content below show through.
+const TRANSPARENT: Cell = Cell::new("");
+
+/// A buffer that is drawn on top of other buffers, in the order of its z-index.
+///
+/// A layer starts out transparent: the cells that no widget writes a symbol to let the content
+/// below show through, and the cells that only get a style (e.g. a background color) patch that
+/// style onto the content below, which can be used to tint or shade it. The cells that are
+/// cleared, e.g. with the `Clear` widget, are opaque.
+///
+/// Layers are usually grouped in a [`Compositor`], or obtained from [`Frame::layer`], which draws
+/// them on top of the frame before it is sent to the terminal.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::{
+///     buffer::{Buffer, Layer},
+///     layout::Rect,
+///     text::Line,
+/// };
+///
+/// let mut buf = Buffer::with_lines(["background"]);
+/// let mut layer = Layer::new(buf.area, 1);
+/// layer.render_widget(Line::raw("top"), Rect::new(3, 0, 3, 1));
+/// layer.composite(&mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["bactopound"]));
+/// ```
+///
+/// [`Frame::layer`]: crate::terminal::Frame::layer
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Layer {
+    z: i32,
+    buffer: Buffer,
+}
+
+impl Layer {
+    /// Creates a new transparent `Layer` covering the given area, with the given z-index.
+    pub fn new(area: Rect, z: i32) -> Self {
+        Self {
+            z,
+            buffer: Buffer::filled(area, TRANSPARENT),
+        }
+    }
+
+    /// Returns the z-index of the layer.
+    ///
+    /// Layers with a higher z-index are drawn on top of the layers with a lower one.
+    pub const fn z(&self) -> i32 {
+        self.z
+    }
+
+    /// Returns the area covered by the layer.
+    pub const fn area(&self) -> Rect {
+        self.buffer.area
+    }
+
+    /// Returns the buffer of the layer.
+    pub const fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Returns the buffer of the layer, to draw on it directly.
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
+    /// Renders a [`Widget`] on the layer.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, &mut self.buffer);
+    }
+
+    /// Renders a [`StatefulWidget`] on the layer.
+    pub fn render_stateful_widget<W>(&mut self, widget: W, area: Rect, state: &mut W::State)
+    where
+        W: StatefulWidget,
+    {
+        widget.render(area, &mut self.buffer, state);
+    }
+
+    /// Makes the whole layer transparent again, e.g. to reuse it for the next frame.
+    pub fn clear(&mut self) {
+        self.buffer.content.fill(TRANSPARENT);
+    }
+
+    /// Draws the layer on top of the given buffer.
+    ///
+    /// The opaque cells replace the cells of the buffer, and the transparent cells patch their
+    /// style onto them. A wide symbol of the buffer that is partly covered by an opaque cell is
+    /// removed, so that it doesn't spill over the layer.
+    pub fn composite(&self, buf: &mut Buffer) {
+        for position in self.buffer.area.intersection(buf.area).positions() {
+            let cell = &self.buffer[position];
+            if cell.symbol().is_empty() {
+                patch(&mut buf[position], cell);
+                continue;
+            }
+            if position.x > buf.area.left() {
+                let left = &mut buf[Position::new(position.x - 1, position.y)];
+                if left.symbol().width() > 1 {
+                    left.reset();
+                }
+            }
+            buf[position] = cell.clone();
+        }
+    }
+}
+
+/// Patches the colors and modifiers that are set on a transparent cell onto the cell below.
+fn patch(below: &mut Cell, cell: &Cell) {
+    if cell.fg != Color::Reset {
+        below.fg = cell.fg;
+    }
+    if cell.bg != Color::Reset {
+        below.bg = cell.bg;
+    }
+    #[cfg(feature = "underline-color")]
+    if cell.underline_color != Color::Reset {
+        below.underline_color = cell.underline_color;
+    }
+    if cell.modifier != Modifier::empty() {
+        below.modifier.insert(cell.modifier);
+    }
+}
+
+/// A set of [`Layer`]s that are composited in the order of their z-index.
+///
+/// Widgets are rendered on the layer of their z-index regardless of the order of the calls, so
+/// that popups, tooltips and notifications end up on top of the rest of the interface without
+/// ordering the calls carefully or clearing the regions they cover. There is one layer per
+/// z-index, created the first time it is used.
+///
+/// [`Frame::layer`] uses a compositor to draw the layers of a frame on top of it before it is
+/// compared with the previous one.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::{
+///     buffer::{Buffer, Compositor},
+///     layout::Rect,
+///     text::Line,
+/// };
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+/// let mut compositor = Compositor::new(buf.area);
+/// compositor.render_widget(2, Line::raw("popup"), Rect::new(1, 0, 5, 1));
+/// compositor.render_widget(1, Line::raw("content"), buf.area);
+/// compositor.composite(&mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["cpopupt"]));
+/// ```
+///
+/// [`Frame::layer`]: crate::terminal::Frame::layer
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Compositor {
+    area: Rect,
+    /// The layers, sorted by z-index
+    layers: Vec<Layer>,
+}
+
+impl Compositor {
+    /// Creates a new `Compositor` without layers, whose layers cover the given area.
+    pub const fn new(area: Rect) -> Self {
+        Self {
+            area,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Returns the area covered by the layers.
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the layers, sorted by z-index.
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// Returns the layer with the given z-index, creating it if needed.
+    pub fn layer(&mut self, z: i32) -> &mut Layer {
+        let index = self.layers.partition_point(|layer| layer.z <= z);
+        if index == 0 || self.layers[index - 1].z != z {
+            self.layers.insert(index, Layer::new(self.area, z));
+            return &mut self.layers[index];
+        }
+        &mut self.layers[index - 1]
+    }
+
+    /// Renders a [`Widget`] on the layer with the given z-index.
+    pub fn render_widget<W: Widget>(&mut self, z: i32, widget: W, area: Rect) {
+        self.layer(z).render_widget(widget, area);
+    }
+
+    /// Renders a [`StatefulWidget`] on the layer with the given z-index.
+    pub fn render_stateful_widget<W>(&mut self, z: i32, widget: W, area: Rect, state: &mut W::State)
+    where
+        W: StatefulWidget,
+    {
+        self.layer(z).render_stateful_widget(widget, area, state);
+    }
+
+    /// Draws the layers on top of the given buffer, from the lowest z-index to the highest.
+    pub fn composite(&self, buf: &mut Buffer) {
+        for layer in &self.layers {
+            layer.composite(buf);
+        }
+    }
+
+    /// Removes all the layers.
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        style::{Style, Stylize},
+        text::Line,
+    };
+
+    #[test]
+    fn transparent() {
+        let mut buf = Buffer::with_lines(["abcd"]);
+        let mut layer = Layer::new(buf.area, 0);
+        layer.render_widget(Line::raw("x"), Rect::new(1, 0, 1, 1));
+        layer
+            .buffer_mut()
+            .set_style(Rect::new(2, 0, 2, 1), Style::new().on_blue().bold());
+        layer.buffer_mut()[(3, 0)].reset();
+        layer.composite(&mut buf);
+        let mut expected = Buffer::with_lines(["axc "]);
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().on_blue().bold());
+        assert_eq!(buf, expected);
+
+        layer.clear();
+        let mut cleared = Buffer::with_lines(["abcd"]);
+        layer.composite(&mut cleared);
+        assert_eq!(cleared, Buffer::with_lines(["abcd"]));
+    }
+
+    #[test]
+    fn z_order() {
+        let area = Rect::new(0, 0, 3, 1);
+        let mut compositor = Compositor::new(area);
+        compositor.render_widget(5, Line::raw("c"), Rect::new(2, 0, 1, 1));
+        compositor.render_widget(-1, Line::raw("aaa"), area);
+        compositor.render_widget(2, Line::raw("bb"), Rect::new(1, 0, 2, 1));
+        compositor.render_widget(2, Line::raw("x"), Rect::new(1, 0, 1, 1));
+        let z: Vec<i32> = compositor.layers().iter().map(Layer::z).collect();
+        assert_eq!(z, [-1, 2, 5]);
+        let mut buf = Buffer::empty(area);
+        compositor.composite(&mut buf);
+        assert_eq!(buf, Buffer::with_lines(["axc"]));
+
+        compositor.clear();
+        assert!(compositor.layers().is_empty());
+    }
+
+    #[test]
+    fn wide_symbol_below() {
+        let mut buf = Buffer::with_lines(["あい"]);
+        let mut layer = Layer::new(buf.area, 0);
+        layer.render_widget(Line::raw("x"), Rect::new(1, 0, 1, 1));
+        layer.composite(&mut buf);
+        assert_eq!(buf, Buffer::with_lines([" xい"]));
+    }
+
+    #[test]
+    fn stateful_widget() {
+        struct Counter;
+        impl StatefulWidget for Counter {
+            type State = u8;
+            fn render(self, area: Rect, buf: &mut Buffer, state: &mut u8) {
+                *state += 1;
+                buf.set_string(area.x, area.y, state.to_string(), Style::new());
+            }
+        }
+        let area = Rect::new(0, 0, 2, 1);
+        let mut compositor = Compositor::new(area);
+        let mut count = 0;
+        compositor.render_stateful_widget(1, Counter, area, &mut count);
+        let mut buf = Buffer::with_lines(["ab"]);
+        compositor.composite(&mut buf);
+        assert_eq!(count, 1);
+        assert_eq!(buf, Buffer::with_lines(["1b"]));
+    }
+}
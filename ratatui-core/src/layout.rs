@@ -5,18 +5,36 @@ mod alignment;
 mod constraint;
 mod direction;
 mod flex;
+mod grid;
 mod layout;
+mod layout_tree;
+mod layout_watcher;
 mod margin;
+mod named_areas;
+mod overflow;
+mod padding;
+mod pane_ratios;
 mod position;
 mod rect;
+mod rounding;
 mod size;
+mod virtual_rect;
 
-pub use alignment::Alignment;
-pub use constraint::Constraint;
+pub use alignment::{Alignment, VerticalAlignment};
+pub use constraint::{Constraint, ParseConstraintError};
 pub use direction::Direction;
 pub use flex::Flex;
-pub use layout::{Layout, Spacing};
+pub use grid::{Grid, GridAreas};
+pub use layout::{solve, Layout, LayoutCacheStats, LayoutEngine, LayoutSolver, Spacing};
+pub use layout_tree::LayoutTree;
+pub use layout_watcher::{LayoutChanges, LayoutWatcher};
 pub use margin::Margin;
+pub use named_areas::NamedAreas;
+pub use overflow::LayoutOverflow;
+pub use padding::Padding;
+pub use pane_ratios::PaneRatios;
 pub use position::Position;
 pub use rect::{Columns, Offset, Positions, Rect, Rows};
+pub use rounding::Rounding;
 pub use size::Size;
+pub use virtual_rect::{VirtualPosition, VirtualRect};
@@ -1,6 +1,7 @@
 use strum::{Display, EnumString};
 
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alignment {
     #[default]
     Left,
@@ -8,6 +9,16 @@ pub enum Alignment {
     Right,
 }
 
+/// The vertical alignment of content within an area.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
 #[cfg(test)]
 mod tests {
     use strum::ParseError;
@@ -28,4 +39,31 @@ mod tests {
         assert_eq!("Right".parse::<Alignment>(), Ok(Alignment::Right));
         assert_eq!("".parse::<Alignment>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn vertical_alignment_to_string() {
+        assert_eq!(VerticalAlignment::Top.to_string(), "Top");
+        assert_eq!(VerticalAlignment::Center.to_string(), "Center");
+        assert_eq!(VerticalAlignment::Bottom.to_string(), "Bottom");
+    }
+
+    #[test]
+    fn vertical_alignment_from_str() {
+        assert_eq!(
+            "Top".parse::<VerticalAlignment>(),
+            Ok(VerticalAlignment::Top)
+        );
+        assert_eq!(
+            "Center".parse::<VerticalAlignment>(),
+            Ok(VerticalAlignment::Center)
+        );
+        assert_eq!(
+            "Bottom".parse::<VerticalAlignment>(),
+            Ok(VerticalAlignment::Bottom)
+        );
+        assert_eq!(
+            "".parse::<VerticalAlignment>(),
+            Err(ParseError::VariantNotFound)
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use strum::EnumIs;
 
@@ -16,8 +16,8 @@ use strum::EnumIs;
 ///
 /// 1. [`Constraint::Min`]
 /// 2. [`Constraint::Max`]
-/// 3. [`Constraint::Length`]
-/// 4. [`Constraint::Percentage`]
+/// 3. [`Constraint::Length`] and [`Constraint::Fit`]
+/// 4. [`Constraint::Percentage`] and [`Constraint::Permille`]
 /// 5. [`Constraint::Ratio`]
 /// 6. [`Constraint::Fill`]
 ///
@@ -34,6 +34,7 @@ use strum::EnumIs;
 /// // Create a centered layout using ratio or percentage constraints
 /// let constraints = Constraint::from_ratios([(1, 4), (1, 2), (1, 4)]);
 /// let constraints = Constraint::from_percentages([25, 50, 25]);
+/// let constraints = Constraint::from_permilles([333, 333, 334]);
 ///
 /// // Create a centered layout with a minimum size constraint for specific elements
 /// let constraints = Constraint::from_mins([0, 100, 0]);
@@ -45,6 +46,7 @@ use strum::EnumIs;
 /// let constraints = Constraint::from_fills([1, 2, 1]);
 /// ```
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIs)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Constraint {
     /// Applies a minimum size constraint to the element
     ///
@@ -115,6 +117,48 @@ pub enum Constraint {
     /// ```
     Length(u16),
 
+    /// Sizes the element to its content
+    ///
+    /// The value is the size of the content, usually obtained from [`Widget::size_hint`]. The
+    /// element is set to that size like a [`Constraint::Length`], but never grows beyond it, even
+    /// when there is excess space that would otherwise be given to it, and it shrinks when there
+    /// is not enough space.
+    ///
+    /// # Examples
+    ///
+    /// `[Fit(10), Fill(1)]` in an area of 50 px
+    ///
+    /// ```plain
+    /// ┌────────┐┌──────────────────────────────────────┐
+    /// │  10 px ││                 40 px                │
+    /// └────────┘└──────────────────────────────────────┘
+    /// ```
+    ///
+    /// `[Fit(60), Length(20)]` in an area of 50 px
+    ///
+    /// ```plain
+    /// ┌────────────────────────────┐┌──────────────────┐
+    /// │            30 px           ││       20 px      │
+    /// └────────────────────────────┘└──────────────────┘
+    /// ```
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     layout::{Constraint::*, Layout, Rect},
+    ///     text::Text,
+    ///     widgets::Widget,
+    /// };
+    ///
+    /// let area = Rect::new(0, 0, 20, 10);
+    /// let header = Text::from("Title\nSubtitle");
+    /// let (_, height) = header.size_hint(area);
+    /// let [header_area, body_area] = Layout::vertical([Fit(height), Fill(1)]).areas(area);
+    /// assert_eq!(header_area, Rect::new(0, 0, 20, 2));
+    /// ```
+    ///
+    /// [`Widget::size_hint`]: crate::widgets::Widget::size_hint
+    Fit(u16),
+
     /// Applies a percentage of the available space to the element
     ///
     /// Converts the given percentage to a floating-point value and multiplies that with area. This
@@ -122,7 +166,7 @@ pub enum Constraint {
     ///
     /// **Note**: As this value only accepts a `u16`, certain percentages that cannot be
     /// represented exactly (e.g. 1/3) are not possible. You might want to use
-    /// [`Constraint::Ratio`] or [`Constraint::Fill`] in such cases.
+    /// [`Constraint::Permille`], [`Constraint::Ratio`] or [`Constraint::Fill`] in such cases.
     ///
     /// # Examples
     ///
@@ -143,6 +187,23 @@ pub enum Constraint {
     /// ```
     Percentage(u16),
 
+    /// Applies a permille (a tenth of a percent) of the available space to the element
+    ///
+    /// This behaves like [`Constraint::Percentage`] with ten times the precision, which avoids
+    /// accumulating a visible bias towards one segment for fine-grained splits of large areas
+    /// (e.g. 33.3% / 33.3% / 33.4% is `[Permille(333), Permille(333), Permille(334)]`).
+    ///
+    /// # Examples
+    ///
+    /// `[Permille(333), Permille(333), Permille(334)]` in an area of 300 px
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌──────────────────┐┌──────────────────┐
+    /// │      100 px      ││      100 px      ││      100 px      │
+    /// └──────────────────┘└──────────────────┘└──────────────────┘
+    /// ```
+    Permille(u16),
+
     /// Applies a ratio of the available space to the element
     ///
     /// Converts the given ratio to a floating-point value and multiplies that with area.
@@ -206,6 +267,11 @@ impl Constraint {
                 let length = f32::from(length);
                 (p * length).min(length) as u16
             }
+            Self::Permille(p) => {
+                let p = f32::from(p) / 1000.0;
+                let length = f32::from(length);
+                (p * length).min(length) as u16
+            }
             Self::Ratio(numerator, denominator) => {
                 // avoid division by zero by using 1 when denominator is 0
                 // this results in 0/0 -> 0 and x/0 -> x for x != 0
@@ -213,7 +279,7 @@ impl Constraint {
                 let length = f32::from(length);
                 (percentage * length).min(length) as u16
             }
-            Self::Length(l) | Self::Fill(l) => length.min(l),
+            Self::Length(l) | Self::Fit(l) | Self::Fill(l) => length.min(l),
             Self::Max(m) => length.min(m),
             Self::Min(m) => length.max(m),
         }
@@ -273,6 +339,42 @@ impl Constraint {
         percentages.into_iter().map(Self::Percentage).collect()
     }
 
+    /// Convert an iterator of permilles into a vector of constraints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// # let area = Rect::default();
+    /// let constraints = Constraint::from_permilles([333, 333, 334]);
+    /// let layout = Layout::default().constraints(constraints).split(area);
+    /// ```
+    pub fn from_permilles<T>(permilles: T) -> Vec<Self>
+    where
+        T: IntoIterator<Item = u16>,
+    {
+        permilles.into_iter().map(Self::Permille).collect()
+    }
+
+    /// Convert an iterator of content sizes into a vector of constraints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// # let area = Rect::default();
+    /// let constraints = Constraint::from_fits([1, 2, 3]);
+    /// let layout = Layout::default().constraints(constraints).split(area);
+    /// ```
+    pub fn from_fits<T>(sizes: T) -> Vec<Self>
+    where
+        T: IntoIterator<Item = u16>,
+    {
+        sizes.into_iter().map(Self::Fit).collect()
+    }
+
     /// Convert an iterator of maxes into a vector of constraints
     ///
     /// # Examples
@@ -371,8 +473,10 @@ impl fmt::Display for Constraint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Percentage(p) => write!(f, "Percentage({p})"),
+            Self::Permille(p) => write!(f, "Permille({p})"),
             Self::Ratio(n, d) => write!(f, "Ratio({n}, {d})"),
             Self::Length(l) => write!(f, "Length({l})"),
+            Self::Fit(l) => write!(f, "Fit({l})"),
             Self::Fill(l) => write!(f, "Fill({l})"),
             Self::Max(m) => write!(f, "Max({m})"),
             Self::Min(m) => write!(f, "Min({m})"),
@@ -380,8 +484,134 @@ impl fmt::Display for Constraint {
     }
 }
 
+/// Error type indicating a failure to parse a [`Constraint`] string.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParseConstraintError;
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse Constraint")
+    }
+}
+
+impl std::error::Error for ParseConstraintError {}
+
+/// Converts a string representation to a [`Constraint`], e.g. to read layouts from a
+/// configuration file.
+///
+/// The following short forms are supported, surrounding whitespace being ignored:
+///
+/// | String           | Constraint                    |
+/// |------------------|-------------------------------|
+/// | `"10"`           | [`Constraint::Length(10)`]    |
+/// | `"50%"`          | [`Constraint::Percentage(50)`]|
+/// | `"1/3"`          | [`Constraint::Ratio(1, 3)`]   |
+/// | `">=5"`          | [`Constraint::Min(5)`]        |
+/// | `"<=20"`         | [`Constraint::Max(20)`]       |
+/// | `"fill:2"`       | [`Constraint::Fill(2)`]       |
+/// | `"fit:10"`       | [`Constraint::Fit(10)`]       |
+/// | `"permille:333"` | [`Constraint::Permille(333)`] |
+///
+/// The format of the [`Display`](fmt::Display) implementation (e.g. `"Length(10)"`) is also
+/// accepted, so that constraints round-trip through strings.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::layout::Constraint;
+///
+/// let constraints: Vec<Constraint> = ["3", "fill:1", "30%"]
+///     .iter()
+///     .map(|s| s.parse().unwrap())
+///     .collect();
+/// assert_eq!(
+///     constraints,
+///     [
+///         Constraint::Length(3),
+///         Constraint::Fill(1),
+///         Constraint::Percentage(30)
+///     ]
+/// );
+///
+/// let constraint = Constraint::Ratio(1, 3);
+/// assert_eq!(constraint.to_string().parse(), Ok(constraint));
+/// assert!("1/".parse::<Constraint>().is_err());
+/// ```
+///
+/// [`Constraint::Length(10)`]: Constraint::Length
+/// [`Constraint::Percentage(50)`]: Constraint::Percentage
+/// [`Constraint::Ratio(1, 3)`]: Constraint::Ratio
+/// [`Constraint::Min(5)`]: Constraint::Min
+/// [`Constraint::Max(20)`]: Constraint::Max
+/// [`Constraint::Fill(2)`]: Constraint::Fill
+/// [`Constraint::Fit(10)`]: Constraint::Fit
+/// [`Constraint::Permille(333)`]: Constraint::Permille
+impl FromStr for Constraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(args) = s.strip_suffix(')') {
+            return parse_named(args);
+        }
+        if let Some(percentage) = s.strip_suffix('%') {
+            Ok(Self::Percentage(parse_number(percentage)?))
+        } else if let Some((numerator, denominator)) = s.split_once('/') {
+            Ok(Self::Ratio(
+                parse_number(numerator)?,
+                parse_number(denominator)?,
+            ))
+        } else if let Some(min) = s.strip_prefix(">=") {
+            Ok(Self::Min(parse_number(min)?))
+        } else if let Some(max) = s.strip_prefix("<=") {
+            Ok(Self::Max(parse_number(max)?))
+        } else if let Some((kind, size)) = s.split_once(':') {
+            let size = parse_number(size)?;
+            match kind.trim().to_lowercase().as_str() {
+                "fill" => Ok(Self::Fill(size)),
+                "fit" => Ok(Self::Fit(size)),
+                "permille" => Ok(Self::Permille(size)),
+                _ => Err(ParseConstraintError),
+            }
+        } else {
+            Ok(Self::Length(parse_number(s)?))
+        }
+    }
+}
+
+/// Parses a number of a constraint string, ignoring surrounding whitespace.
+fn parse_number<T: FromStr>(s: &str) -> Result<T, ParseConstraintError> {
+    s.trim().parse().ok().ok_or(ParseConstraintError)
+}
+
+/// Parses the `Name(args` form of a constraint, as written by its `Display` implementation,
+/// without the closing parenthesis.
+fn parse_named(s: &str) -> Result<Constraint, ParseConstraintError> {
+    let (name, args) = s.split_once('(').ok_or(ParseConstraintError)?;
+    if name.trim().eq_ignore_ascii_case("ratio") {
+        let (numerator, denominator) = args.split_once(',').ok_or(ParseConstraintError)?;
+        return Ok(Constraint::Ratio(
+            parse_number(numerator)?,
+            parse_number(denominator)?,
+        ));
+    }
+    let value = parse_number(args)?;
+    match name.trim().to_lowercase().as_str() {
+        "percentage" => Ok(Constraint::Percentage(value)),
+        "permille" => Ok(Constraint::Permille(value)),
+        "length" => Ok(Constraint::Length(value)),
+        "fit" => Ok(Constraint::Fit(value)),
+        "fill" => Ok(Constraint::Fill(value)),
+        "max" => Ok(Constraint::Max(value)),
+        "min" => Ok(Constraint::Min(value)),
+        _ => Err(ParseConstraintError),
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -392,12 +622,58 @@ mod tests {
     #[test]
     fn to_string() {
         assert_eq!(Constraint::Percentage(50).to_string(), "Percentage(50)");
+        assert_eq!(Constraint::Permille(333).to_string(), "Permille(333)");
         assert_eq!(Constraint::Ratio(1, 2).to_string(), "Ratio(1, 2)");
         assert_eq!(Constraint::Length(10).to_string(), "Length(10)");
+        assert_eq!(Constraint::Fit(10).to_string(), "Fit(10)");
         assert_eq!(Constraint::Max(10).to_string(), "Max(10)");
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
     }
 
+    #[rstest]
+    #[case("10", Constraint::Length(10))]
+    #[case(" 50% ", Constraint::Percentage(50))]
+    #[case("1/3", Constraint::Ratio(1, 3))]
+    #[case(">=5", Constraint::Min(5))]
+    #[case("<= 20", Constraint::Max(20))]
+    #[case("fill:2", Constraint::Fill(2))]
+    #[case("fit:10", Constraint::Fit(10))]
+    #[case("Permille:333", Constraint::Permille(333))]
+    #[case("Ratio(1, 2)", Constraint::Ratio(1, 2))]
+    #[case("length(4)", Constraint::Length(4))]
+    fn from_str(#[case] input: &str, #[case] expected: Constraint) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("abc")]
+    #[case("-1")]
+    #[case("1/")]
+    #[case("grow:2")]
+    #[case("Length(1, 2)")]
+    #[case("Size(3)")]
+    fn from_str_error(#[case] input: &str) {
+        assert_eq!(input.parse::<Constraint>(), Err(ParseConstraintError));
+    }
+
+    #[test]
+    fn round_trip() {
+        let constraints = [
+            Constraint::Percentage(50),
+            Constraint::Permille(333),
+            Constraint::Ratio(1, 2),
+            Constraint::Length(10),
+            Constraint::Fit(10),
+            Constraint::Fill(2),
+            Constraint::Max(10),
+            Constraint::Min(10),
+        ];
+        for constraint in constraints {
+            assert_eq!(constraint.to_string().parse(), Ok(constraint));
+        }
+    }
+
     #[test]
     fn from_lengths() {
         let expected = [
@@ -434,6 +710,24 @@ mod tests {
         assert_eq!(Constraint::from_percentages(vec![25, 50, 25]), expected);
     }
 
+    #[test]
+    fn from_permilles() {
+        let expected = [
+            Constraint::Permille(333),
+            Constraint::Permille(333),
+            Constraint::Permille(334),
+        ];
+        assert_eq!(Constraint::from_permilles([333, 333, 334]), expected);
+        assert_eq!(Constraint::from_permilles(vec![333, 333, 334]), expected);
+    }
+
+    #[test]
+    fn from_fits() {
+        let expected = [Constraint::Fit(1), Constraint::Fit(2), Constraint::Fit(3)];
+        assert_eq!(Constraint::from_fits([1, 2, 3]), expected);
+        assert_eq!(Constraint::from_fits(vec![1, 2, 3]), expected);
+    }
+
     #[test]
     fn from_maxes() {
         let expected = [Constraint::Max(1), Constraint::Max(2), Constraint::Max(3)];
@@ -468,6 +762,10 @@ mod tests {
         assert_eq!(Constraint::Percentage(200).apply(100), 100);
         assert_eq!(Constraint::Percentage(u16::MAX).apply(100), 100);
 
+        assert_eq!(Constraint::Permille(0).apply(1000), 0);
+        assert_eq!(Constraint::Permille(333).apply(1000), 333);
+        assert_eq!(Constraint::Permille(2000).apply(1000), 1000);
+
         // 0/0 intentionally avoids a panic by returning 0.
         assert_eq!(Constraint::Ratio(0, 0).apply(100), 0);
         // 1/0 intentionally avoids a panic by returning 100% of the length.
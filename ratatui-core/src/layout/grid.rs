@@ -0,0 +1,247 @@
+use std::ops::Index;
+
+use crate::layout::{Constraint, Flex, Layout, Margin, Rect, Spacing};
+
+/// A two-dimensional layout that splits an area into a matrix of cells.
+///
+/// The heights of the rows and the widths of the columns are each solved once, with the same
+/// rules as a [`Layout`], and every cell is the intersection of a row and a column. This is both
+/// cheaper than nesting a horizontal layout inside each row of a vertical one, and guarantees that
+/// the columns line up and that spacing and [`Flex`] behave the same way on both axes.
+///
+/// Create a grid with [`Layout::grid`] or [`Grid::new`], and split an area with [`Grid::split`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+///
+/// let grid = Layout::grid([Length(1), Fill(1)], [Length(4), Fill(1), Fill(1)]).spacing(1);
+/// let cells = grid.split(Rect::new(0, 0, 16, 5));
+/// assert_eq!(cells[(0, 0)], Rect::new(0, 0, 4, 1));
+/// assert_eq!(cells[(1, 2)], Rect::new(11, 2, 5, 3));
+/// for row in cells.rows() {
+///     assert_eq!(row.len(), 3);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid {
+    rows: Layout,
+    columns: Layout,
+    margin: Margin,
+}
+
+impl Grid {
+    /// Creates a grid with the given row and column constraints.
+    ///
+    /// The row constraints set the heights of the rows, and the column constraints set the widths
+    /// of the columns.
+    pub fn new<R, C>(rows: R, columns: C) -> Self
+    where
+        R: IntoIterator,
+        R::Item: Into<Constraint>,
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+    {
+        Self {
+            rows: Layout::vertical(rows),
+            columns: Layout::horizontal(columns),
+            margin: Margin::new(0, 0),
+        }
+    }
+
+    /// Sets the spacing between both the rows and the columns.
+    ///
+    /// See [`Layout::spacing`] for the meaning of negative spacing.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacing<T>(self, spacing: T) -> Self
+    where
+        T: Into<Spacing>,
+    {
+        let spacing = spacing.into();
+        self.row_spacing(spacing.clone()).column_spacing(spacing)
+    }
+
+    /// Sets the spacing between the rows.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_spacing<T>(mut self, spacing: T) -> Self
+    where
+        T: Into<Spacing>,
+    {
+        self.rows = self.rows.spacing(spacing);
+        self
+    }
+
+    /// Sets the spacing between the columns.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_spacing<T>(mut self, spacing: T) -> Self
+    where
+        T: Into<Spacing>,
+    {
+        self.columns = self.columns.spacing(spacing);
+        self
+    }
+
+    /// Sets how the excess space is distributed, on both axes.
+    ///
+    /// See [`Layout::flex`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn flex(mut self, flex: Flex) -> Self {
+        self.rows = self.rows.flex(flex);
+        self.columns = self.columns.flex(flex);
+        self
+    }
+
+    /// Sets the margin around the grid, on all sides.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn margin(mut self, margin: u16) -> Self {
+        self.margin = Margin::new(margin, margin);
+        self
+    }
+
+    /// Splits the area into cells.
+    ///
+    /// The returned [`GridAreas`] can be indexed by `(row, column)`.
+    pub fn split(&self, area: Rect) -> GridAreas {
+        let area = area.inner(self.margin);
+        let rows = self.rows.split(area);
+        let columns = self.columns.split(area);
+        let cells = rows
+            .iter()
+            .flat_map(|row| {
+                columns
+                    .iter()
+                    .map(|column| Rect::new(column.x, row.y, column.width, row.height))
+            })
+            .collect();
+        GridAreas {
+            cells,
+            columns: columns.len(),
+        }
+    }
+}
+
+/// The cells of a [`Grid`] split, indexable by `(row, column)`.
+///
+/// Indexing with a row or column that does not exist panics, use [`GridAreas::get`] to handle
+/// missing cells.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridAreas {
+    cells: Vec<Rect>,
+    columns: usize,
+}
+
+impl GridAreas {
+    /// Returns the cell at the given row and column, or `None` if there is no such cell.
+    pub fn get(&self, row: usize, column: usize) -> Option<Rect> {
+        if column >= self.columns {
+            return None;
+        }
+        self.cells.get(row * self.columns + column).copied()
+    }
+
+    /// Returns the number of rows.
+    pub fn row_count(&self) -> usize {
+        self.cells.len().checked_div(self.columns).unwrap_or(0)
+    }
+
+    /// Returns the number of columns.
+    pub const fn column_count(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the cells of the given row, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row does not exist.
+    pub fn row(&self, row: usize) -> &[Rect] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// Returns an iterator over the rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[Rect]> {
+        self.cells.chunks(self.columns.max(1))
+    }
+
+    /// Returns an iterator over all the cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Rect> + '_ {
+        self.cells.iter().copied()
+    }
+}
+
+impl Index<(usize, usize)> for GridAreas {
+    type Output = Rect;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(
+            column < self.columns,
+            "column {column} out of range for a grid of {} columns",
+            self.columns
+        );
+        &self.cells[row * self.columns + column]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Constraint::{Fill, Length, Percentage};
+
+    #[test]
+    fn split() {
+        let grid = Grid::new([Length(2), Fill(1)], [Percentage(50), Fill(1)]);
+        let cells = grid.split(Rect::new(1, 1, 10, 6));
+        assert_eq!(cells.row_count(), 2);
+        assert_eq!(cells.column_count(), 2);
+        assert_eq!(
+            cells.iter().collect::<Vec<_>>(),
+            [
+                Rect::new(1, 1, 5, 2),
+                Rect::new(6, 1, 5, 2),
+                Rect::new(1, 3, 5, 4),
+                Rect::new(6, 3, 5, 4),
+            ]
+        );
+        assert_eq!(cells.row(1), [Rect::new(1, 3, 5, 4), Rect::new(6, 3, 5, 4)]);
+        assert_eq!(cells.get(1, 1), Some(Rect::new(6, 3, 5, 4)));
+        assert_eq!(cells.get(0, 2), None);
+        assert_eq!(cells.get(2, 0), None);
+    }
+
+    #[test]
+    fn spacing_and_margin() {
+        let cells = Layout::grid([Fill(1), Fill(1)], [Fill(1), Fill(1)])
+            .row_spacing(1)
+            .column_spacing(2)
+            .margin(1)
+            .split(Rect::new(0, 0, 12, 7));
+        assert_eq!(cells[(0, 0)], Rect::new(1, 1, 4, 2));
+        assert_eq!(cells[(1, 1)], Rect::new(7, 4, 4, 2));
+    }
+
+    #[test]
+    fn flex() {
+        let cells = Layout::grid([Length(1)], [Length(2)])
+            .flex(Flex::Center)
+            .split(Rect::new(0, 0, 4, 3));
+        assert_eq!(cells[(0, 0)], Rect::new(1, 1, 2, 1));
+    }
+
+    #[test]
+    fn empty() {
+        let cells = Layout::grid([Fill(1)], [] as [Constraint; 0]).split(Rect::new(0, 0, 4, 4));
+        assert_eq!(cells.row_count(), 0);
+        assert_eq!(cells.rows().count(), 0);
+        assert_eq!(cells.get(0, 0), None);
+    }
+
+    #[test]
+    #[should_panic = "column 2 out of range for a grid of 2 columns"]
+    fn index_out_of_range() {
+        let cells = Layout::grid([Fill(1)], [Fill(1), Fill(1)]).split(Rect::new(0, 0, 4, 4));
+        let _ = cells[(0, 2)];
+    }
+}
@@ -1,4 +1,10 @@
-use std::{cell::RefCell, collections::HashMap, iter, num::NonZeroUsize, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{BinaryHeap, HashMap},
+    iter,
+    num::NonZeroUsize,
+    rc::Rc,
+};
 
 use cassowary::{
     strength::REQUIRED,
@@ -12,7 +18,12 @@ use self::strengths::{
     ALL_SEGMENT_GROW, FILL_GROW, GROW, LENGTH_SIZE_EQ, MAX_SIZE_EQ, MAX_SIZE_LE, MIN_SIZE_EQ,
     MIN_SIZE_GE, PERCENTAGE_SIZE_EQ, RATIO_SIZE_EQ, SPACER_SIZE_EQ, SPACE_GROW,
 };
-use crate::layout::{Constraint, Direction, Flex, Margin, Rect};
+pub(super) use self::tree::solve_tree;
+pub use self::{engine::LayoutEngine, solver::LayoutSolver};
+use crate::layout::{
+    Constraint, Direction, Flex, Grid, LayoutOverflow, LayoutTree, Margin, NamedAreas, Rect,
+    Rounding,
+};
 
 type Rects = Rc<[Rect]>;
 type Segments = Rects;
@@ -35,12 +46,124 @@ type Cache = LruCache<(Rect, Layout), (Segments, Spacers)>;
 // calculations.
 const FLOAT_PRECISION_MULTIPLIER: f64 = 100.0;
 
+mod engine;
+mod solver;
+mod tree;
+
 thread_local! {
-    static LAYOUT_CACHE: RefCell<Cache> = RefCell::new(Cache::new(
+    static LAYOUT_CACHE: RefCell<LayoutCache> = RefCell::new(LayoutCache::new(
         NonZeroUsize::new(Layout::DEFAULT_CACHE_SIZE).unwrap(),
     ));
 }
 
+/// The thread-local layout cache together with the counters reported by [`Layout::cache_stats`].
+#[derive(Debug)]
+struct LayoutCache {
+    entries: Cache,
+    enabled: bool,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl LayoutCache {
+    fn new(cap: NonZeroUsize) -> Self {
+        Self {
+            entries: Cache::new(cap),
+            enabled: true,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    fn resize(&mut self, cap: NonZeroUsize) {
+        let dropped = self.entries.len().saturating_sub(cap.get());
+        self.evictions += dropped as u64;
+        self.entries.resize(cap);
+    }
+
+    fn get_or_insert(
+        &mut self,
+        key: (Rect, Layout),
+        f: impl FnOnce() -> (Segments, Spacers),
+    ) -> (Segments, Spacers) {
+        if !self.enabled {
+            return f();
+        }
+        if let Some(value) = self.entries.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = f();
+        // the key is known to be absent, so any returned entry is an evicted one
+        if self.entries.push(key, value.clone()).is_some() {
+            self.evictions += 1;
+        }
+        value
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+        self.evictions = 0;
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.entries.clear();
+        }
+    }
+
+    fn stats(&self) -> LayoutCacheStats {
+        LayoutCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            len: self.entries.len(),
+            capacity: self.entries.cap().get(),
+        }
+    }
+}
+
+/// Statistics about the layout cache of the current thread, as returned by
+/// [`Layout::cache_stats`].
+///
+/// The counters accumulate from the start of the thread, or from the last call to
+/// [`Layout::clear_cache`]. They can be used to tune the size passed to [`Layout::init_cache`]:
+/// a high number of evictions compared to hits means that the cache is too small for the number
+/// of distinct layouts and areas used each frame.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint, Layout, Rect};
+///
+/// Layout::clear_cache();
+/// let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+/// layout.split(Rect::new(0, 0, 10, 10));
+/// layout.split(Rect::new(0, 0, 10, 10));
+///
+/// let stats = Layout::cache_stats();
+/// assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LayoutCacheStats {
+    /// The number of splits that were answered from the cache.
+    pub hits: u64,
+    /// The number of splits that had to be solved because they were not in the cache.
+    pub misses: u64,
+    /// The number of entries dropped to make room for new ones or when the cache was shrunk.
+    pub evictions: u64,
+    /// The number of entries currently in the cache.
+    pub len: usize,
+    /// The maximum number of entries the cache can hold.
+    pub capacity: usize,
+}
+
 /// Represents the spacing between segments in a layout.
 ///
 /// The `Spacing` enum is used to define the spacing between segments in a layout. It can represent
@@ -70,6 +193,7 @@ thread_local! {
 ///
 /// See the [`Layout::spacing`] method for details on how to use this enum.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Spacing {
     Space(u16),
     Overlap(u16),
@@ -142,8 +266,10 @@ impl From<i16> for Spacing {
 /// - [`Layout::margin`]: set the margin of the layout
 /// - [`Layout::horizontal_margin`]: set the horizontal margin of the layout
 /// - [`Layout::vertical_margin`]: set the vertical margin of the layout
+/// - [`Layout::margin_percent`]: set the margin of the layout as a percentage of the area
 /// - [`Layout::flex`]: set the way the space is distributed when the constraints are satisfied
 /// - [`Layout::spacing`]: sets the gap between the constraints of the layout
+/// - [`Layout::reversed`]: lays out the constraints from right to left or from bottom to top
 ///
 /// # Example
 ///
@@ -172,12 +298,18 @@ impl From<i16> for Spacing {
 /// [`cassowary`]: https://crates.io/crates/cassowary
 /// [Examples]: https://github.com/ratatui/ratatui/blob/main/examples/README.md
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Layout {
     direction: Direction,
     constraints: Vec<Constraint>,
     margin: Margin,
+    margin_percent: Option<u16>,
     flex: Flex,
     spacing: Spacing,
+    outer_spacing: u16,
+    rounding: Rounding,
+    reversed: bool,
+    names: Vec<String>,
 }
 
 impl Layout {
@@ -200,6 +332,9 @@ impl Layout {
     /// - `margin`: 0, 0
     /// - `flex`: [`Flex::Start`]
     /// - `spacing`: 0
+    /// - `outer_spacing`: 0
+    /// - `rounding`: [`Rounding::Round`]
+    /// - `reversed`: false
     ///
     /// # Examples
     ///
@@ -273,15 +408,96 @@ impl Layout {
         )
     }
 
+    /// Creates a new [`Grid`] that splits an area into rows and columns.
+    ///
+    /// The `rows` constraints set the heights of the rows, and the `columns` constraints set the
+    /// widths of the columns. Each axis is solved once, so this is cheaper and more consistent than
+    /// nesting a horizontal layout inside each area of a vertical one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let cells = Layout::grid([Fill(1); 3], [Fill(1); 3]).split(Rect::new(0, 0, 9, 9));
+    /// assert_eq!(cells[(1, 2)], Rect::new(6, 3, 3, 3));
+    /// ```
+    pub fn grid<R, C>(rows: R, columns: C) -> Grid
+    where
+        R: IntoIterator,
+        R::Item: Into<Constraint>,
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+    {
+        Grid::new(rows, columns)
+    }
+
     /// Initialize an empty cache with a custom size. The cache is keyed on the layout and area, so
     /// that subsequent calls with the same parameters are faster. The cache is a `LruCache`, and
     /// grows until `cache_size` is reached.
     ///
     /// By default, the cache size is [`Self::DEFAULT_CACHE_SIZE`].
+    ///
+    /// This cache is shared by all the layouts split on the current thread. Libraries that want a
+    /// cache of their own, which they can size and drop independently, can use a
+    /// [`LayoutEngine`] instead.
     pub fn init_cache(cache_size: NonZeroUsize) {
         LAYOUT_CACHE.with_borrow_mut(|c| c.resize(cache_size));
     }
 
+    /// Returns the hit, miss and eviction counts and the current size of the layout cache of the
+    /// current thread.
+    ///
+    /// See [`LayoutCacheStats`] for details.
+    pub fn cache_stats() -> LayoutCacheStats {
+        LAYOUT_CACHE.with_borrow(LayoutCache::stats)
+    }
+
+    /// Removes all entries from the layout cache of the current thread and resets the counters
+    /// reported by [`Layout::cache_stats`]. The trees solved by [`LayoutTree`] are removed too.
+    ///
+    /// The capacity of the cache is unchanged.
+    pub fn clear_cache() {
+        LAYOUT_CACHE.with_borrow_mut(LayoutCache::clear);
+        LayoutTree::clear_cache();
+    }
+
+    /// Enables or disables the layout cache of the current thread. The cache is enabled by
+    /// default.
+    ///
+    /// Disabling the cache drops its entries, and every split is then solved again without being
+    /// stored. This also applies to the trees solved by [`LayoutTree`]. This is useful for
+    /// applications that split an unbounded number of distinct areas, e.g. when animating the size
+    /// of a layout every frame, where caching would only evict useful entries. Splitting is slower
+    /// while the cache is disabled, so it can be enabled again once the animation is over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// Layout::set_cache_enabled(false);
+    /// let layout = Layout::vertical([Constraint::Fill(1); 2]);
+    /// for height in 0..100 {
+    ///     layout.split(Rect::new(0, 0, 10, height));
+    /// }
+    /// assert_eq!(Layout::cache_stats().len, 0);
+    /// Layout::set_cache_enabled(true);
+    /// ```
+    pub fn set_cache_enabled(enabled: bool) {
+        LAYOUT_CACHE.with_borrow_mut(|c| c.set_enabled(enabled));
+        if !enabled {
+            LayoutTree::clear_cache();
+        }
+    }
+
+    /// Returns whether the layout cache of the current thread is enabled.
+    ///
+    /// See [`Layout::set_cache_enabled`].
+    pub fn is_cache_enabled() -> bool {
+        LAYOUT_CACHE.with_borrow(|c| c.enabled)
+    }
+
     /// Set the direction of the layout.
     ///
     /// # Examples
@@ -359,6 +575,39 @@ impl Layout {
         I::Item: Into<Constraint>,
     {
         self.constraints = constraints.into_iter().map(Into::into).collect();
+        self.names.clear();
+        self
+    }
+
+    /// Sets the constraints of the layout, each tagged with a name.
+    ///
+    /// The areas can then be looked up by name in the [`NamedAreas`] returned by
+    /// [`Layout::split_named`]. This makes large layouts self-documenting and keeps the code that
+    /// uses the areas correct when the constraints are reordered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Direction, Layout, Rect};
+    ///
+    /// let areas = Layout::default()
+    ///     .direction(Direction::Horizontal)
+    ///     .constraints_named([("sidebar", Length(30)), ("body", Fill(1))])
+    ///     .split_named(Rect::new(0, 0, 100, 20));
+    /// assert_eq!(areas["sidebar"], Rect::new(0, 0, 30, 20));
+    /// assert_eq!(areas["body"], Rect::new(30, 0, 70, 20));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn constraints_named<I, N, C>(mut self, constraints: I) -> Self
+    where
+        I: IntoIterator<Item = (N, C)>,
+        N: Into<String>,
+        C: Into<Constraint>,
+    {
+        (self.names, self.constraints) = constraints
+            .into_iter()
+            .map(|(name, constraint)| (name.into(), constraint.into()))
+            .unzip();
         self
     }
 
@@ -381,6 +630,7 @@ impl Layout {
             horizontal: margin,
             vertical: margin,
         };
+        self.margin_percent = None;
         self
     }
 
@@ -400,6 +650,7 @@ impl Layout {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn horizontal_margin(mut self, horizontal: u16) -> Self {
         self.margin.horizontal = horizontal;
+        self.margin_percent = None;
         self
     }
 
@@ -419,6 +670,33 @@ impl Layout {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn vertical_margin(mut self, vertical: u16) -> Self {
         self.margin.vertical = vertical;
+        self.margin_percent = None;
+        self
+    }
+
+    /// Set the margin of the layout as a percentage of the size of the area.
+    ///
+    /// The horizontal margin is the given percentage of the width of the area and the vertical
+    /// margin the given percentage of its height, rounded down. The margin scales with the area,
+    /// e.g. to keep some room around a centered dialog regardless of the size of the terminal.
+    ///
+    /// This replaces the margin set with [`Layout::margin`], [`Layout::horizontal_margin`] or
+    /// [`Layout::vertical_margin`], and setting one of these replaces the percentage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// let layout = Layout::default()
+    ///     .constraints([Constraint::Min(0)])
+    ///     .margin_percent(10)
+    ///     .split(Rect::new(0, 0, 100, 20));
+    /// assert_eq!(layout[..], [Rect::new(10, 2, 80, 16)]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn margin_percent(mut self, percent: u16) -> Self {
+        self.margin_percent = Some(percent);
         self
     }
 
@@ -498,6 +776,104 @@ impl Layout {
         self
     }
 
+    /// Sets how the fractional sizes computed by the solver are mapped to whole cells.
+    ///
+    /// By default, the start and end of each segment are rounded to the nearest cell
+    /// ([`Rounding::Round`]). Applications that need column widths that are stable across resizes
+    /// can use [`Rounding::LargestRemainder`], which distributes the leftover cells to the
+    /// segments with the largest fractional parts, and [`Rounding::Stable`] ensures that segment
+    /// sizes change monotonically while the area is resized one cell at a time.
+    ///
+    /// The leftover cells can also be given to the first segments ([`Rounding::FirstRemainder`]),
+    /// the last segments ([`Rounding::LastRemainder`]) or spread evenly across the layout
+    /// ([`Rounding::SpreadRemainder`]). These strategies only give the leftover cells to segments,
+    /// never to the spacers between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect, Rounding};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let constraints = [Percentage(14), Percentage(14), Percentage(12)];
+    ///
+    /// let areas = Layout::horizontal(constraints).split(area);
+    /// assert_eq!(areas.iter().map(|a| a.width).collect::<Vec<_>>(), [1, 2, 1]);
+    ///
+    /// let areas = Layout::horizontal(constraints)
+    ///     .rounding(Rounding::LargestRemainder)
+    ///     .split(area);
+    /// assert_eq!(areas.iter().map(|a| a.width).collect::<Vec<_>>(), [2, 1, 1]);
+    ///
+    /// let areas = Layout::horizontal([Fill(1); 6])
+    ///     .rounding(Rounding::LastRemainder)
+    ///     .split(area);
+    /// assert_eq!(
+    ///     areas.iter().map(|a| a.width).collect::<Vec<_>>(),
+    ///     [1, 1, 2, 2, 2, 2]
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the space between the edges of the layout and the first and last segments.
+    ///
+    /// Unlike [`Layout::margin`], which insets the area on both axes, the outer spacing is only
+    /// applied along the direction of the layout: at the left and right ends of a horizontal
+    /// layout, or at the top and bottom ends of a vertical layout. This is useful e.g. for toolbars
+    /// that need some breathing room at their ends without shrinking their height. The outer
+    /// spacing is applied inside the margin, and the first and last spacers returned by
+    /// [`Layout::split_with_spacers`] include it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let [left, right] = Layout::horizontal([Length(3), Length(3)])
+    ///     .spacing(1)
+    ///     .outer_spacing(1)
+    ///     .areas(area);
+    /// assert_eq!(left, Rect::new(1, 0, 3, 1));
+    /// assert_eq!(right, Rect::new(5, 0, 3, 1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn outer_spacing(mut self, outer_spacing: u16) -> Self {
+        self.outer_spacing = outer_spacing;
+        self
+    }
+
+    /// Sets whether the constraints are laid out in reverse: from right to left in a horizontal
+    /// layout, or from bottom to top in a vertical layout.
+    ///
+    /// The layout is solved as usual and the resulting areas are mirrored, so the first constraint
+    /// gets the rightmost (or bottommost) area, and the [`Flex`] mode applies from that edge: with
+    /// [`Flex::Start`], the segments are packed against the right (or bottom) edge. The areas and
+    /// spacers are still returned in the order of the constraints. This is useful for
+    /// right-to-left locales, or for status lines stacked from the bottom of the screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let [first, second] = Layout::horizontal([Length(2), Length(3)])
+    ///     .reversed(true)
+    ///     .areas(area);
+    /// assert_eq!(first, Rect::new(8, 0, 2, 1));
+    /// assert_eq!(second, Rect::new(5, 0, 3, 1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -600,6 +976,93 @@ impl Layout {
         self.split_with_spacers(area).0
     }
 
+    /// Splits the given area like [`Layout::split`] and returns the areas by the names given with
+    /// [`Layout::constraints_named`].
+    ///
+    /// If the constraints were not named, the returned [`NamedAreas`] is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let layout = Layout::default().constraints_named([("header", Length(1)), ("main", Fill(1))]);
+    /// let areas = layout.split_named(Rect::new(0, 0, 10, 10));
+    /// assert_eq!(areas.get("header"), Some(Rect::new(0, 0, 10, 1)));
+    /// assert_eq!(areas.get("footer"), None);
+    /// ```
+    pub fn split_named(&self, area: Rect) -> NamedAreas {
+        let areas = self.split(area);
+        self.names
+            .iter()
+            .cloned()
+            .zip(areas.iter().copied())
+            .collect()
+    }
+
+    /// Splits the given area like [`Layout::split`], and returns an error if the constraints do
+    /// not fit in it.
+    ///
+    /// [`Layout::split`] silently shrinks the segments (down to a width or height of zero) when
+    /// the area is too small for the fixed sizes of the constraints ([`Constraint::Length`],
+    /// [`Constraint::Fit`] and [`Constraint::Min`]) and the spacing. This method detects it, e.g.
+    /// to switch to a more compact UI, and returns a [`LayoutOverflow`] with how much space is
+    /// missing and which segments are smaller than their fixed size. The areas are still
+    /// available in [`LayoutOverflow::areas`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Length(20), Min(10)]).spacing(1);
+    /// let areas = match layout.split_checked(Rect::new(0, 0, 25, 1)) {
+    ///     Ok(areas) => areas,
+    ///     Err(overflow) => {
+    ///         assert_eq!(overflow.total_deficit(), 6);
+    ///         Layout::vertical([Length(1), Min(1)]).split(Rect::new(0, 0, 25, 5))
+    ///     }
+    /// };
+    /// ```
+    pub fn split_checked(&self, area: Rect) -> Result<Rects, LayoutOverflow> {
+        let areas = self.split(area);
+        let inner_area = self.inset_outer_spacing(area.inner(self.margin_in(area)));
+        let available = match self.direction {
+            Direction::Horizontal => inner_area.width,
+            Direction::Vertical => inner_area.height,
+        };
+        let fixed_sizes = self
+            .constraints
+            .iter()
+            .map(|constraint| match *constraint {
+                Constraint::Length(size) | Constraint::Fit(size) | Constraint::Min(size) => size,
+                _ => 0,
+            })
+            .collect_vec();
+        let gaps = i64::try_from(self.constraints.len().saturating_sub(1)).unwrap_or(i64::MAX);
+        let spacing = i64::from(self.signed_spacing()).saturating_mul(gaps);
+        let required = fixed_sizes.iter().copied().map(i64::from).sum::<i64>() + spacing;
+        let required = u32::try_from(required.max(0)).unwrap_or(u32::MAX);
+        let deficits = fixed_sizes
+            .iter()
+            .zip(areas.iter())
+            .map(|(size, area)| match self.direction {
+                Direction::Horizontal => size.saturating_sub(area.width),
+                Direction::Vertical => size.saturating_sub(area.height),
+            })
+            .collect_vec();
+        if required > u32::from(available) || deficits.iter().any(|&deficit| deficit > 0) {
+            Err(LayoutOverflow {
+                areas,
+                available,
+                required,
+                deficits,
+            })
+        } else {
+            Ok(areas)
+        }
+    }
+
     /// Wrapper function around the cassowary solver that splits the given area into smaller ones
     /// based on the preferred widths or heights and the direction, with the ability to include
     /// spacers between the areas.
@@ -650,11 +1113,73 @@ impl Layout {
         LAYOUT_CACHE.with_borrow_mut(|c| {
             let key = (area, self.clone());
             c.get_or_insert(key, || self.try_split(area).expect("failed to split"))
-                .clone()
         })
     }
 
+    /// Returns the segments of the area at the given point of an animated transition from this
+    /// layout to another one.
+    ///
+    /// The area is split by both layouts, and the edges of each segment are interpolated linearly
+    /// from the segment of this layout (at `t = 0.0`) to the segment with the same index in the
+    /// other layout (at `t = 1.0`). `t` is clamped to that range. Calling this method on each frame
+    /// with an increasing `t` animates a change of layout, e.g. a pane opening or closing.
+    ///
+    /// If the layouts have a different number of segments, the extra segments grow from (or
+    /// shrink to) an empty segment at the end of the last segment of the layout that lacks them, so
+    /// the result always has as many segments as the larger layout. Segments that share an edge in
+    /// both layouts keep sharing it during the transition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let closed = Layout::horizontal([Fill(1)]);
+    /// let open = Layout::horizontal([Fill(1), Length(4)]);
+    ///
+    /// let areas = closed.interpolate(&open, 0.5, area);
+    /// assert_eq!(areas[..], [Rect::new(0, 0, 8, 1), Rect::new(8, 0, 2, 1)]);
+    ///
+    /// let areas = closed.interpolate(&open, 1.0, area);
+    /// assert_eq!(areas[..], open.split(area)[..]);
+    /// ```
+    pub fn interpolate(&self, other: &Self, t: f32, area: Rect) -> Rects {
+        let from = self.split(area);
+        let to = other.split(area);
+        let t = t.clamp(0.0, 1.0);
+        (0..from.len().max(to.len()))
+            .map(|index| {
+                let (start, end) = match (from.get(index), to.get(index)) {
+                    (Some(&start), Some(&end)) => (start, end),
+                    (Some(&start), None) => {
+                        (start, collapsed_segment(other.direction, &to, start, area))
+                    }
+                    (None, Some(&end)) => {
+                        (collapsed_segment(self.direction, &from, end, area), end)
+                    }
+                    (None, None) => unreachable!("index is below the number of segments"),
+                };
+                interpolate_rect(start, end, t)
+            })
+            .collect()
+    }
+
+    /// Returns the names given to the constraints with [`Layout::constraints_named`].
+    pub(super) fn names(&self) -> &[String] {
+        &self.names
+    }
+
     fn try_split(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "layout solve",
+            direction = %self.direction,
+            constraints = self.constraints.len(),
+            %area,
+        )
+        .entered();
+
         // To take advantage of all of cassowary features, we would want to store the `Solver` in
         // one of the fields of the Layout struct. And we would want to set it up such that we could
         // add or remove constraints as and when needed.
@@ -666,10 +1191,9 @@ impl Layout {
         // added, and which variables they correspond to.
         // This will also require introducing and maintaining the API for users to do so.
         //
-        // Currently we don't support that use case and do not intend to support it in the future,
-        // and instead we require that the user re-solve the layout every time they call `split`.
-        // To minimize the time it takes to solve the same problem over and over again, we
-        // cache the `Layout` struct along with the results.
+        // This use case is supported by the opt-in `LayoutSolver`, while `split` re-solves the
+        // layout every time it is called. To minimize the time it takes to solve the same problem
+        // over and over again, we cache the `Layout` struct along with the results.
         //
         // `try_split` is the inner method in `split` that is called only when the LRU cache doesn't
         // match the key. So inside `try_split`, we create a new instance of the solver.
@@ -677,17 +1201,9 @@ impl Layout {
         // This is equivalent to storing the solver in `Layout` and calling `solver.reset()` here.
         let mut solver = Solver::new();
 
-        let inner_area = area.inner(self.margin);
-        let (area_start, area_end) = match self.direction {
-            Direction::Horizontal => (
-                f64::from(inner_area.x) * FLOAT_PRECISION_MULTIPLIER,
-                f64::from(inner_area.right()) * FLOAT_PRECISION_MULTIPLIER,
-            ),
-            Direction::Vertical => (
-                f64::from(inner_area.y) * FLOAT_PRECISION_MULTIPLIER,
-                f64::from(inner_area.bottom()) * FLOAT_PRECISION_MULTIPLIER,
-            ),
-        };
+        let outer_area = area.inner(self.margin_in(area));
+        let inner_area = self.inset_outer_spacing(outer_area);
+        let (area_start, area_end) = self.area_bounds(inner_area);
 
         // ```plain
         // <───────────────────────────────────area_size──────────────────────────────────>
@@ -724,43 +1240,221 @@ impl Layout {
             .map(|(a, b)| Element::from((*a, *b)))
             .collect_vec();
 
-        let flex = self.flex;
+        let area_size = Element::from((*variables.first().unwrap(), *variables.last().unwrap()));
+        configure_area(&mut solver, area_size, area_start, area_end)?;
+        self.configure_elements(&mut solver, &variables, &spacers, &segments)?;
 
-        let spacing = match self.spacing {
-            Spacing::Space(x) => x as i16,
-            Spacing::Overlap(x) => -(x as i16),
-        };
+        // `solver.fetch_changes()` can only be called once per solve
+        let changes: HashMap<Variable, f64> = solver.fetch_changes().iter().copied().collect();
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            segments = ?element_positions(&segments, &changes),
+            spacers = ?element_positions(&spacers, &changes),
+            "solved layout",
+        );
+
+        let positions = round_positions(&changes, &variables, self.rounding);
+        let mut segment_rects = changes_to_rects(&positions, &segments, inner_area, self.direction);
+        let mut spacer_rects = changes_to_rects(&positions, &spacers, inner_area, self.direction);
+        if inner_area != outer_area {
+            extend_outer_spacers(&mut spacer_rects, outer_area, self.direction);
+        }
+        if self.reversed {
+            mirror_rects(&mut segment_rects, outer_area, self.direction);
+            mirror_rects(&mut spacer_rects, outer_area, self.direction);
+        }
+
+        Ok((segment_rects, spacer_rects))
+    }
+
+    /// Adds the constraints of the layout between its spacers and segments, which span the area
+    /// between the first and the last of the `variables`.
+    ///
+    /// The area itself is left unconstrained, so that the caller can pin it to fixed values or,
+    /// for the layouts of a [`LayoutTree`], to the segment of a parent layout.
+    fn configure_elements(
+        &self,
+        solver: &mut impl ConstraintSink,
+        variables: &[Variable],
+        spacers: &[Element],
+        segments: &[Element],
+    ) -> Result<(), AddConstraintError> {
+        let flex = self.flex;
+        let spacing = self.signed_spacing();
 
         let constraints = &self.constraints;
 
         let area_size = Element::from((*variables.first().unwrap(), *variables.last().unwrap()));
-        configure_area(&mut solver, area_size, area_start, area_end)?;
-        configure_variable_in_area_constraints(&mut solver, &variables, area_size)?;
-        configure_variable_constraints(&mut solver, &variables)?;
-        configure_flex_constraints(&mut solver, area_size, &spacers, flex, spacing)?;
-        configure_constraints(&mut solver, area_size, &segments, constraints, flex)?;
-        configure_fill_constraints(&mut solver, &segments, constraints, flex)?;
+        configure_variable_in_area_constraints(solver, variables, area_size)?;
+        configure_variable_constraints(solver, variables)?;
+        configure_flex_constraints(solver, area_size, spacers, flex, spacing)?;
+        configure_constraints(solver, area_size, segments, constraints, flex)?;
+        configure_fill_constraints(solver, segments, constraints, flex)?;
 
         if !flex.is_legacy() {
             for (left, right) in segments.iter().tuple_windows() {
                 solver.add_constraint(left.has_size(right, ALL_SEGMENT_GROW))?;
             }
         }
+        Ok(())
+    }
 
-        // `solver.fetch_changes()` can only be called once per solve
-        let changes: HashMap<Variable, f64> = solver.fetch_changes().iter().copied().collect();
-        // debug_elements(&segments, &changes);
-        // debug_elements(&spacers, &changes);
+    /// Returns the start and end of the area in the direction of the layout, as solver values.
+    fn area_bounds(&self, area: Rect) -> (f64, f64) {
+        let (start, end) = match self.direction {
+            Direction::Horizontal => (area.x, area.right()),
+            Direction::Vertical => (area.y, area.bottom()),
+        };
+        (
+            f64::from(start) * FLOAT_PRECISION_MULTIPLIER,
+            f64::from(end) * FLOAT_PRECISION_MULTIPLIER,
+        )
+    }
 
-        let segment_rects = changes_to_rects(&changes, &segments, inner_area, self.direction);
-        let spacer_rects = changes_to_rects(&changes, &spacers, inner_area, self.direction);
+    /// Returns the spacing between segments, negative when the segments overlap.
+    const fn signed_spacing(&self) -> i16 {
+        match self.spacing {
+            Spacing::Space(x) => x as i16,
+            Spacing::Overlap(x) => -(x as i16),
+        }
+    }
 
-        Ok((segment_rects, spacer_rects))
+    /// Returns the margin of the layout in the given area.
+    fn margin_in(&self, area: Rect) -> Margin {
+        self.margin_percent.map_or(self.margin, |percent| {
+            let scale = |size: u16| {
+                u16::try_from(u32::from(size) * u32::from(percent) / 100).unwrap_or(u16::MAX)
+            };
+            Margin::new(scale(area.width), scale(area.height))
+        })
+    }
+
+    /// Shrinks the area by the outer spacing at both ends of the direction of the layout.
+    const fn inset_outer_spacing(&self, area: Rect) -> Rect {
+        let spacing = self.outer_spacing;
+        match self.direction {
+            Direction::Horizontal => Rect {
+                x: area.x.saturating_add(spacing),
+                width: area.width.saturating_sub(spacing.saturating_mul(2)),
+                ..area
+            },
+            Direction::Vertical => Rect {
+                y: area.y.saturating_add(spacing),
+                height: area.height.saturating_sub(spacing.saturating_mul(2)),
+                ..area
+            },
+        }
+    }
+}
+
+/// Solves the given constraints for a single dimension of `size` cells.
+///
+/// Returns the offset and the size of each segment, in the same order as the constraints. This is
+/// the same solve that [`Layout::split`] does for a horizontal layout, without having to build a
+/// layout and an area and to pick the positions back out of the resulting [`Rect`]s. It is what
+/// e.g. the `Table` widget uses to compute the widths of its columns, and is useful for custom
+/// widgets that negotiate the widths of their parts.
+///
+/// The results are cached the same way as the results of [`Layout::split`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{self, Constraint::*, Flex};
+///
+/// let segments = layout::solve(20, &[Length(5), Fill(1), Length(3)], Flex::Start, 1);
+/// assert_eq!(segments, [(0, 5), (6, 10), (17, 3)]);
+/// ```
+pub fn solve<T>(size: u16, constraints: &[Constraint], flex: Flex, spacing: T) -> Vec<(u16, u16)>
+where
+    T: Into<Spacing>,
+{
+    Layout::horizontal(constraints.iter().copied())
+        .flex(flex)
+        .spacing(spacing)
+        .split(Rect::new(0, 0, size, 1))
+        .iter()
+        .map(|segment| (segment.x, segment.width))
+        .collect()
+}
+
+/// Extends the first and last spacers to the edges of the area, so that they include the outer
+/// spacing.
+fn extend_outer_spacers(spacers: &mut Rects, area: Rect, direction: Direction) {
+    let mut rects = spacers.to_vec();
+    if let Some(first) = rects.first_mut() {
+        match direction {
+            Direction::Horizontal => {
+                first.width = first.right() - area.x;
+                first.x = area.x;
+            }
+            Direction::Vertical => {
+                first.height = first.bottom() - area.y;
+                first.y = area.y;
+            }
+        }
+    }
+    if let Some(last) = rects.last_mut() {
+        match direction {
+            Direction::Horizontal => last.width = area.right() - last.x,
+            Direction::Vertical => last.height = area.bottom() - last.y,
+        }
+    }
+    *spacers = rects.into();
+}
+
+/// Mirrors the rects within the area along the direction of the layout, for reversed layouts.
+fn mirror_rects(rects: &mut Rects, area: Rect, direction: Direction) {
+    let mut mirrored = rects.to_vec();
+    for rect in &mut mirrored {
+        match direction {
+            Direction::Horizontal => {
+                rect.x = area
+                    .right()
+                    .saturating_sub(rect.right().saturating_sub(area.x));
+            }
+            Direction::Vertical => {
+                rect.y = area
+                    .bottom()
+                    .saturating_sub(rect.bottom().saturating_sub(area.y));
+            }
+        }
+    }
+    *rects = mirrored.into();
+}
+
+/// Something that cassowary constraints can be added to.
+///
+/// This lets the `configure_*` functions add constraints directly to a [`Solver`] when solving a
+/// layout from scratch, or collect them so that [`LayoutSolver`] can remove them later.
+trait ConstraintSink {
+    fn add_constraint(
+        &mut self,
+        constraint: cassowary::Constraint,
+    ) -> Result<(), AddConstraintError>;
+}
+
+impl ConstraintSink for Solver {
+    fn add_constraint(
+        &mut self,
+        constraint: cassowary::Constraint,
+    ) -> Result<(), AddConstraintError> {
+        Self::add_constraint(self, constraint)
+    }
+}
+
+impl ConstraintSink for Vec<cassowary::Constraint> {
+    fn add_constraint(
+        &mut self,
+        constraint: cassowary::Constraint,
+    ) -> Result<(), AddConstraintError> {
+        self.push(constraint);
+        Ok(())
     }
 }
 
 fn configure_area(
-    solver: &mut Solver,
+    solver: &mut impl ConstraintSink,
     area: Element,
     area_start: f64,
     area_end: f64,
@@ -771,7 +1465,7 @@ fn configure_area(
 }
 
 fn configure_variable_in_area_constraints(
-    solver: &mut Solver,
+    solver: &mut impl ConstraintSink,
     variables: &[Variable],
     area: Element,
 ) -> Result<(), AddConstraintError> {
@@ -785,7 +1479,7 @@ fn configure_variable_in_area_constraints(
 }
 
 fn configure_variable_constraints(
-    solver: &mut Solver,
+    solver: &mut impl ConstraintSink,
     variables: &[Variable],
 ) -> Result<(), AddConstraintError> {
     // ┌────┬───────────────────┬────┬─────variables─────┬────┬───────────────────┬────┐
@@ -804,7 +1498,7 @@ fn configure_variable_constraints(
 }
 
 fn configure_constraints(
-    solver: &mut Solver,
+    solver: &mut impl ConstraintSink,
     area: Element,
     segments: &[Element],
     constraints: &[Constraint],
@@ -827,10 +1521,18 @@ fn configure_constraints(
             Constraint::Length(length) => {
                 solver.add_constraint(segment.has_int_size(length, LENGTH_SIZE_EQ))?;
             }
+            Constraint::Fit(size) => {
+                solver.add_constraint(segment.has_max_size(size, MAX_SIZE_LE))?;
+                solver.add_constraint(segment.has_int_size(size, LENGTH_SIZE_EQ))?;
+            }
             Constraint::Percentage(p) => {
                 let size = area.size() * f64::from(p) / 100.00;
                 solver.add_constraint(segment.has_size(size, PERCENTAGE_SIZE_EQ))?;
             }
+            Constraint::Permille(p) => {
+                let size = area.size() * f64::from(p) / 1000.00;
+                solver.add_constraint(segment.has_size(size, PERCENTAGE_SIZE_EQ))?;
+            }
             Constraint::Ratio(num, den) => {
                 // avoid division by zero by using 1 when denominator is 0
                 let size = area.size() * f64::from(num) / f64::from(den.max(1));
@@ -846,7 +1548,7 @@ fn configure_constraints(
 }
 
 fn configure_flex_constraints(
-    solver: &mut Solver,
+    solver: &mut impl ConstraintSink,
     area: Element,
     spacers: &[Element],
     flex: Flex,
@@ -938,7 +1640,7 @@ fn configure_flex_constraints(
 ///
 /// `size == base_element * scaling_factor`
 fn configure_fill_constraints(
-    solver: &mut Solver,
+    solver: &mut impl ConstraintSink,
     segments: &[Element],
     constraints: &[Constraint],
     flex: Flex,
@@ -968,8 +1670,236 @@ fn configure_fill_constraints(
     Ok(())
 }
 
-fn changes_to_rects(
+/// Returns an empty segment at the end of the last of the `segments`, or at the start of the area
+/// if there are none, that spans the same cells as `counterpart` across the `direction`.
+fn collapsed_segment(
+    direction: Direction,
+    segments: &[Rect],
+    counterpart: Rect,
+    area: Rect,
+) -> Rect {
+    match direction {
+        Direction::Horizontal => Rect {
+            x: segments.last().map_or(area.x, |last| last.right()),
+            width: 0,
+            ..counterpart
+        },
+        Direction::Vertical => Rect {
+            y: segments.last().map_or(area.y, |last| last.bottom()),
+            height: 0,
+            ..counterpart
+        },
+    }
+}
+
+/// Interpolates the edges of two rects linearly, `t` being between `0.0` (`start`) and `1.0`
+/// (`end`).
+fn interpolate_rect(start: Rect, end: Rect, t: f32) -> Rect {
+    let lerp = |a: u16, b: u16| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u16;
+    let x = lerp(start.x, end.x);
+    let y = lerp(start.y, end.y);
+    let right = lerp(start.right(), end.right());
+    let bottom = lerp(start.bottom(), end.bottom());
+    Rect::new(x, y, right.saturating_sub(x), bottom.saturating_sub(y))
+}
+
+/// Converts the solved values of the variables to cell positions using the given rounding
+/// strategy.
+///
+/// The variables must be in the order of the elements of the layout, i.e. alternating between the
+/// start of a spacer and the start of a segment, so that the differences between consecutive
+/// values are the sizes of the spacers and segments.
+fn round_positions(
     changes: &HashMap<Variable, f64>,
+    variables: &[Variable],
+    rounding: Rounding,
+) -> HashMap<Variable, u16> {
+    // work with whole multiples of the precision to avoid accumulating floating point errors
+    let precision = FLOAT_PRECISION_MULTIPLIER as i64;
+    let values = variables
+        .iter()
+        .map(|variable| changes.get(variable).unwrap_or(&0.0).round() as i64)
+        .collect_vec();
+    let positions = match rounding {
+        Rounding::FirstRemainder => distribute_leftover(&values, precision, |sizes, leftover| {
+            fractional_segments(sizes, precision)
+                .take(leftover)
+                .collect()
+        }),
+        Rounding::LastRemainder => distribute_leftover(&values, precision, |sizes, leftover| {
+            fractional_segments(sizes, precision)
+                .rev()
+                .take(leftover)
+                .collect()
+        }),
+        Rounding::SpreadRemainder => spread_remainder(&values, precision),
+        Rounding::LargestRemainder => largest_remainder(&values, precision),
+        Rounding::Round => values
+            .iter()
+            .map(|&value| (value as f64 / FLOAT_PRECISION_MULTIPLIER).round() as i64)
+            .collect_vec(),
+        Rounding::Truncate => values
+            .iter()
+            .map(|&value| value.div_euclid(precision))
+            .collect_vec(),
+        Rounding::Stable => stable_apportionment(&values, precision),
+    };
+    variables
+        .iter()
+        .zip(positions)
+        .map(|(&variable, position)| (variable, position.clamp(0, i64::from(u16::MAX)) as u16))
+        .collect()
+}
+
+/// Truncates the sizes between consecutive values and distributes the leftover cells to the sizes
+/// with the largest remainders (ties are broken in order).
+fn largest_remainder(values: &[i64], precision: i64) -> Vec<i64> {
+    // the cells that are not selected are given to the largest remainders
+    distribute_leftover(values, precision, |_, _| Vec::new())
+}
+
+/// Truncates the sizes between consecutive values and distributes the leftover cells evenly
+/// across the segments that have a remainder, regardless of how large the remainders are.
+fn spread_remainder(values: &[i64], precision: i64) -> Vec<i64> {
+    distribute_leftover(values, precision, |sizes, leftover| {
+        let candidates = fractional_segments(sizes, precision).collect_vec();
+        let count = candidates.len();
+        let leftover = leftover.min(count);
+        // pick the candidate in the middle of each of `leftover` equally sized groups
+        (0..leftover)
+            .map(|j| candidates[(2 * j + 1) * count / (2 * leftover)])
+            .collect()
+    })
+}
+
+/// Returns the indices of the segment sizes that are not a whole number of cells.
+///
+/// The sizes alternate between spacers and segments, starting with a spacer, so the segments are
+/// at the odd indices.
+fn fractional_segments(
+    sizes: &[i64],
+    precision: i64,
+) -> impl DoubleEndedIterator<Item = usize> + '_ {
+    (1..sizes.len())
+        .step_by(2)
+        .filter(move |&i| sizes[i].rem_euclid(precision) > 0)
+}
+
+/// Truncates the sizes between consecutive values and adds one cell to each of the sizes at the
+/// indices returned by `select`, which is called with the sizes and the number of leftover cells.
+///
+/// The cells that `select` leaves over (e.g. when only the spacers have a fractional part) are
+/// given to the sizes with the largest remainders, so that the positions always cover the area.
+fn distribute_leftover<F>(values: &[i64], precision: i64, select: F) -> Vec<i64>
+where
+    F: FnOnce(&[i64], usize) -> Vec<usize>,
+{
+    let (Some(&first), Some(&last)) = (values.first(), values.last()) else {
+        return Vec::new();
+    };
+    let sizes = values
+        .iter()
+        .tuple_windows()
+        .map(|(a, b)| b - a)
+        .collect_vec();
+    let mut cells = sizes
+        .iter()
+        .map(|size| size.div_euclid(precision))
+        .collect_vec();
+    let total = (last - first + precision / 2).div_euclid(precision);
+    let leftover = usize::try_from(total - cells.iter().sum::<i64>()).unwrap_or(0);
+    let selected = select(&sizes, leftover);
+    let mut rest = (0..sizes.len())
+        .filter(|i| !selected.contains(i))
+        .collect_vec();
+    // sort_by_key is stable, so equal remainders keep their order
+    rest.sort_by_key(|&i| std::cmp::Reverse(sizes[i].rem_euclid(precision)));
+    rest.truncate(leftover.saturating_sub(selected.len()));
+    for i in selected.into_iter().chain(rest) {
+        cells[i] += 1;
+    }
+    let mut position = (first + precision / 2).div_euclid(precision);
+    iter::once(position)
+        .chain(cells.iter().map(|size| {
+            position += size;
+            position
+        }))
+        .collect()
+}
+
+/// Distributes the cells between consecutive values using the Sainte-Laguë (Webster) divisor
+/// method.
+///
+/// Divisor methods never take a cell away from a size when the total grows and the relative
+/// sizes stay the same. Negative sizes (overlapping spacers) are kept as is.
+fn stable_apportionment(values: &[i64], precision: i64) -> Vec<i64> {
+    let (Some(&first), Some(&last)) = (values.first(), values.last()) else {
+        return Vec::new();
+    };
+    let sizes = values
+        .iter()
+        .tuple_windows()
+        .map(|(a, b)| b - a)
+        .collect_vec();
+    let mut cells = sizes
+        .iter()
+        .map(|size| (*size).min(0).div_euclid(precision))
+        .collect_vec();
+    let total = (last - first + precision / 2).div_euclid(precision) - cells.iter().sum::<i64>();
+    let mut quotients = sizes
+        .iter()
+        .enumerate()
+        .filter(|(_, size)| **size > 0)
+        .map(|(index, size)| Quotient {
+            // quantize the sizes to a tenth of a cell, so that tiny differences in the solver
+            // results between equal segments do not decide which of them receives a cell
+            weight: (size + precision / 20).div_euclid(precision / 10),
+            cells: 0,
+            index,
+        })
+        .collect::<BinaryHeap<_>>();
+    for _ in 0..total {
+        let Some(mut quotient) = quotients.pop() else {
+            break;
+        };
+        quotient.cells += 1;
+        cells[quotient.index] = quotient.cells;
+        quotients.push(quotient);
+    }
+    let mut position = (first + precision / 2).div_euclid(precision);
+    iter::once(position)
+        .chain(cells.iter().map(|size| {
+            position += size;
+            position
+        }))
+        .collect()
+}
+
+/// The Sainte-Laguë quotient `weight / (2 * cells + 1)` of a size, ordered so that the largest
+/// quotient (and the first index among equal quotients) is the greatest.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Quotient {
+    weight: i64,
+    cells: i64,
+    index: usize,
+}
+
+impl Ord for Quotient {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let lhs = i128::from(self.weight) * i128::from(2 * other.cells + 1);
+        let rhs = i128::from(other.weight) * i128::from(2 * self.cells + 1);
+        lhs.cmp(&rhs).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Quotient {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn changes_to_rects(
+    positions: &HashMap<Variable, u16>,
     elements: &[Element],
     area: Rect,
     direction: Direction,
@@ -978,10 +1908,8 @@ fn changes_to_rects(
     elements
         .iter()
         .map(|element| {
-            let start = changes.get(&element.start).unwrap_or(&0.0);
-            let end = changes.get(&element.end).unwrap_or(&0.0);
-            let start = (start.round() / FLOAT_PRECISION_MULTIPLIER).round() as u16;
-            let end = (end.round() / FLOAT_PRECISION_MULTIPLIER).round() as u16;
+            let start = positions.get(&element.start).copied().unwrap_or_default();
+            let end = positions.get(&element.end).copied().unwrap_or_default();
             let size = end.saturating_sub(start);
             match direction {
                 Direction::Horizontal => Rect {
@@ -1001,21 +1929,19 @@ fn changes_to_rects(
         .collect::<Rects>()
 }
 
-/// please leave this here as it's useful for debugging unit tests when we make any changes to
-/// layout code - we should replace this with tracing in the future.
-#[allow(dead_code)]
-fn debug_elements(elements: &[Element], changes: &HashMap<Variable, f64>) {
-    let variables = format!(
-        "{:?}",
-        elements
-            .iter()
-            .map(|e| (
+/// Returns the unrounded start and end of the elements found by the solver, which is useful to
+/// trace when debugging changes to the layout code.
+#[cfg(feature = "tracing")]
+fn element_positions(elements: &[Element], changes: &HashMap<Variable, f64>) -> Vec<(f64, f64)> {
+    elements
+        .iter()
+        .map(|e| {
+            (
                 changes.get(&e.start).unwrap_or(&0.0) / FLOAT_PRECISION_MULTIPLIER,
                 changes.get(&e.end).unwrap_or(&0.0) / FLOAT_PRECISION_MULTIPLIER,
-            ))
-            .collect::<Vec<(f64, f64)>>()
-    );
-    dbg!(variables);
+            )
+        })
+        .collect()
 }
 
 /// A container used by the solver inside split
@@ -1169,6 +2095,8 @@ mod strengths {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -1190,15 +2118,132 @@ mod tests {
         assert!(SPACE_GROW > ALL_SEGMENT_GROW);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let layout = Layout::horizontal([Constraint::Length(10), Constraint::Fill(1)])
+            .flex(Flex::Center)
+            .spacing(-1)
+            .margin(1);
+        let json = serde_json::to_string(&layout)?;
+        assert_eq!(serde_json::from_str::<Layout>(&json)?, layout);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::start(0.0, [Rect::new(0, 0, 5, 2), Rect::new(5, 0, 5, 2)])]
+    #[case::middle(0.5, [Rect::new(0, 0, 4, 2), Rect::new(4, 0, 6, 2)])]
+    #[case::end(1.0, [Rect::new(0, 0, 2, 2), Rect::new(2, 0, 8, 2)])]
+    #[case::clamped(2.0, [Rect::new(0, 0, 2, 2), Rect::new(2, 0, 8, 2)])]
+    fn interpolate(#[case] t: f32, #[case] expected: [Rect; 2]) {
+        let from = Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]);
+        let to = Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]);
+        let areas = from.interpolate(&to, t, Rect::new(0, 0, 10, 2));
+        assert_eq!(areas[..], expected);
+    }
+
+    #[rstest]
+    #[case::opening(0.0, [Rect::new(0, 0, 2, 10), Rect::new(0, 10, 2, 0)])]
+    #[case::half_open(0.5, [Rect::new(0, 0, 2, 7), Rect::new(0, 7, 2, 3)])]
+    #[case::open(1.0, [Rect::new(0, 0, 2, 4), Rect::new(0, 4, 2, 6)])]
+    fn interpolate_different_segment_counts(#[case] t: f32, #[case] expected: [Rect; 2]) {
+        let area = Rect::new(0, 0, 2, 10);
+        let closed = Layout::vertical([Constraint::Fill(1)]);
+        let open = Layout::vertical([Constraint::Length(4), Constraint::Fill(1)]);
+        assert_eq!(closed.interpolate(&open, t, area)[..], expected);
+        // closing is the same transition in reverse
+        assert_eq!(open.interpolate(&closed, 1.0 - t, area)[..], expected);
+    }
+
+    #[rstest]
+    #[case::start(Flex::Start, 0, &[(0, 3), (3, 4)])]
+    #[case::end(Flex::End, 0, &[(3, 3), (6, 4)])]
+    #[case::spacing(Flex::Start, 2, &[(0, 3), (5, 4)])]
+    #[case::space_between(Flex::SpaceBetween, 0, &[(0, 3), (6, 4)])]
+    fn solve(#[case] flex: Flex, #[case] spacing: u16, #[case] expected: &[(u16, u16)]) {
+        let constraints = [Constraint::Length(3), Constraint::Length(4)];
+        assert_eq!(super::solve(10, &constraints, flex, spacing), expected);
+    }
+
+    #[test]
+    fn solve_matches_split() {
+        let constraints = [
+            Constraint::Percentage(30),
+            Constraint::Min(5),
+            Constraint::Ratio(1, 3),
+        ];
+        let layout = Layout::horizontal(constraints).spacing(1);
+        let rects = layout.split(Rect::new(0, 0, 37, 1));
+        let expected = rects
+            .iter()
+            .map(|rect| (rect.x, rect.width))
+            .collect::<Vec<_>>();
+        assert_eq!(super::solve(37, &constraints, Flex::Start, 1), expected);
+    }
+
+    #[test]
+    fn cache_disabled() {
+        Layout::clear_cache();
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+        layout.split(Rect::new(0, 0, 10, 4));
+        assert_eq!(Layout::cache_stats().len, 1);
+
+        Layout::set_cache_enabled(false);
+        assert!(!Layout::is_cache_enabled());
+        assert_eq!(Layout::cache_stats().len, 0);
+        let areas = layout.split(Rect::new(0, 0, 10, 4));
+        assert_eq!(*areas, [Rect::new(0, 0, 10, 1), Rect::new(0, 1, 10, 3)]);
+        layout.split(Rect::new(0, 0, 10, 4));
+        let stats = Layout::cache_stats();
+        assert_eq!((stats.len, stats.hits, stats.misses), (0, 0, 1));
+
+        Layout::set_cache_enabled(true);
+        assert!(Layout::is_cache_enabled());
+        layout.split(Rect::new(0, 0, 10, 4));
+        assert_eq!(Layout::cache_stats().len, 1);
+    }
+
+    #[test]
+    fn cache_stats() {
+        Layout::init_cache(NonZeroUsize::new(2).unwrap());
+        Layout::clear_cache();
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+        for height in [4, 4, 5, 6, 4] {
+            layout.split(Rect::new(0, 0, 10, height));
+        }
+        assert_eq!(
+            Layout::cache_stats(),
+            LayoutCacheStats {
+                hits: 1,
+                misses: 4,
+                evictions: 2,
+                len: 2,
+                capacity: 2,
+            }
+        );
+
+        Layout::init_cache(NonZeroUsize::new(1).unwrap());
+        assert_eq!(Layout::cache_stats().evictions, 3);
+
+        Layout::clear_cache();
+        assert_eq!(
+            Layout::cache_stats(),
+            LayoutCacheStats {
+                capacity: 1,
+                ..LayoutCacheStats::default()
+            }
+        );
+    }
+
     #[test]
     fn cache_size() {
         LAYOUT_CACHE.with_borrow(|c| {
-            assert_eq!(c.cap().get(), Layout::DEFAULT_CACHE_SIZE);
+            assert_eq!(c.entries.cap().get(), Layout::DEFAULT_CACHE_SIZE);
         });
 
         Layout::init_cache(NonZeroUsize::new(10).unwrap());
         LAYOUT_CACHE.with_borrow(|c| {
-            assert_eq!(c.cap().get(), 10);
+            assert_eq!(c.entries.cap().get(), 10);
         });
     }
 
@@ -1209,9 +2254,14 @@ mod tests {
             Layout {
                 direction: Direction::Vertical,
                 margin: Margin::new(0, 0),
+                margin_percent: None,
                 constraints: vec![],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                outer_spacing: 0,
+                rounding: Rounding::Round,
+                reversed: false,
+                names: Vec::new(),
             }
         );
     }
@@ -1254,9 +2304,14 @@ mod tests {
             Layout {
                 direction: Direction::Vertical,
                 margin: Margin::new(0, 0),
+                margin_percent: None,
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                outer_spacing: 0,
+                rounding: Rounding::Round,
+                reversed: false,
+                names: Vec::new(),
             }
         );
     }
@@ -1268,9 +2323,14 @@ mod tests {
             Layout {
                 direction: Direction::Horizontal,
                 margin: Margin::new(0, 0),
+                margin_percent: None,
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                outer_spacing: 0,
+                rounding: Rounding::Round,
+                reversed: false,
+                names: Vec::new(),
             }
         );
     }
@@ -1369,6 +2429,17 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::percent(Layout::default().margin_percent(10), Rect::new(10, 2, 80, 16))]
+    #[case::rounded_down(Layout::default().margin_percent(15), Rect::new(15, 3, 70, 14))]
+    #[case::too_large(Layout::default().margin_percent(60), Rect::ZERO)]
+    #[case::replaced_by_margin(Layout::default().margin_percent(10).margin(1), Rect::new(1, 1, 98, 18))]
+    #[case::replaces_margin(Layout::default().margin(1).margin_percent(10), Rect::new(10, 2, 80, 16))]
+    fn margin_percent(#[case] layout: Layout, #[case] expected: Rect) {
+        let layout = layout.constraints([Constraint::Min(0)]);
+        assert_eq!(layout.split(Rect::new(0, 0, 100, 20))[..], [expected]);
+    }
+
     #[test]
     fn flex() {
         assert_eq!(Layout::default().flex, Flex::Start);
@@ -1382,6 +2453,26 @@ mod tests {
         assert_eq!(Layout::default().spacing(-10).spacing, Spacing::Overlap(10));
     }
 
+    #[test]
+    fn rounding() {
+        assert_eq!(Layout::default().rounding, Rounding::Round);
+        assert_eq!(
+            Layout::default().rounding(Rounding::Truncate).rounding,
+            Rounding::Truncate
+        );
+    }
+
+    #[test]
+    fn outer_spacing() {
+        assert_eq!(Layout::default().outer_spacing(2).outer_spacing, 2);
+    }
+
+    #[test]
+    fn reversed() {
+        assert!(!Layout::default().reversed);
+        assert!(Layout::default().reversed(true).reversed);
+    }
+
     /// Tests for the `Layout::split()` function.
     ///
     /// There are many tests in this as the number of edge cases that are caused by the interaction
@@ -1410,7 +2501,7 @@ mod tests {
             buffer::Buffer,
             layout::{
                 Constraint::{self, *},
-                Direction, Flex, Layout, Rect,
+                Direction, Flex, Layout, Rect, Rounding,
             },
             text::Text,
             widgets::Widget,
@@ -1696,6 +2787,160 @@ mod tests {
             letters(flex, constraints, width, expected);
         }
 
+        #[rstest]
+        #[case::round(Rounding::Round, "abbc      ")]
+        #[case::truncate(Rounding::Truncate, "abcc      ")]
+        #[case::largest_remainder(Rounding::LargestRemainder, "aabc      ")]
+        #[case::stable(Rounding::Stable, "aabc      ")]
+        fn rounding_strategy(#[case] rounding: Rounding, #[case] expected: &str) {
+            let area = Rect::new(0, 0, 10, 1);
+            let layout = Layout::horizontal([Percentage(14), Percentage(14), Percentage(12)])
+                .rounding(rounding)
+                .split(area);
+            let mut buffer = Buffer::empty(area);
+            for (c, &area) in ('a'..='c').zip(layout.iter()) {
+                let s = c.to_string().repeat(area.width as usize);
+                Text::from(s).render(area, &mut buffer);
+            }
+            assert_eq!(buffer, Buffer::with_lines([expected]));
+        }
+
+        #[rstest]
+        #[case::first(Rounding::FirstRemainder, [2, 2, 2, 2, 1, 1])]
+        #[case::last(Rounding::LastRemainder, [1, 1, 2, 2, 2, 2])]
+        #[case::spread(Rounding::SpreadRemainder, [2, 1, 2, 2, 1, 2])]
+        fn remainder_distribution(
+            #[case] rounding: Rounding,
+            #[case] expected: [u16; 6],
+            #[values(0, 1, 3)] spacing: u16,
+        ) {
+            let width = 10 + 5 * spacing;
+            let (segments, spacers) = Layout::horizontal([Fill(1); 6])
+                .spacing(spacing)
+                .rounding(rounding)
+                .split_with_spacers(Rect::new(0, 0, width, 1));
+            assert_eq!(segments.iter().map(|r| r.width).collect_vec(), expected);
+            // the leftover cells only go to the segments
+            assert!(spacers[1..6].iter().all(|r| r.width == spacing));
+        }
+
+        #[rstest]
+        fn remainder_skips_fractional_spacers(
+            #[values(
+                Rounding::FirstRemainder,
+                Rounding::LastRemainder,
+                Rounding::SpreadRemainder
+            )]
+            rounding: Rounding,
+        ) {
+            // the segments are 1.5 cells wide and the spacers between them 2.75 cells wide
+            let (segments, spacers) = Layout::horizontal([Percentage(15); 3])
+                .flex(Flex::SpaceBetween)
+                .spacing(1)
+                .rounding(rounding)
+                .split_with_spacers(Rect::new(0, 0, 10, 1));
+            assert_eq!(segments.iter().map(|r| r.width).collect_vec(), [2, 2, 2]);
+            assert_eq!(spacers.iter().map(|r| r.width).collect_vec(), [0, 2, 2, 0]);
+        }
+
+        #[rstest]
+        #[case::ratios(&[Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)])]
+        #[case::fills(&[Fill(1), Fill(2), Fill(3), Fill(5)])]
+        #[case::percentages(&[Percentage(20), Percentage(45), Percentage(35)])]
+        #[case::length_and_fills(&[Length(5), Fill(1), Fill(1)])]
+        fn stable_rounding_is_monotonic(#[case] constraints: &[Constraint]) {
+            let layout = Layout::horizontal(constraints)
+                .spacing(1)
+                .rounding(Rounding::Stable);
+            let widths = |width| {
+                layout
+                    .split(Rect::new(0, 0, width, 1))
+                    .iter()
+                    .map(|r| r.width)
+                    .collect_vec()
+            };
+            for width in 10..100 {
+                let (smaller, larger) = (widths(width), widths(width + 1));
+                assert!(
+                    smaller.iter().zip(&larger).all(|(a, b)| a <= b),
+                    "{smaller:?} -> {larger:?} at width {width}"
+                );
+                assert_eq!(larger.iter().sum::<u16>(), smaller.iter().sum::<u16>() + 1);
+            }
+        }
+
+        #[rstest]
+        #[case::ratios(&[Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)], 10)]
+        #[case::fills(&[Fill(1), Fill(1), Fill(1)], 17)]
+        #[case::spacing(&[Length(3), Fill(1), Percentage(33)], 29)]
+        fn apportionment_covers_area(
+            #[case] constraints: &[Constraint],
+            #[case] width: u16,
+            #[values(Rounding::LargestRemainder, Rounding::Stable)] rounding: Rounding,
+        ) {
+            let area = Rect::new(2, 0, width, 1);
+            let (segments, spacers) = Layout::horizontal(constraints)
+                .spacing(1)
+                .rounding(rounding)
+                .split_with_spacers(area);
+            let total: u16 = segments.iter().chain(spacers.iter()).map(|r| r.width).sum();
+            assert_eq!(total, width);
+            assert_eq!(spacers.last().unwrap().right(), area.right());
+        }
+
+        #[rstest]
+        #[case::ratios(&[Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)], 10)]
+        #[case::fills(&[Fill(1), Fill(1), Fill(1)], 17)]
+        #[case::spacing(&[Length(3), Fill(1), Percentage(33)], 29)]
+        fn remainder_covers_area(
+            #[case] constraints: &[Constraint],
+            #[case] width: u16,
+            #[values(
+                Rounding::FirstRemainder,
+                Rounding::LastRemainder,
+                Rounding::SpreadRemainder
+            )]
+            rounding: Rounding,
+        ) {
+            let area = Rect::new(2, 0, width, 1);
+            let (segments, spacers) = Layout::horizontal(constraints)
+                .spacing(1)
+                .rounding(rounding)
+                .split_with_spacers(area);
+            let total: u16 = segments.iter().chain(spacers.iter()).map(|r| r.width).sum();
+            assert_eq!(total, width);
+            assert_eq!(spacers.last().unwrap().right(), area.right());
+        }
+
+        #[rstest]
+        #[case(Flex::Legacy, 10, &[Fit(3), Fit(4)], "aaa   bbbb" )]
+        #[case(Flex::Legacy, 10, &[Length(3), Fit(4)], "aaaaaabbbb" )]
+        #[case(Flex::Legacy, 10, &[Fit(3), Length(4)], "aaabbbbbbb" )]
+        #[case(Flex::Start, 10, &[Fit(3), Fill(1)], "aaabbbbbbb" )]
+        #[case(Flex::Start, 10, &[Fit(8), Length(4)], "aaaaaabbbb" )]
+        #[case(Flex::Center, 10, &[Fit(2), Fit(2)], "   aabb   " )]
+        fn fit(
+            #[case] flex: Flex,
+            #[case] width: u16,
+            #[case] constraints: &[Constraint],
+            #[case] expected: &str,
+        ) {
+            letters(flex, constraints, width, expected);
+        }
+
+        #[rstest]
+        #[case(Flex::Start, 10, &[Permille(300), Permille(700)], "aaabbbbbbb" )]
+        #[case(Flex::Start, 10, &[Permille(250), Permille(500)], "aaabbbbb  " )]
+        #[case(Flex::Start, 30, &[Permille(333), Permille(333), Permille(334)], "aaaaaaaaaabbbbbbbbbbcccccccccc" )]
+        fn permille(
+            #[case] flex: Flex,
+            #[case] width: u16,
+            #[case] constraints: &[Constraint],
+            #[case] expected: &str,
+        ) {
+            letters(flex, constraints, width, expected);
+        }
+
         #[rstest]
         #[case(Flex::Start, 10, &[Percentage(0),   Percentage(0)],    "          " )]
         #[case(Flex::Start, 10, &[Percentage(0),   Percentage(25)],  "bbb       " )]
@@ -2654,6 +3899,86 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[test]
+        fn outer_spacing() {
+            let rect = Rect::new(0, 0, 20, 3);
+            let (segments, spacers) = Layout::horizontal([Length(5), Fill(1)])
+                .spacing(1)
+                .outer_spacing(2)
+                .split_with_spacers(rect);
+            assert_eq!(
+                segments[..],
+                [Rect::new(2, 0, 5, 3), Rect::new(8, 0, 10, 3)]
+            );
+            assert_eq!(
+                spacers[..],
+                [
+                    Rect::new(0, 0, 2, 3),
+                    Rect::new(7, 0, 1, 3),
+                    Rect::new(18, 0, 2, 3)
+                ]
+            );
+
+            let [top, bottom] = Layout::vertical([Fill(1), Fill(1)])
+                .outer_spacing(1)
+                .margin(1)
+                .areas(Rect::new(0, 0, 10, 8));
+            assert_eq!(top, Rect::new(1, 2, 8, 2));
+            assert_eq!(bottom, Rect::new(1, 4, 8, 2));
+        }
+
+        #[test]
+        fn reversed() {
+            let rect = Rect::new(2, 0, 20, 3);
+            let (segments, spacers) = Layout::horizontal([Length(5), Fill(1)])
+                .spacing(1)
+                .outer_spacing(2)
+                .reversed(true)
+                .split_with_spacers(rect);
+            assert_eq!(
+                segments[..],
+                [Rect::new(15, 0, 5, 3), Rect::new(4, 0, 10, 3)]
+            );
+            assert_eq!(
+                spacers[..],
+                [
+                    Rect::new(20, 0, 2, 3),
+                    Rect::new(14, 0, 1, 3),
+                    Rect::new(2, 0, 2, 3)
+                ]
+            );
+
+            let [first, second] = Layout::vertical([Length(1), Length(2)])
+                .flex(Flex::Start)
+                .reversed(true)
+                .areas(Rect::new(0, 1, 4, 10));
+            assert_eq!(first, Rect::new(0, 10, 4, 1));
+            assert_eq!(second, Rect::new(0, 8, 4, 2));
+        }
+
+        #[test]
+        fn split_checked() {
+            let layout = Layout::vertical([Length(3), Min(2), Percentage(50)]).spacing(1);
+            let areas = layout.split_checked(Rect::new(0, 0, 5, 10)).unwrap();
+            assert_eq!(areas, layout.split(Rect::new(0, 0, 5, 10)));
+
+            let overflow = layout.split_checked(Rect::new(0, 0, 5, 5)).unwrap_err();
+            assert_eq!(overflow.areas, layout.split(Rect::new(0, 0, 5, 5)));
+            assert_eq!(overflow.available, 5);
+            assert_eq!(overflow.required, 7);
+            assert_eq!(overflow.total_deficit(), 2);
+            assert_eq!(overflow.deficits.iter().sum::<u16>(), 2);
+        }
+
+        #[test]
+        fn split_checked_overlap() {
+            let layout = Layout::horizontal([Length(3), Length(3)]).spacing(-1);
+            assert!(layout.split_checked(Rect::new(0, 0, 5, 1)).is_ok());
+            let overflow = layout.split_checked(Rect::new(0, 0, 4, 1)).unwrap_err();
+            assert_eq!(overflow.required, 5);
+            assert_eq!(overflow.deficits.iter().sum::<u16>(), 1);
+        }
+
         #[rstest]
         #[case::compare(vec![(0, 90), (90, 10)], vec![Min(10), Length(10)], Flex::Legacy)]
         #[case::compare(vec![(0, 90), (90, 10)], vec![Min(10), Length(10)], Flex::Start)]
@@ -0,0 +1,160 @@
+use std::{cell::RefCell, fmt, num::NonZeroUsize};
+
+use super::{Layout, LayoutCache, LayoutCacheStats, Rects, Segments, Spacers};
+use crate::layout::Rect;
+
+/// Splits [`Layout`]s using its own cache instead of the thread-local one.
+///
+/// [`Layout::split`] and the related methods store their results in a cache shared by all the
+/// layouts of the current thread, which is sized with [`Layout::init_cache`]. When several
+/// libraries share a thread, they compete for this cache and for its size. A `LayoutEngine` owns
+/// a separate cache, which can be sized, inspected, cleared and dropped independently of the
+/// others.
+///
+/// The cache is behind a [`RefCell`], so the engine only needs to be borrowed immutably to split
+/// a layout, e.g. from a widget's `render` method.
+///
+/// # Example
+///
+/// ```rust
+/// use std::num::NonZeroUsize;
+///
+/// use ratatui_core::layout::{Constraint, Layout, LayoutEngine, Rect};
+///
+/// let engine = LayoutEngine::new(NonZeroUsize::new(16).unwrap());
+/// let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+/// let [header, body] = engine.areas(&layout, Rect::new(0, 0, 10, 10));
+/// assert_eq!(header, Rect::new(0, 0, 10, 1));
+/// assert_eq!(body, Rect::new(0, 1, 10, 9));
+/// assert_eq!(engine.cache_stats().len, 1);
+/// ```
+pub struct LayoutEngine {
+    cache: RefCell<LayoutCache>,
+}
+
+impl Default for LayoutEngine {
+    /// Creates an engine with a cache of [`Layout::DEFAULT_CACHE_SIZE`] entries.
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(Layout::DEFAULT_CACHE_SIZE).unwrap())
+    }
+}
+
+impl fmt::Debug for LayoutEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayoutEngine")
+            .field("cache_stats", &self.cache_stats())
+            .finish()
+    }
+}
+
+impl LayoutEngine {
+    /// Creates an engine with an empty cache holding at most `cache_size` entries.
+    pub fn new(cache_size: NonZeroUsize) -> Self {
+        Self {
+            cache: RefCell::new(LayoutCache::new(cache_size)),
+        }
+    }
+
+    /// Changes the number of entries the cache can hold, dropping the least recently used ones if
+    /// it shrinks.
+    pub fn resize_cache(&self, cache_size: NonZeroUsize) {
+        self.cache.borrow_mut().resize(cache_size);
+    }
+
+    /// Returns the hit, miss and eviction counts and the current size of the cache.
+    pub fn cache_stats(&self) -> LayoutCacheStats {
+        self.cache.borrow().stats()
+    }
+
+    /// Removes all entries from the cache and resets its counters.
+    pub fn clear_cache(&self) {
+        self.cache.borrow_mut().clear();
+    }
+
+    /// Splits the area according to the layout, like [`Layout::split`].
+    pub fn split(&self, layout: &Layout, area: Rect) -> Rects {
+        self.split_with_spacers(layout, area).0
+    }
+
+    /// Splits the area according to the layout and returns the segments and the spacers between
+    /// them, like [`Layout::split_with_spacers`].
+    pub fn split_with_spacers(&self, layout: &Layout, area: Rect) -> (Segments, Spacers) {
+        self.cache
+            .borrow_mut()
+            .get_or_insert((area, layout.clone()), || {
+                layout.try_split(area).expect("failed to split")
+            })
+    }
+
+    /// Splits the area according to the layout into a fixed number of areas, like
+    /// [`Layout::areas`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of constraints is not equal to the length of the returned array.
+    pub fn areas<const N: usize>(&self, layout: &Layout, area: Rect) -> [Rect; N] {
+        let (areas, _) = self.split_with_spacers(layout, area);
+        areas.as_ref().try_into().expect("invalid number of rects")
+    }
+
+    /// Returns the spacers between the areas of the layout, like [`Layout::spacers`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of spacers is not equal to the length of the returned array.
+    pub fn spacers<const N: usize>(&self, layout: &Layout, area: Rect) -> [Rect; N] {
+        let (_, spacers) = self.split_with_spacers(layout, area);
+        spacers
+            .as_ref()
+            .try_into()
+            .expect("invalid number of rects")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Constraint;
+
+    #[test]
+    fn matches_layout() {
+        let engine = LayoutEngine::default();
+        let layout = Layout::horizontal([Constraint::Length(3), Constraint::Fill(1)]).spacing(1);
+        let area = Rect::new(0, 0, 10, 2);
+        assert_eq!(
+            engine.split_with_spacers(&layout, area),
+            layout.split_with_spacers(area)
+        );
+    }
+
+    #[test]
+    fn cache_is_independent() {
+        Layout::clear_cache();
+        let engine = LayoutEngine::new(NonZeroUsize::new(1).unwrap());
+        let layout = Layout::vertical([Constraint::Fill(1); 2]);
+        engine.split(&layout, Rect::new(0, 0, 4, 4));
+        engine.split(&layout, Rect::new(0, 0, 4, 4));
+        engine.split(&layout, Rect::new(0, 0, 4, 6));
+        assert_eq!(
+            engine.cache_stats(),
+            LayoutCacheStats {
+                hits: 1,
+                misses: 2,
+                evictions: 1,
+                len: 1,
+                capacity: 1,
+            }
+        );
+        assert_eq!(Layout::cache_stats().misses, 0);
+
+        engine.resize_cache(NonZeroUsize::new(8).unwrap());
+        engine.clear_cache();
+        assert_eq!(
+            engine.cache_stats(),
+            LayoutCacheStats {
+                capacity: 8,
+                ..LayoutCacheStats::default()
+            }
+        );
+    }
+}
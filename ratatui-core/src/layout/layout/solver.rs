@@ -0,0 +1,475 @@
+use std::{collections::HashMap, fmt, iter};
+
+use cassowary::{strength::REQUIRED, Solver, Variable, WeightedRelation::LE};
+use itertools::Itertools;
+
+use super::{
+    changes_to_rects, configure_area, configure_constraints, configure_fill_constraints,
+    configure_flex_constraints, configure_variable_in_area_constraints, extend_outer_spacers,
+    mirror_rects, round_positions, strengths::ALL_SEGMENT_GROW, Element, Rects, Segments, Spacers,
+};
+use crate::layout::{Constraint, Layout, Rect};
+
+/// A [`Layout`] that keeps its cassowary solver alive between splits.
+///
+/// [`Layout::split`] builds every constraint of the layout from scratch each time the area or the
+/// layout changes (the results are cached, but a cache miss costs a full solve). A
+/// `LayoutSolver` instead keeps the solver and updates it incrementally:
+///
+/// - splitting a new area only replaces the two constraints that pin the ends of the area,
+/// - [`set_constraint`](Self::set_constraint) only replaces the constraints of one segment,
+/// - [`insert_constraint`](Self::insert_constraint), [`push_constraint`](Self::push_constraint) and
+///   [`remove_constraint`](Self::remove_constraint) add or remove the constraints of one segment,
+///   and rebuild the constraints that relate the segments and spacers to each other.
+///
+/// This is useful for layouts that are split with a different area every frame, e.g. the rows of
+/// a resizable dashboard, or that gain and lose segments one at a time. The results are the same
+/// as those of [`Layout::split_with_spacers`], but are not stored in the layout cache. Splitting
+/// the same area twice without changing the constraints returns the previous result without
+/// touching the solver.
+///
+/// When the constraints conflict in a way that several splits are equally good (e.g. two
+/// percentages of the same strength that cannot both be met), the incremental solver may settle
+/// on a different one of these splits than a solve from scratch.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Layout, LayoutSolver, Rect};
+///
+/// let mut solver = LayoutSolver::new(Layout::vertical([Length(1), Fill(1)]));
+/// assert_eq!(
+///     solver.split(Rect::new(0, 0, 10, 5))[..],
+///     [Rect::new(0, 0, 10, 1), Rect::new(0, 1, 10, 4)]
+/// );
+///
+/// solver.push_constraint(Length(2));
+/// assert_eq!(
+///     solver.split(Rect::new(0, 0, 10, 8))[..],
+///     [
+///         Rect::new(0, 0, 10, 1),
+///         Rect::new(0, 1, 10, 5),
+///         Rect::new(0, 6, 10, 2)
+///     ]
+/// );
+/// ```
+pub struct LayoutSolver {
+    layout: Layout,
+    solver: Solver,
+    /// The solver variables, in the same order as in [`Layout::split`]: the start of each spacer
+    /// followed by the start of each segment, and the end of the last spacer.
+    variables: Vec<Variable>,
+    /// The area of the last split, if any.
+    area: Option<Rect>,
+    /// The constraints that keep the ends of the area in order.
+    bounds_constraints: Vec<cassowary::Constraint>,
+    /// The constraints that pin the ends of the area.
+    area_constraints: Vec<cassowary::Constraint>,
+    /// The constraints of each segment.
+    segment_constraints: Vec<Vec<cassowary::Constraint>>,
+    /// The constraints on the spacers that implement the flex and spacing of the layout.
+    flex_constraints: Vec<cassowary::Constraint>,
+    /// The constraints that make the segments grow relative to each other.
+    grow_constraints: Vec<cassowary::Constraint>,
+    /// The result of the last split, if nothing changed since.
+    solution: Option<(Segments, Spacers)>,
+    /// Whether the solver failed to update incrementally and must be rebuilt before solving.
+    ///
+    /// Removing constraints from a cassowary solver can fail because of floating point errors
+    /// with the large strengths used by the layout. The constraints of each group are still kept
+    /// up to date while the solver is invalid, so that it can be rebuilt from them.
+    invalid: bool,
+}
+
+impl LayoutSolver {
+    /// Creates a new solver for the given layout.
+    pub fn new(layout: Layout) -> Self {
+        let variables = iter::repeat_with(Variable::new)
+            .take(layout.constraints.len() * 2 + 2)
+            .collect_vec();
+        let mut solver = Self {
+            layout,
+            solver: Solver::new(),
+            variables,
+            area: None,
+            bounds_constraints: Vec::new(),
+            area_constraints: Vec::new(),
+            segment_constraints: Vec::new(),
+            flex_constraints: Vec::new(),
+            grow_constraints: Vec::new(),
+            solution: None,
+            invalid: false,
+        };
+        let area = solver.area_element();
+        let mut bounds = Vec::new();
+        configure_variable_in_area_constraints(&mut bounds, &[area.start, area.end], area)
+            .expect("failed to configure layout");
+        solver.add_constraints(&bounds);
+        solver.bounds_constraints = bounds;
+        for index in 0..solver.layout.constraints.len() {
+            let constraints = solver.configure_segment(index);
+            solver.segment_constraints.push(constraints);
+        }
+        solver.configure_flex();
+        solver.configure_grow();
+        solver
+    }
+
+    /// Returns the layout, including the changes made to its constraints.
+    pub const fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Splits the given area like [`Layout::split`].
+    pub fn split(&mut self, area: Rect) -> Rects {
+        self.split_with_spacers(area).0
+    }
+
+    /// Splits the given area like [`Layout::split_with_spacers`].
+    pub fn split_with_spacers(&mut self, area: Rect) -> (Segments, Spacers) {
+        if self.area != Some(area) {
+            self.configure_area(area);
+        }
+        if let Some(solution) = &self.solution {
+            return solution.clone();
+        }
+        if self.invalid {
+            self.rebuild();
+        }
+        let outer_area = area.inner(self.layout.margin_in(area));
+        let inner_area = self.layout.inset_outer_spacing(outer_area);
+        let values: HashMap<Variable, f64> = self
+            .variables
+            .iter()
+            .map(|&variable| (variable, self.solver.get_value(variable)))
+            .collect();
+        let positions = round_positions(&values, &self.variables, self.layout.rounding);
+        let direction = self.layout.direction;
+        let mut segment_rects =
+            changes_to_rects(&positions, &self.segments(), inner_area, direction);
+        let mut spacer_rects = changes_to_rects(&positions, &self.spacers(), inner_area, direction);
+        if inner_area != outer_area {
+            extend_outer_spacers(&mut spacer_rects, outer_area, direction);
+        }
+        if self.layout.reversed {
+            mirror_rects(&mut segment_rects, outer_area, direction);
+            mirror_rects(&mut spacer_rects, outer_area, direction);
+        }
+        let solution = (segment_rects, spacer_rects);
+        self.solution = Some(solution.clone());
+        solution
+    }
+
+    /// Replaces the constraint of the segment at `index`, returning the previous constraint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_constraint<C: Into<Constraint>>(
+        &mut self,
+        index: usize,
+        constraint: C,
+    ) -> Constraint {
+        let previous = std::mem::replace(&mut self.layout.constraints[index], constraint.into());
+        let old_constraints = std::mem::take(&mut self.segment_constraints[index]);
+        self.remove_constraints(&old_constraints);
+        self.segment_constraints[index] = self.configure_segment(index);
+        if self.grows(previous) || self.grows(self.layout.constraints[index]) {
+            self.configure_grow();
+        }
+        previous
+    }
+
+    /// Inserts a segment with the given constraint at `index`, shifting the following segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of constraints.
+    pub fn insert_constraint<C: Into<Constraint>>(&mut self, index: usize, constraint: C) {
+        self.layout.constraints.insert(index, constraint.into());
+        if !self.layout.names.is_empty() {
+            self.layout.names.insert(index, String::new());
+        }
+        // the segment is inserted after the start of the spacer that precedes it, which splits
+        // that spacer in two around the new segment
+        let position = index * 2 + 1;
+        self.variables
+            .splice(position..position, iter::repeat_with(Variable::new).take(2));
+        let constraints = self.configure_segment(index);
+        self.segment_constraints.insert(index, constraints);
+        self.configure_flex();
+        self.configure_grow();
+    }
+
+    /// Appends a segment with the given constraint.
+    pub fn push_constraint<C: Into<Constraint>>(&mut self, constraint: C) {
+        self.insert_constraint(self.layout.constraints.len(), constraint);
+    }
+
+    /// Removes the segment at `index`, returning its constraint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_constraint(&mut self, index: usize) -> Constraint {
+        let constraint = self.layout.constraints.remove(index);
+        if index < self.layout.names.len() {
+            self.layout.names.remove(index);
+        }
+        // the constraints between the segments refer to the variables of the removed segment, so
+        // they are removed before the variables are left unconstrained
+        let flex_constraints = std::mem::take(&mut self.flex_constraints);
+        self.remove_constraints(&flex_constraints);
+        let grow_constraints = std::mem::take(&mut self.grow_constraints);
+        self.remove_constraints(&grow_constraints);
+        let constraints = self.segment_constraints.remove(index);
+        self.remove_constraints(&constraints);
+        let position = index * 2 + 1;
+        self.variables.drain(position..position + 2);
+        self.configure_flex();
+        self.configure_grow();
+        constraint
+    }
+
+    /// Returns the element that spans the whole area, from the first to the last variable.
+    fn area_element(&self) -> Element {
+        Element::from((self.variables[0], self.variables[self.variables.len() - 1]))
+    }
+
+    fn segments(&self) -> Vec<Element> {
+        self.variables
+            .iter()
+            .skip(1)
+            .tuples()
+            .map(|(a, b)| Element::from((*a, *b)))
+            .collect()
+    }
+
+    fn spacers(&self) -> Vec<Element> {
+        self.variables
+            .iter()
+            .tuples()
+            .map(|(a, b)| Element::from((*a, *b)))
+            .collect()
+    }
+
+    /// Returns whether the constraint takes part in the constraints that grow the segments.
+    const fn grows(&self, constraint: Constraint) -> bool {
+        constraint.is_fill() || (!self.layout.flex.is_legacy() && constraint.is_min())
+    }
+
+    fn configure_area(&mut self, area: Rect) {
+        let old_constraints = std::mem::take(&mut self.area_constraints);
+        self.remove_constraints(&old_constraints);
+        let outer_area = area.inner(self.layout.margin_in(area));
+        let inner_area = self.layout.inset_outer_spacing(outer_area);
+        let (area_start, area_end) = self.layout.area_bounds(inner_area);
+        let mut constraints = Vec::new();
+        configure_area(&mut constraints, self.area_element(), area_start, area_end)
+            .expect("failed to configure layout");
+        self.add_constraints(&constraints);
+        self.area_constraints = constraints;
+        self.area = Some(area);
+    }
+
+    /// Adds the constraints of the segment at `index` and returns them.
+    fn configure_segment(&mut self, index: usize) -> Vec<cassowary::Constraint> {
+        let area = self.area_element();
+        let start = self.variables[index * 2 + 1];
+        let end = self.variables[index * 2 + 2];
+        let segment = Element::from((start, end));
+        let mut constraints = Vec::new();
+        configure_variable_in_area_constraints(&mut constraints, &[start, end], area)
+            .expect("failed to configure layout");
+        constraints.push(start | LE(REQUIRED) | end);
+        configure_constraints(
+            &mut constraints,
+            area,
+            &[segment],
+            &self.layout.constraints[index..=index],
+            self.layout.flex,
+        )
+        .expect("failed to configure layout");
+        self.add_constraints(&constraints);
+        constraints
+    }
+
+    fn configure_flex(&mut self) {
+        let old_constraints = std::mem::take(&mut self.flex_constraints);
+        self.remove_constraints(&old_constraints);
+        let mut constraints = Vec::new();
+        configure_flex_constraints(
+            &mut constraints,
+            self.area_element(),
+            &self.spacers(),
+            self.layout.flex,
+            self.layout.signed_spacing(),
+        )
+        .expect("failed to configure layout");
+        self.add_constraints(&constraints);
+        self.flex_constraints = constraints;
+    }
+
+    fn configure_grow(&mut self) {
+        let old_constraints = std::mem::take(&mut self.grow_constraints);
+        self.remove_constraints(&old_constraints);
+        let segments = self.segments();
+        let mut constraints = Vec::new();
+        configure_fill_constraints(
+            &mut constraints,
+            &segments,
+            &self.layout.constraints,
+            self.layout.flex,
+        )
+        .expect("failed to configure layout");
+        if !self.layout.flex.is_legacy() {
+            for (left, right) in segments.iter().tuple_windows() {
+                constraints.push(left.has_size(right, ALL_SEGMENT_GROW));
+            }
+        }
+        self.add_constraints(&constraints);
+        self.grow_constraints = constraints;
+    }
+
+    fn add_constraints(&mut self, constraints: &[cassowary::Constraint]) {
+        self.solution = None;
+        if !self.invalid && self.solver.add_constraints(constraints).is_err() {
+            self.invalid = true;
+        }
+    }
+
+    fn remove_constraints(&mut self, constraints: &[cassowary::Constraint]) {
+        self.solution = None;
+        if self.invalid {
+            return;
+        }
+        for constraint in constraints {
+            if self.solver.remove_constraint(constraint).is_err() {
+                self.invalid = true;
+                return;
+            }
+        }
+    }
+
+    /// Creates a new solver with all the constraints of the layout.
+    fn rebuild(&mut self) {
+        self.solver = Solver::new();
+        // pinning the area first avoids unbounded intermediate problems, like in `Layout::split`
+        let groups = [&self.area_constraints, &self.bounds_constraints]
+            .into_iter()
+            .chain(&self.segment_constraints)
+            .chain([&self.flex_constraints, &self.grow_constraints]);
+        for constraints in groups {
+            self.solver
+                .add_constraints(constraints)
+                .expect("failed to split");
+        }
+        self.invalid = false;
+    }
+}
+
+impl fmt::Debug for LayoutSolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayoutSolver")
+            .field("layout", &self.layout)
+            .field("area", &self.area)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<Layout> for LayoutSolver {
+    fn from(layout: Layout) -> Self {
+        Self::new(layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::layout::{
+        Constraint::{self, *},
+        Flex,
+    };
+
+    fn assert_same_split(solver: &mut LayoutSolver, area: Rect) {
+        let expected = solver.layout().clone().split_with_spacers(area);
+        assert_eq!(solver.split_with_spacers(area), expected, "area {area}");
+    }
+
+    #[rstest]
+    #[case::legacy(Flex::Legacy)]
+    #[case::start(Flex::Start)]
+    #[case::center(Flex::Center)]
+    #[case::end(Flex::End)]
+    #[case::space_around(Flex::SpaceAround)]
+    #[case::space_between(Flex::SpaceBetween)]
+    fn split_matches_layout(#[case] flex: Flex) {
+        let constraints = [Length(3), Percentage(20), Fill(1), Min(2), Max(4), Fill(2)];
+        let layout = Layout::horizontal(constraints)
+            .flex(flex)
+            .spacing(1)
+            .margin(1);
+        let mut solver = LayoutSolver::new(layout);
+        for width in [0, 1, 10, 25, 40, 100, 40, 7] {
+            assert_same_split(&mut solver, Rect::new(2, 1, width, 3));
+        }
+    }
+
+    #[test]
+    fn reversed_matches_layout() {
+        let layout = Layout::vertical([Length(3), Fill(1), Max(4)])
+            .spacing(1)
+            .outer_spacing(1)
+            .reversed(true);
+        let mut solver = LayoutSolver::new(layout);
+        for height in [2, 5, 12, 30] {
+            assert_same_split(&mut solver, Rect::new(0, 2, 4, height));
+        }
+    }
+
+    #[rstest]
+    #[case::legacy(Flex::Legacy)]
+    #[case::start(Flex::Start)]
+    #[case::space_between(Flex::SpaceBetween)]
+    fn edit_constraints_matches_layout(#[case] flex: Flex) {
+        let area = Rect::new(0, 0, 50, 1);
+        let mut solver = LayoutSolver::new(Layout::horizontal([Length(5), Fill(1)]).flex(flex));
+        assert_same_split(&mut solver, area);
+
+        solver.push_constraint(Percentage(30));
+        assert_same_split(&mut solver, area);
+        solver.insert_constraint(0, Fill(2));
+        assert_same_split(&mut solver, area);
+        assert_eq!(solver.set_constraint(2, Min(4)), Fill(1));
+        assert_same_split(&mut solver, area);
+        assert_eq!(solver.remove_constraint(1), Length(5));
+        assert_same_split(&mut solver, area);
+        assert_eq!(
+            solver.layout().clone(),
+            Layout::horizontal([Fill(2), Min(4), Percentage(30)]).flex(flex)
+        );
+        while !solver.layout().constraints.is_empty() {
+            solver.remove_constraint(0);
+            assert_same_split(&mut solver, area);
+        }
+        solver.push_constraint(Constraint::Ratio(1, 3));
+        assert_same_split(&mut solver, Rect::new(0, 0, 9, 1));
+    }
+
+    #[test]
+    fn insert_keeps_names() {
+        let mut solver = LayoutSolver::new(
+            Layout::default().constraints_named([("a", Length(1)), ("b", Length(1))]),
+        );
+        solver.insert_constraint(1, Length(2));
+        let areas = solver.layout().split_named(Rect::new(0, 0, 1, 10));
+        assert_eq!(areas["a"], Rect::new(0, 0, 1, 1));
+        assert_eq!(areas["b"], Rect::new(0, 3, 1, 1));
+        solver.remove_constraint(0);
+        let areas = solver.layout().split_named(Rect::new(0, 0, 1, 10));
+        assert!(!areas.contains("a"));
+        assert_eq!(areas["b"], Rect::new(0, 2, 1, 1));
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, iter};
+
+use cassowary::{
+    strength::REQUIRED,
+    AddConstraintError, Expression, Solver, Variable,
+    WeightedRelation::{EQ, GE, LE},
+};
+use itertools::Itertools;
+
+use super::{
+    changes_to_rects, mirror_rects, round_positions, strengths::SPACER_SIZE_EQ, ConstraintSink,
+    Element, Layout, FLOAT_PRECISION_MULTIPLIER,
+};
+use crate::layout::{Direction, LayoutTree, Margin, Rect};
+
+/// The factor applied to the strength of the constraints of a layout for each level that it is
+/// nested in a [`LayoutTree`].
+///
+/// This keeps the preferences of a layout (e.g. its `Fill` segments growing as much as possible)
+/// ahead of those of its children, while the sizes that a child asks for (e.g. `Length` or `Min`
+/// segments) are still stronger than the preferences of its parent.
+const NESTED_STRENGTH_FACTOR: f64 = 0.01;
+
+/// The deepest level whose strengths are scaled further, so that the weakest constraints of
+/// deeply nested layouts do not become too small for the solver to tell apart from zero.
+const MAX_SCALED_DEPTH: i32 = 3;
+
+/// Solves all the layouts of a tree as a single cassowary system and returns the leaf segments in
+/// depth-first order, together with their names (empty for unnamed segments).
+///
+/// The area of each child layout is tied to the segment of its parent, so the constraints of a
+/// child take part in sizing that segment and, through it, the siblings of the segment and their
+/// own children. E.g. a child made of two `Length(8)` segments makes the `Fill` segment it is
+/// attached to grow to 16 cells at the expense of the other `Fill` segments of the parent.
+///
+/// The solved positions of each layout are rounded to cells within the area that its parent
+/// segment received after rounding, so that the leaves always fit in their parents.
+pub(in crate::layout) fn solve_tree(
+    tree: &LayoutTree,
+    area: Rect,
+) -> Result<Vec<(Rect, &str)>, AddConstraintError> {
+    let mut solver = Solver::new();
+    let layout = tree.layout();
+    let inner_area = layout.inset_outer_spacing(area.inner(layout.margin_in(area)));
+    let node = Node::configure(&mut solver, tree, Edges::from(inner_area), 0)?;
+    // `solver.fetch_changes()` can only be called once per solve
+    let changes: HashMap<Variable, f64> = solver.fetch_changes().iter().copied().collect();
+    let mut leaves = Vec::new();
+    node.collect_leaves(area, &changes, &mut leaves);
+    Ok(leaves)
+}
+
+/// Adds constraints to a solver with their strength scaled by a factor, except for the required
+/// constraints.
+struct ScaledSink<'a> {
+    solver: &'a mut Solver,
+    factor: f64,
+}
+
+impl ConstraintSink for ScaledSink<'_> {
+    fn add_constraint(
+        &mut self,
+        constraint: cassowary::Constraint,
+    ) -> Result<(), AddConstraintError> {
+        let strength = constraint.strength();
+        if strength >= REQUIRED {
+            return self.solver.add_constraint(constraint);
+        }
+        self.solver.add_constraint(cassowary::Constraint::new(
+            constraint.expr().clone(),
+            constraint.op(),
+            strength * self.factor,
+        ))
+    }
+}
+
+/// The edges of an area, as expressions of the solver variables.
+#[derive(Debug, Clone)]
+struct Edges {
+    left: Expression,
+    right: Expression,
+    top: Expression,
+    bottom: Expression,
+}
+
+impl From<Rect> for Edges {
+    fn from(area: Rect) -> Self {
+        let edge =
+            |value: u16| Expression::from_constant(f64::from(value) * FLOAT_PRECISION_MULTIPLIER);
+        Self {
+            left: edge(area.left()),
+            right: edge(area.right()),
+            top: edge(area.top()),
+            bottom: edge(area.bottom()),
+        }
+    }
+}
+
+impl Edges {
+    /// Returns the area shrunk by the margin of the layout.
+    fn inner(&self, layout: &Layout) -> Self {
+        let (horizontal, vertical) = layout.margin_percent.map_or_else(
+            || {
+                let margin = layout.margin;
+                (
+                    Expression::from_constant(
+                        f64::from(margin.horizontal) * FLOAT_PRECISION_MULTIPLIER,
+                    ),
+                    Expression::from_constant(
+                        f64::from(margin.vertical) * FLOAT_PRECISION_MULTIPLIER,
+                    ),
+                )
+            },
+            |percent| {
+                let scale = f64::from(percent) / 100.0;
+                (
+                    (self.right.clone() - self.left.clone()) * scale,
+                    (self.bottom.clone() - self.top.clone()) * scale,
+                )
+            },
+        );
+        Self {
+            left: self.left.clone() + horizontal.clone(),
+            right: self.right.clone() - horizontal,
+            top: self.top.clone() + vertical.clone(),
+            bottom: self.bottom.clone() - vertical,
+        }
+    }
+
+    /// Returns the start and end of the area in the given direction.
+    fn bounds(&self, direction: Direction) -> (Expression, Expression) {
+        match direction {
+            Direction::Horizontal => (self.left.clone(), self.right.clone()),
+            Direction::Vertical => (self.top.clone(), self.bottom.clone()),
+        }
+    }
+
+    /// Returns the area with the start and end in the given direction replaced by an element.
+    fn with_bounds(&self, direction: Direction, element: Element) -> Self {
+        match direction {
+            Direction::Horizontal => Self {
+                left: element.start.into(),
+                right: element.end.into(),
+                ..self.clone()
+            },
+            Direction::Vertical => Self {
+                top: element.start.into(),
+                bottom: element.end.into(),
+                ..self.clone()
+            },
+        }
+    }
+}
+
+/// A layout of the tree together with the solver variables of its spacers and segments.
+struct Node<'a> {
+    tree: &'a LayoutTree,
+    /// The solver variables, in the same order as in [`Layout::split`].
+    variables: Vec<Variable>,
+    segments: Vec<Element>,
+    /// The children of the layout, keyed on the index of the segment they are attached to.
+    children: HashMap<usize, Self>,
+}
+
+impl<'a> Node<'a> {
+    /// Adds the constraints of the layout of the tree and of all its children to the solver.
+    ///
+    /// The root layout (at depth 0) is given the area inside its margin and outer spacing, which
+    /// are already known. The other layouts are given the area of the segment they are attached
+    /// to, and their margin and outer spacing are strong but not required, so that a margin that
+    /// does not fit collapses the layout instead of making the whole tree unsolvable.
+    fn configure(
+        solver: &mut Solver,
+        tree: &'a LayoutTree,
+        edges: Edges,
+        depth: i32,
+    ) -> Result<Self, AddConstraintError> {
+        let layout = tree.layout();
+        let direction = layout.direction;
+        let mut sink = ScaledSink {
+            solver,
+            factor: NESTED_STRENGTH_FACTOR.powi(depth.min(MAX_SCALED_DEPTH)),
+        };
+
+        let variable_count = layout.constraints.len() * 2 + 2;
+        let variables = iter::repeat_with(Variable::new)
+            .take(variable_count)
+            .collect_vec();
+        let spacers = variables
+            .iter()
+            .tuples()
+            .map(|(a, b)| Element::from((*a, *b)))
+            .collect_vec();
+        let segments = variables
+            .iter()
+            .skip(1)
+            .tuples()
+            .map(|(a, b)| Element::from((*a, *b)))
+            .collect_vec();
+
+        let first = *variables.first().unwrap();
+        let last = *variables.last().unwrap();
+        let (start, end) = edges.bounds(direction);
+        let has_margin = layout.margin != Margin::new(0, 0)
+            || layout.margin_percent.is_some()
+            || layout.outer_spacing > 0;
+        let edges = if depth == 0 || !has_margin {
+            sink.add_constraint(first | EQ(REQUIRED) | start)?;
+            sink.add_constraint(last | EQ(REQUIRED) | end)?;
+            edges
+        } else {
+            let inner = edges.inner(layout);
+            let (inner_start, inner_end) = inner.bounds(direction);
+            let outer_spacing = f64::from(layout.outer_spacing) * FLOAT_PRECISION_MULTIPLIER;
+            sink.add_constraint(first | GE(REQUIRED) | start)?;
+            sink.add_constraint(last | LE(REQUIRED) | end)?;
+            sink.add_constraint(first | EQ(SPACER_SIZE_EQ) | (inner_start + outer_spacing))?;
+            sink.add_constraint(last | EQ(SPACER_SIZE_EQ) | (inner_end - outer_spacing))?;
+            inner
+        };
+        layout.configure_elements(&mut sink, &variables, &spacers, &segments)?;
+
+        let mut children = HashMap::new();
+        for (&index, child) in tree.children() {
+            let Some(&segment) = segments.get(index) else {
+                continue;
+            };
+            let child_edges = edges.with_bounds(direction, segment);
+            children.insert(
+                index,
+                Self::configure(sink.solver, child, child_edges, depth + 1)?,
+            );
+        }
+
+        Ok(Self {
+            tree,
+            variables,
+            segments,
+            children,
+        })
+    }
+
+    /// Converts the solved positions of the layout to the areas of its leaves, in the area that
+    /// the layout received.
+    fn collect_leaves(
+        &self,
+        area: Rect,
+        changes: &HashMap<Variable, f64>,
+        leaves: &mut Vec<(Rect, &'a str)>,
+    ) {
+        let layout = self.tree.layout();
+        let outer_area = area.inner(layout.margin_in(area));
+        let inner_area = layout.inset_outer_spacing(outer_area);
+
+        // The area received from the parent can be a cell larger or smaller than the solved one
+        // because of the rounding of the parent, or in another place for a reversed parent, so
+        // the solved positions are mapped onto it before they are rounded.
+        let value = |variable: &Variable| changes.get(variable).copied().unwrap_or_default();
+        let solved_start = value(self.variables.first().unwrap());
+        let solved_end = value(self.variables.last().unwrap());
+        let (start, end) = layout.area_bounds(inner_area);
+        let scale = if solved_end > solved_start {
+            (end - start) / (solved_end - solved_start)
+        } else {
+            0.0
+        };
+        let mapped = self
+            .variables
+            .iter()
+            .map(|variable| (*variable, start + (value(variable) - solved_start) * scale))
+            .collect();
+
+        let positions = round_positions(&mapped, &self.variables, layout.rounding);
+        let mut segments =
+            changes_to_rects(&positions, &self.segments, inner_area, layout.direction);
+        if layout.reversed {
+            mirror_rects(&mut segments, outer_area, layout.direction);
+        }
+
+        let names = layout.names();
+        for (index, segment) in segments.iter().enumerate() {
+            if let Some(child) = self.children.get(&index) {
+                child.collect_leaves(*segment, changes, leaves);
+                continue;
+            }
+            let name = names.get(index).map_or("", String::as_str);
+            leaves.push((*segment, name));
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::{cell::RefCell, collections::BTreeMap, num::NonZeroUsize, rc::Rc};
+
+use lru::LruCache;
+
+use super::layout::solve_tree;
+use crate::layout::{Layout, NamedAreas, Rect};
+
+type Cache = LruCache<(Rect, LayoutTree), (Rc<[Rect]>, NamedAreas)>;
+
+thread_local! {
+    static LAYOUT_TREE_CACHE: RefCell<Cache> = RefCell::new(Cache::new(
+        NonZeroUsize::new(LayoutTree::CACHE_SIZE).unwrap(),
+    ));
+}
+
+/// A tree of nested [`Layout`]s that is solved in a single call.
+///
+/// Each segment of a layout can have a child layout attached to it, which splits the area of that
+/// segment further. [`LayoutTree::solve`] returns the areas of all the leaf segments (segments
+/// without a child) at once, and [`LayoutTree::solve_named`] returns the leaves that were named
+/// with [`Layout::constraints_named`].
+///
+/// All the layouts of the tree are solved together as one constraint system, so the constraints of
+/// a child layout also size the segment it is attached to, and in turn the siblings of that
+/// segment. E.g. a `Fill(1)` segment whose child needs two `Length(8)` rows grows to 16 rows,
+/// while splitting the layouts one by one would give it half of the area and cut the second row
+/// short. The constraints of a layout still take precedence over those of the layouts nested in
+/// it, e.g. a `Length` segment keeps its length even if the margin of its child does not fit.
+///
+/// The results are stored in a thread-local cache keyed on the whole tree and the area, so
+/// solving the same tree again does not look up the individual layouts in the [`Layout`] cache.
+/// This cache follows the [`Layout`] cache: it is cleared by [`Layout::clear_cache`] and bypassed
+/// while [`Layout::set_cache_enabled`] is disabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Direction, Layout, LayoutTree, Rect};
+///
+/// let tree = LayoutTree::new(
+///     Layout::default().constraints_named([("header", Length(1)), ("main", Fill(1))]),
+/// )
+/// .child(
+///     1,
+///     Layout::default()
+///         .direction(Direction::Horizontal)
+///         .constraints_named([("sidebar", Length(10)), ("body", Fill(1))]),
+/// );
+///
+/// let areas = tree.solve_named(Rect::new(0, 0, 40, 10));
+/// assert_eq!(areas["header"], Rect::new(0, 0, 40, 1));
+/// assert_eq!(areas["sidebar"], Rect::new(0, 1, 10, 9));
+/// assert_eq!(areas["body"], Rect::new(10, 1, 30, 9));
+/// assert!(!areas.contains("main"));
+///
+/// let leaves = tree.solve(Rect::new(0, 0, 40, 10));
+/// assert_eq!(leaves.len(), 3);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LayoutTree {
+    layout: Layout,
+    children: BTreeMap<usize, Self>,
+}
+
+impl LayoutTree {
+    /// The number of solved trees kept in the thread-local cache.
+    pub const CACHE_SIZE: usize = 64;
+
+    /// Creates a new tree with the given root layout and no children.
+    pub const fn new(layout: Layout) -> Self {
+        Self {
+            layout,
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// Attaches a child layout or tree to the segment of the root layout at the given index.
+    ///
+    /// Attaching a child to a segment that already has one replaces it. Children attached to an
+    /// index greater than the number of constraints of the layout are ignored.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn child<T: Into<Self>>(mut self, index: usize, child: T) -> Self {
+        self.children.insert(index, child.into());
+        self
+    }
+
+    /// Returns the root layout of the tree.
+    pub const fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Returns the children of the tree, keyed on the index of the segment they are attached to.
+    pub const fn children(&self) -> &BTreeMap<usize, Self> {
+        &self.children
+    }
+
+    /// Solves the tree and returns the areas of all the leaf segments.
+    ///
+    /// The leaves are returned in depth-first order, i.e. the leaves of the child attached to a
+    /// segment replace that segment in the list of areas of its parent.
+    pub fn solve(&self, area: Rect) -> Rc<[Rect]> {
+        self.solve_cached(area).0
+    }
+
+    /// Solves the tree and returns the areas of the named leaf segments.
+    ///
+    /// Names are given to segments with [`Layout::constraints_named`]. A named segment that has a
+    /// child is not included, only the leaves of the child are. Segments named with an empty
+    /// string are treated as unnamed.
+    pub fn solve_named(&self, area: Rect) -> NamedAreas {
+        self.solve_cached(area).1
+    }
+
+    /// Removes all the solved trees from the cache of the current thread.
+    pub(super) fn clear_cache() {
+        LAYOUT_TREE_CACHE.with_borrow_mut(Cache::clear);
+    }
+
+    fn solve_cached(&self, area: Rect) -> (Rc<[Rect]>, NamedAreas) {
+        if !Layout::is_cache_enabled() {
+            return self.solve_uncached(area);
+        }
+        LAYOUT_TREE_CACHE.with_borrow_mut(|c| {
+            c.get_or_insert((area, self.clone()), || self.solve_uncached(area))
+                .clone()
+        })
+    }
+
+    fn solve_uncached(&self, area: Rect) -> (Rc<[Rect]>, NamedAreas) {
+        let solved = solve_tree(self, area).expect("failed to solve the layout tree");
+        let named_leaves = solved
+            .iter()
+            .filter(|(_, name)| !name.is_empty())
+            .map(|(leaf, name)| ((*name).to_string(), *leaf))
+            .collect();
+        let leaves = solved.into_iter().map(|(leaf, _)| leaf).collect();
+        (leaves, named_leaves)
+    }
+}
+
+impl From<Layout> for LayoutTree {
+    fn from(layout: Layout) -> Self {
+        Self::new(layout)
+    }
+}
+
+/// Declares a [`LayoutTree`] of nested vertical and horizontal splits in a single expression.
+///
+/// A layout is written as its direction (`vertical` or `horizontal`) followed by a list of
+/// segments in brackets. Each segment is one of:
+///
+/// - `"name": constraint`, a leaf whose area is returned under that name by
+///   [`LayoutTree::solve_named`],
+/// - `constraint`, an unnamed leaf, e.g. for spacing between other segments,
+/// - `constraint => direction [...]`, a segment that is split further by a nested layout.
+///
+/// The constraints are any expression that converts into a [`Constraint`]. Methods of [`Layout`]
+/// can be applied to each layout by chaining them after the direction, e.g.
+/// `horizontal.spacing(1)[...]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Rect};
+///
+/// let tree = ratatui_core::layout!(vertical [
+///     "header": Length(1),
+///     Fill(1) => horizontal.spacing(1) [
+///         "sidebar": Length(10),
+///         "body": Fill(1),
+///     ],
+///     "footer": Length(1),
+/// ]);
+///
+/// let areas = tree.solve_named(Rect::new(0, 0, 40, 10));
+/// assert_eq!(areas["header"], Rect::new(0, 0, 40, 1));
+/// assert_eq!(areas["sidebar"], Rect::new(0, 1, 10, 8));
+/// assert_eq!(areas["body"], Rect::new(11, 1, 29, 8));
+/// assert_eq!(areas["footer"], Rect::new(0, 9, 40, 1));
+/// ```
+///
+/// [`Constraint`]: crate::layout::Constraint
+/// [`Layout`]: crate::layout::Layout
+/// [`LayoutTree`]: crate::layout::LayoutTree
+/// [`LayoutTree::solve_named`]: crate::layout::LayoutTree::solve_named
+#[macro_export]
+macro_rules! layout {
+    (@segments $constraints:ident $children:ident) => {};
+    (@segments $constraints:ident $children:ident
+        $name:literal : $constraint:expr $(, $($rest:tt)*)?
+    ) => {
+        $constraints.push(($name, $constraint.into()));
+        $crate::layout!(@segments $constraints $children $($($rest)*)?);
+    };
+    (@segments $constraints:ident $children:ident
+        $constraint:expr => $direction:ident $(.$method:ident($($args:tt)*))* [$($segments:tt)*]
+        $(, $($rest:tt)*)?
+    ) => {
+        $children.push((
+            $constraints.len(),
+            $crate::layout!($direction $(.$method($($args)*))* [$($segments)*]),
+        ));
+        $constraints.push(("", $constraint.into()));
+        $crate::layout!(@segments $constraints $children $($($rest)*)?);
+    };
+    (@segments $constraints:ident $children:ident $constraint:expr $(, $($rest:tt)*)?) => {
+        $constraints.push(("", $constraint.into()));
+        $crate::layout!(@segments $constraints $children $($($rest)*)?);
+    };
+    ($direction:ident $(.$method:ident($($args:tt)*))* [$($segments:tt)*]) => {{
+        let mut constraints: ::std::vec::Vec<(&str, $crate::layout::Constraint)> =
+            ::std::vec::Vec::new();
+        #[allow(unused_mut)]
+        let mut children: ::std::vec::Vec<(usize, $crate::layout::LayoutTree)> =
+            ::std::vec::Vec::new();
+        $crate::layout!(@segments constraints children $($segments)*);
+        let layout = $crate::layout::Layout::$direction(
+            ::std::iter::empty::<$crate::layout::Constraint>(),
+        )
+        .constraints_named(constraints)
+        $(.$method($($args)*))*;
+        children
+            .into_iter()
+            .fold($crate::layout::LayoutTree::new(layout), |tree, (index, child)| {
+                tree.child(index, child)
+            })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{
+        Constraint::{Fill, Length, Percentage},
+        Direction,
+    };
+
+    #[test]
+    fn solve_without_children() {
+        let tree = LayoutTree::new(Layout::vertical([Length(1), Fill(1)]));
+        let area = Rect::new(0, 0, 10, 10);
+        assert_eq!(
+            tree.solve(area)[..],
+            Layout::vertical([Length(1), Fill(1)]).split(area)[..]
+        );
+        assert!(tree.solve_named(area).is_empty());
+    }
+
+    #[test]
+    fn solve_nested() {
+        let tree = LayoutTree::new(Layout::vertical([Fill(1), Fill(1)]))
+            .child(0, Layout::horizontal([Fill(1), Fill(1)]))
+            .child(
+                1,
+                LayoutTree::new(Layout::horizontal([Length(2), Fill(1)]))
+                    .child(1, Layout::vertical([Fill(1), Fill(1)])),
+            );
+        assert_eq!(
+            tree.solve(Rect::new(0, 0, 8, 4))[..],
+            [
+                Rect::new(0, 0, 4, 2),
+                Rect::new(4, 0, 4, 2),
+                Rect::new(0, 2, 2, 2),
+                Rect::new(2, 2, 6, 1),
+                Rect::new(2, 3, 6, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn child_constraints_size_sibling_subtrees() {
+        let tree = LayoutTree::new(Layout::vertical([Fill(1), Fill(1)]))
+            .child(0, Layout::vertical([Length(8), Length(8)]))
+            .child(1, Layout::horizontal([Fill(1), Fill(1)]));
+        assert_eq!(
+            tree.solve(Rect::new(0, 0, 4, 20))[..],
+            [
+                Rect::new(0, 0, 4, 8),
+                Rect::new(0, 8, 4, 8),
+                Rect::new(0, 16, 2, 4),
+                Rect::new(2, 16, 2, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_margin_larger_than_segment() {
+        let tree = LayoutTree::new(Layout::vertical([Length(1), Fill(1)]))
+            .child(0, Layout::vertical([Fill(1)]).margin(2));
+        assert_eq!(
+            tree.solve(Rect::new(0, 0, 10, 10))[..],
+            [Rect::new(0, 0, 0, 0), Rect::new(0, 1, 10, 9)]
+        );
+    }
+
+    #[test]
+    fn cache_follows_layout_cache() {
+        let tree = LayoutTree::new(Layout::vertical([Fill(1), Fill(1)]));
+        let area = Rect::new(0, 0, 4, 4);
+        let cached_len = || LAYOUT_TREE_CACHE.with_borrow(Cache::len);
+        Layout::clear_cache();
+        assert_eq!(cached_len(), 0);
+        tree.solve(area);
+        assert_eq!(cached_len(), 1);
+        Layout::clear_cache();
+        assert_eq!(cached_len(), 0);
+
+        Layout::set_cache_enabled(false);
+        assert_eq!(
+            tree.solve(area)[..],
+            [Rect::new(0, 0, 4, 2), Rect::new(0, 2, 4, 2)]
+        );
+        assert_eq!(cached_len(), 0);
+        Layout::set_cache_enabled(true);
+    }
+
+    #[test]
+    fn child_out_of_range_is_ignored() {
+        let tree =
+            LayoutTree::new(Layout::vertical([Fill(1)])).child(3, Layout::vertical([Fill(1)]));
+        assert_eq!(
+            tree.solve(Rect::new(0, 0, 2, 2))[..],
+            [Rect::new(0, 0, 2, 2)]
+        );
+    }
+
+    #[test]
+    fn layout_macro() {
+        let tree = crate::layout!(horizontal [
+            "left": Percentage(50),
+            Length(1),
+            Fill(1) => vertical.margin(1) [
+                "top": Length(1),
+                "bottom": Fill(1),
+            ],
+        ]);
+        let expected = LayoutTree::new(
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints_named([("left", Percentage(50)), ("", Length(1)), ("", Fill(1))]),
+        )
+        .child(
+            2,
+            Layout::default()
+                .constraints_named([("top", Length(1)), ("bottom", Fill(1))])
+                .margin(1),
+        );
+        assert_eq!(tree, expected);
+
+        let areas = tree.solve_named(Rect::new(0, 0, 10, 5));
+        assert_eq!(
+            areas.iter().collect::<Vec<_>>(),
+            [
+                ("left", Rect::new(0, 0, 5, 5)),
+                ("top", Rect::new(7, 1, 2, 1)),
+                ("bottom", Rect::new(7, 2, 2, 2)),
+            ]
+        );
+    }
+}
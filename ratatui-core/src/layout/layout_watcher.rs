@@ -0,0 +1,157 @@
+use std::rc::Rc;
+
+use crate::layout::{Layout, Rect, Size};
+
+/// Detects which segments of a layout changed size since the previous solve.
+///
+/// Applications that keep a cache per pane (e.g. wrapped text or a rendered image) can use a
+/// `LayoutWatcher` to invalidate only the caches of the panes whose size changed. The watcher
+/// remembers the areas of the previous call to [`LayoutWatcher::split`] and reports the indices
+/// of the segments whose size is different in the returned [`LayoutChanges`]. Segments that only
+/// moved without changing size are not reported.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Layout, LayoutWatcher, Rect};
+///
+/// let layout = Layout::horizontal([Length(10), Fill(1)]);
+/// let mut watcher = LayoutWatcher::new();
+///
+/// // everything is reported as changed the first time
+/// let changes = watcher.split(&layout, Rect::new(0, 0, 40, 10));
+/// assert_eq!(changes.changed(), [0, 1]);
+///
+/// // only the second segment changes size when the area gets wider
+/// let changes = watcher.split(&layout, Rect::new(0, 0, 50, 10));
+/// assert_eq!(changes.changed(), [1]);
+/// assert_eq!(changes.areas()[1], Rect::new(10, 0, 40, 10));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct LayoutWatcher {
+    previous: Option<Rc<[Rect]>>,
+}
+
+/// The result of a [`LayoutWatcher::split`], containing the areas and the segments that changed
+/// size.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LayoutChanges {
+    areas: Rc<[Rect]>,
+    changed: Vec<usize>,
+}
+
+impl LayoutWatcher {
+    /// Creates a new watcher that has not seen any solve yet.
+    pub const fn new() -> Self {
+        Self { previous: None }
+    }
+
+    /// Splits the area with the given layout and compares the result to the previous split.
+    ///
+    /// All the segments are reported as changed the first time, after [`LayoutWatcher::reset`],
+    /// and when the number of segments changes.
+    pub fn split(&mut self, layout: &Layout, area: Rect) -> LayoutChanges {
+        self.update(layout.split(area))
+    }
+
+    /// Compares the given areas to the previous ones and remembers them for the next call.
+    ///
+    /// This is useful to watch areas that were not computed by a single [`Layout`], e.g. the
+    /// leaves of a [`LayoutTree`](crate::layout::LayoutTree).
+    pub fn update<A: Into<Rc<[Rect]>>>(&mut self, areas: A) -> LayoutChanges {
+        let areas = areas.into();
+        let changed = match &self.previous {
+            Some(previous) if previous.len() == areas.len() => previous
+                .iter()
+                .zip(areas.iter())
+                .enumerate()
+                .filter(|(_, (previous, area))| previous.as_size() != area.as_size())
+                .map(|(index, _)| index)
+                .collect(),
+            _ => (0..areas.len()).collect(),
+        };
+        self.previous = Some(Rc::clone(&areas));
+        LayoutChanges { areas, changed }
+    }
+
+    /// Forgets the previous areas, so that all segments are reported as changed on the next call.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl LayoutChanges {
+    /// Returns the areas of the segments.
+    pub fn areas(&self) -> &[Rect] {
+        &self.areas
+    }
+
+    /// Returns the indices of the segments that changed size.
+    pub fn changed(&self) -> &[usize] {
+        &self.changed
+    }
+
+    /// Returns `true` if the segment at the given index changed size.
+    pub fn is_changed(&self, index: usize) -> bool {
+        self.changed.contains(&index)
+    }
+
+    /// Returns `true` if any segment changed size.
+    pub fn any_changed(&self) -> bool {
+        !self.changed.is_empty()
+    }
+
+    /// Returns the size of the segment at the given index, if it exists.
+    pub fn size(&self, index: usize) -> Option<Size> {
+        self.areas.get(index).map(|area| area.as_size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Constraint::{Fill, Length};
+
+    #[test]
+    fn first_split_changes_everything() {
+        let mut watcher = LayoutWatcher::new();
+        let changes = watcher.split(
+            &Layout::vertical([Length(1), Fill(1)]),
+            Rect::new(0, 0, 5, 5),
+        );
+        assert_eq!(changes.changed(), [0, 1]);
+        assert!(changes.any_changed());
+    }
+
+    #[test]
+    fn same_area_changes_nothing() {
+        let layout = Layout::vertical([Length(1), Fill(1)]);
+        let mut watcher = LayoutWatcher::new();
+        watcher.split(&layout, Rect::new(0, 0, 5, 5));
+        let changes = watcher.split(&layout, Rect::new(3, 3, 5, 5));
+        assert!(!changes.any_changed());
+        assert_eq!(changes.size(1), Some(Size::new(5, 4)));
+    }
+
+    #[test]
+    fn resize_changes_affected_segments() {
+        let layout = Layout::vertical([Length(1), Fill(1)]);
+        let mut watcher = LayoutWatcher::new();
+        watcher.split(&layout, Rect::new(0, 0, 5, 5));
+        let changes = watcher.split(&layout, Rect::new(0, 0, 5, 8));
+        assert_eq!(changes.changed(), [1]);
+        assert!(!changes.is_changed(0));
+        assert!(changes.is_changed(1));
+    }
+
+    #[test]
+    fn segment_count_change_and_reset() {
+        let mut watcher = LayoutWatcher::new();
+        watcher.update(vec![Rect::new(0, 0, 1, 1)]);
+        let changes = watcher.update(vec![Rect::new(0, 0, 1, 1), Rect::new(1, 0, 1, 1)]);
+        assert_eq!(changes.changed(), [0, 1]);
+        watcher.reset();
+        let changes = watcher.update(vec![Rect::new(0, 0, 1, 1), Rect::new(1, 0, 1, 1)]);
+        assert_eq!(changes.changed(), [0, 1]);
+    }
+}
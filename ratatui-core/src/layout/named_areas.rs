@@ -0,0 +1,127 @@
+use std::ops::Index;
+
+use crate::layout::Rect;
+
+/// The areas of a [`Layout`] split, looked up by the names given to their constraints.
+///
+/// This is returned by [`Layout::split_named`]. Indexing with a name that does not exist panics,
+/// use [`NamedAreas::get`] to handle missing names.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+///
+/// let areas = Layout::default()
+///     .constraints_named([("header", Length(1)), ("body", Fill(1))])
+///     .split_named(Rect::new(0, 0, 10, 10));
+/// assert_eq!(areas["body"], Rect::new(0, 1, 10, 9));
+/// for (name, area) in areas.iter() {
+///     println!("{name}: {area}");
+/// }
+/// ```
+///
+/// [`Layout`]: crate::layout::Layout
+/// [`Layout::split_named`]: crate::layout::Layout::split_named
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NamedAreas {
+    areas: Vec<(String, Rect)>,
+}
+
+impl NamedAreas {
+    /// Returns the area with the given name, or `None` if there is no such area.
+    pub fn get(&self, name: &str) -> Option<Rect> {
+        self.areas
+            .iter()
+            .find(|(area_name, _)| area_name == name)
+            .map(|(_, area)| *area)
+    }
+
+    /// Returns `true` if there is an area with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Returns an iterator over the names and areas, in the order of the constraints.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Rect)> {
+        self.areas.iter().map(|(name, area)| (name.as_str(), *area))
+    }
+
+    /// Returns the number of areas.
+    pub fn len(&self) -> usize {
+        self.areas.len()
+    }
+
+    /// Returns `true` if there are no areas.
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+}
+
+impl Index<&str> for NamedAreas {
+    type Output = Rect;
+
+    fn index(&self, name: &str) -> &Self::Output {
+        self.areas
+            .iter()
+            .find(|(area_name, _)| area_name == name)
+            .map_or_else(|| panic!("no area named `{name}`"), |(_, area)| area)
+    }
+}
+
+impl<N: Into<String>> FromIterator<(N, Rect)> for NamedAreas {
+    fn from_iter<T: IntoIterator<Item = (N, Rect)>>(iter: T) -> Self {
+        Self {
+            areas: iter
+                .into_iter()
+                .map(|(name, area)| (name.into(), area))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Constraint, Direction, Layout};
+
+    #[test]
+    fn split_named() {
+        let areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints_named([
+                ("left", Constraint::Length(3)),
+                ("right", Constraint::Fill(1)),
+            ])
+            .split_named(Rect::new(0, 0, 10, 1));
+        assert_eq!(areas.len(), 2);
+        assert_eq!(areas["left"], Rect::new(0, 0, 3, 1));
+        assert_eq!(areas.get("right"), Some(Rect::new(3, 0, 7, 1)));
+        assert!(!areas.contains("missing"));
+        assert_eq!(
+            areas.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["left", "right"]
+        );
+    }
+
+    #[test]
+    fn unnamed_constraints() {
+        let areas = Layout::vertical([Constraint::Fill(1)]).split_named(Rect::new(0, 0, 10, 10));
+        assert!(areas.is_empty());
+    }
+
+    #[test]
+    fn constraints_clears_names() {
+        let layout = Layout::default()
+            .constraints_named([("a", Constraint::Fill(1))])
+            .constraints([Constraint::Fill(1)]);
+        assert!(layout.split_named(Rect::new(0, 0, 10, 10)).is_empty());
+    }
+
+    #[test]
+    #[should_panic = "no area named `missing`"]
+    fn index_missing() {
+        let _ = NamedAreas::default()["missing"];
+    }
+}
@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use thiserror::Error;
+
+use crate::layout::Rect;
+
+/// The constraints of a [`Layout`] do not fit in the split area.
+///
+/// This is returned by [`Layout::split_checked`] when the sum of the fixed sizes of the
+/// constraints ([`Length`], [`Fit`] and [`Min`]) and of the spacing is larger than the area, or
+/// when a segment ends up smaller than its fixed size. The areas that the layout produced anyway
+/// are available in [`areas`](Self::areas), so that they can still be used.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+///
+/// let layout = Layout::horizontal([Length(10), Length(20), Fill(1)]);
+/// let overflow = layout.split_checked(Rect::new(0, 0, 25, 1)).unwrap_err();
+/// assert_eq!(overflow.required, 30);
+/// assert_eq!(overflow.available, 25);
+/// assert_eq!(overflow.deficits, [0, 5, 0]);
+/// ```
+///
+/// [`Layout`]: crate::layout::Layout
+/// [`Layout::split_checked`]: crate::layout::Layout::split_checked
+/// [`Length`]: crate::layout::Constraint::Length
+/// [`Fit`]: crate::layout::Constraint::Fit
+/// [`Min`]: crate::layout::Constraint::Min
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Error)]
+#[error("the layout requires {required} cells but only {available} are available")]
+pub struct LayoutOverflow {
+    /// The areas produced by the layout, as returned by [`Layout::split`].
+    ///
+    /// [`Layout::split`]: crate::layout::Layout::split
+    pub areas: Rc<[Rect]>,
+    /// The size of the area along the direction of the layout, without the margin and the outer
+    /// spacing.
+    pub available: u16,
+    /// The sum of the fixed sizes of the constraints and of the spacing between the segments.
+    pub required: u32,
+    /// How much smaller than its fixed size each segment is, in the order of the constraints.
+    pub deficits: Vec<u16>,
+}
+
+impl LayoutOverflow {
+    /// Returns how much larger the area would need to be for the constraints to fit.
+    pub fn total_deficit(&self) -> u32 {
+        self.required.saturating_sub(u32::from(self.available))
+    }
+}
@@ -1,6 +1,8 @@
-/// Defines the padding for a [`Block`].
+/// Defines the padding inside an area, e.g. of a `Block`.
 ///
-/// See the [`padding`] method of [`Block`] to configure its padding.
+/// [`Rect::inner_padded`] returns the area inside the padding. The `Block` widget uses the same
+/// computation for the area inside its borders, so an area is padded in the same way whether or
+/// not a `Block` is involved.
 ///
 /// This concept is similar to [CSS padding].
 ///
@@ -10,7 +12,13 @@
 /// # Example
 ///
 /// ```
-/// use ratatui::widgets::Padding;
+/// use ratatui_core::layout::{Padding, Rect};
+///
+/// let area = Rect::new(0, 0, 10, 5);
+/// assert_eq!(
+///     area.inner_padded(Padding::uniform(1)),
+///     Rect::new(1, 1, 8, 3)
+/// );
 ///
 /// Padding::uniform(1);
 /// Padding::horizontal(2);
@@ -19,10 +27,10 @@
 /// Padding::symmetric(5, 6);
 /// ```
 ///
-/// [`Block`]: crate::block::Block
-/// [`padding`]: crate::block::Block::padding
+/// [`Rect::inner_padded`]: crate::layout::Rect::inner_padded
 /// [CSS padding]: https://developer.mozilla.org/en-US/docs/Web/CSS/padding
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Padding {
     /// Left padding
     pub left: u16,
@@ -0,0 +1,197 @@
+use crate::layout::{Constraint, Layout};
+
+/// The total of the ratios of a [`PaneRatios`].
+const TOTAL: u16 = 1000;
+
+/// The sizes of resizable panes, stored as proportions of the space they share.
+///
+/// UIs where the user can drag the borders between panes need to remember the sizes chosen by the
+/// user. Storing them in cells breaks as soon as the terminal is resized, so `PaneRatios` stores
+/// them as permilles of the whole space instead. [`PaneRatios::constraints`] turns them back into
+/// [`Constraint::Permille`] constraints for a [`Layout`], which keeps the proportions whatever
+/// the size of the area. With the `serde` feature, the ratios can be saved and restored across
+/// restarts.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Layout, PaneRatios, Rect};
+///
+/// let mut ratios = PaneRatios::new(2);
+/// // the user dragged the border between the panes to make the first one 30 cells wide
+/// ratios.resize_pane(0, 30, 100);
+///
+/// let layout = Layout::horizontal(ratios.constraints());
+/// let [left, right] = layout.areas(Rect::new(0, 0, 100, 10));
+/// assert_eq!((left.width, right.width), (30, 70));
+///
+/// // the proportions are kept when the terminal is resized
+/// let [left, right] = layout.areas(Rect::new(0, 0, 50, 10));
+/// assert_eq!((left.width, right.width), (15, 35));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaneRatios {
+    permilles: Vec<u16>,
+}
+
+impl PaneRatios {
+    /// Creates ratios for the given number of panes of equal size.
+    pub fn new(panes: usize) -> Self {
+        Self::from_sizes(vec![1; panes])
+    }
+
+    /// Creates ratios from the current sizes of the panes, in cells.
+    ///
+    /// If all the sizes are zero, the panes get equal ratios.
+    pub fn from_sizes<I: IntoIterator<Item = u16>>(sizes: I) -> Self {
+        let sizes: Vec<u16> = sizes.into_iter().collect();
+        let permilles = if sizes.iter().all(|&size| size == 0) {
+            distribute(&vec![1; sizes.len()], TOTAL)
+        } else {
+            distribute(&sizes, TOTAL)
+        };
+        Self { permilles }
+    }
+
+    /// Returns the ratio of each pane, in permilles of the whole space.
+    pub fn permilles(&self) -> &[u16] {
+        &self.permilles
+    }
+
+    /// Returns the number of panes.
+    pub fn len(&self) -> usize {
+        self.permilles.len()
+    }
+
+    /// Returns `true` if there are no panes.
+    pub fn is_empty(&self) -> bool {
+        self.permilles.is_empty()
+    }
+
+    /// Changes the size of a pane to `size` cells out of `total`, as when the user drags the
+    /// border after it.
+    ///
+    /// The space is taken from or given to the next pane (or the previous one for the last pane),
+    /// so that the other panes keep their size. The size is limited to the space shared by the two
+    /// panes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn resize_pane(&mut self, index: usize, size: u16, total: u16) {
+        assert!(index < self.len(), "pane index out of bounds");
+        let Some(neighbor) = (if index + 1 < self.len() {
+            Some(index + 1)
+        } else {
+            index.checked_sub(1)
+        }) else {
+            return;
+        };
+        let target = if total == 0 {
+            0
+        } else {
+            (u32::from(size) * u32::from(TOTAL) + u32::from(total) / 2) / u32::from(total)
+        };
+        let shared = self.permilles[index] + self.permilles[neighbor];
+        let permille = u16::try_from(target).unwrap_or(u16::MAX).min(shared);
+        self.permilles[index] = permille;
+        self.permilles[neighbor] = shared - permille;
+    }
+
+    /// Returns the sizes of the panes, in cells, when they share `total` cells.
+    ///
+    /// The sizes add up to `total`, the rounding errors being given to the panes with the largest
+    /// remainders.
+    pub fn sizes(&self, total: u16) -> Vec<u16> {
+        distribute(&self.permilles, total)
+    }
+
+    /// Returns the [`Constraint::Permille`] constraints that split an area with these ratios.
+    pub fn constraints(&self) -> Vec<Constraint> {
+        Constraint::from_permilles(self.permilles.iter().copied())
+    }
+
+    /// Replaces the constraints of the layout with the constraints of these ratios.
+    pub fn apply(&self, layout: Layout) -> Layout {
+        layout.constraints(self.constraints())
+    }
+}
+
+/// Splits `total` proportionally to the weights, with the largest remainder method so that the
+/// parts add up to `total`.
+fn distribute(weights: &[u16], total: u16) -> Vec<u16> {
+    let sum: u32 = weights.iter().map(|&weight| u32::from(weight)).sum();
+    if sum == 0 {
+        return vec![0; weights.len()];
+    }
+    let products = weights
+        .iter()
+        .map(|&weight| u32::from(weight) * u32::from(total));
+    let mut parts: Vec<u16> = products
+        .clone()
+        .map(|product| u16::try_from(product / sum).unwrap_or(u16::MAX))
+        .collect();
+    let mut by_remainder: Vec<(usize, u32)> =
+        products.map(|product| product % sum).enumerate().collect();
+    by_remainder.sort_by(|(_, a), (_, b)| b.cmp(a));
+    let missing = total - parts.iter().sum::<u16>();
+    for (index, _) in by_remainder.into_iter().take(usize::from(missing)) {
+        parts[index] += 1;
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Rect;
+
+    #[test]
+    fn new() {
+        assert_eq!(PaneRatios::new(3).permilles(), [334, 333, 333]);
+        assert!(PaneRatios::new(0).is_empty());
+    }
+
+    #[test]
+    fn from_sizes() {
+        assert_eq!(
+            PaneRatios::from_sizes([20, 60, 20]).permilles(),
+            [200, 600, 200]
+        );
+        assert_eq!(PaneRatios::from_sizes([0, 0]).permilles(), [500, 500]);
+    }
+
+    #[test]
+    fn sizes() {
+        let ratios = PaneRatios::from_sizes([1, 1, 1]);
+        assert_eq!(ratios.sizes(10), [4, 3, 3]);
+        assert_eq!(ratios.sizes(0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn resize_pane() {
+        let mut ratios = PaneRatios::from_sizes([25, 25, 50]);
+        ratios.resize_pane(0, 40, 100);
+        assert_eq!(ratios.permilles(), [400, 100, 500]);
+        ratios.resize_pane(2, 90, 100);
+        assert_eq!(ratios.permilles(), [400, 0, 600]);
+        ratios.resize_pane(0, 100, 0);
+        assert_eq!(ratios.permilles(), [0, 400, 600]);
+    }
+
+    #[test]
+    fn resize_single_pane() {
+        let mut ratios = PaneRatios::new(1);
+        ratios.resize_pane(0, 10, 100);
+        assert_eq!(ratios.permilles(), [1000]);
+    }
+
+    #[test]
+    fn apply() {
+        let ratios = PaneRatios::from_sizes([30, 70]);
+        let layout = ratios.apply(Layout::default());
+        let [top, bottom] = layout.areas(Rect::new(0, 0, 1, 20));
+        assert_eq!((top.height, bottom.height), (6, 14));
+    }
+}
@@ -1,7 +1,10 @@
 #![warn(missing_docs)]
-use std::fmt;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
 
-use crate::layout::Rect;
+use crate::layout::{Offset, Rect, Size};
 
 /// Position in the terminal
 ///
@@ -23,6 +26,19 @@ use crate::layout::Rect;
 /// // position can be converted back into the components when needed
 /// let (x, y) = position.into();
 /// ```
+///
+/// Positions can be moved by an [`Offset`] or a [`Size`], and subtracting two positions gives the
+/// [`Offset`] between them. The results saturate at the edges of the `u16` range instead of
+/// overflowing.
+///
+/// ```
+/// use ratatui_core::layout::{Offset, Position, Size};
+///
+/// let position = Position::new(5, 5);
+/// assert_eq!(position + Offset::new(2, -3), Position::new(7, 2));
+/// assert_eq!(position + Size::new(10, 1), Position::new(15, 6));
+/// assert_eq!(Position::new(2, 8) - position, Offset::new(-3, 3));
+/// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
@@ -67,6 +83,85 @@ impl From<Rect> for Position {
     }
 }
 
+impl Add<Offset> for Position {
+    type Output = Self;
+
+    /// Moves the position by the offset, saturating at the edges of the `u16` range.
+    fn add(self, offset: Offset) -> Self {
+        Self {
+            x: saturating_offset(self.x, offset.x),
+            y: saturating_offset(self.y, offset.y),
+        }
+    }
+}
+
+impl Sub<Offset> for Position {
+    type Output = Self;
+
+    /// Moves the position back by the offset, saturating at the edges of the `u16` range.
+    fn sub(self, offset: Offset) -> Self {
+        Self {
+            x: saturating_offset(self.x, offset.x.saturating_neg()),
+            y: saturating_offset(self.y, offset.y.saturating_neg()),
+        }
+    }
+}
+
+impl AddAssign<Offset> for Position {
+    fn add_assign(&mut self, offset: Offset) {
+        *self = *self + offset;
+    }
+}
+
+impl SubAssign<Offset> for Position {
+    fn sub_assign(&mut self, offset: Offset) {
+        *self = *self - offset;
+    }
+}
+
+impl Add<Size> for Position {
+    type Output = Self;
+
+    /// Moves the position right by the width and down by the height of the size, saturating at
+    /// `u16::MAX`.
+    fn add(self, size: Size) -> Self {
+        Self {
+            x: self.x.saturating_add(size.width),
+            y: self.y.saturating_add(size.height),
+        }
+    }
+}
+
+impl Sub<Size> for Position {
+    type Output = Self;
+
+    /// Moves the position left by the width and up by the height of the size, saturating at `0`.
+    fn sub(self, size: Size) -> Self {
+        Self {
+            x: self.x.saturating_sub(size.width),
+            y: self.y.saturating_sub(size.height),
+        }
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    /// Returns the offset that moves `other` to this position.
+    fn sub(self, other: Self) -> Offset {
+        Offset::new(
+            i32::from(self.x) - i32::from(other.x),
+            i32::from(self.y) - i32::from(other.y),
+        )
+    }
+}
+
+/// Moves a coordinate by a signed amount, clamping the result to the `u16` range.
+fn saturating_offset(value: u16, offset: i32) -> u16 {
+    let value = i32::from(value).saturating_add(offset);
+    u16::try_from(value.max(0)).unwrap_or(u16::MAX)
+}
+
 impl fmt::Display for Position {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
@@ -107,6 +202,33 @@ mod tests {
         assert_eq!(position.y, 2);
     }
 
+    #[test]
+    fn add_offset() {
+        let mut position = Position::new(5, 5) + Offset::new(2, -3);
+        assert_eq!(position, Position::new(7, 2));
+        position -= Offset::new(10, 1);
+        assert_eq!(position, Position::new(0, 1));
+        position += Offset::new(i32::MAX, i32::MIN);
+        assert_eq!(position, Position::new(u16::MAX, 0));
+    }
+
+    #[test]
+    fn add_size() {
+        assert_eq!(Position::new(1, 2) + Size::new(3, 4), Position::new(4, 6));
+        assert_eq!(
+            Position::new(u16::MAX, 2) + Size::new(3, 4),
+            Position::new(u16::MAX, 6)
+        );
+        assert_eq!(Position::new(1, 6) - Size::new(3, 4), Position::new(0, 2));
+    }
+
+    #[test]
+    fn sub_position() {
+        let offset = Position::new(2, 8) - Position::new(5, 5);
+        assert_eq!(offset, Offset::new(-3, 3));
+        assert_eq!(Position::new(5, 5) + offset, Position::new(2, 8));
+    }
+
     #[test]
     fn to_string() {
         let position = Position::new(1, 2);
@@ -4,7 +4,7 @@ use std::{
     fmt,
 };
 
-use crate::layout::{Margin, Position, Size};
+use crate::layout::{Alignment, Margin, Padding, Position, Size, VerticalAlignment};
 
 mod iter;
 pub use iter::*;
@@ -155,6 +155,43 @@ impl Rect {
         }
     }
 
+    /// Returns a new `Rect` inside the current one, with the given padding on each side.
+    ///
+    /// Each axis is padded on its own: if the padding of an axis is larger than the `Rect`, the
+    /// returned `Rect` has no width (or height), and its position is kept within the `Rect`. This
+    /// is the computation used by the `Block` widget for its padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Padding, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// assert_eq!(
+    ///     area.inner_padded(Padding::new(1, 2, 0, 1)),
+    ///     Rect::new(1, 0, 7, 4)
+    /// );
+    /// assert_eq!(
+    ///     area.inner_padded(Padding::horizontal(6)),
+    ///     Rect::new(6, 0, 0, 5)
+    /// );
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn inner_padded(self, padding: Padding) -> Self {
+        let x = self.x.saturating_add(padding.left);
+        let y = self.y.saturating_add(padding.top);
+        Self {
+            x: if x < self.right() { x } else { self.right() },
+            y: if y < self.bottom() { y } else { self.bottom() },
+            width: self
+                .width
+                .saturating_sub(padding.left.saturating_add(padding.right)),
+            height: self
+                .height
+                .saturating_sub(padding.top.saturating_add(padding.bottom)),
+        }
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -235,6 +272,60 @@ impl Rect {
             && position.y < self.bottom()
     }
 
+    /// Returns the given position relative to the top left corner of the `Rect`, or `None` if the
+    /// position is outside of the `Rect`.
+    ///
+    /// This maps a position on the screen, e.g. of a mouse click, into the local space of the
+    /// widget rendered in the `Rect`. See [`Rect::absolute_position`] for the reverse mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Position, Rect};
+    ///
+    /// let rect = Rect::new(1, 2, 3, 4);
+    /// assert_eq!(
+    ///     rect.relative_position(Position::new(2, 5)),
+    ///     Some(Position::new(1, 3))
+    /// );
+    /// assert_eq!(rect.relative_position(Position::new(4, 5)), None);
+    /// ```
+    pub const fn relative_position(self, position: Position) -> Option<Position> {
+        if self.contains(position) {
+            Some(Position::new(position.x - self.x, position.y - self.y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position on the screen of the given position relative to the top left corner
+    /// of the `Rect`, or `None` if the relative position is outside of the `Rect`.
+    ///
+    /// This is the reverse of [`Rect::relative_position`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Position, Rect};
+    ///
+    /// let rect = Rect::new(1, 2, 3, 4);
+    /// assert_eq!(
+    ///     rect.absolute_position(Position::new(1, 3)),
+    ///     Some(Position::new(2, 5))
+    /// );
+    /// assert_eq!(rect.absolute_position(Position::new(3, 0)), None);
+    /// ```
+    pub const fn absolute_position(self, relative: Position) -> Option<Position> {
+        if relative.x < self.width && relative.y < self.height {
+            Some(Position::new(
+                self.x.saturating_add(relative.x),
+                self.y.saturating_add(relative.y),
+            ))
+        } else {
+            None
+        }
+    }
+
     /// Clamp this `Rect` to fit inside the other `Rect`.
     ///
     /// If the width or height of this `Rect` is larger than the other `Rect`, it will be clamped to
@@ -268,6 +359,195 @@ impl Rect {
         Self::new(x, y, width, height)
     }
 
+    /// Splits the `Rect` into a top part of the given height and the rest below it.
+    ///
+    /// This is a shortcut for the common case of a two-way split with a fixed size part, which
+    /// does not need the constraint solver used by [`Layout`](crate::layout::Layout). If the
+    /// height is larger than the height of the `Rect`, the top part is the whole `Rect` and the
+    /// rest is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let (header, body) = area.split_top(1);
+    /// assert_eq!(header, Rect::new(0, 0, 10, 1));
+    /// assert_eq!(body, Rect::new(0, 1, 10, 9));
+    /// ```
+    pub const fn split_top(self, height: u16) -> (Self, Self) {
+        let height = if height < self.height {
+            height
+        } else {
+            self.height
+        };
+        let top = Self { height, ..self };
+        let rest = Self {
+            y: self.y.saturating_add(height),
+            height: self.height - height,
+            ..self
+        };
+        (top, rest)
+    }
+
+    /// Splits the `Rect` into a bottom part of the given height and the rest above it.
+    ///
+    /// The parts are returned as `(bottom, rest)`. See [`Rect::split_top`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let (status_bar, body) = area.split_bottom(1);
+    /// assert_eq!(status_bar, Rect::new(0, 9, 10, 1));
+    /// assert_eq!(body, Rect::new(0, 0, 10, 9));
+    /// ```
+    pub const fn split_bottom(self, height: u16) -> (Self, Self) {
+        let (rest, bottom) = self.split_top(self.height.saturating_sub(height));
+        (bottom, rest)
+    }
+
+    /// Splits the `Rect` into a left part of the given width and the rest to the right of it.
+    ///
+    /// The parts are returned as `(left, rest)`. See [`Rect::split_top`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let (sidebar, main) = area.split_left(3);
+    /// assert_eq!(sidebar, Rect::new(0, 0, 3, 10));
+    /// assert_eq!(main, Rect::new(3, 0, 7, 10));
+    /// ```
+    pub const fn split_left(self, width: u16) -> (Self, Self) {
+        let width = if width < self.width {
+            width
+        } else {
+            self.width
+        };
+        let left = Self { width, ..self };
+        let rest = Self {
+            x: self.x.saturating_add(width),
+            width: self.width - width,
+            ..self
+        };
+        (left, rest)
+    }
+
+    /// Splits the `Rect` into a right part of the given width and the rest to the left of it.
+    ///
+    /// The parts are returned as `(right, rest)`. See [`Rect::split_top`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let (scrollbar, content) = area.split_right(1);
+    /// assert_eq!(scrollbar, Rect::new(9, 0, 1, 10));
+    /// assert_eq!(content, Rect::new(0, 0, 9, 10));
+    /// ```
+    pub const fn split_right(self, width: u16) -> (Self, Self) {
+        let (rest, right) = self.split_left(self.width.saturating_sub(width));
+        (right, rest)
+    }
+
+    /// Moves the `Rect` inside the container according to the given alignments.
+    ///
+    /// Only the size of this `Rect` is used, its position is replaced. If the `Rect` is larger
+    /// than the container, its size is reduced to fit in it. When the `Rect` cannot be centered
+    /// exactly, it is moved one cell towards the left or the top.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Alignment, Rect, VerticalAlignment};
+    ///
+    /// let area = Rect::new(0, 0, 100, 50);
+    /// let popup = Rect::new(0, 0, 40, 10).align_in(area, Alignment::Center, VerticalAlignment::Top);
+    /// assert_eq!(popup, Rect::new(30, 0, 40, 10));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn align_in(
+        self,
+        container: Self,
+        horizontal: Alignment,
+        vertical: VerticalAlignment,
+    ) -> Self {
+        // keep the container within the bounds of `u16` before fitting the `Rect` in it
+        let container = Self::new(container.x, container.y, container.width, container.height);
+        let width = if self.width < container.width {
+            self.width
+        } else {
+            container.width
+        };
+        let height = if self.height < container.height {
+            self.height
+        } else {
+            container.height
+        };
+        let x = match horizontal {
+            Alignment::Left => container.x,
+            Alignment::Center => container
+                .x
+                .saturating_add(container.width.saturating_sub(width) / 2),
+            Alignment::Right => container.right().saturating_sub(width),
+        };
+        let y = match vertical {
+            VerticalAlignment::Top => container.y,
+            VerticalAlignment::Center => container
+                .y
+                .saturating_add(container.height.saturating_sub(height) / 2),
+            VerticalAlignment::Bottom => container.bottom().saturating_sub(height),
+        };
+        Self::new(x, y, width, height)
+    }
+
+    /// Returns a `Rect` of the given size centered in this `Rect`.
+    ///
+    /// The size is reduced to fit in this `Rect` if needed. See [`Rect::align_in`] for other
+    /// alignments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 100, 50);
+    /// assert_eq!(area.centered(40, 10), Rect::new(30, 20, 40, 10));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn centered(self, width: u16, height: u16) -> Self {
+        Self::new(0, 0, width, height).align_in(self, Alignment::Center, VerticalAlignment::Center)
+    }
+
+    /// Returns a `Rect` of the given size in the bottom right corner of this `Rect`, e.g. for a
+    /// notification or a floating button.
+    ///
+    /// The size is reduced to fit in this `Rect` if needed. Use [`Rect::inner`] first to keep
+    /// some space between the corner and the returned `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Margin, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 100, 50);
+    /// assert_eq!(area.anchor_bottom_right(20, 3), Rect::new(80, 47, 20, 3));
+    /// let toast = area.inner(Margin::new(2, 1)).anchor_bottom_right(20, 3);
+    /// assert_eq!(toast, Rect::new(78, 46, 20, 3));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn anchor_bottom_right(self, width: u16, height: u16) -> Self {
+        Self::new(0, 0, width, height).align_in(self, Alignment::Right, VerticalAlignment::Bottom)
+    }
+
     /// An iterator over rows within the `Rect`.
     ///
     /// # Example
@@ -435,6 +715,17 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::zero(Padding::ZERO, Rect::new(1, 2, 10, 5))]
+    #[case::uniform(Padding::uniform(1), Rect::new(2, 3, 8, 3))]
+    #[case::asymmetric(Padding::new(1, 2, 3, 1), Rect::new(2, 5, 7, 1))]
+    #[case::too_wide(Padding::horizontal(6), Rect::new(7, 2, 0, 5))]
+    #[case::too_tall(Padding::vertical(3), Rect::new(1, 5, 10, 0))]
+    #[case::saturated(Padding::uniform(u16::MAX), Rect::new(11, 7, 0, 0))]
+    fn inner_padded(#[case] padding: Padding, #[case] expected: Rect) {
+        assert_eq!(Rect::new(1, 2, 10, 5).inner_padded(padding), expected);
+    }
+
     #[test]
     fn offset() {
         assert_eq!(
@@ -518,6 +809,26 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::top_left(Position::new(1, 2), Some(Position::new(0, 0)))]
+    #[case::bottom_right(Position::new(3, 5), Some(Position::new(2, 3)))]
+    #[case::outside_left(Position::new(0, 2), None)]
+    #[case::outside_bottom(Position::new(1, 6), None)]
+    fn relative_position(#[case] position: Position, #[case] expected: Option<Position>) {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(rect.relative_position(position), expected);
+        if let Some(relative) = expected {
+            assert_eq!(rect.absolute_position(relative), Some(position));
+        }
+    }
+
+    #[test]
+    fn absolute_position_outside() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(rect.absolute_position(Position::new(3, 0)), None);
+        assert_eq!(rect.absolute_position(Position::new(0, 4)), None);
+    }
+
     #[test]
     fn size_truncation() {
         assert_eq!(
@@ -593,6 +904,91 @@ mod tests {
         assert_eq!(rect.clamp(other), expected);
     }
 
+    #[rstest]
+    #[case::partial(3, Rect::new(1, 2, 10, 3), Rect::new(1, 5, 10, 5))]
+    #[case::zero(0, Rect::new(1, 2, 10, 0), Rect::new(1, 2, 10, 8))]
+    #[case::too_large(20, Rect::new(1, 2, 10, 8), Rect::new(1, 10, 10, 0))]
+    fn split_top(#[case] height: u16, #[case] top: Rect, #[case] rest: Rect) {
+        assert_eq!(Rect::new(1, 2, 10, 8).split_top(height), (top, rest));
+    }
+
+    #[rstest]
+    #[case::partial(3, Rect::new(1, 7, 10, 3), Rect::new(1, 2, 10, 5))]
+    #[case::too_large(20, Rect::new(1, 2, 10, 8), Rect::new(1, 2, 10, 0))]
+    fn split_bottom(#[case] height: u16, #[case] bottom: Rect, #[case] rest: Rect) {
+        assert_eq!(Rect::new(1, 2, 10, 8).split_bottom(height), (bottom, rest));
+    }
+
+    #[rstest]
+    #[case::partial(3, Rect::new(1, 2, 3, 8), Rect::new(4, 2, 7, 8))]
+    #[case::too_large(20, Rect::new(1, 2, 10, 8), Rect::new(11, 2, 0, 8))]
+    fn split_left(#[case] width: u16, #[case] left: Rect, #[case] rest: Rect) {
+        assert_eq!(Rect::new(1, 2, 10, 8).split_left(width), (left, rest));
+    }
+
+    #[rstest]
+    #[case::partial(3, Rect::new(8, 2, 3, 8), Rect::new(1, 2, 7, 8))]
+    #[case::too_large(20, Rect::new(1, 2, 10, 8), Rect::new(1, 2, 0, 8))]
+    fn split_right(#[case] width: u16, #[case] right: Rect, #[case] rest: Rect) {
+        assert_eq!(Rect::new(1, 2, 10, 8).split_right(width), (right, rest));
+    }
+
+    #[rstest]
+    #[case::top_left(Alignment::Left, VerticalAlignment::Top, Rect::new(2, 1, 4, 2))]
+    #[case::center(Alignment::Center, VerticalAlignment::Center, Rect::new(5, 3, 4, 2))]
+    #[case::bottom_right(Alignment::Right, VerticalAlignment::Bottom, Rect::new(8, 5, 4, 2))]
+    fn align_in(
+        #[case] horizontal: Alignment,
+        #[case] vertical: VerticalAlignment,
+        #[case] expected: Rect,
+    ) {
+        let container = Rect::new(2, 1, 10, 6);
+        let rect = Rect::new(50, 50, 4, 2).align_in(container, horizontal, vertical);
+        assert_eq!(rect, expected);
+    }
+
+    #[test]
+    fn align_in_larger_than_container() {
+        let container = Rect::new(2, 1, 10, 6);
+        let rect = Rect::new(0, 0, 20, 3).align_in(
+            container,
+            Alignment::Center,
+            VerticalAlignment::Center,
+        );
+        assert_eq!(rect, Rect::new(2, 2, 10, 3));
+    }
+
+    #[rstest]
+    #[case::center(Alignment::Center, VerticalAlignment::Center)]
+    #[case::bottom_right(Alignment::Right, VerticalAlignment::Bottom)]
+    fn align_in_oversized_near_max(
+        #[case] horizontal: Alignment,
+        #[case] vertical: VerticalAlignment,
+    ) {
+        let container = Rect {
+            x: u16::MAX - 2,
+            y: u16::MAX - 2,
+            width: 10,
+            height: 10,
+        };
+        let rect = Rect::new(0, 0, u16::MAX, u16::MAX).align_in(container, horizontal, vertical);
+        assert_eq!(rect, Rect::new(u16::MAX - 2, u16::MAX - 2, 2, 2));
+    }
+
+    #[test]
+    fn centered() {
+        let area = Rect::new(1, 1, 11, 5);
+        assert_eq!(area.centered(4, 2), Rect::new(4, 2, 4, 2));
+        assert_eq!(area.centered(20, 20), area);
+    }
+
+    #[test]
+    fn anchor_bottom_right() {
+        let area = Rect::new(1, 1, 10, 5);
+        assert_eq!(area.anchor_bottom_right(4, 2), Rect::new(7, 4, 4, 2));
+        assert_eq!(area.anchor_bottom_right(20, 20), area);
+    }
+
     #[test]
     fn rows() {
         let area = Rect::new(0, 0, 3, 2);
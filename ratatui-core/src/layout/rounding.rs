@@ -0,0 +1,78 @@
+use strum::{Display, EnumIs, EnumString};
+
+/// Defines how the fractional positions computed by the layout solver are mapped to cells.
+///
+/// The solver works with fractional sizes (e.g. three `Ratio(1, 3)` constraints in 10 cells are
+/// each 3.33 cells wide), which have to be converted to whole cells. The strategies differ in which
+/// segments receive the leftover cells.
+///
+/// The strategies that truncate the sizes ([`Rounding::LargestRemainder`],
+/// [`Rounding::FirstRemainder`], [`Rounding::LastRemainder`] and [`Rounding::SpreadRemainder`])
+/// hand out the leftover cells one at a time to the segments that have a fractional part, and only
+/// differ in which of those segments are chosen.
+///
+/// See [`Layout::rounding`](crate::layout::Layout::rounding) for details.
+#[derive(Copy, Debug, Default, Display, EnumString, Clone, Eq, PartialEq, Hash, EnumIs)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Rounds the start and end position of each segment to the nearest cell.
+    ///
+    /// This is the default, and matches the behavior of previous versions of Ratatui.
+    #[default]
+    Round,
+
+    /// Truncates the start and end position of each segment to the cell before it.
+    ///
+    /// This moves all the leftover cells to the segments at the end of the layout.
+    Truncate,
+
+    /// Truncates the size of each segment and gives the leftover cells to the segments with the
+    /// largest fractional parts, in order.
+    ///
+    /// This is also known as the Hamilton method. Unlike rounding the positions, the size of each
+    /// segment depends only on its own fractional size, which keeps the widths stable when
+    /// unrelated segments change.
+    LargestRemainder,
+
+    /// Truncates the size of each segment and gives the leftover cells to the first segments that
+    /// have a fractional part.
+    FirstRemainder,
+
+    /// Truncates the size of each segment and gives the leftover cells to the last segments that
+    /// have a fractional part.
+    LastRemainder,
+
+    /// Truncates the size of each segment and spreads the leftover cells evenly across the
+    /// segments that have a fractional part.
+    ///
+    /// Unlike [`Rounding::LargestRemainder`], the size of the fractional parts does not matter, so
+    /// the leftover cells are not bunched up at one end of the layout when several segments have
+    /// similar sizes.
+    SpreadRemainder,
+
+    /// Distributes the cells so that segment sizes change monotonically as the area is resized.
+    ///
+    /// This uses the Sainte-Laguë divisor method, which never takes a cell away from a segment
+    /// when the area grows, as long as the relative sizes of the segments stay the same (e.g.
+    /// for percentages, ratios and fills). This eliminates the column "jitter" that can be
+    /// seen with the other strategies while a terminal is being resized one cell at a time.
+    Stable,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Rounding::Round.to_string(), "Round");
+        assert_eq!(Rounding::LargestRemainder.to_string(), "LargestRemainder");
+        assert_eq!(Rounding::FirstRemainder.to_string(), "FirstRemainder");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("Truncate".parse::<Rounding>(), Ok(Rounding::Truncate));
+        assert!("".parse::<Rounding>().is_err());
+    }
+}
@@ -1,5 +1,8 @@
 #![warn(missing_docs)]
-use std::fmt;
+use std::{
+    fmt,
+    ops::{Add, Sub},
+};
 
 use crate::layout::Rect;
 
@@ -7,6 +10,16 @@ use crate::layout::Rect;
 ///
 /// The width and height are stored as `u16` values and represent the number of columns and rows
 /// respectively.
+///
+/// Sizes can be added to and subtracted from each other. The results saturate at the edges of the
+/// `u16` range instead of overflowing.
+///
+/// ```
+/// use ratatui_core::layout::Size;
+///
+/// assert_eq!(Size::new(10, 5) + Size::new(2, 1), Size::new(12, 6));
+/// assert_eq!(Size::new(10, 5) - Size::new(12, 1), Size::new(0, 4));
+/// ```
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
@@ -38,6 +51,30 @@ impl From<Rect> for Size {
     }
 }
 
+impl Add for Size {
+    type Output = Self;
+
+    /// Adds the widths and the heights, saturating at `u16::MAX`.
+    fn add(self, other: Self) -> Self {
+        Self {
+            width: self.width.saturating_add(other.width),
+            height: self.height.saturating_add(other.height),
+        }
+    }
+}
+
+impl Sub for Size {
+    type Output = Self;
+
+    /// Subtracts the widths and the heights, saturating at `0`.
+    fn sub(self, other: Self) -> Self {
+        Self {
+            width: self.width.saturating_sub(other.width),
+            height: self.height.saturating_sub(other.height),
+        }
+    }
+}
+
 impl fmt::Display for Size {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}x{}", self.width, self.height)
@@ -69,6 +106,21 @@ mod tests {
         assert_eq!(size.height, 20);
     }
 
+    #[test]
+    fn add() {
+        assert_eq!(Size::new(10, 5) + Size::new(2, 1), Size::new(12, 6));
+        assert_eq!(
+            Size::new(u16::MAX, 5) + Size::new(2, 1),
+            Size::new(u16::MAX, 6)
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(Size::new(10, 5) - Size::new(2, 1), Size::new(8, 4));
+        assert_eq!(Size::new(10, 5) - Size::new(12, 1), Size::new(0, 4));
+    }
+
     #[test]
     fn display() {
         assert_eq!(Size::new(10, 20).to_string(), "10x20");
@@ -0,0 +1,412 @@
+#![warn(missing_docs)]
+use std::{
+    cmp::{max, min},
+    fmt,
+};
+
+use crate::layout::{Position, Rect, Size};
+
+/// A position in a large virtual coordinate space, such as the content of a scrollable widget.
+///
+/// This is the `u32` counterpart of [`Position`], for content that can be taller or wider than
+/// the `u16` coordinates of the terminal, e.g. a log with millions of lines. See [`VirtualRect`]
+/// for mapping virtual positions to the screen and back.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::layout::{Position, VirtualPosition};
+///
+/// let position = VirtualPosition::new(1, 100_000);
+/// let position = VirtualPosition::from((1, 100_000));
+/// let position = VirtualPosition::from(Position::new(1, 2));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VirtualPosition {
+    /// The x coordinate of the position
+    pub x: u32,
+    /// The y coordinate of the position
+    pub y: u32,
+}
+
+impl VirtualPosition {
+    /// Position at the origin, the top left corner at 0,0
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    /// Create a new virtual position
+    pub const fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(u32, u32)> for VirtualPosition {
+    fn from((x, y): (u32, u32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<VirtualPosition> for (u32, u32) {
+    fn from(position: VirtualPosition) -> Self {
+        (position.x, position.y)
+    }
+}
+
+impl From<Position> for VirtualPosition {
+    fn from(position: Position) -> Self {
+        Self::new(u32::from(position.x), u32::from(position.y))
+    }
+}
+
+impl fmt::Display for VirtualPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A rectangular area in a large virtual coordinate space, such as the content of a scrollable
+/// widget.
+///
+/// This is the `u32` counterpart of [`Rect`]: widgets with very long content (logs, tables with
+/// millions of rows) can keep track of the positions of their content and of the part of it that
+/// is visible without overflowing the `u16` coordinates of the terminal.
+///
+/// The visible part of the content is a viewport, a `VirtualRect` in content coordinates that is
+/// shown in an area of the screen. [`VirtualRect::viewport`] creates a viewport of the size of an
+/// area, and [`to_screen`](VirtualRect::to_screen) and [`to_virtual`](VirtualRect::to_virtual)
+/// map rectangles and positions between the viewport and the area.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::layout::{Position, Rect, VirtualPosition, VirtualRect};
+///
+/// let area = Rect::new(2, 1, 20, 10);
+/// let viewport = VirtualRect::viewport(VirtualPosition::new(0, 1_000_000), area);
+///
+/// // the line at row 1 000 004 of the content is the fifth line of the area
+/// let line = VirtualRect::new(0, 1_000_004, 80, 1);
+/// assert_eq!(viewport.to_screen(line, area), Some(Rect::new(2, 5, 20, 1)));
+///
+/// // and a click on that line is mapped back to the content
+/// assert_eq!(
+///     viewport.to_virtual(Position::new(3, 5), area),
+///     Some(VirtualPosition::new(1, 1_000_004))
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VirtualRect {
+    /// The x coordinate of the top left corner of the `VirtualRect`.
+    pub x: u32,
+    /// The y coordinate of the top left corner of the `VirtualRect`.
+    pub y: u32,
+    /// The width of the `VirtualRect`.
+    pub width: u32,
+    /// The height of the `VirtualRect`.
+    pub height: u32,
+}
+
+impl VirtualRect {
+    /// A zero sized `VirtualRect` at position 0,0
+    pub const ZERO: Self = Self::new(0, 0, 0, 0);
+
+    /// Creates a new `VirtualRect`, with width and height limited to keep both bounds within
+    /// `u32`.
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        // these calculations avoid using min so that this function can be const
+        let max_width = u32::MAX - x;
+        let max_height = u32::MAX - y;
+        let width = if width > max_width { max_width } else { width };
+        let height = if height > max_height {
+            max_height
+        } else {
+            height
+        };
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a viewport at the given position of the content, with the size of the given area.
+    pub const fn viewport(position: VirtualPosition, area: Rect) -> Self {
+        Self::new(
+            position.x,
+            position.y,
+            area.width as u32,
+            area.height as u32,
+        )
+    }
+
+    /// The area of the `VirtualRect`.
+    pub const fn area(self) -> u64 {
+        (self.width as u64) * (self.height as u64)
+    }
+
+    /// Returns true if the `VirtualRect` has no area.
+    pub const fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns the left coordinate of the `VirtualRect`.
+    pub const fn left(self) -> u32 {
+        self.x
+    }
+
+    /// Returns the right coordinate of the `VirtualRect`. This is the first coordinate outside of
+    /// the `VirtualRect`.
+    pub const fn right(self) -> u32 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Returns the top coordinate of the `VirtualRect`.
+    pub const fn top(self) -> u32 {
+        self.y
+    }
+
+    /// Returns the bottom coordinate of the `VirtualRect`. This is the first coordinate outside
+    /// of the `VirtualRect`.
+    pub const fn bottom(self) -> u32 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Returns the top left corner of the `VirtualRect`.
+    pub const fn as_position(self) -> VirtualPosition {
+        VirtualPosition::new(self.x, self.y)
+    }
+
+    /// Returns true if the given position is inside the `VirtualRect`.
+    pub const fn contains(self, position: VirtualPosition) -> bool {
+        position.x >= self.x
+            && position.x < self.right()
+            && position.y >= self.y
+            && position.y < self.bottom()
+    }
+
+    /// Returns a new `VirtualRect` that is the intersection of the current one and the given one.
+    ///
+    /// If the two `VirtualRect`s do not intersect, the returned `VirtualRect` will have no area.
+    #[must_use = "method returns the modified value"]
+    pub fn intersection(self, other: Self) -> Self {
+        let x1 = max(self.x, other.x);
+        let y1 = max(self.y, other.y);
+        let x2 = min(self.right(), other.right());
+        let y2 = min(self.bottom(), other.bottom());
+        Self {
+            x: x1,
+            y: y1,
+            width: x2.saturating_sub(x1),
+            height: y2.saturating_sub(y1),
+        }
+    }
+
+    /// Returns true if the two `VirtualRect`s intersect.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.x < other.right()
+            && self.right() > other.x
+            && self.y < other.bottom()
+            && self.bottom() > other.y
+    }
+
+    /// Clamps the `VirtualRect` to fit inside the given one.
+    ///
+    /// This moves a viewport back inside the bounds of the content, e.g. after the content
+    /// shrinks. If the `VirtualRect` is larger than the other one, its size is truncated.
+    #[must_use = "method returns the modified value"]
+    pub fn clamp(self, other: Self) -> Self {
+        let width = self.width.min(other.width);
+        let height = self.height.min(other.height);
+        let x = self.x.clamp(other.x, other.right().saturating_sub(width));
+        let y = self.y.clamp(other.y, other.bottom().saturating_sub(height));
+        Self::new(x, y, width, height)
+    }
+
+    /// Moves the viewport as little as possible so that the given target is inside it.
+    ///
+    /// This is useful to keep the selected item of a long list visible. If the target is larger
+    /// than the viewport, the viewport is aligned with the top left corner of the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::VirtualRect;
+    ///
+    /// let viewport = VirtualRect::new(0, 100, 10, 5);
+    /// let selected = VirtualRect::new(0, 200, 10, 1);
+    /// assert_eq!(
+    ///     viewport.scroll_to(selected),
+    ///     VirtualRect::new(0, 196, 10, 5)
+    /// );
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn scroll_to(self, target: Self) -> Self {
+        let scroll = |start: u32, size: u32, target_start: u32, target_end: u32| {
+            if target_start < start || target_end.saturating_sub(target_start) > size {
+                target_start
+            } else if target_end > start.saturating_add(size) {
+                target_end - size
+            } else {
+                start
+            }
+        };
+        Self::new(
+            scroll(self.x, self.width, target.x, target.right()),
+            scroll(self.y, self.height, target.y, target.bottom()),
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Maps a `VirtualRect` of the content to the screen, where the viewport `self` is shown in
+    /// the given area.
+    ///
+    /// The result is clipped to the viewport and to the area. Returns `None` if no part of the
+    /// `VirtualRect` is visible.
+    pub fn to_screen(self, rect: Self, area: Rect) -> Option<Rect> {
+        let visible = self.intersection(rect);
+        if visible.is_empty() {
+            return None;
+        }
+        let x = u16::try_from(visible.x - self.x).ok()?;
+        let y = u16::try_from(visible.y - self.y).ok()?;
+        let width = u16::try_from(visible.width).unwrap_or(u16::MAX);
+        let height = u16::try_from(visible.height).unwrap_or(u16::MAX);
+        let screen = Rect::new(
+            area.x.saturating_add(x),
+            area.y.saturating_add(y),
+            width,
+            height,
+        )
+        .intersection(area);
+        (!screen.is_empty()).then_some(screen)
+    }
+
+    /// Maps a position on the screen, e.g. of a mouse click, to the content, where the viewport
+    /// `self` is shown in the given area.
+    ///
+    /// Returns `None` if the position is outside of the area or of the viewport.
+    pub fn to_virtual(self, position: Position, area: Rect) -> Option<VirtualPosition> {
+        let relative = area.relative_position(position)?;
+        let virtual_position = VirtualPosition::new(
+            self.x.checked_add(u32::from(relative.x))?,
+            self.y.checked_add(u32::from(relative.y))?,
+        );
+        self.contains(virtual_position).then_some(virtual_position)
+    }
+}
+
+impl From<Rect> for VirtualRect {
+    fn from(rect: Rect) -> Self {
+        Self::new(
+            u32::from(rect.x),
+            u32::from(rect.y),
+            u32::from(rect.width),
+            u32::from(rect.height),
+        )
+    }
+}
+
+impl From<(VirtualPosition, Size)> for VirtualRect {
+    fn from((position, size): (VirtualPosition, Size)) -> Self {
+        Self::new(
+            position.x,
+            position.y,
+            u32::from(size.width),
+            u32::from(size.height),
+        )
+    }
+}
+
+impl fmt::Display for VirtualRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}+{}+{}", self.width, self.height, self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn new_limits_size() {
+        let rect = VirtualRect::new(u32::MAX - 10, 0, 100, 100);
+        assert_eq!(rect.width, 10);
+        assert_eq!(rect.right(), u32::MAX);
+        assert_eq!(rect.area(), 1000);
+    }
+
+    #[test]
+    fn from_rect() {
+        assert_eq!(
+            VirtualRect::from(Rect::new(1, 2, 3, 4)),
+            VirtualRect::new(1, 2, 3, 4)
+        );
+        assert_eq!(
+            VirtualRect::from((VirtualPosition::new(1, 70_000), Size::new(3, 4))),
+            VirtualRect::new(1, 70_000, 3, 4)
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let rect = VirtualRect::new(0, 100_000, 10, 10);
+        let other = VirtualRect::new(5, 100_005, 10, 10);
+        assert!(rect.intersects(other));
+        assert_eq!(rect.intersection(other), VirtualRect::new(5, 100_005, 5, 5));
+        assert!(!rect.intersects(VirtualRect::new(0, 100_010, 10, 10)));
+    }
+
+    #[test]
+    fn clamp() {
+        let content = VirtualRect::new(0, 0, 80, 1_000_000);
+        let viewport = VirtualRect::new(0, 999_998, 80, 10);
+        assert_eq!(
+            viewport.clamp(content),
+            VirtualRect::new(0, 999_990, 80, 10)
+        );
+    }
+
+    #[rstest]
+    #[case::visible(VirtualRect::new(0, 102, 1, 1), VirtualRect::new(0, 100, 10, 5))]
+    #[case::above(VirtualRect::new(0, 50, 1, 1), VirtualRect::new(0, 50, 10, 5))]
+    #[case::below(VirtualRect::new(0, 200, 1, 2), VirtualRect::new(0, 197, 10, 5))]
+    #[case::right(VirtualRect::new(12, 100, 3, 1), VirtualRect::new(5, 100, 10, 5))]
+    #[case::larger(VirtualRect::new(0, 200, 1, 10), VirtualRect::new(0, 200, 10, 5))]
+    fn scroll_to(#[case] target: VirtualRect, #[case] expected: VirtualRect) {
+        let viewport = VirtualRect::new(0, 100, 10, 5);
+        assert_eq!(viewport.scroll_to(target), expected);
+    }
+
+    #[rstest]
+    #[case::inside(VirtualRect::new(2, 100_001, 3, 2), Some(Rect::new(3, 3, 3, 2)))]
+    #[case::clipped(VirtualRect::new(0, 99_000, 100, 1_002), Some(Rect::new(1, 2, 10, 2)))]
+    #[case::outside(VirtualRect::new(0, 100_005, 10, 1), None)]
+    fn to_screen(#[case] rect: VirtualRect, #[case] expected: Option<Rect>) {
+        let area = Rect::new(1, 2, 10, 5);
+        let viewport = VirtualRect::viewport(VirtualPosition::new(0, 100_000), area);
+        assert_eq!(viewport.to_screen(rect, area), expected);
+    }
+
+    #[rstest]
+    #[case::inside(Position::new(3, 4), Some(VirtualPosition::new(2, 100_002)))]
+    #[case::outside(Position::new(0, 4), None)]
+    fn to_virtual(#[case] position: Position, #[case] expected: Option<VirtualPosition>) {
+        let area = Rect::new(1, 2, 10, 5);
+        let viewport = VirtualRect::viewport(VirtualPosition::new(0, 100_000), area);
+        assert_eq!(viewport.to_virtual(position, area), expected);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(
+            VirtualRect::new(1, 100_000, 3, 4).to_string(),
+            "3x4+1+100000"
+        );
+        assert_eq!(VirtualPosition::new(1, 2).to_string(), "(1, 2)");
+    }
+}
@@ -81,6 +81,7 @@ pub use stylize::{Styled, Stylize};
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
+mod blend;
 mod color;
 pub mod palette;
 #[cfg(feature = "palette")]
@@ -245,6 +246,7 @@ impl fmt::Debug for Modifier {
 /// ```
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Style {
     /// The foreground color.
     pub fg: Option<Color>,
@@ -419,12 +421,101 @@ impl Style {
         self
     }
 
+    /// Explicitly resets the foreground color to the terminal default.
+    ///
+    /// Unlike a style without a foreground color, which inherits the foreground color of the style
+    /// it is [patched](Self::patch) onto, this clears any inherited foreground color.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style};
+    ///
+    /// let parent = Style::new().fg(Color::Red).bg(Color::Blue);
+    /// let child = Style::new().reset_fg();
+    /// assert_eq!(
+    ///     parent.patch(child),
+    ///     Style::new().fg(Color::Reset).bg(Color::Blue)
+    /// );
+    /// ```
+    #[must_use = "`reset_fg` returns the modified style without modifying the original"]
+    pub const fn reset_fg(self) -> Self {
+        self.fg(Color::Reset)
+    }
+
+    /// Explicitly resets the background color to the terminal default.
+    ///
+    /// Unlike a style without a background color, which inherits the background color of the style
+    /// it is [patched](Self::patch) onto, this clears any inherited background color.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style};
+    ///
+    /// let parent = Style::new().fg(Color::Red).bg(Color::Blue);
+    /// let child = Style::new().reset_bg();
+    /// assert_eq!(
+    ///     parent.patch(child),
+    ///     Style::new().fg(Color::Red).bg(Color::Reset)
+    /// );
+    /// ```
+    #[must_use = "`reset_bg` returns the modified style without modifying the original"]
+    pub const fn reset_bg(self) -> Self {
+        self.bg(Color::Reset)
+    }
+
+    /// Explicitly resets the underline color to the terminal default.
+    ///
+    /// Unlike a style without an underline color, which inherits the underline color of the style
+    /// it is [patched](Self::patch) onto, this clears any inherited underline color.
+    #[cfg(feature = "underline-color")]
+    #[must_use = "`reset_underline_color` returns the modified style without modifying the original"]
+    pub const fn reset_underline_color(self) -> Self {
+        self.underline_color(Color::Reset)
+    }
+
+    /// Explicitly removes all the modifiers.
+    ///
+    /// Unlike a style without modifiers, which inherits the modifiers of the style it is
+    /// [patched](Self::patch) onto, this clears all inherited modifiers. Modifiers added after
+    /// calling this method are kept.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Modifier, Style};
+    ///
+    /// let parent = Style::new().add_modifier(Modifier::BOLD | Modifier::ITALIC);
+    /// let child = Style::new()
+    ///     .reset_modifiers()
+    ///     .add_modifier(Modifier::UNDERLINED);
+    /// let patched = parent.patch(child);
+    /// assert_eq!(patched.add_modifier, Modifier::UNDERLINED);
+    /// assert_eq!(patched.sub_modifier, Modifier::all() - Modifier::UNDERLINED);
+    /// ```
+    #[must_use = "`reset_modifiers` returns the modified style without modifying the original"]
+    pub const fn reset_modifiers(mut self) -> Self {
+        self.add_modifier = Modifier::empty();
+        self.sub_modifier = Modifier::all();
+        self
+    }
+
     /// Results in a combined style that is equivalent to applying the two individual styles to
     /// a style one after the other.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
     /// your own type that implements [`Into<Style>`]).
     ///
+    /// The properties of `other` cascade onto `self` with the following rules:
+    ///
+    /// - A color that is set in `other` replaces the color of `self`, and a color that is not set
+    ///   (`None`) is inherited from `self`. Setting a color to [`Color::Reset`] (e.g. with
+    ///   [`reset_fg`](Self::reset_fg)) explicitly clears the inherited color.
+    /// - Modifiers added by `other` are added, modifiers removed by `other` are removed, and all
+    ///   other modifiers are inherited from `self`. Removing all the modifiers (e.g. with
+    ///   [`reset_modifiers`](Self::reset_modifiers)) explicitly clears the inherited modifiers.
+    ///
     /// ## Examples
     /// ```
     /// use ratatui_core::style::{Color, Modifier, Style};
@@ -438,20 +529,48 @@ impl Style {
     /// );
     /// ```
     #[must_use = "`patch` returns the modified style without modifying the original"]
-    pub fn patch<S: Into<Self>>(mut self, other: S) -> Self {
-        let other = other.into();
-        self.fg = other.fg.or(self.fg);
-        self.bg = other.bg.or(self.bg);
+    pub fn patch<S: Into<Self>>(self, other: S) -> Self {
+        self.merge(other.into())
+    }
+
+    /// Patches the style with another style in `const` contexts.
+    ///
+    /// This is the same as [`patch`](Self::patch), but only accepts a [`Style`] so that it can be
+    /// used to define the styles of an application as constants.
+    ///
+    /// ## Examples
+    /// ```
+    /// use ratatui_core::style::{Color, Modifier, Style};
+    ///
+    /// const BASE: Style = Style::new().fg(Color::White).bg(Color::Black);
+    /// const HIGHLIGHT: Style =
+    ///     BASE.merge(Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    /// assert_eq!(
+    ///     HIGHLIGHT,
+    ///     Style::new()
+    ///         .fg(Color::Yellow)
+    ///         .bg(Color::Black)
+    ///         .add_modifier(Modifier::BOLD)
+    /// );
+    /// ```
+    #[must_use = "`merge` returns the modified style without modifying the original"]
+    pub const fn merge(mut self, other: Self) -> Self {
+        self.fg = or_color(other.fg, self.fg);
+        self.bg = or_color(other.bg, self.bg);
 
         #[cfg(feature = "underline-color")]
         {
-            self.underline_color = other.underline_color.or(self.underline_color);
+            self.underline_color = or_color(other.underline_color, self.underline_color);
         }
 
-        self.add_modifier.remove(other.sub_modifier);
-        self.add_modifier.insert(other.add_modifier);
-        self.sub_modifier.remove(other.add_modifier);
-        self.sub_modifier.insert(other.sub_modifier);
+        self.add_modifier = self
+            .add_modifier
+            .difference(other.sub_modifier)
+            .union(other.add_modifier);
+        self.sub_modifier = self
+            .sub_modifier
+            .difference(other.add_modifier)
+            .union(other.sub_modifier);
 
         self
     }
@@ -505,6 +624,14 @@ impl Style {
     }
 }
 
+/// Returns `color` if it is set, otherwise `fallback`, like [`Option::or`] in `const` contexts.
+const fn or_color(color: Option<Color>, fallback: Option<Color>) -> Option<Color> {
+    match color {
+        Some(_) => color,
+        None => fallback,
+    }
+}
+
 impl From<Color> for Style {
     /// Creates a new `Style` with the given foreground color.
     ///
@@ -748,6 +875,7 @@ mod tests {
             .bg(BLACK)
             .add_modifier(BOLD)
             .remove_modifier(ITALIC);
+        const _MERGED: Style = ALL.merge(Style::new().fg(BLACK).add_modifier(ITALIC));
         assert_eq!(
             ALL,
             Style::new()
@@ -830,6 +958,47 @@ mod tests {
         assert_eq!(stylized, Style::new().remove_modifier(expected));
     }
 
+    #[test]
+    fn patch_reset_colors() {
+        let parent = Style::new().fg(Color::Red).bg(Color::Blue);
+        assert_eq!(
+            parent.patch(Style::new().reset_fg()),
+            Style::new().fg(Color::Reset).bg(Color::Blue)
+        );
+        assert_eq!(
+            parent.patch(Style::new().reset_bg()),
+            Style::new().fg(Color::Red).bg(Color::Reset)
+        );
+        assert_eq!(
+            parent
+                .patch(Style::new().reset_fg().reset_bg())
+                .patch(Style::new().fg(Color::Green)),
+            Style::new().fg(Color::Green).bg(Color::Reset)
+        );
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn patch_reset_underline_color() {
+        let parent = Style::new().underline_color(Color::Red);
+        assert_eq!(
+            parent.patch(Style::new().reset_underline_color()),
+            Style::new().underline_color(Color::Reset)
+        );
+    }
+
+    #[test]
+    fn patch_reset_modifiers() {
+        let parent = Style::new().add_modifier(Modifier::BOLD | Modifier::ITALIC);
+        let patched = parent.patch(Style::new().reset_modifiers());
+        assert_eq!(patched.add_modifier, Modifier::empty());
+        assert_eq!(patched.sub_modifier, Modifier::all());
+
+        let patched = parent.patch(Style::new().reset_modifiers().add_modifier(Modifier::DIM));
+        assert_eq!(patched.add_modifier, Modifier::DIM);
+        assert_eq!(patched.sub_modifier, Modifier::all() - Modifier::DIM);
+    }
+
     #[test]
     fn reset_can_be_stylized() {
         assert_eq!(Style::new().reset(), Style::reset());
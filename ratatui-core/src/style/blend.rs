@@ -0,0 +1,167 @@
+//! Approximate alpha blending of [`Color`]s, used by [`Buffer::dim`] and [`Buffer::blend_bg`].
+//!
+//! [`Buffer::dim`]: crate::buffer::Buffer::dim
+//! [`Buffer::blend_bg`]: crate::buffer::Buffer::blend_bg
+use crate::style::Color;
+
+/// The levels of each channel in the 6×6×6 color cube of the indexed colors 16 to 231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 named colors, in the order of their ANSI index.
+const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// How many colors a color can be approximated with, from the fewest to the most.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+enum Depth {
+    Named,
+    Indexed,
+    Rgb,
+}
+
+impl Color {
+    /// Returns the RGB components of the color, or `None` for [`Color::Reset`].
+    ///
+    /// The named colors and the first 16 indexed colors depend on the theme of the terminal, so
+    /// their components are those of the xterm defaults.
+    pub(crate) const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            Self::Reset => return None,
+            Self::Black => (0, 0, 0),
+            Self::Red => (128, 0, 0),
+            Self::Green => (0, 128, 0),
+            Self::Yellow => (128, 128, 0),
+            Self::Blue => (0, 0, 128),
+            Self::Magenta => (128, 0, 128),
+            Self::Cyan => (0, 128, 128),
+            Self::Gray => (192, 192, 192),
+            Self::DarkGray => (128, 128, 128),
+            Self::LightRed => (255, 0, 0),
+            Self::LightGreen => (0, 255, 0),
+            Self::LightYellow => (255, 255, 0),
+            Self::LightBlue => (0, 0, 255),
+            Self::LightMagenta => (255, 0, 255),
+            Self::LightCyan => (0, 255, 255),
+            Self::White => (255, 255, 255),
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(i @ 0..=15) => return NAMED[i as usize].to_rgb(),
+            Self::Indexed(i @ 16..=231) => {
+                let i = i - 16;
+                (
+                    CUBE_LEVELS[(i / 36) as usize],
+                    CUBE_LEVELS[(i / 6 % 6) as usize],
+                    CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            Self::Indexed(i) => {
+                let level = 8 + (i - 232) * 10;
+                (level, level, level)
+            }
+        };
+        Some(rgb)
+    }
+
+    /// Blends `other` over this color with the given opacity, between 0 and 1.
+    ///
+    /// The result is an RGB color only when both colors are RGB colors. Otherwise, it is the
+    /// closest indexed color (when neither color is a named color) or the closest named color, so
+    /// that terminals that do not support true colors or 256 colors still display something
+    /// sensible. Returns `None` if either color is [`Color::Reset`], as its actual value is not
+    /// known.
+    pub(crate) fn blend(self, other: Self, alpha: f64) -> Option<Self> {
+        let (r1, g1, b1) = self.to_rgb()?;
+        let (r2, g2, b2) = other.to_rgb()?;
+        let alpha = alpha.clamp(0.0, 1.0);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mix = |a: u8, b: u8| {
+            (f64::from(b) - f64::from(a))
+                .mul_add(alpha, f64::from(a))
+                .round() as u8
+        };
+        let rgb = (mix(r1, r2), mix(g1, g2), mix(b1, b2));
+        let color = match self.depth().min(other.depth()) {
+            Depth::Rgb => Self::Rgb(rgb.0, rgb.1, rgb.2),
+            Depth::Indexed => Self::closest((16..=255).map(Self::Indexed), rgb),
+            Depth::Named => Self::closest(NAMED.into_iter(), rgb),
+        };
+        Some(color)
+    }
+
+    const fn depth(self) -> Depth {
+        match self {
+            Self::Rgb(..) => Depth::Rgb,
+            Self::Indexed(16..=255) => Depth::Indexed,
+            _ => Depth::Named,
+        }
+    }
+
+    /// Returns the candidate with the RGB components closest to `rgb`.
+    fn closest(candidates: impl Iterator<Item = Self>, (r, g, b): (u8, u8, u8)) -> Self {
+        let distance = |color: &Self| {
+            let (r2, g2, b2) = color.to_rgb().unwrap_or_default();
+            let [dr, dg, db] = [r.abs_diff(r2), g.abs_diff(g2), b.abs_diff(b2)].map(u32::from);
+            dr * dr + dg * dg + db * db
+        };
+        candidates
+            .min_by_key(distance)
+            .expect("there is at least one candidate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::named(Color::LightBlue, Some((0, 0, 255)))]
+    #[case::indexed_named(Color::Indexed(9), Some((255, 0, 0)))]
+    #[case::indexed_cube(Color::Indexed(67), Some((95, 135, 175)))]
+    #[case::indexed_gray(Color::Indexed(244), Some((128, 128, 128)))]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some((1, 2, 3)))]
+    #[case::reset(Color::Reset, None)]
+    fn to_rgb(#[case] color: Color, #[case] expected: Option<(u8, u8, u8)>) {
+        assert_eq!(color.to_rgb(), expected);
+    }
+
+    #[rstest]
+    #[case::rgb(
+        Color::Rgb(200, 100, 0),
+        Color::Rgb(0, 0, 100),
+        Some(Color::Rgb(100, 50, 50))
+    )]
+    #[case::indexed(Color::Indexed(231), Color::Rgb(0, 0, 0), Some(Color::Indexed(244)))]
+    #[case::named(Color::White, Color::Black, Some(Color::DarkGray))]
+    #[case::named_over_rgb(Color::Rgb(255, 0, 0), Color::Red, Some(Color::LightRed))]
+    #[case::reset(Color::Reset, Color::Black, None)]
+    fn blend(#[case] color: Color, #[case] other: Color, #[case] expected: Option<Color>) {
+        assert_eq!(color.blend(other, 0.5), expected);
+    }
+
+    #[test]
+    fn blend_clamps_alpha() {
+        let color = Color::Rgb(10, 20, 30);
+        assert_eq!(color.blend(Color::Rgb(0, 0, 0), -1.0), Some(color));
+        assert_eq!(
+            color.blend(Color::Rgb(0, 0, 0), 2.0),
+            Some(Color::Rgb(0, 0, 0))
+        );
+    }
+}
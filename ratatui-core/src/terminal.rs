@@ -32,9 +32,13 @@
 //! [`Buffer`]: crate::buffer::Buffer
 
 mod frame;
+mod state_store;
 mod terminal;
+mod tooltip;
 mod viewport;
 
 pub use frame::{CompletedFrame, Frame};
-pub use terminal::{Options as TerminalOptions, Terminal};
+pub use state_store::{StateStore, WidgetId};
+pub use terminal::{CursorState, Options as TerminalOptions, Terminal};
+pub use tooltip::Tooltip;
 pub use viewport::Viewport;
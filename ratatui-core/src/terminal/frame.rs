@@ -1,7 +1,11 @@
+use std::any::Any;
+
 use crate::{
-    buffer::Buffer,
+    buffer::{Buffer, Compositor, Layer},
     layout::{Position, Rect},
-    widgets::{StatefulWidget, Widget},
+    style::Style,
+    terminal::{StateStore, Tooltip, WidgetId},
+    widgets::{HasCursor, StatefulWidget, Widget},
 };
 
 /// A consistent view into the terminal state for rendering a single frame.
@@ -29,8 +33,23 @@ pub struct Frame<'a> {
     /// The buffer that is used to draw the current frame
     pub(crate) buffer: &'a mut Buffer,
 
+    /// The states of the widgets, kept by the terminal across frames
+    pub(crate) states: &'a mut StateStore,
+
     /// The frame count indicating the sequence number of this frame.
     pub(crate) count: usize,
+
+    /// The position of the mouse pointer, as set by `Terminal::set_pointer_position()`
+    pub(crate) pointer_position: Option<Position>,
+
+    /// The tooltips registered for this frame and the regions they belong to
+    pub(crate) tooltips: Vec<(Rect, Tooltip)>,
+
+    /// The ambient styles pushed with `Frame::push_style()`, innermost last
+    pub(crate) styles: Vec<Style>,
+
+    /// The layers drawn on top of the buffer once the frame has been rendered
+    pub(crate) layers: Compositor,
 }
 
 /// `CompletedFrame` represents the state of the terminal after all changes performed in the last
@@ -93,6 +112,10 @@ impl Frame<'_> {
     ///
     /// [`Layout`]: crate::layout::Layout
     pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("render", widget = std::any::type_name::<W>(), %area).entered();
+        self.apply_ambient_style(area);
         widget.render(area, self.buffer);
     }
 
@@ -127,9 +150,197 @@ impl Frame<'_> {
     where
         W: StatefulWidget,
     {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("render", widget = std::any::type_name::<W>(), %area).entered();
+        self.apply_ambient_style(area);
+        widget.render(area, self.buffer, state);
+    }
+
+    /// Render a [`StatefulWidget`] to the current buffer with the state stored for the given id.
+    ///
+    /// The state is kept by the [`Terminal`] across frames in its [`StateStore`], so that the
+    /// application does not need to own it. The first time a widget is rendered with an id, its
+    /// state is created with [`Default::default`]. The state can be read or updated between frames
+    /// with [`Terminal::states_mut`], or during the frame with [`Frame::states`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new(vec![ListItem::new("Item 1"), ListItem::new("Item 2")]);
+    /// frame.render_stateful_widget_with_id(list, frame.area(), "menu");
+    /// ```
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    /// [`Terminal::states_mut`]: crate::terminal::Terminal::states_mut
+    pub fn render_stateful_widget_with_id<W, I>(&mut self, widget: W, area: Rect, id: I)
+    where
+        W: StatefulWidget,
+        W::State: Any + Clone + Default + Send,
+        I: Into<WidgetId>,
+    {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("render", widget = std::any::type_name::<W>(), %area).entered();
+        self.apply_ambient_style(area);
+        let state = self.states.get_or_default::<W::State>(id.into());
         widget.render(area, self.buffer, state);
     }
 
+    /// Gets the states of the widgets kept by the terminal across frames.
+    ///
+    /// See [`Frame::render_stateful_widget_with_id`].
+    pub fn states(&mut self) -> &mut StateStore {
+        self.states
+    }
+
+    /// Pushes a style onto the ambient style of the frame.
+    ///
+    /// Until it is popped with [`Frame::pop_style`], the area of each widget rendered with
+    /// [`Frame::render_widget`] or [`Frame::render_stateful_widget`] is styled with the ambient
+    /// style before the widget is rendered. The widgets inherit it as their base style: the styles
+    /// they set themselves are added on top of it. This lets the application style whole parts of
+    /// the screen, e.g. dim everything inside an inactive pane, without each widget exposing a
+    /// style parameter.
+    ///
+    /// Pushed styles are combined, the last one being added on top of the others. Container
+    /// widgets that only have access to the [`Buffer`] can get the same effect by calling
+    /// [`Buffer::set_style`] on the area of their children before rendering them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::Text,
+    /// };
+    ///
+    /// frame.push_style(Style::new().dim());
+    /// frame.render_widget(Text::raw("inactive pane"), frame.area());
+    /// frame.pop_style();
+    /// ```
+    pub fn push_style<S: Into<Style>>(&mut self, style: S) {
+        self.styles.push(style.into());
+    }
+
+    /// Pops the last style pushed with [`Frame::push_style`], returning it.
+    ///
+    /// Returns `None` if there is no pushed style.
+    pub fn pop_style(&mut self) -> Option<Style> {
+        self.styles.pop()
+    }
+
+    /// Returns the combination of the styles pushed with [`Frame::push_style`].
+    ///
+    /// This is an empty style if there is no pushed style.
+    pub fn ambient_style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(Style::new(), |ambient, &style| ambient.patch(style))
+    }
+
+    /// Styles the area with the ambient style before a widget is rendered into it.
+    fn apply_ambient_style(&mut self, area: Rect) {
+        if !self.styles.is_empty() {
+            let style = self.ambient_style();
+            self.buffer.set_style(area, style);
+        }
+    }
+
+    /// Registers a [`Tooltip`] for the given region.
+    ///
+    /// After the frame has been rendered, the tooltip of the region under the mouse pointer, or
+    /// the focused tooltip if the pointer is not over any region, is drawn on top of it. When
+    /// regions overlap, the one registered last wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::{layout::Rect, widgets::Block, Tooltip};
+    ///
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// frame.render_widget(Block::bordered(), area);
+    /// frame.set_tooltip(area, Tooltip::new("An empty block"));
+    /// ```
+    pub fn set_tooltip(&mut self, area: Rect, tooltip: Tooltip) {
+        self.tooltips.push((area, tooltip));
+    }
+
+    /// Returns the position of the mouse pointer set with [`Terminal::set_pointer_position`].
+    ///
+    /// [`Terminal::set_pointer_position`]: crate::terminal::Terminal::set_pointer_position
+    pub const fn pointer_position(&self) -> Option<Position> {
+        self.pointer_position
+    }
+
+    /// Gets the [`Layer`] with the given z-index, to render widgets on top of the frame.
+    ///
+    /// After the frame has been rendered, its layers are drawn on top of it from the lowest
+    /// z-index to the highest, before the tooltip. This lets popups and notifications be rendered
+    /// at any point, without clearing the regions they cover. The cells of a layer that no widget
+    /// draws to are transparent, see [`Layer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{backend::TestBackend, layout::Rect, terminal::Terminal, text::Line};
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(7, 1))?;
+    /// terminal.draw(|frame| {
+    ///     frame
+    ///         .layer(1)
+    ///         .render_widget(Line::raw("popup"), Rect::new(1, 0, 5, 1));
+    ///     frame.render_widget(Line::raw("content"), frame.area());
+    /// })?;
+    /// terminal.backend().assert_buffer_lines(["cpopupt"]);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn layer(&mut self, z: i32) -> &mut Layer {
+        self.layers.layer(z)
+    }
+
+    /// Draws the layers on top of the frame.
+    pub(crate) fn composite_layers(&mut self) {
+        self.layers.composite(self.buffer);
+    }
+
+    /// Draws the tooltip of the hovered region, or of the focused region, on top of the frame.
+    pub(crate) fn render_tooltip(&mut self) {
+        let pointer = self.pointer_position;
+        let hovered = pointer.and_then(|pointer| {
+            self.tooltips
+                .iter()
+                .rev()
+                .find(|(area, _)| area.contains(pointer))
+                .map(|tooltip| (tooltip, Some(pointer)))
+        });
+        let target = hovered.or_else(|| {
+            self.tooltips
+                .iter()
+                .rev()
+                .find(|(_, tooltip)| tooltip.is_focused())
+                .map(|tooltip| (tooltip, None))
+        });
+        if let Some(((region, tooltip), pointer)) = target {
+            let area = tooltip.area(*region, pointer, self.viewport_area);
+            tooltip.render(area, self.buffer);
+        }
+    }
+
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
     /// coordinates. If this method is not called, the cursor will be hidden.
     ///
@@ -144,6 +355,76 @@ impl Frame<'_> {
         self.cursor_position = Some(position.into());
     }
 
+    /// After drawing this frame, make the cursor visible and put it at the given offset from the
+    /// top left corner of an area, e.g. the area of the widget being edited.
+    ///
+    /// The position is clamped to the area, so that the cursor stays within the widget, e.g. when
+    /// it is placed after the last character of a full input field. If the area is empty, the
+    /// cursor is put at its top left corner.
+    ///
+    /// See [`Frame::set_cursor_position`] for how this interacts with the other cursor APIs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(20, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui_core::{layout::Rect, text::Line};
+    ///
+    /// let input = "hello";
+    /// let area = Rect::new(2, 1, 10, 1);
+    /// frame.render_widget(Line::raw(input), area);
+    /// frame.set_cursor_in(area, input.len() as u16, 0);
+    /// ```
+    pub fn set_cursor_in(&mut self, area: Rect, x_offset: u16, y_offset: u16) {
+        let x = area
+            .x
+            .saturating_add(x_offset.min(area.width.saturating_sub(1)));
+        let y = area
+            .y
+            .saturating_add(y_offset.min(area.height.saturating_sub(1)));
+        self.set_cursor_position(Position { x, y });
+    }
+
+    /// After drawing this frame, make the cursor visible at the position requested by the state
+    /// of a widget that was rendered, such as a text input. The cursor is left unchanged if the
+    /// state does not request a position, e.g. when its widget is not focused.
+    ///
+    /// See [`HasCursor`] for how widgets report the position of the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(40, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui_core::{layout::Position, widgets::HasCursor};
+    ///
+    /// // the state of a text input, updated when the input is rendered
+    /// struct InputState {
+    ///     cursor_position: Option<Position>,
+    /// }
+    ///
+    /// impl HasCursor for InputState {
+    ///     fn cursor_position(&self) -> Option<Position> {
+    ///         self.cursor_position
+    ///     }
+    /// }
+    ///
+    /// let state = InputState {
+    ///     cursor_position: Some(Position::new(7, 2)),
+    /// };
+    /// frame.set_cursor_from(&state);
+    /// ```
+    pub fn set_cursor_from<S: HasCursor + ?Sized>(&mut self, state: &S) {
+        if let Some(position) = state.cursor_position() {
+            self.set_cursor_position(position);
+        }
+    }
+
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
     /// coordinates. If this method is not called, the cursor will be hidden.
     ///
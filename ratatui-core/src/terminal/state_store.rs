@@ -0,0 +1,293 @@
+use std::{
+    any::Any,
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// The identity of a widget across frames, used to look up its state in a [`StateStore`].
+///
+/// An id is created from a number or from a name. Widgets that are rendered in a loop can derive
+/// distinct ids from a common one with [`WidgetId::with_index`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::terminal::WidgetId;
+///
+/// let sidebar = WidgetId::from("sidebar");
+/// assert_eq!(sidebar, WidgetId::from_name("sidebar"));
+/// assert_ne!(sidebar.with_index(0), sidebar.with_index(1));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct WidgetId(u64);
+
+impl WidgetId {
+    /// Creates an id from a number.
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Creates an id from a name.
+    ///
+    /// The same name always gives the same id during the execution of the program.
+    pub fn from_name(name: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+
+    /// Returns the id of the `index`-th child of this widget.
+    #[must_use]
+    pub fn with_index(self, index: usize) -> Self {
+        let mut hasher = DefaultHasher::new();
+        (self.0, index).hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+impl From<u64> for WidgetId {
+    fn from(id: u64) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<&str> for WidgetId {
+    fn from(name: &str) -> Self {
+        Self::from_name(name)
+    }
+}
+
+/// A state that can be kept in a [`StateStore`].
+///
+/// This is implemented for all the types that are `Clone`, `Send` and `'static`, so that the
+/// store, and the terminal that owns it, can be sent to another thread.
+trait StoredState: Any + Send {
+    fn clone_box(&self) -> Box<dyn StoredState>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: Any + Clone + Send> StoredState for T {
+    fn clone_box(&self) -> Box<dyn StoredState> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// The states of stateful widgets, kept across frames by the [`Terminal`].
+///
+/// Applications usually own the state of each stateful widget (such as the selection of a list or
+/// the offset of a scrolled paragraph) and pass it to [`Frame::render_stateful_widget`]. The store
+/// removes the need to thread each of these states through the application: the state is looked
+/// up by a [`WidgetId`] with [`Frame::render_stateful_widget_with_id`], and created with its
+/// default value the first time the widget is rendered.
+///
+/// The store can also be used outside of [`Terminal::draw`] with [`Terminal::states_mut`], e.g. to
+/// move the selection of a list when a key is pressed.
+///
+/// Each id holds a single state. Storing a state of a different type under an existing id
+/// replaces the previous state. As the states are opaque, two stores are equal when they hold
+/// states for the same ids.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::terminal::{StateStore, WidgetId};
+///
+/// #[derive(Debug, Default, Clone, PartialEq)]
+/// struct Counter(u32);
+///
+/// let mut states = StateStore::new();
+/// let id = WidgetId::from("counter");
+/// states.get_or_default::<Counter>(id).0 += 1;
+/// states.get_or_default::<Counter>(id).0 += 1;
+/// assert_eq!(states.get::<Counter>(id), Some(&Counter(2)));
+/// ```
+///
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+/// [`Terminal::states_mut`]: crate::terminal::Terminal::states_mut
+/// [`Frame::render_stateful_widget`]: crate::terminal::Frame::render_stateful_widget
+/// [`Frame::render_stateful_widget_with_id`]: crate::terminal::Frame::render_stateful_widget_with_id
+#[derive(Default)]
+pub struct StateStore {
+    states: HashMap<WidgetId, Box<dyn StoredState>>,
+}
+
+impl StateStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the state of the widget, or `None` if there is no state of type `S` for it.
+    pub fn get<S: Any + Send>(&self, id: WidgetId) -> Option<&S> {
+        self.states.get(&id)?.as_ref().as_any().downcast_ref()
+    }
+
+    /// Returns the state of the widget as a mutable reference, or `None` if there is no state of
+    /// type `S` for it.
+    pub fn get_mut<S: Any + Send>(&mut self, id: WidgetId) -> Option<&mut S> {
+        self.states
+            .get_mut(&id)?
+            .as_mut()
+            .as_any_mut()
+            .downcast_mut()
+    }
+
+    /// Returns the state of the widget, inserting the default state if there is no state of type
+    /// `S` for it.
+    pub fn get_or_default<S: Any + Clone + Default + Send>(&mut self, id: WidgetId) -> &mut S {
+        let state = self
+            .states
+            .entry(id)
+            .or_insert_with(|| Box::new(S::default()));
+        if !state.as_ref().as_any().is::<S>() {
+            *state = Box::new(S::default());
+        }
+        state
+            .as_mut()
+            .as_any_mut()
+            .downcast_mut()
+            .expect("the state was just checked to be of type `S`")
+    }
+
+    /// Sets the state of the widget, returning the previous state if it was of type `S`.
+    pub fn insert<S: Any + Clone + Send>(&mut self, id: WidgetId, state: S) -> Option<S> {
+        let previous = self.states.insert(id, Box::new(state))?;
+        previous.into_any().downcast().ok().map(|state| *state)
+    }
+
+    /// Removes the state of the widget, returning it if it was of type `S`.
+    pub fn remove<S: Any + Send>(&mut self, id: WidgetId) -> Option<S> {
+        let state = self.states.remove(&id)?;
+        state.into_any().downcast().ok().map(|state| *state)
+    }
+
+    /// Returns whether there is a state for the widget.
+    pub fn contains(&self, id: WidgetId) -> bool {
+        self.states.contains_key(&id)
+    }
+
+    /// Returns the number of states.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns whether the store is empty.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Removes all the states.
+    pub fn clear(&mut self) {
+        self.states.clear();
+    }
+
+    /// Returns the ids of the states, sorted.
+    fn sorted_ids(&self) -> Vec<WidgetId> {
+        let mut ids: Vec<WidgetId> = self.states.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+impl Clone for StateStore {
+    fn clone(&self) -> Self {
+        Self {
+            states: self
+                .states
+                .iter()
+                .map(|(&id, state)| (id, state.as_ref().clone_box()))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Debug for StateStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateStore")
+            .field("ids", &self.sorted_ids())
+            .finish()
+    }
+}
+
+impl PartialEq for StateStore {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.states.keys().all(|&id| other.contains(id))
+    }
+}
+
+impl Eq for StateStore {}
+
+impl Hash for StateStore {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sorted_ids().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_or_default() {
+        let mut states = StateStore::new();
+        let id = WidgetId::new(1);
+        assert_eq!(states.get::<u32>(id), None);
+        *states.get_or_default::<u32>(id) += 2;
+        assert_eq!(states.get::<u32>(id), Some(&2));
+        // a state of another type replaces the previous one
+        assert_eq!(states.get_or_default::<String>(id), "");
+        assert_eq!(states.get::<u32>(id), None);
+        assert_eq!(states.len(), 1);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut states = StateStore::new();
+        let id = WidgetId::from("list");
+        assert_eq!(states.insert(id, 1_u8), None);
+        assert_eq!(states.insert(id, 2_u8), Some(1));
+        *states.get_mut::<u8>(id).unwrap() += 1;
+        assert_eq!(states.remove::<i64>(id), None);
+        assert!(!states.contains(id));
+        states.insert(id, 4_u8);
+        assert_eq!(states.remove::<u8>(id), Some(4));
+        assert!(states.is_empty());
+    }
+
+    #[test]
+    fn is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<StateStore>();
+    }
+
+    #[test]
+    fn clone_and_compare() {
+        let mut states = StateStore::new();
+        states.insert(WidgetId::new(1), vec![1, 2]);
+        let mut cloned = states.clone();
+        assert_eq!(cloned, states);
+        cloned
+            .get_mut::<Vec<i32>>(WidgetId::new(1))
+            .unwrap()
+            .push(3);
+        assert_eq!(states.get::<Vec<i32>>(WidgetId::new(1)), Some(&vec![1, 2]));
+        cloned.clear();
+        assert_ne!(cloned, states);
+    }
+}
@@ -1,10 +1,13 @@
-use std::io;
+use std::{
+    fmt, hash, io,
+    sync::{Arc, Mutex, PoisonError},
+};
 
 use crate::{
-    backend::{Backend, ClearType},
-    buffer::{Buffer, Cell},
+    backend::{Backend, ClearType, NotificationProtocol},
+    buffer::{Buffer, Cell, Compositor},
     layout::{Position, Rect, Size},
-    terminal::{CompletedFrame, Frame, TerminalOptions, Viewport},
+    terminal::{CompletedFrame, Frame, StateStore, TerminalOptions, Viewport},
 };
 
 /// An interface to interact and draw [`Frame`]s on the user's terminal.
@@ -77,6 +80,43 @@ where
     last_known_cursor_pos: Position,
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// Cursor states saved by [`Terminal::push_cursor_state`].
+    cursor_stack: Vec<CursorState>,
+    /// Regions excluded from clearing and diffing by [`Terminal::reserve_region`].
+    reserved_regions: Vec<Rect>,
+    /// The states of the widgets rendered with [`Frame::render_stateful_widget_with_id`].
+    states: StateStore,
+    /// The position of the mouse pointer, used to display tooltips.
+    pointer_position: Option<Position>,
+    /// The size before the last autoresize, if it changed the size of the terminal.
+    size_change: Option<Size>,
+    /// The hook set with [`Terminal::set_resize_hook`].
+    resize_hook: Option<ResizeHook>,
+    /// The hashes of the rows of the previous and current buffers, reused by every flush to find
+    /// the rows that moved.
+    #[cfg(feature = "scrolling-regions")]
+    row_hashes: Vec<u64>,
+}
+
+// Fails to compile if a field stops the terminal from being moved to another thread.
+const _: () = {
+    const fn assert_send<T: Send>() {}
+    assert_send::<Terminal<crate::backend::TestBackend>>();
+};
+
+/// A callback called with the previous and new sizes when the terminal is autoresized.
+#[derive(Clone)]
+struct ResizeHook(Arc<Mutex<dyn FnMut(Size, Size) + Send>>);
+
+/// A snapshot of the visibility and position of the cursor.
+///
+/// See [`Terminal::push_cursor_state`] and [`Terminal::pop_cursor_state`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CursorState {
+    /// Whether the cursor was hidden
+    pub hidden: bool,
+    /// The position of the cursor
+    pub position: Position,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -86,6 +126,27 @@ pub struct Options {
     pub viewport: Viewport,
 }
 
+impl fmt::Debug for ResizeHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ResizeHook")
+    }
+}
+
+// hooks are compared by identity, as closures cannot be compared
+impl PartialEq for ResizeHook {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ResizeHook {}
+
+impl hash::Hash for ResizeHook {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
 impl<B> Drop for Terminal<B>
 where
     B: Backend,
@@ -164,20 +225,60 @@ where
             last_known_area: area,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            cursor_stack: Vec::new(),
+            reserved_regions: Vec::new(),
+            states: StateStore::new(),
+            pointer_position: None,
+            size_change: None,
+            resize_hook: None,
+            #[cfg(feature = "scrolling-regions")]
+            row_hashes: Vec::new(),
         })
     }
 
     /// Get a Frame object which provides a consistent view into the terminal state for rendering.
     pub fn get_frame(&mut self) -> Frame {
-        let count = self.frame_count;
         Frame {
             cursor_position: None,
             viewport_area: self.viewport_area,
-            buffer: self.current_buffer_mut(),
-            count,
+            buffer: &mut self.buffers[self.current],
+            states: &mut self.states,
+            count: self.frame_count,
+            pointer_position: self.pointer_position,
+            tooltips: Vec::new(),
+            styles: Vec::new(),
+            layers: Compositor::new(self.viewport_area),
         }
     }
 
+    /// Sets the position of the mouse pointer, or `None` if it is unknown or outside the terminal.
+    ///
+    /// The position is used to display the tooltip of the hovered region, see
+    /// [`Frame::set_tooltip`]. It is usually updated from the mouse move events of the backend
+    /// before drawing the next frame.
+    pub fn set_pointer_position(&mut self, position: Option<Position>) {
+        self.pointer_position = position;
+    }
+
+    /// Returns the position of the mouse pointer set with [`Terminal::set_pointer_position`].
+    pub const fn pointer_position(&self) -> Option<Position> {
+        self.pointer_position
+    }
+
+    /// Returns the states of the widgets rendered with [`Frame::render_stateful_widget_with_id`].
+    pub const fn states(&self) -> &StateStore {
+        &self.states
+    }
+
+    /// Returns the states of the widgets rendered with [`Frame::render_stateful_widget_with_id`]
+    /// as a mutable reference.
+    ///
+    /// This allows updating the state of a widget between two frames, e.g. in response to a key
+    /// press.
+    pub fn states_mut(&mut self) -> &mut StateStore {
+        &mut self.states
+    }
+
     /// Gets the current buffer as a mutable reference.
     pub fn current_buffer_mut(&mut self) -> &mut Buffer {
         &mut self.buffers[self.current]
@@ -196,12 +297,23 @@ where
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
     pub fn flush(&mut self) -> io::Result<()> {
+        for region in &self.reserved_regions {
+            self.buffers[self.current].set_skip(*region, true);
+        }
+        #[cfg(feature = "scrolling-regions")]
+        self.scroll_moved_rows()?;
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
-        let updates = previous_buffer.diff(current_buffer);
+        let updates = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("diff").entered();
+            previous_buffer.diff(current_buffer)
+        };
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = Position { x: *col, y: *row };
         }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("flush", updates = updates.len()).entered();
         self.backend.draw(updates.into_iter())
     }
 
@@ -240,17 +352,78 @@ where
     }
 
     /// Queries the backend for size and resizes if it doesn't match the previous size.
+    ///
+    /// This is called at the start of each [`Terminal::draw`]. When the size changed, the hook set
+    /// with [`Terminal::set_resize_hook`] is called and [`Terminal::size_changed`] returns the
+    /// previous size until the next autoresize.
     pub fn autoresize(&mut self) -> io::Result<()> {
+        self.size_change = None;
         // fixed viewports do not get autoresized
         if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
             let area = Rect::from((Position::ORIGIN, self.size()?));
             if area != self.last_known_area {
+                let previous = self.last_known_area.as_size();
                 self.resize(area)?;
+                self.size_change = Some(previous);
+                if let Some(ResizeHook(hook)) = &self.resize_hook {
+                    let mut hook = hook.lock().unwrap_or_else(PoisonError::into_inner);
+                    (hook)(previous, area.as_size());
+                }
             }
         };
         Ok(())
     }
 
+    /// Returns the previous size of the terminal if the last draw observed a different size, or
+    /// `None` if the size did not change.
+    ///
+    /// This lets applications run expensive layout computations only when the terminal is
+    /// actually resized, rather than on every frame. The size is checked by
+    /// [`Terminal::autoresize`] at the start of each draw, and fixed viewports are never resized.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # let backend = ratatui::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui::Terminal::new(backend)?;
+    /// terminal.draw(|frame| { /* ... */ })?;
+    /// if let Some(previous) = terminal.size_changed() {
+    ///     println!("resized from {previous} to {}", terminal.size()?);
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub const fn size_changed(&self) -> Option<Size> {
+        self.size_change
+    }
+
+    /// Sets a hook called with the previous and the new size whenever the terminal is
+    /// autoresized, before the frame is rendered.
+    ///
+    /// This replaces the previous hook, if any. See [`Terminal::size_changed`] to check for a
+    /// resize after drawing instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # let backend = ratatui::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui::Terminal::new(backend)?;
+    /// terminal.set_resize_hook(|previous, size| {
+    ///     println!("resized from {previous} to {size}");
+    /// });
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_resize_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(Size, Size) + Send + 'static,
+    {
+        self.resize_hook = Some(ResizeHook(Arc::new(Mutex::new(hook))));
+    }
+
+    /// Removes the hook set with [`Terminal::set_resize_hook`].
+    pub fn clear_resize_hook(&mut self) {
+        self.resize_hook = None;
+    }
+
     /// Draws a single frame to the terminal.
     ///
     /// Returns a [`CompletedFrame`] if successful, otherwise a [`std::io::Error`].
@@ -388,6 +561,8 @@ where
         let mut frame = self.get_frame();
 
         render_callback(&mut frame).map_err(Into::into)?;
+        frame.composite_layers();
+        frame.render_tooltip();
 
         // We can't change the cursor position right away because we have to flush the frame to
         // stdout first. But we also can't keep the frame around, since it holds a &mut to
@@ -436,6 +611,73 @@ where
         Ok(())
     }
 
+    /// Saves the current visibility and position of the cursor on a stack.
+    ///
+    /// The saved state can be restored with [`Terminal::pop_cursor_state`]. Calls can be nested, so
+    /// helpers that temporarily move or hide the cursor can save and restore it without knowing
+    /// what their caller did with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let backend = ratatui_core::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui_core::terminal::Terminal::new(backend)?;
+    /// terminal.push_cursor_state()?;
+    /// terminal.set_cursor_position((5, 5))?;
+    /// terminal.show_cursor()?;
+    /// // ...
+    /// terminal.pop_cursor_state()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn push_cursor_state(&mut self) -> io::Result<()> {
+        let state = CursorState {
+            hidden: self.hidden_cursor,
+            position: self.get_cursor_position()?,
+        };
+        self.cursor_stack.push(state);
+        Ok(())
+    }
+
+    /// Restores the cursor state most recently saved with [`Terminal::push_cursor_state`].
+    ///
+    /// Returns the restored state, or `None` if the stack is empty in which case the cursor is left
+    /// untouched.
+    pub fn pop_cursor_state(&mut self) -> io::Result<Option<CursorState>> {
+        let Some(state) = self.cursor_stack.pop() else {
+            return Ok(None);
+        };
+        self.set_cursor_position(state.position)?;
+        if state.hidden {
+            self.hide_cursor()?;
+        } else {
+            self.show_cursor()?;
+        }
+        Ok(Some(state))
+    }
+
+    /// Runs the given closure with the cursor hidden, then restores the previous cursor state.
+    ///
+    /// The cursor state is restored even if the closure moves or shows the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let backend = ratatui_core::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui_core::terminal::Terminal::new(backend)?;
+    /// terminal.with_cursor_hidden(|terminal| terminal.insert_before(1, |_buf| {}))??;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn with_cursor_hidden<F, T>(&mut self, f: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        self.push_cursor_state()?;
+        self.hide_cursor()?;
+        let result = f(self);
+        self.pop_cursor_state()?;
+        Ok(result)
+    }
+
     /// Gets the current cursor position.
     ///
     /// This is the position of the cursor after the last draw call and is returned as a tuple of
@@ -468,27 +710,173 @@ where
     }
 
     /// Clear the terminal and force a full redraw on the next draw call.
+    ///
+    /// Regions reserved with [`Terminal::reserve_region`] are left untouched.
     pub fn clear(&mut self) -> io::Result<()> {
-        match self.viewport {
-            Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
-            Viewport::Inline(_) => {
-                self.backend
-                    .set_cursor_position(self.viewport_area.as_position())?;
-                self.backend.clear_region(ClearType::AfterCursor)?;
-            }
-            Viewport::Fixed(_) => {
-                let area = self.viewport_area;
-                for y in area.top()..area.bottom() {
-                    self.backend.set_cursor_position(Position { x: 0, y })?;
+        if self.reserved_regions.is_empty() {
+            match self.viewport {
+                Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
+                Viewport::Inline(_) => {
+                    self.backend
+                        .set_cursor_position(self.viewport_area.as_position())?;
                     self.backend.clear_region(ClearType::AfterCursor)?;
                 }
+                Viewport::Fixed(_) => {
+                    let area = self.viewport_area;
+                    for y in area.top()..area.bottom() {
+                        self.backend.set_cursor_position(Position { x: 0, y })?;
+                        self.backend.clear_region(ClearType::AfterCursor)?;
+                    }
+                }
             }
+        } else {
+            self.clear_unreserved()?;
         }
         // Reset the back buffer to make sure the next update will redraw everything.
         self.buffers[1 - self.current].reset();
         Ok(())
     }
 
+    /// Clears the viewport cell by cell, skipping the reserved regions.
+    fn clear_unreserved(&mut self) -> io::Result<()> {
+        let regions = &self.reserved_regions;
+        let empty = Cell::EMPTY;
+        let cells = self
+            .viewport_area
+            .positions()
+            .filter(|position| !regions.iter().any(|region| region.contains(*position)))
+            .map(|position| (position.x, position.y, &empty));
+        self.backend.draw(cells)
+    }
+
+    /// Reserves a region of the terminal for content drawn by something other than Ratatui.
+    ///
+    /// The cells of a reserved region are never written by [`Terminal::draw`] and are not cleared
+    /// by [`Terminal::clear`], so an embedded terminal, an image or a video player can draw there
+    /// without flickering. Widgets may still render into the region, but their output is ignored.
+    ///
+    /// Use [`Terminal::release_region`] to give the region back to Ratatui.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::layout::Rect;
+    /// # let backend = ratatui_core::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui_core::terminal::Terminal::new(backend)?;
+    /// let video = Rect::new(2, 2, 6, 4);
+    /// terminal.reserve_region(video);
+    /// // ... let the video player draw into `video` ...
+    /// terminal.release_region(video)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn reserve_region(&mut self, area: Rect) {
+        if !self.reserved_regions.contains(&area) {
+            self.reserved_regions.push(area);
+        }
+    }
+
+    /// Releases a region reserved with [`Terminal::reserve_region`].
+    ///
+    /// The region is blanked and repainted by Ratatui on the next draw call (see
+    /// [`Terminal::repaint_region`]). Returns `false` without doing anything if the region was not
+    /// reserved.
+    pub fn release_region(&mut self, area: Rect) -> io::Result<bool> {
+        let Some(index) = self.reserved_regions.iter().position(|r| *r == area) else {
+            return Ok(false);
+        };
+        self.reserved_regions.remove(index);
+        self.repaint_region(area)?;
+        Ok(true)
+    }
+
+    /// Returns the regions reserved with [`Terminal::reserve_region`].
+    pub fn reserved_regions(&self) -> &[Rect] {
+        &self.reserved_regions
+    }
+
+    /// Blanks a region of the terminal and forces Ratatui to repaint it on the next draw call.
+    ///
+    /// This is useful when the content of the region on the screen no longer matches what Ratatui
+    /// last drew there, e.g. after an external program drew over it.
+    pub fn repaint_region(&mut self, area: Rect) -> io::Result<()> {
+        let area = area.intersection(self.viewport_area);
+        let empty = Cell::EMPTY;
+        let cells = area
+            .positions()
+            .map(|position| (position.x, position.y, &empty));
+        self.backend.draw(cells)?;
+        let previous_buffer = &mut self.buffers[1 - self.current];
+        for position in area.positions() {
+            previous_buffer[position].reset();
+        }
+        self.backend.flush()
+    }
+
+    /// Rings the terminal bell.
+    ///
+    /// This is useful to alert the user when a long-running task finishes while the application
+    /// is in the background. Returns an error of kind [`io::ErrorKind::Unsupported`] if the
+    /// backend does not support ringing the bell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::ErrorKind;
+    ///
+    /// # let backend = ratatui_core::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui_core::terminal::Terminal::new(backend)?;
+    /// match terminal.bell() {
+    ///     Err(error) if error.kind() == ErrorKind::Unsupported => { /* no bell to ring */ }
+    ///     result => result?,
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn bell(&mut self) -> io::Result<()> {
+        self.backend.bell()?;
+        self.backend.flush()
+    }
+
+    /// Shows a desktop notification with the given title and body.
+    ///
+    /// The notification protocol is detected from the environment using
+    /// [`NotificationProtocol::detect`]. If the terminal is not known to support desktop
+    /// notifications, or the backend cannot send them, an error of kind
+    /// [`io::ErrorKind::Unsupported`] is returned and nothing is written to the terminal. Use
+    /// [`Terminal::notify_with`] to choose the protocol explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let backend = ratatui_core::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui_core::terminal::Terminal::new(backend)?;
+    /// if terminal.notify("Build", "finished successfully").is_err() {
+    ///     // fall back to the bell when notifications are unavailable, if the backend has one
+    ///     let _ = terminal.bell();
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn notify(&mut self, title: &str, body: &str) -> io::Result<()> {
+        let protocol = NotificationProtocol::detect().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the terminal does not support desktop notifications",
+            )
+        })?;
+        self.notify_with(protocol, title, body)
+    }
+
+    /// Shows a desktop notification with the given title and body using the given
+    /// [`NotificationProtocol`].
+    pub fn notify_with(
+        &mut self,
+        protocol: NotificationProtocol,
+        title: &str,
+        body: &str,
+    ) -> io::Result<()> {
+        self.backend.notify(protocol, title, body)?;
+        self.backend.flush()
+    }
+
     /// Clears the inactive buffer and swaps it with the current buffer
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
@@ -761,6 +1149,39 @@ where
         Ok(())
     }
 
+    /// Scrolls the rows of the screen that moved vertically since the previous frame.
+    ///
+    /// When the rows that changed between the previous and the current buffer are the same rows
+    /// shifted up or down (e.g. when a pane of text is scrolled), the terminal is asked to scroll
+    /// them with [`Backend::scroll_region_up`] or [`Backend::scroll_region_down`] and the previous
+    /// buffer is shifted accordingly, so that the diff only draws the rows scrolled into view. If
+    /// the backend doesn't support scrolling regions, the moved rows are redrawn by the diff.
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_moved_rows(&mut self) -> io::Result<()> {
+        if !self.reserved_regions.is_empty() {
+            return Ok(());
+        }
+        let previous_buffer = &self.buffers[1 - self.current];
+        let current_buffer = &self.buffers[self.current];
+        let Some(scroll) = RowScroll::find(previous_buffer, current_buffer, &mut self.row_hashes)
+        else {
+            return Ok(());
+        };
+        let top = previous_buffer.area.y;
+        let region = top + scroll.rows.start..top + scroll.rows.end;
+        let result = if scroll.up {
+            self.backend.scroll_region_up(region, scroll.line_count)
+        } else {
+            self.backend.scroll_region_down(region, scroll.line_count)
+        };
+        match result {
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => return Ok(()),
+            result => result?,
+        }
+        scroll.apply(&mut self.buffers[1 - self.current]);
+        Ok(())
+    }
+
     /// Draw lines at the given vertical offset. The slice of cells must contain enough cells
     /// for the requested lines. A slice of the unused cells are returned.
     fn draw_lines<'a>(
@@ -821,6 +1242,96 @@ where
     }
 }
 
+/// Rows of a buffer that moved up or down between two frames.
+#[cfg(feature = "scrolling-regions")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RowScroll {
+    /// The rows that changed, relative to the top of the buffer.
+    rows: std::ops::Range<u16>,
+    /// The number of rows by which the content moved.
+    line_count: u16,
+    /// Whether the content moved up.
+    up: bool,
+}
+
+#[cfg(feature = "scrolling-regions")]
+impl RowScroll {
+    /// Finds the smallest scroll that turns the changed rows of `previous` into those of
+    /// `current`.
+    ///
+    /// Returns `None` if no rows changed, if the changed rows didn't just move, or if they contain
+    /// skipped cells which must not be moved. Scrolling up a region that starts at the top of the
+    /// screen is avoided, as it would push the rows into the scrollback.
+    ///
+    /// Each row is hashed once into `hashes`, which is reused across calls, and the rows are then
+    /// compared by their hashes. A hash collision can only cause a needless scroll, as the diff
+    /// that follows still redraws every cell that differs.
+    fn find(previous: &Buffer, current: &Buffer, hashes: &mut Vec<u64>) -> Option<Self> {
+        if previous.area != current.area || previous.area.width == 0 {
+            return None;
+        }
+        let width = usize::from(previous.area.width);
+        let height = previous.content.len() / width;
+        hashes.clear();
+        hashes.extend(
+            previous
+                .content
+                .chunks(width)
+                .chain(current.content.chunks(width))
+                .map(hash_row),
+        );
+        let (previous_rows, current_rows) = hashes.split_at(height);
+        let changed = |y: &usize| previous_rows[*y] != current_rows[*y];
+        let top = (0..height).find(changed)?;
+        let bottom = (0..height).rfind(changed)? + 1;
+        let has_skipped_cells = previous.content[top * width..bottom * width]
+            .iter()
+            .chain(&current.content[top * width..bottom * width])
+            .any(|cell| cell.skip);
+        if has_skipped_cells {
+            return None;
+        }
+        let can_scroll_up = previous.area.y > 0 || top > 0;
+        (1..bottom - top).find_map(|shift| {
+            let moved_up = can_scroll_up
+                && current_rows[top..bottom - shift] == previous_rows[top + shift..bottom];
+            let moved_down = !moved_up
+                && current_rows[top + shift..bottom] == previous_rows[top..bottom - shift];
+            (moved_up || moved_down).then_some(Self {
+                rows: top as u16..bottom as u16,
+                line_count: shift as u16,
+                up: moved_up,
+            })
+        })
+    }
+
+    /// Moves the rows of the buffer like the terminal does when scrolling, leaving empty rows.
+    fn apply(&self, buffer: &mut Buffer) {
+        let width = usize::from(buffer.area.width);
+        let start = usize::from(self.rows.start) * width;
+        let end = usize::from(self.rows.end) * width;
+        let shift = usize::from(self.line_count) * width;
+        let rows = &mut buffer.content[start..end];
+        if self.up {
+            rows.rotate_left(shift);
+            rows[end - start - shift..].fill(Cell::EMPTY);
+        } else {
+            rows.rotate_right(shift);
+            rows[..shift].fill(Cell::EMPTY);
+        }
+    }
+}
+
+/// Returns the hash of a row of cells.
+#[cfg(feature = "scrolling-regions")]
+fn hash_row(row: &[Cell]) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    row.hash(&mut hasher);
+    hasher.finish()
+}
+
 fn compute_inline_size<B: Backend>(
     backend: &mut B,
     height: u16,
@@ -0,0 +1,209 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Modifier, Style, Styled},
+    text::Text,
+    widgets::Widget,
+};
+
+/// A short text displayed next to a region of the screen when the mouse pointer is over it, or
+/// when it is focused.
+///
+/// Tooltips are registered during rendering with [`Frame::set_tooltip`], usually by passing the
+/// area a widget was rendered to. Once the frame has been drawn, the terminal renders the tooltip
+/// of the region under the mouse pointer (see [`Terminal::set_pointer_position`]), or if there is
+/// none, the tooltip marked as [`focused`](Tooltip::focused). Only one tooltip is displayed per
+/// frame.
+///
+/// The tooltip is placed below its region, or above it when there is not enough room below, and
+/// is shifted horizontally to stay inside the viewport.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::{
+///     backend::TestBackend,
+///     layout::{Position, Rect},
+///     terminal::{Terminal, Tooltip},
+///     text::Line,
+/// };
+///
+/// let mut terminal = Terminal::new(TestBackend::new(12, 3))?;
+/// terminal.set_pointer_position(Some(Position::new(1, 0)));
+/// terminal.draw(|frame| {
+///     let area = Rect::new(0, 0, 4, 1);
+///     frame.render_widget(Line::raw("Save"), area);
+///     frame.set_tooltip(area, Tooltip::new("Ctrl+S"));
+/// })?;
+/// let buffer = terminal.backend().buffer();
+/// assert_eq!(buffer[(2, 1)].symbol(), "C");
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`Frame::set_tooltip`]: crate::terminal::Frame::set_tooltip
+/// [`Terminal::set_pointer_position`]: crate::terminal::Terminal::set_pointer_position
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Tooltip {
+    text: Text<'static>,
+    style: Style,
+    focused: bool,
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            text: Text::default(),
+            style: Style::new().add_modifier(Modifier::REVERSED),
+            focused: false,
+        }
+    }
+}
+
+impl Tooltip {
+    /// Creates a new tooltip with the given text.
+    ///
+    /// The tooltip is rendered reversed by default.
+    pub fn new<T>(text: T) -> Self
+    where
+        T: Into<Text<'static>>,
+    {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the style of the tooltip.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the region of the tooltip is focused.
+    ///
+    /// A focused tooltip is displayed when the mouse pointer is not over any region with a
+    /// tooltip.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns whether the region of the tooltip is focused.
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns the area where the tooltip is displayed for the given region.
+    ///
+    /// The tooltip is placed on the row after `pointer`, or after `target` if there is no pointer,
+    /// and moved above it if it does not fit below. It starts at the column of the pointer (or of
+    /// the target) and is shifted left to stay inside `bounds`.
+    pub fn area(&self, target: Rect, pointer: Option<Position>, bounds: Rect) -> Rect {
+        let text_width = u16::try_from(self.text.width()).unwrap_or(u16::MAX);
+        let text_height = u16::try_from(self.text.height()).unwrap_or(u16::MAX);
+        let width = text_width.saturating_add(2).min(bounds.width);
+        let height = text_height.min(bounds.height);
+
+        let (x, top, bottom) = pointer.map_or_else(
+            || (target.x, target.y, target.bottom()),
+            |p| (p.x, p.y, p.y.saturating_add(1)),
+        );
+        let y = if bottom.saturating_add(height) <= bounds.bottom() {
+            bottom
+        } else if top.saturating_sub(bounds.y) >= height {
+            top - height
+        } else {
+            bounds.bottom() - height
+        };
+        let x = x.min(bounds.right() - width).max(bounds.x);
+        Rect::new(x, y.max(bounds.y), width, height)
+    }
+}
+
+impl Widget for Tooltip {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Tooltip {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for position in area.positions() {
+            buf[position].reset();
+        }
+        buf.set_style(area, self.style);
+        let inner = Rect {
+            x: area.x.saturating_add(1),
+            width: area.width.saturating_sub(2),
+            ..area
+        };
+        (&self.text).render(inner, buf);
+    }
+}
+
+impl Styled for Tooltip {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::below_pointer(Some(Position::new(3, 2)), Rect::new(3, 3, 6, 1))]
+    #[case::below_target(None, Rect::new(2, 3, 6, 1))]
+    #[case::shifted_left(Some(Position::new(7, 2)), Rect::new(4, 3, 6, 1))]
+    fn area(#[case] pointer: Option<Position>, #[case] expected: Rect) {
+        let tooltip = Tooltip::new("help");
+        let target = Rect::new(2, 2, 5, 1);
+        let bounds = Rect::new(0, 0, 10, 5);
+        assert_eq!(tooltip.area(target, pointer, bounds), expected);
+    }
+
+    #[test]
+    fn area_above_when_no_room_below() {
+        let tooltip = Tooltip::new("a\nb");
+        let bounds = Rect::new(0, 0, 10, 5);
+        let target = Rect::new(0, 3, 5, 2);
+        assert_eq!(tooltip.area(target, None, bounds), Rect::new(0, 1, 3, 2));
+        assert_eq!(
+            tooltip.area(target, Some(Position::new(1, 4)), bounds),
+            Rect::new(1, 2, 3, 2)
+        );
+    }
+
+    #[test]
+    fn area_larger_than_bounds() {
+        let tooltip = Tooltip::new("a long tooltip");
+        let bounds = Rect::new(0, 0, 6, 1);
+        let target = Rect::new(0, 0, 2, 1);
+        assert_eq!(tooltip.area(target, None, bounds), Rect::new(0, 0, 6, 1));
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::with_lines(["xxxxxxxx"]);
+        Tooltip::new("tip")
+            .style(Style::new())
+            .render(Rect::new(1, 0, 5, 1), &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["x tip xx"]));
+    }
+}
@@ -51,6 +51,11 @@
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
+#[cfg(feature = "regex")]
+mod highlighter;
+#[cfg(feature = "regex")]
+pub use highlighter::Highlighter;
+
 mod line;
 pub use line::{Line, ToLine};
 
@@ -61,4 +66,4 @@ mod span;
 pub use span::{Span, ToSpan};
 
 mod text;
-pub use text::{Text, ToText};
+pub use text::{Text, TextMatch, ToText};
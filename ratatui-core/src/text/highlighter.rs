@@ -0,0 +1,184 @@
+use std::ops::Range;
+
+use regex::Regex;
+
+use crate::{
+    style::Style,
+    text::{Line, Text},
+};
+
+/// Highlights text with an ordered list of rules, each a regular expression and a [`Style`].
+///
+/// The regular expressions are compiled once when the rules are added, and the highlighter can
+/// then be applied to each line as it is received or displayed, e.g. to color the severity and the
+/// timestamps of log lines.
+///
+/// The style of a rule is patched onto the style of the text it matches. When the matches of
+/// several rules overlap, the rule that was added first wins: the parts of the text matched by a
+/// rule are not highlighted by the rules after it. Empty matches are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::{
+///     style::{Style, Stylize},
+///     text::{Highlighter, Line, Span},
+/// };
+/// use regex::Regex;
+///
+/// let highlighter = Highlighter::new()
+///     .rule(Regex::new(r"\bERROR\b").unwrap(), Style::new().red().bold())
+///     .rule(Regex::new(r"\bWARN\b").unwrap(), Style::new().yellow())
+///     .rule(
+///         Regex::new(r"^\d{2}:\d{2}:\d{2}").unwrap(),
+///         Style::new().dim(),
+///     );
+///
+/// let line = highlighter.highlight_line(&Line::from("12:03:44 ERROR disk full"));
+/// assert_eq!(
+///     line.spans,
+///     [
+///         Span::raw("12:03:44").dim(),
+///         Span::raw(" "),
+///         Span::raw("ERROR").red().bold(),
+///         Span::raw(" disk full"),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Highlighter {
+    rules: Vec<(Regex, Style)>,
+}
+
+impl Highlighter {
+    /// Creates a new `Highlighter` without rules.
+    pub const fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds a rule highlighting the matches of `regex` with `style`.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn rule<S: Into<Style>>(mut self, regex: Regex, style: S) -> Self {
+        self.push_rule(regex, style);
+        self
+    }
+
+    /// Adds a rule highlighting the matches of `regex` with `style`.
+    ///
+    /// This is the same as [`Highlighter::rule`], for a highlighter that is not built in one
+    /// expression.
+    pub fn push_rule<S: Into<Style>>(&mut self, regex: Regex, style: S) {
+        self.rules.push((regex, style.into()));
+    }
+
+    /// Returns the number of rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns whether the highlighter has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns a copy of the line with the matches of the rules highlighted.
+    pub fn highlight_line<'a>(&self, line: &Line<'a>) -> Line<'a> {
+        let content: String = line.spans.iter().map(|span| &*span.content).collect();
+        self.find(&content)
+            .into_iter()
+            .fold(line.clone(), |line, (range, style)| {
+                line.style_range(range, style)
+            })
+    }
+
+    /// Returns the byte ranges of `content` highlighted by the rules and their styles, sorted by
+    /// their start.
+    pub(crate) fn find(&self, content: &str) -> Vec<(Range<usize>, Style)> {
+        let mut highlighted: Vec<(Range<usize>, Style)> = Vec::new();
+        for (regex, style) in &self.rules {
+            for found in regex.find_iter(content).filter(|m| !m.is_empty()) {
+                let range = found.range();
+                let overlaps = highlighted
+                    .iter()
+                    .any(|(other, _)| range.start < other.end && other.start < range.end);
+                if !overlaps {
+                    highlighted.push((range, *style));
+                }
+            }
+        }
+        highlighted.sort_by_key(|(range, _)| range.start);
+        highlighted
+    }
+
+    /// Returns a copy of the text with the matches of the rules highlighted in each line.
+    pub fn highlight<'a>(&self, text: &Text<'a>) -> Text<'a> {
+        Text {
+            lines: text
+                .lines
+                .iter()
+                .map(|line| self.highlight_line(line))
+                .collect(),
+            ..text.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{style::Stylize, text::Span};
+
+    fn regex(pattern: &str) -> Regex {
+        Regex::new(pattern).unwrap()
+    }
+
+    #[test]
+    fn first_rule_wins() {
+        let highlighter = Highlighter::new()
+            .rule(regex("ERROR"), Style::new().red())
+            .rule(regex(r"[A-Z]+ \w+"), Style::new().bold());
+        assert_eq!(highlighter.len(), 2);
+        assert_eq!(
+            highlighter
+                .highlight_line(&Line::from("ERROR disk INFO ok"))
+                .spans,
+            [
+                Span::raw("ERROR").red(),
+                Span::raw(" disk "),
+                Span::raw("INFO ok").bold(),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_existing_styles() {
+        let mut highlighter = Highlighter::new();
+        highlighter.push_rule(regex(r"\d+"), Style::new().red());
+        let line = Line::from(vec![Span::raw("id ").italic(), Span::raw("42")]);
+        assert_eq!(
+            highlighter.highlight_line(&line).spans,
+            [Span::raw("id ").italic(), Span::raw("42").red()]
+        );
+    }
+
+    #[test]
+    fn highlight_text() {
+        let highlighter = Highlighter::new().rule(regex("x*"), Style::new().red());
+        let text = Text::from("axb\nc").centered();
+        let result = highlighter.highlight(&text);
+        assert_eq!(result.alignment, text.alignment);
+        assert_eq!(
+            result.lines,
+            [
+                Line::from(vec![Span::raw("a"), Span::raw("x").red(), Span::raw("b")]),
+                Line::from("c"),
+            ]
+        );
+        assert!(Highlighter::new().is_empty());
+    }
+}
@@ -1,15 +1,15 @@
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
 
 use unicode_truncate::UnicodeTruncateStr;
 
 use crate::{
-    buffer::Buffer,
+    buffer::{Buffer, BufferMut},
     layout::{Alignment, Rect},
     style::{Style, Styled},
     text::{Span, StyledGrapheme, Text},
-    widgets::Widget,
+    widgets::{SliceWidget, Widget},
 };
 
 /// A line of text, consisting of one or more [`Span`]s.
@@ -57,6 +57,8 @@ use crate::{
 ///
 /// - [`Line::patch_style`] patches the style of the line, adding modifiers from the given style.
 /// - [`Line::reset_style`] resets the style of the line.
+/// - [`Line::style_range`] patches the style of a byte range of the line, splitting spans as
+///   needed.
 /// - [`Line::width`] returns the unicode width of the content held by this line.
 /// - [`Line::styled_graphemes`] returns an iterator over the graphemes held by this line.
 /// - [`Line::push_span`] adds a span to the line.
@@ -368,11 +370,9 @@ impl<'a> Line<'a> {
     /// )
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn alignment(self, alignment: Alignment) -> Self {
-        Self {
-            alignment: Some(alignment),
-            ..self
-        }
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
     }
 
     /// Left-aligns this line of text.
@@ -389,7 +389,7 @@ impl<'a> Line<'a> {
     /// let line = Line::from("Hi, what's up?").left_aligned();
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn left_aligned(self) -> Self {
+    pub const fn left_aligned(self) -> Self {
         self.alignment(Alignment::Left)
     }
 
@@ -407,7 +407,7 @@ impl<'a> Line<'a> {
     /// let line = Line::from("Hi, what's up?").centered();
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn centered(self) -> Self {
+    pub const fn centered(self) -> Self {
         self.alignment(Alignment::Center)
     }
 
@@ -425,7 +425,7 @@ impl<'a> Line<'a> {
     /// let line = Line::from("Hi, what's up?").right_aligned();
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn right_aligned(self) -> Self {
+    pub const fn right_aligned(self) -> Self {
         self.alignment(Alignment::Right)
     }
 
@@ -539,8 +539,70 @@ impl<'a> Line<'a> {
     /// assert_eq!(Style::reset(), line.reset_style().style);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn reset_style(self) -> Self {
-        self.patch_style(Style::reset())
+    pub const fn reset_style(mut self) -> Self {
+        self.style = self.style.merge(Style::reset());
+        self
+    }
+
+    /// Patches the style of the given byte range of the content of this line.
+    ///
+    /// The range is made of byte offsets into the content of the line (the concatenation of the
+    /// content of its spans). Spans are split where the range starts or ends inside them, and the
+    /// style is patched onto the style of each span in the range, so that the existing colors and
+    /// modifiers are kept unless overridden. This is the building block for search highlighting,
+    /// selections or diagnostics.
+    ///
+    /// The range is clamped to the length of the content, and offsets that are not on a character
+    /// boundary are moved back to the start of the character.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Color, Style, Stylize},
+    ///     text::{Line, Span},
+    /// };
+    ///
+    /// let line = Line::from(vec!["Hello ".bold(), "world".into()]).style_range(3..8, Color::Red);
+    /// assert_eq!(
+    ///     line.spans,
+    ///     ["Hel".bold(), "lo ".bold().red(), "wo".red(), "rld".into(),]
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style_range<S: Into<Style>>(self, range: Range<usize>, style: S) -> Self {
+        let start = self.floor_char_boundary(range.start);
+        let end = self.floor_char_boundary(range.end);
+        if start >= end {
+            return self;
+        }
+        self.patch_style_ranges(&[Range { start, end }], style.into())
+    }
+
+    /// Returns the largest character boundary of the content that is not after `offset`.
+    fn floor_char_boundary(&self, offset: usize) -> usize {
+        let mut start = 0_usize;
+        for span in &self.spans {
+            let content = span.content.as_ref();
+            let end = start.saturating_add(content.len());
+            if offset < end {
+                let relative = offset.saturating_sub(start);
+                let boundary = (0..=relative)
+                    .rev()
+                    .find(|&index| content.is_char_boundary(index))
+                    .unwrap_or_default();
+                return start.saturating_add(boundary);
+            }
+            start = end;
+        }
+        start
     }
 
     /// Returns an iterator over the spans of this line.
@@ -570,6 +632,58 @@ impl<'a> Line<'a> {
     pub fn push_span<T: Into<Span<'a>>>(&mut self, span: T) {
         self.spans.push(span.into());
     }
+
+    /// Returns a copy of the line with the style patched onto the given byte ranges.
+    ///
+    /// The ranges are byte offsets into the content of the line (the concatenation of the content
+    /// of its spans), and must be sorted, non-overlapping and on character boundaries. Spans are
+    /// split where a range starts or ends inside them.
+    pub(crate) fn patch_style_ranges(&self, ranges: &[Range<usize>], style: Style) -> Self {
+        let mut spans = Vec::with_capacity(self.spans.len());
+        let mut start = 0_usize;
+        for span in &self.spans {
+            let end = start.saturating_add(span.content.len());
+            // offsets relative to the start of the span
+            let relative = |offset: usize| offset.saturating_sub(start);
+            let mut cursor = start;
+            for range in ranges.iter().filter(|r| r.start < end && r.end > start) {
+                let range_start = range.start.max(start);
+                let range_end = range.end.min(end);
+                if range_start > cursor {
+                    let unpatched = relative(cursor)..relative(range_start);
+                    spans.push(slice_span(span, unpatched, span.style));
+                }
+                let patched = relative(range_start)..relative(range_end);
+                spans.push(slice_span(span, patched, span.style.patch(style)));
+                cursor = range_end;
+            }
+            if cursor < end || start == end {
+                spans.push(slice_span(
+                    span,
+                    relative(cursor)..relative(end),
+                    span.style,
+                ));
+            }
+            start = end;
+        }
+        Self {
+            style: self.style,
+            alignment: self.alignment,
+            spans,
+        }
+    }
+}
+
+/// Returns a span with the given byte range of the content of `span` and the given style.
+fn slice_span<'a>(span: &Span<'a>, range: Range<usize>, style: Style) -> Span<'a> {
+    let content = match &span.content {
+        Cow::Borrowed(content) => {
+            let content: &'a str = content;
+            Cow::Borrowed(content.get(range).unwrap_or_default())
+        }
+        Cow::Owned(content) => Cow::Owned(content.get(range).unwrap_or_default().to_string()),
+    };
+    Span { style, content }
 }
 
 impl<'a> IntoIterator for Line<'a> {
@@ -685,10 +799,31 @@ impl Widget for Line<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
     }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        Widget::size_hint(&self, area)
+    }
 }
 
 impl Widget for &Line<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_slice(area, &mut buf.view_mut());
+    }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        (width.min(area.width), area.height.min(1))
+    }
+}
+
+impl SliceWidget for Line<'_> {
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>) {
+        SliceWidget::render_slice(&self, area, buf);
+    }
+}
+
+impl SliceWidget for &Line<'_> {
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>) {
         self.render_with_alignment(area, buf, None);
     }
 }
@@ -699,7 +834,7 @@ impl Line<'_> {
     pub(crate) fn render_with_alignment(
         &self,
         area: Rect,
-        buf: &mut Buffer,
+        buf: &mut BufferMut<'_>,
         parent_alignment: Option<Alignment>,
     ) {
         let area = area.intersection(buf.area);
@@ -741,13 +876,13 @@ impl Line<'_> {
 }
 
 /// Renders all the spans of the line that should be visible.
-fn render_spans(spans: &[Span], mut area: Rect, buf: &mut Buffer, span_skip_width: usize) {
+fn render_spans(spans: &[Span], mut area: Rect, buf: &mut BufferMut<'_>, span_skip_width: usize) {
     for (span, span_width, offset) in spans_after_width(spans, span_skip_width) {
         area = area.indent_x(offset);
         if area.is_empty() {
             break;
         }
-        span.render(area, buf);
+        span.render_slice(area, buf);
         let span_width = u16::try_from(span_width).unwrap_or(u16::MAX);
         area = area.indent_x(span_width);
     }
@@ -965,6 +1100,24 @@ mod tests {
         assert_eq!(Style::reset(), line.style);
     }
 
+    #[rstest]
+    #[case::inside_span(1..3, vec!["a".into(), "bc".red(), "d".into(), "ef".blue()])]
+    #[case::across_spans(2..5, vec!["ab".into(), "cd".red(), "e".blue().red(), "f".blue()])]
+    #[case::whole_line(0..6, vec!["abcd".red(), "ef".blue().red()])]
+    #[case::clamped(4..100, vec!["abcd".into(), "ef".blue().red()])]
+    #[case::empty(3..3, vec!["abcd".into(), "ef".blue()])]
+    fn style_range(#[case] range: Range<usize>, #[case] expected: Vec<Span>) {
+        let line = Line::from(vec!["abcd".into(), "ef".blue()]).style_range(range, Color::Red);
+        assert_eq!(line.spans, expected);
+    }
+
+    #[test]
+    fn style_range_char_boundary() {
+        // "é" is 2 bytes long, so the offsets 1 and 4 are inside a character
+        let line = Line::from("éaé").style_range(1..4, Modifier::BOLD);
+        assert_eq!(line.spans, ["éa".bold(), "é".into()]);
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(Line::default().green().style, Color::Green.into());
@@ -4,11 +4,11 @@ use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    buffer::Buffer,
+    buffer::{Buffer, BufferMut},
     layout::Rect,
     style::{Style, Styled},
     text::{Line, StyledGrapheme},
-    widgets::Widget,
+    widgets::{SliceWidget, Widget},
 };
 
 /// Represents a part of a line that is contiguous and where all characters share the same style.
@@ -272,8 +272,9 @@ impl<'a> Span<'a> {
     /// assert_eq!(span.style, Style::reset());
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn reset_style(self) -> Self {
-        self.patch_style(Style::reset())
+    pub const fn reset_style(mut self) -> Self {
+        self.style = self.style.merge(Style::reset());
+        self
     }
 
     /// Returns the unicode width of the content held by this span.
@@ -420,10 +421,31 @@ impl Widget for Span<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
     }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        Widget::size_hint(&self, area)
+    }
 }
 
 impl Widget for &Span<'_> {
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        (width.min(area.width), area.height.min(1))
+    }
+
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_slice(area, &mut buf.view_mut());
+    }
+}
+
+impl SliceWidget for Span<'_> {
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>) {
+        SliceWidget::render_slice(&self, area, buf);
+    }
+}
+
+impl SliceWidget for &Span<'_> {
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>) {
         let area = area.intersection(buf.area);
         if area.is_empty() {
             return;
@@ -1,12 +1,14 @@
 #![warn(missing_docs)]
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
+
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    buffer::Buffer,
-    layout::{Alignment, Rect},
+    buffer::{Buffer, BufferMut},
+    layout::{Alignment, Position, Rect},
     style::{Style, Styled},
     text::{Line, Span},
-    widgets::Widget,
+    widgets::{SliceWidget, Widget},
 };
 
 /// A string split over one or more lines.
@@ -52,6 +54,10 @@ use crate::{
 ///
 /// - [`Text::width`] returns the max width of all the lines.
 /// - [`Text::height`] returns the height.
+/// - [`Text::char_count`] returns the number of characters.
+/// - [`Text::word_count`] returns the number of words.
+/// - [`Text::char_to_screen`] maps a character of the text to its position once wrapped.
+/// - [`Text::screen_to_char`] maps a position in the wrapped text to a character.
 /// - [`Text::patch_style`] patches the style of this `Text`, adding modifiers from the given style.
 /// - [`Text::reset_style`] resets the style of the `Text`.
 /// - [`Text::push_line`] adds a line to the text.
@@ -306,6 +312,136 @@ impl<'a> Text<'a> {
         self.lines.len()
     }
 
+    /// Returns the number of characters of the text, not counting line breaks.
+    ///
+    /// Characters are counted as user-perceived characters (grapheme clusters), so an emoji made
+    /// of several code points counts as a single character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Text;
+    ///
+    /// let text = Text::from("Hello\nwörld 👋🏽");
+    /// assert_eq!(12, text.char_count());
+    /// ```
+    pub fn char_count(&self) -> usize {
+        self.iter()
+            .map(|line| line.styled_graphemes(Style::new()).count())
+            .sum()
+    }
+
+    /// Returns the number of words of the text, separated by whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Line, Span, Text};
+    ///
+    /// let text = Text::from(vec![
+    ///     Line::from(vec![Span::raw("The first"), Span::raw(" line")]),
+    ///     Line::from("The second  line"),
+    /// ]);
+    /// assert_eq!(6, text.word_count());
+    /// ```
+    pub fn word_count(&self) -> usize {
+        self.iter()
+            .map(|line| {
+                let content: String = line.spans.iter().map(|span| &*span.content).collect();
+                content.split_whitespace().count()
+            })
+            .sum()
+    }
+
+    /// Returns the screen position of a character when the text is wrapped at `wrap_width`.
+    ///
+    /// `position` is the position of the character in the text: `x` is the index of the character
+    /// (grapheme cluster) in the line and `y` is the index of the line. The returned position is
+    /// the column and row of the first cell of the character, relative to the top left of the
+    /// wrapped text, which makes it suitable to place a cursor.
+    ///
+    /// Lines are wrapped at character boundaries: a character that doesn't fit in the remaining
+    /// width of a row is moved to the next row, taking the width of wide characters into account.
+    /// Use `u16::MAX` as the width to map positions of text that isn't wrapped.
+    ///
+    /// An index past the end of the line is mapped to the position right after the last character
+    /// of the line. Returns `None` if the line doesn't exist.
+    ///
+    /// See [`Text::screen_to_char`] for the inverse mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{layout::Position, text::Text};
+    ///
+    /// let text = Text::from("first\n界界界");
+    /// // the third wide character doesn't fit on the first row of the second line
+    /// assert_eq!(
+    ///     text.char_to_screen(Position::new(2, 1), 5),
+    ///     Some(Position::new(0, 2))
+    /// );
+    /// assert_eq!(text.char_to_screen(Position::new(0, 2), 5), None);
+    /// ```
+    pub fn char_to_screen(&self, position: Position, wrap_width: u16) -> Option<Position> {
+        let line = self.lines.get(usize::from(position.y))?;
+        let row = self.lines[..usize::from(position.y)]
+            .iter()
+            .map(|line| row_count(&wrapped_positions(line, wrap_width)))
+            .fold(0_u16, u16::saturating_add);
+        let positions = wrapped_positions(line, wrap_width);
+        let index = usize::from(position.x).min(positions.len() - 1);
+        let Position { x, y } = positions[index];
+        Some(Position::new(x, row.saturating_add(y)))
+    }
+
+    /// Returns the character at a screen position when the text is wrapped at `wrap_width`.
+    ///
+    /// This is the inverse of [`Text::char_to_screen`]: `position` is a column and row relative to
+    /// the top left of the wrapped text (e.g. where a mouse click happened), and the returned
+    /// position has the index of the character in the line as `x` and the index of the line as
+    /// `y`.
+    ///
+    /// A position on the second cell of a wide character is mapped to that character. A position
+    /// past the end of a row is mapped to the last character of the row, or to the end of the
+    /// line for the last row of a line. Returns `None` if the position is below the text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{layout::Position, text::Text};
+    ///
+    /// let text = Text::from("first\n界界界");
+    /// assert_eq!(
+    ///     text.screen_to_char(Position::new(3, 1), 5),
+    ///     Some(Position::new(1, 1))
+    /// );
+    /// assert_eq!(
+    ///     text.screen_to_char(Position::new(4, 2), 5),
+    ///     Some(Position::new(3, 1))
+    /// );
+    /// assert_eq!(text.screen_to_char(Position::new(0, 3), 5), None);
+    /// ```
+    pub fn screen_to_char(&self, position: Position, wrap_width: u16) -> Option<Position> {
+        let mut row = position.y;
+        for (index, line) in self.lines.iter().enumerate() {
+            let positions = wrapped_positions(line, wrap_width);
+            let rows = row_count(&positions);
+            if row >= rows {
+                row -= rows;
+                continue;
+            }
+            let char_index = positions
+                .iter()
+                .rposition(|p| p.y == row && p.x <= position.x)
+                .or_else(|| positions.iter().position(|p| p.y == row))
+                .unwrap_or_default();
+            let x = u16::try_from(char_index).unwrap_or(u16::MAX);
+            let y = u16::try_from(index).unwrap_or(u16::MAX);
+            return Some(Position::new(x, y));
+        }
+        None
+    }
+
     /// Sets the style of this text.
     ///
     /// Defaults to [`Style::default()`].
@@ -398,8 +534,9 @@ impl<'a> Text<'a> {
     /// assert_eq!(Style::reset(), text.style);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn reset_style(self) -> Self {
-        self.patch_style(Style::reset())
+    pub const fn reset_style(mut self) -> Self {
+        self.style = self.style.merge(Style::reset());
+        self
     }
 
     /// Sets the alignment for this text.
@@ -451,11 +588,9 @@ impl<'a> Text<'a> {
     ///       right
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn alignment(self, alignment: Alignment) -> Self {
-        Self {
-            alignment: Some(alignment),
-            ..self
-        }
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
     }
 
     /// Left-aligns the whole text.
@@ -474,7 +609,7 @@ impl<'a> Text<'a> {
     /// let text = Text::from("Hi, what's up?").left_aligned();
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn left_aligned(self) -> Self {
+    pub const fn left_aligned(self) -> Self {
         self.alignment(Alignment::Left)
     }
 
@@ -494,7 +629,7 @@ impl<'a> Text<'a> {
     /// let text = Text::from("Hi, what's up?").centered();
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn centered(self) -> Self {
+    pub const fn centered(self) -> Self {
         self.alignment(Alignment::Center)
     }
 
@@ -514,7 +649,7 @@ impl<'a> Text<'a> {
     /// let text = Text::from("Hi, what's up?").right_aligned();
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn right_aligned(self) -> Self {
+    pub const fn right_aligned(self) -> Self {
         self.alignment(Alignment::Right)
     }
 
@@ -569,6 +704,238 @@ impl<'a> Text<'a> {
             self.lines.push(Line::from(span));
         }
     }
+
+    /// Returns a copy of the text with every occurrence of `pattern` highlighted, and the
+    /// positions of the occurrences.
+    ///
+    /// `style` is patched onto the style of the matched parts of the text, splitting spans where
+    /// needed. Occurrences are searched in each line separately and don't overlap. The matches are
+    /// returned in order, which makes it easy to implement jumping to the next or previous match.
+    ///
+    /// An empty pattern matches nothing. With the `regex` feature enabled,
+    /// [`Text::highlight_regex`] can be used to search for regular expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::{Line, Span, Text, TextMatch},
+    /// };
+    ///
+    /// let text = Text::from(vec![Line::from("one two"), Line::from("two one")]);
+    /// let (highlighted, matches) = text.highlight_matches("one", Style::new().reversed());
+    /// assert_eq!(
+    ///     highlighted.lines[0],
+    ///     Line::from(vec![Span::raw("one").reversed(), Span::raw(" two")])
+    /// );
+    /// assert_eq!(
+    ///     matches,
+    ///     [
+    ///         TextMatch {
+    ///             line: 0,
+    ///             range: 0..3
+    ///         },
+    ///         TextMatch {
+    ///             line: 1,
+    ///             range: 4..7
+    ///         }
+    ///     ]
+    /// );
+    /// ```
+    pub fn highlight_matches<S: Into<Style>>(
+        &self,
+        pattern: &str,
+        style: S,
+    ) -> (Self, Vec<TextMatch>) {
+        self.highlight_with(style, |content| {
+            if pattern.is_empty() {
+                return Vec::new();
+            }
+            content
+                .match_indices(pattern)
+                .map(|(start, matched)| start..start + matched.len())
+                .collect()
+        })
+    }
+
+    /// Returns a copy of the text with every match of `regex` highlighted, and the positions of
+    /// the matches.
+    ///
+    /// This works like [`Text::highlight_matches`], except that empty matches are ignored. It is
+    /// the same as a [`Highlighter`] with a single rule, which also returns the positions of the
+    /// matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::{Text, TextMatch},
+    /// };
+    /// use regex::Regex;
+    ///
+    /// let text = Text::from("error at 12:3, error at 40:1");
+    /// let regex = Regex::new(r"\d+:\d+").unwrap();
+    /// let (highlighted, matches) = text.highlight_regex(&regex, Style::new().red());
+    /// assert_eq!(
+    ///     matches[1],
+    ///     TextMatch {
+    ///         line: 0,
+    ///         range: 24..28
+    ///     }
+    /// );
+    /// ```
+    ///
+    /// [`Highlighter`]: crate::text::Highlighter
+    #[cfg(feature = "regex")]
+    pub fn highlight_regex<S: Into<Style>>(
+        &self,
+        regex: &regex::Regex,
+        style: S,
+    ) -> (Self, Vec<TextMatch>) {
+        let style = style.into();
+        let highlighter = crate::text::Highlighter::new().rule(regex.clone(), style);
+        self.highlight_with(style, |content| {
+            highlighter
+                .find(content)
+                .into_iter()
+                .map(|(range, _)| range)
+                .collect()
+        })
+    }
+
+    /// Returns a copy of the text with the byte ranges returned by `find` highlighted, and the
+    /// positions of the ranges.
+    ///
+    /// `find` is called with the content of each line, and returns the ranges to highlight in
+    /// it. This works like [`Text::highlight_matches`] for other ways to search, e.g. a
+    /// case-insensitive search.
+    ///
+    /// The ranges are clamped to the content and widened to the character boundaries around them.
+    /// They are then sorted and the overlapping ranges are merged, so each part of the content is
+    /// highlighted once and reported in a single match. Empty ranges are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::Text,
+    /// };
+    ///
+    /// let text = Text::from("error at 12, warning at 4");
+    /// let (_, matches) = text.highlight_with(Style::new().red(), |content| {
+    ///     content
+    ///         .char_indices()
+    ///         .filter(|(_, c)| c.is_ascii_digit())
+    ///         .map(|(index, _)| index..index + 1)
+    ///         .collect()
+    /// });
+    /// assert_eq!(matches.len(), 3);
+    /// ```
+    pub fn highlight_with<S, F>(&self, style: S, find: F) -> (Self, Vec<TextMatch>)
+    where
+        S: Into<Style>,
+        F: Fn(&str) -> Vec<Range<usize>>,
+    {
+        let style = style.into();
+        let mut matches = Vec::new();
+        let lines = self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let content: String = line.spans.iter().map(|span| &*span.content).collect();
+                let ranges = normalize_ranges(&content, find(&content));
+                if ranges.is_empty() {
+                    return line.clone();
+                }
+                let highlighted = line.patch_style_ranges(&ranges, style);
+                matches.extend(
+                    ranges
+                        .into_iter()
+                        .map(|range| TextMatch { line: index, range }),
+                );
+                highlighted
+            })
+            .collect();
+        let text = Self {
+            lines,
+            style: self.style,
+            alignment: self.alignment,
+        };
+        (text, matches)
+    }
+}
+
+/// Clamps the ranges to the content and widens them to character boundaries, then sorts them and
+/// merges the overlapping ones.
+fn normalize_ranges(content: &str, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    let floor = |mut offset: usize| {
+        offset = offset.min(content.len());
+        while !content.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    };
+    let ceil = |mut offset: usize| {
+        offset = offset.min(content.len());
+        while !content.is_char_boundary(offset) {
+            offset += 1;
+        }
+        offset
+    };
+    let mut ranges: Vec<Range<usize>> = ranges
+        .into_iter()
+        .map(|range| floor(range.start)..ceil(range.end))
+        .filter(|range| range.start < range.end)
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start < last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Lays out the characters of the line in rows of `wrap_width` columns.
+///
+/// Returns the position of each character of the line, followed by the position right after the
+/// last character, relative to the top left of the line.
+fn wrapped_positions(line: &Line, wrap_width: u16) -> Vec<Position> {
+    let wrap_width = wrap_width.max(1);
+    let mut position = Position::ORIGIN;
+    let mut positions = Vec::new();
+    for grapheme in line.styled_graphemes(Style::new()) {
+        let width = u16::try_from(grapheme.symbol.width()).unwrap_or(u16::MAX);
+        if position.x > 0 && position.x.saturating_add(width) > wrap_width {
+            position = Position::new(0, position.y.saturating_add(1));
+        }
+        positions.push(position);
+        position.x = position.x.saturating_add(width);
+    }
+    positions.push(position);
+    positions
+}
+
+/// Returns the number of rows taken by a line laid out by [`wrapped_positions`].
+fn row_count(positions: &[Position]) -> u16 {
+    positions
+        .last()
+        .map_or(1, |position| position.y.saturating_add(1))
+}
+
+/// The position of a match found by [`Text::highlight_matches`] or [`Text::highlight_with`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TextMatch {
+    /// The index of the line containing the match.
+    pub line: usize,
+    /// The byte range of the match in the content of the line.
+    pub range: Range<usize>,
 }
 
 impl<'a> IntoIterator for Text<'a> {
@@ -732,10 +1099,32 @@ impl Widget for Text<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
     }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        Widget::size_hint(&self, area)
+    }
 }
 
 impl Widget for &Text<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_slice(area, &mut buf.view_mut());
+    }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        let height = u16::try_from(self.height()).unwrap_or(u16::MAX);
+        (width.min(area.width), height.min(area.height))
+    }
+}
+
+impl SliceWidget for Text<'_> {
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>) {
+        SliceWidget::render_slice(&self, area, buf);
+    }
+}
+
+impl SliceWidget for &Text<'_> {
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>) {
         let area = area.intersection(buf.area);
         buf.set_style(area, self.style);
         for (line, line_area) in self.iter().zip(area.rows()) {
@@ -802,6 +1191,15 @@ mod tests {
         assert_eq!(2, text.height());
     }
 
+    #[test]
+    fn size_hint() {
+        let text = Text::from("The first line\nThe second line");
+        assert_eq!(text.size_hint(Rect::new(0, 0, 20, 5)), (15, 2));
+        assert_eq!(text.size_hint(Rect::new(0, 0, 10, 1)), (10, 1));
+        assert_eq!(Line::from("line").size_hint(Rect::new(0, 0, 20, 5)), (4, 1));
+        assert_eq!(Span::raw("span").size_hint(Rect::new(0, 0, 2, 0)), (2, 0));
+    }
+
     #[test]
     fn patch_style() {
         let style = Style::new().yellow().italic();
@@ -1398,4 +1796,174 @@ mod tests {
             ]).on_blue().italic().centered()"#}
         );
     }
+
+    #[test]
+    fn highlight_matches_across_spans() {
+        let text = Text::from(Line::from(vec![
+            Span::raw("foo b").red(),
+            Span::raw(String::from("ar baz")),
+        ]))
+        .centered();
+        let (highlighted, matches) = text.highlight_matches("bar", Style::new().bold());
+        assert_eq!(
+            matches,
+            [TextMatch {
+                line: 0,
+                range: 4..7
+            }]
+        );
+        assert_eq!(
+            highlighted,
+            Text::from(Line::from(vec![
+                Span::raw("foo ").red(),
+                Span::raw("b").red().bold(),
+                Span::raw("ar").bold(),
+                Span::raw(" baz"),
+            ]))
+            .centered()
+        );
+    }
+
+    #[rstest]
+    #[case::no_match("xyz", 0)]
+    #[case::empty_pattern("", 0)]
+    #[case::repeated("aa", 2)]
+    fn highlight_matches_count(#[case] pattern: &str, #[case] count: usize) {
+        let text = Text::from("aaaa\nb");
+        let (highlighted, matches) = text.highlight_matches(pattern, Style::new().bold());
+        assert_eq!(matches.len(), count);
+        if count == 0 {
+            assert_eq!(highlighted, text);
+        }
+    }
+
+    #[test]
+    fn char_and_word_count() {
+        let text = Text::from("  two words\n\nthree  more words ");
+        assert_eq!(text.char_count(), 29);
+        assert_eq!(text.word_count(), 5);
+        assert_eq!(Text::default().char_count(), 0);
+        assert_eq!(Text::default().word_count(), 0);
+    }
+
+    #[rstest]
+    #[case::start((0, 0), Some((0, 0)))]
+    #[case::first_row((3, 0), Some((3, 0)))]
+    #[case::wrapped((4, 0), Some((0, 1)))]
+    #[case::end_of_line((6, 0), Some((2, 1)))]
+    #[case::past_end_of_line((9, 0), Some((2, 1)))]
+    #[case::empty_line((0, 1), Some((0, 2)))]
+    #[case::wide_char_wrapped((2, 2), Some((0, 4)))]
+    #[case::after_wide_char((3, 2), Some((2, 4)))]
+    #[case::missing_line((0, 3), None)]
+    fn char_to_screen(#[case] position: (u16, u16), #[case] expected: Option<(u16, u16)>) {
+        let text = Text::from("abcdef\n\n界界界");
+        let screen = text.char_to_screen(position.into(), 4);
+        assert_eq!(screen, expected.map(Position::from));
+    }
+
+    #[rstest]
+    #[case::start((0, 0), Some((0, 0)))]
+    #[case::wrapped((1, 1), Some((5, 0)))]
+    #[case::past_end_of_row((3, 1), Some((6, 0)))]
+    #[case::empty_line((2, 2), Some((0, 1)))]
+    #[case::second_cell_of_wide_char((1, 3), Some((0, 2)))]
+    #[case::past_end_of_wrapped_row((3, 3), Some((1, 2)))]
+    #[case::past_end_of_last_row((3, 4), Some((3, 2)))]
+    #[case::below_text((0, 5), None)]
+    fn screen_to_char(#[case] position: (u16, u16), #[case] expected: Option<(u16, u16)>) {
+        let text = Text::from("abcdef\n\n界界界");
+        let char_position = text.screen_to_char(position.into(), 4);
+        assert_eq!(char_position, expected.map(Position::from));
+    }
+
+    #[test]
+    fn screen_to_char_inverts_char_to_screen() {
+        let text = Text::from("a界bc 👋🏽 d\nlonger line\n\nend");
+        for (y, line) in text.iter().enumerate() {
+            for x in 0..=line.styled_graphemes(Style::new()).count() {
+                let position = Position::new(x as u16, y as u16);
+                let screen = text.char_to_screen(position, 3).unwrap();
+                assert_eq!(text.screen_to_char(screen, 3), Some(position));
+            }
+        }
+    }
+
+    #[test]
+    fn highlight_with_unsorted_and_overlapping_ranges() {
+        let text = Text::from("abcdefgh");
+        let (highlighted, matches) =
+            text.highlight_with(Color::Red, |_| vec![5..7, 1..3, 2..4, 6..6, 6..20]);
+        assert_eq!(
+            matches,
+            [
+                TextMatch {
+                    line: 0,
+                    range: 1..4
+                },
+                TextMatch {
+                    line: 0,
+                    range: 5..8
+                }
+            ]
+        );
+        assert_eq!(
+            highlighted.lines[0],
+            Line::from(vec![
+                Span::raw("a"),
+                Span::raw("bcd").red(),
+                Span::raw("e"),
+                Span::raw("fgh").red(),
+            ])
+        );
+    }
+
+    #[test]
+    fn highlight_with_ranges_inside_characters() {
+        // "é" and "界" are 2 and 3 bytes long
+        let text = Text::from("aé界b");
+        #[allow(clippy::single_range_in_vec_init)]
+        let (highlighted, matches) = text.highlight_with(Color::Red, |_| vec![2..4]);
+        assert_eq!(
+            matches,
+            [TextMatch {
+                line: 0,
+                range: 1..6
+            }]
+        );
+        assert_eq!(
+            highlighted.lines[0],
+            Line::from(vec![Span::raw("a"), Span::raw("é界").red(), Span::raw("b")])
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn highlight_regex() {
+        let text = Text::from("a1 b22");
+        let regex = regex::Regex::new(r"\d*").unwrap();
+        let (highlighted, matches) = text.highlight_regex(&regex, Color::Red);
+        assert_eq!(
+            matches,
+            [
+                TextMatch {
+                    line: 0,
+                    range: 1..2
+                },
+                TextMatch {
+                    line: 0,
+                    range: 4..6
+                }
+            ]
+        );
+        assert_eq!(
+            highlighted.lines[0],
+            Line::from(vec![
+                Span::raw("a"),
+                Span::raw("1").red(),
+                Span::raw(" b"),
+                Span::raw("22").red(),
+            ])
+        );
+    }
 }
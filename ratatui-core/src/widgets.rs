@@ -2,7 +2,12 @@
 //! The `widgets` module contains the `Widget` and `StatefulWidget` traits, which are used to
 //! render UI elements on the screen.
 
-pub use self::{stateful_widget::StatefulWidget, widget::Widget};
+pub use self::{
+    has_cursor::HasCursor, slice_widget::SliceWidget, stateful_widget::StatefulWidget,
+    widget::Widget,
+};
 
+mod has_cursor;
+mod slice_widget;
 mod stateful_widget;
 mod widget;
@@ -0,0 +1,50 @@
+use crate::layout::Position;
+
+/// The state of a widget that shows the terminal cursor, such as a text input.
+///
+/// Widgets that edit text record in their state where the terminal cursor should be shown while
+/// they are rendered, as only the widget knows how its content is laid out (scrolled, wrapped,
+/// etc.). After rendering the widget, pass its state to [`Frame::set_cursor_from`] to place the
+/// cursor, instead of computing its position in the application.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::{
+///     buffer::Buffer,
+///     layout::{Position, Rect},
+///     text::Line,
+///     widgets::{HasCursor, StatefulWidget, Widget},
+/// };
+///
+/// struct Input;
+///
+/// #[derive(Default)]
+/// struct InputState {
+///     value: String,
+///     cursor_position: Option<Position>,
+/// }
+///
+/// impl StatefulWidget for Input {
+///     type State = InputState;
+///
+///     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+///         Line::raw(state.value.as_str()).render(area, buf);
+///         let column = u16::try_from(state.value.len()).unwrap_or(u16::MAX);
+///         state.cursor_position = Some(Position::new(area.x.saturating_add(column), area.y));
+///     }
+/// }
+///
+/// impl HasCursor for InputState {
+///     fn cursor_position(&self) -> Option<Position> {
+///         self.cursor_position
+///     }
+/// }
+/// ```
+///
+/// [`Frame::set_cursor_from`]: crate::terminal::Frame::set_cursor_from
+pub trait HasCursor {
+    /// Returns where the terminal cursor should be shown after the widget was rendered, or `None`
+    /// if the cursor should not be shown, e.g. because the widget is not focused.
+    fn cursor_position(&self) -> Option<Position>;
+}
@@ -0,0 +1,36 @@
+use crate::{buffer::BufferMut, layout::Rect};
+
+/// A `SliceWidget` is a widget that can be drawn on a [`BufferMut`], i.e. on cells that are
+/// borrowed from the caller, in a given [`Rect`].
+///
+/// Rendering into a `BufferMut` does not allocate, which makes it possible to render into a
+/// fixed-size array of cells on memory-constrained targets, or into storage reused across the
+/// iterations of a hot loop.
+///
+/// The text widgets ([`Span`], [`Line`] and [`Text`]) implement this trait, and their [`Widget`]
+/// implementations render through it.
+///
+/// [`Span`]: crate::text::Span
+/// [`Line`]: crate::text::Line
+/// [`Text`]: crate::text::Text
+/// [`Widget`]: crate::widgets::Widget
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::{
+///     buffer::{BufferMut, Cell},
+///     layout::Rect,
+///     text::Text,
+///     widgets::SliceWidget,
+/// };
+///
+/// let mut cells = [Cell::EMPTY; 12];
+/// let mut buf = BufferMut::new(Rect::new(0, 0, 6, 2), &mut cells);
+/// Text::from("Hello\nworld").render_slice(buf.area, &mut buf);
+/// assert_eq!(buf[(0, 1)].symbol(), "w");
+/// ```
+pub trait SliceWidget {
+    /// Draws the current state of the widget in the given borrowed buffer.
+    fn render_slice(self, area: Rect, buf: &mut BufferMut<'_>);
+}
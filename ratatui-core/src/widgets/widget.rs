@@ -61,6 +61,21 @@ pub trait Widget {
     fn render(self, area: Rect, buf: &mut Buffer)
     where
         Self: Sized;
+
+    /// Returns the size, as `(width, height)`, that the widget needs to display its content in the
+    /// given area.
+    ///
+    /// This lets a layout size a segment to the content of the widget with [`Constraint::Fit`],
+    /// e.g. the height of a paragraph once its text is wrapped at the width of the area. The
+    /// returned size is at most the size of the area.
+    ///
+    /// The default implementation returns the size of the area, for widgets that fill all the
+    /// space they are given.
+    ///
+    /// [`Constraint::Fit`]: crate::layout::Constraint::Fit
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        (area.width, area.height)
+    }
 }
 
 /// Renders a string slice as a widget.
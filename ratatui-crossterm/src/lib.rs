@@ -29,7 +29,7 @@ use crossterm::{
     terminal::{self, Clear},
 };
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, ClearType, MouseCapture, NotificationProtocol, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -65,6 +65,8 @@ use ratatui_core::{
 /// let mut backend = CrosstermBackend::new(stdout());
 /// // or
 /// let backend = CrosstermBackend::new(stderr());
+/// // or
+/// let backend = CrosstermBackend::stderr();
 /// let mut terminal = Terminal::new(backend)?;
 ///
 /// enable_raw_mode()?;
@@ -81,6 +83,10 @@ use ratatui_core::{
 /// # std::io::Result::Ok(())
 /// ```
 ///
+/// The backend can write to any [`Write`] implementation, such as a `Vec<u8>` in tests. Commands
+/// are queued and only written out when the backend is flushed, and [`Self::flush_stats`] reports
+/// how much output was flushed.
+///
 /// See the the [Examples] directory for more examples. See the [`backend`] module documentation
 /// for more details on raw mode and alternate screen.
 ///
@@ -93,6 +99,27 @@ use ratatui_core::{
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
     writer: W,
+    /// Statistics about the data written to the writer.
+    stats: FlushStats,
+}
+
+/// Statistics about the data written by a [`CrosstermBackend`] to its writer.
+///
+/// The backend queues the commands it sends to the terminal, so that the output of a frame is
+/// written in a batch when the backend is flushed (e.g. at the end of [`Terminal::draw`]). These
+/// statistics can be used to measure how much output each frame produces.
+///
+/// [`Terminal::draw`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html#method.draw
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FlushStats {
+    /// The number of times the writer was flushed.
+    pub flushes: u64,
+    /// The total number of bytes written by all the flushes.
+    pub bytes_flushed: u64,
+    /// The number of bytes written by the last flush.
+    pub last_flush_bytes: u64,
+    /// The number of bytes written since the last flush.
+    pub pending_bytes: u64,
 }
 
 impl<W> CrosstermBackend<W>
@@ -116,7 +143,15 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            stats: FlushStats {
+                flushes: 0,
+                bytes_flushed: 0,
+                last_flush_bytes: 0,
+                pending_bytes: 0,
+            },
+        }
     }
 
     /// Gets the writer.
@@ -139,6 +174,63 @@ where
     pub fn writer_mut(&mut self) -> &mut W {
         &mut self.writer
     }
+
+    /// Returns statistics about the data written to the writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::backend::{Backend, CrosstermBackend};
+    ///
+    /// // write to a buffer instead of the terminal, e.g. in tests
+    /// let mut backend = CrosstermBackend::new(Vec::new());
+    /// backend.bell()?;
+    /// Backend::flush(&mut backend)?;
+    /// assert_eq!(backend.flush_stats().flushes, 1);
+    /// assert_eq!(backend.flush_stats().last_flush_bytes, 1);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub const fn flush_stats(&self) -> FlushStats {
+        self.stats
+    }
+
+    /// Resets the statistics about the data written to the writer.
+    pub fn reset_flush_stats(&mut self) {
+        self.stats = FlushStats::default();
+    }
+}
+
+impl CrosstermBackend<io::Stdout> {
+    /// Creates a new `CrosstermBackend` that writes to the standard output.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui::backend::CrosstermBackend;
+    ///
+    /// let backend = CrosstermBackend::stdout();
+    /// ```
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl CrosstermBackend<io::Stderr> {
+    /// Creates a new `CrosstermBackend` that writes to the standard error.
+    ///
+    /// Rendering the UI on the standard error keeps the standard output free for data that is
+    /// piped to another program.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui::backend::CrosstermBackend;
+    ///
+    /// let backend = CrosstermBackend::stderr();
+    /// ```
+    pub fn stderr() -> Self {
+        Self::new(io::stderr())
+    }
 }
 
 impl<W> Write for CrosstermBackend<W>
@@ -147,12 +239,19 @@ where
 {
     /// Writes a buffer of bytes to the underlying buffer.
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.writer.write(buf)
+        let written = self.writer.write(buf)?;
+        self.stats.pending_bytes += written as u64;
+        Ok(written)
     }
 
     /// Flushes the underlying buffer.
     fn flush(&mut self) -> io::Result<()> {
-        self.writer.flush()
+        self.writer.flush()?;
+        let bytes = std::mem::take(&mut self.stats.pending_bytes);
+        self.stats.flushes += 1;
+        self.stats.bytes_flushed += bytes;
+        self.stats.last_flush_bytes = bytes;
+        Ok(())
     }
 }
 
@@ -173,7 +272,7 @@ where
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
             if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
-                queue!(self.writer, MoveTo(x, y))?;
+                queue!(self, MoveTo(x, y))?;
             }
             last_pos = Some(Position { x, y });
             if cell.modifier != modifier {
@@ -181,12 +280,12 @@ where
                     from: modifier,
                     to: cell.modifier,
                 };
-                diff.queue(&mut self.writer)?;
+                diff.queue(&mut *self)?;
                 modifier = cell.modifier;
             }
             if cell.fg != fg || cell.bg != bg {
                 queue!(
-                    self.writer,
+                    self,
                     SetColors(CrosstermColors::new(
                         cell.fg.into_crossterm(),
                         cell.bg.into_crossterm(),
@@ -198,16 +297,16 @@ where
             #[cfg(feature = "underline-color")]
             if cell.underline_color != underline_color {
                 let color = cell.underline_color.into_crossterm();
-                queue!(self.writer, SetUnderlineColor(color))?;
+                queue!(self, SetUnderlineColor(color))?;
                 underline_color = cell.underline_color;
             }
 
-            queue!(self.writer, Print(cell.symbol()))?;
+            queue!(self, Print(cell.symbol()))?;
         }
 
         #[cfg(feature = "underline-color")]
         return queue!(
-            self.writer,
+            self,
             SetForegroundColor(CrosstermColor::Reset),
             SetBackgroundColor(CrosstermColor::Reset),
             SetUnderlineColor(CrosstermColor::Reset),
@@ -215,7 +314,7 @@ where
         );
         #[cfg(not(feature = "underline-color"))]
         return queue!(
-            self.writer,
+            self,
             SetForegroundColor(CrosstermColor::Reset),
             SetBackgroundColor(CrosstermColor::Reset),
             SetAttribute(CrosstermAttribute::Reset),
@@ -223,11 +322,11 @@ where
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
-        execute!(self.writer, Hide)
+        execute!(self, Hide)
     }
 
     fn show_cursor(&mut self) -> io::Result<()> {
-        execute!(self.writer, Show)
+        execute!(self, Show)
     }
 
     fn get_cursor_position(&mut self) -> io::Result<Position> {
@@ -238,7 +337,7 @@ where
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
         let Position { x, y } = position.into();
-        execute!(self.writer, MoveTo(x, y))
+        execute!(self, MoveTo(x, y))
     }
 
     fn clear(&mut self) -> io::Result<()> {
@@ -247,7 +346,7 @@ where
 
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
         execute!(
-            self.writer,
+            self,
             Clear(match clear_type {
                 ClearType::All => crossterm::terminal::ClearType::All,
                 ClearType::AfterCursor => crossterm::terminal::ClearType::FromCursorDown,
@@ -260,9 +359,9 @@ where
 
     fn append_lines(&mut self, n: u16) -> io::Result<()> {
         for _ in 0..n {
-            queue!(self.writer, Print("\n"))?;
+            queue!(self, Print("\n"))?;
         }
-        self.writer.flush()
+        Write::flush(self)
     }
 
     fn size(&self) -> io::Result<Size> {
@@ -287,33 +386,59 @@ where
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.writer.flush()
+        Write::flush(self)
+    }
+
+    fn bell(&mut self) -> io::Result<()> {
+        queue!(self, Print("\x07"))
+    }
+
+    fn notify(
+        &mut self,
+        protocol: NotificationProtocol,
+        title: &str,
+        body: &str,
+    ) -> io::Result<()> {
+        queue!(self, Print(protocol.escape_sequence(title, body)))
+    }
+
+    fn set_mouse_capture(&mut self, capture: MouseCapture) -> io::Result<()> {
+        // the Windows console API does not distinguish between the tracking modes
+        #[cfg(windows)]
+        if capture.is_enabled() {
+            execute!(self, crossterm::event::EnableMouseCapture)?;
+        } else {
+            execute!(self, crossterm::event::DisableMouseCapture)?;
+        }
+        #[cfg(not(windows))]
+        execute!(self, Print(capture.escape_sequence()))?;
+        Ok(())
     }
 
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
-            self.writer,
+            self,
             ScrollUpInRegion {
                 first_row: region.start,
                 last_row: region.end.saturating_sub(1),
                 lines_to_scroll: amount,
             }
         )?;
-        self.writer.flush()
+        Write::flush(self)
     }
 
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_down(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
-            self.writer,
+            self,
             ScrollDownInRegion {
                 first_row: region.start,
                 last_row: region.end.saturating_sub(1),
                 lines_to_scroll: amount,
             }
         )?;
-        self.writer.flush()
+        Write::flush(self)
     }
 }
 
@@ -673,6 +798,55 @@ mod tests {
         assert_eq!(Color::from_crossterm(crossterm_color), color);
     }
 
+    #[test]
+    fn bell() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.bell().unwrap();
+        assert_eq!(backend.writer, b"\x07");
+    }
+
+    #[test]
+    fn flush_stats() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.bell().unwrap();
+        backend.bell().unwrap();
+        assert_eq!(backend.flush_stats().pending_bytes, 2);
+        Backend::flush(&mut backend).unwrap();
+        backend.hide_cursor().unwrap();
+        assert_eq!(
+            backend.flush_stats(),
+            FlushStats {
+                flushes: 2,
+                bytes_flushed: 8,
+                last_flush_bytes: 6,
+                pending_bytes: 0,
+            }
+        );
+        backend.reset_flush_stats();
+        assert_eq!(backend.flush_stats(), FlushStats::default());
+    }
+
+    #[rstest]
+    #[case::click(MouseCapture::Click)]
+    #[case::drag(MouseCapture::Drag)]
+    #[case::all_motion(MouseCapture::AllMotion)]
+    #[case::disabled(MouseCapture::Disabled)]
+    #[cfg(not(windows))]
+    fn set_mouse_capture(#[case] capture: MouseCapture) {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.set_mouse_capture(capture).unwrap();
+        assert_eq!(backend.writer, capture.escape_sequence().as_bytes());
+    }
+
+    #[test]
+    fn notify() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .notify(NotificationProtocol::Osc777, "Build", "done")
+            .unwrap();
+        assert_eq!(backend.writer, b"\x1b]777;notify;Build;done\x07");
+    }
+
     mod modifier {
         use super::*;
 
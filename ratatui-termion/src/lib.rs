@@ -20,7 +20,7 @@ use std::{
 };
 
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, ClearType, MouseCapture, NotificationProtocol, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -251,6 +251,24 @@ where
         self.writer.flush()
     }
 
+    fn bell(&mut self) -> io::Result<()> {
+        write!(self.writer, "\x07")
+    }
+
+    fn notify(
+        &mut self,
+        protocol: NotificationProtocol,
+        title: &str,
+        body: &str,
+    ) -> io::Result<()> {
+        write!(self.writer, "{}", protocol.escape_sequence(title, body))
+    }
+
+    fn set_mouse_capture(&mut self, capture: MouseCapture) -> io::Result<()> {
+        write!(self.writer, "{}", capture.escape_sequence())?;
+        self.writer.flush()
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         write!(
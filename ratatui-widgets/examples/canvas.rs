@@ -67,10 +67,11 @@ pub fn render_canvas(frame: &mut Frame, area: Rect) {
         .y_bounds([-90.0, 90.0])
         .marker(Marker::Braille)
         .paint(|ctx| {
-            ctx.draw(&Map {
-                resolution: MapResolution::High,
-                color: Color::White,
-            });
+            ctx.draw(
+                &Map::default()
+                    .resolution(MapResolution::High)
+                    .color(Color::White),
+            );
             ctx.layer();
             ctx.draw(&Line::new(0.0, 10.0, 10.0, 10.0, Color::Blue));
             ctx.draw(&Rectangle {
@@ -0,0 +1,195 @@
+//! The [`Align`] widget positions another widget of a given size within a larger area.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Alignment, Rect, Size, VerticalAlignment},
+    widgets::Widget,
+};
+
+/// A widget that renders another widget at a fixed size, aligned within the render area.
+///
+/// This is useful for one-off positioning (e.g. centering a popup or putting a status indicator
+/// in the top right corner) without having to set up a [`Layout`] with constraints. Each
+/// dimension that is not set fills the whole area. When the area is smaller than the requested
+/// size, the child is clamped to the area.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::{Alignment, VerticalAlignment},
+///     widgets::{Align, Paragraph},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame) {
+/// // a 20x3 popup in the center of the screen
+/// let popup = Align::new(Paragraph::new("Hello"))
+///     .width(20)
+///     .height(3)
+///     .centered();
+/// frame.render_widget(popup, frame.area());
+///
+/// // a status indicator in the top right corner
+/// let status = Align::new(Paragraph::new("●"))
+///     .width(1)
+///     .height(1)
+///     .horizontal(Alignment::Right)
+///     .vertical(VerticalAlignment::Top);
+/// frame.render_widget(status, frame.area());
+/// # }
+/// ```
+///
+/// [`Layout`]: ratatui_core::layout::Layout
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Align<W> {
+    widget: W,
+    width: Option<u16>,
+    height: Option<u16>,
+    horizontal: Alignment,
+    vertical: VerticalAlignment,
+}
+
+impl<W> Align<W> {
+    /// Creates a new `Align` widget that fills the whole area and is aligned to the top left.
+    pub const fn new(widget: W) -> Self {
+        Self {
+            widget,
+            width: None,
+            height: None,
+            horizontal: Alignment::Left,
+            vertical: VerticalAlignment::Top,
+        }
+    }
+
+    /// Sets the width of the wrapped widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the wrapped widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the size of the wrapped widget.
+    ///
+    /// This is convenient when the size of the child has been measured beforehand, e.g. from the
+    /// width and height of a [`Text`].
+    ///
+    /// [`Text`]: ratatui_core::text::Text
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn size(self, size: Size) -> Self {
+        self.width(size.width).height(size.height)
+    }
+
+    /// Sets the horizontal alignment of the wrapped widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal(mut self, alignment: Alignment) -> Self {
+        self.horizontal = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the wrapped widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical = alignment;
+        self
+    }
+
+    /// Centers the wrapped widget both horizontally and vertically.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn centered(self) -> Self {
+        self.horizontal(Alignment::Center)
+            .vertical(VerticalAlignment::Center)
+    }
+
+    /// Consumes the `Align` widget and returns the wrapped widget.
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+
+    /// Computes the area that the wrapped widget is rendered into.
+    pub fn inner(&self, area: Rect) -> Rect {
+        let width = self.width.unwrap_or(area.width);
+        let height = self.height.unwrap_or(area.height);
+        Rect::new(0, 0, width, height).align_in(area, self.horizontal, self.vertical)
+    }
+}
+
+impl<W: Widget> Widget for Align<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area.intersection(buf.area));
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<W> Widget for &Align<W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area.intersection(buf.area));
+        self.widget.render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::paragraph::Paragraph;
+
+    #[rstest]
+    #[case::top_left(Alignment::Left, VerticalAlignment::Top, Rect::new(2, 1, 4, 2))]
+    #[case::center(Alignment::Center, VerticalAlignment::Center, Rect::new(5, 3, 4, 2))]
+    #[case::bottom_right(Alignment::Right, VerticalAlignment::Bottom, Rect::new(8, 5, 4, 2))]
+    fn inner(
+        #[case] horizontal: Alignment,
+        #[case] vertical: VerticalAlignment,
+        #[case] expected: Rect,
+    ) {
+        let align = Align::new(())
+            .size(Size::new(4, 2))
+            .horizontal(horizontal)
+            .vertical(vertical);
+        assert_eq!(align.inner(Rect::new(2, 1, 10, 6)), expected);
+    }
+
+    #[test]
+    fn inner_fills_unset_dimensions() {
+        let align = Align::new(()).width(4).centered();
+        assert_eq!(align.inner(Rect::new(0, 0, 10, 6)), Rect::new(3, 0, 4, 6));
+    }
+
+    #[test]
+    fn inner_clamps_to_area() {
+        let align = Align::new(()).width(20).height(20).centered();
+        assert_eq!(align.inner(Rect::new(1, 1, 10, 6)), Rect::new(1, 1, 10, 6));
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Align::new(Paragraph::new("abc"))
+            .width(3)
+            .height(1)
+            .centered()
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["       ", "  abc  ", "       "]));
+    }
+
+    #[test]
+    fn render_ref() {
+        let align = Align::new(Paragraph::new("ab"))
+            .width(2)
+            .horizontal(Alignment::Right);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        (&align).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["   ab"]));
+    }
+}
@@ -6,6 +6,9 @@
 //! [title](Block::title) and [padding](Block::padding).
 
 use itertools::Itertools;
+/// Re-exported from the [`layout`](ratatui_core::layout) module, where the padding is shared
+/// with [`Rect::inner_padded`].
+pub use ratatui_core::layout::Padding;
 use ratatui_core::{
     buffer::Buffer,
     layout::{Alignment, Rect},
@@ -15,13 +18,9 @@ use ratatui_core::{
     widgets::Widget,
 };
 
-pub use self::{
-    padding::Padding,
-    title::{Position, Title},
-};
+pub use self::title::{Position, Title};
 use crate::borders::{BorderType, Borders};
 
-mod padding;
 pub mod title;
 
 /// Base widget to be used to display a box border around all other built-in widgets.
@@ -396,6 +395,12 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Adds the given style on top of the style of the borders.
+    pub(crate) fn patch_border_style(mut self, style: Style) -> Self {
+        self.border_style = self.border_style.patch(style);
+        self
+    }
+
     /// Defines the style of the entire block.
     ///
     /// This is the most generic [`Style`] a block can receive, it will be merged with any other
@@ -581,17 +586,7 @@ impl<'a> Block<'a> {
             inner.height = inner.height.saturating_sub(1);
         }
 
-        inner.x = inner.x.saturating_add(self.padding.left);
-        inner.y = inner.y.saturating_add(self.padding.top);
-
-        inner.width = inner
-            .width
-            .saturating_sub(self.padding.left + self.padding.right);
-        inner.height = inner
-            .height
-            .saturating_sub(self.padding.top + self.padding.bottom);
-
-        inner
+        inner.inner_padded(self.padding)
     }
 
     fn has_title_at_position(&self, position: Position) -> bool {
@@ -9,6 +9,7 @@
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
 //! - [`Points`]: A scatter of points
+//! - [`Polyline`]: Points joined by lines, optionally simplified with [`simplify`]
 //! - [`Rectangle`]: A basic rectangle
 //!
 //! You can also implement your own custom [`Shape`]s.
@@ -27,17 +28,21 @@ use ratatui_core::{
 
 pub use self::{
     circle::Circle,
+    grid_lines::GridLines,
     line::Line,
-    map::{Map, MapResolution},
+    map::{Map, MapProjection, MapResolution},
     points::Points,
+    polyline::{simplify, Polyline},
     rectangle::Rectangle,
 };
 use crate::block::{Block, BlockExt};
 
 mod circle;
+mod grid_lines;
 mod line;
 mod map;
 mod points;
+mod polyline;
 mod rectangle;
 mod world;
 
@@ -610,10 +615,11 @@ impl<'a> Context<'a> {
 ///     .x_bounds([-180.0, 180.0])
 ///     .y_bounds([-90.0, 90.0])
 ///     .paint(|ctx| {
-///         ctx.draw(&Map {
-///             resolution: MapResolution::High,
-///             color: Color::White,
-///         });
+///         ctx.draw(
+///             &Map::default()
+///                 .resolution(MapResolution::High)
+///                 .color(Color::White),
+///         );
 ///         ctx.layer();
 ///         ctx.draw(&Line {
 ///             x1: 0.0,
@@ -644,6 +650,7 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: Marker,
+    grid: Option<GridLines>,
 }
 
 impl<F> Default for Canvas<'_, F>
@@ -658,6 +665,7 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: Marker::Braille,
+            grid: None,
         }
     }
 }
@@ -760,6 +768,17 @@ where
         self.marker = marker;
         self
     }
+
+    /// Draws a coordinate grid behind the shapes of the canvas.
+    ///
+    /// See [`GridLines`] for the available options.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn grid(mut self, grid: GridLines) -> Self {
+        self.grid = Some(grid);
+        self
+    }
 }
 
 impl<F> Widget for Canvas<'_, F>
@@ -783,6 +802,9 @@ where
         }
 
         buf.set_style(canvas_area, Style::default().bg(self.background_color));
+        if let Some(grid) = &self.grid {
+            grid.render(canvas_area, buf);
+        }
 
         let width = canvas_area.width as usize;
 
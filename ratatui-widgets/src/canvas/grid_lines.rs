@@ -0,0 +1,169 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols::line,
+};
+
+/// Gridlines drawn behind the content of a [`Canvas`] or of the plot area of a [`Chart`].
+///
+/// The area is divided into a number of equal parts along each axis, and a line is drawn between
+/// each part. The gridlines are drawn first, so shapes and datasets are drawn on top of them.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::canvas::{Canvas, GridLines},
+/// };
+///
+/// let canvas = Canvas::default()
+///     .x_bounds([0.0, 100.0])
+///     .y_bounds([0.0, 100.0])
+///     .grid(
+///         GridLines::new()
+///             .divisions(10, 5)
+///             .dashed(true)
+///             .style(Style::new().dark_gray()),
+///     )
+///     .paint(|_ctx| {});
+/// ```
+///
+/// [`Canvas`]: crate::canvas::Canvas
+/// [`Chart`]: crate::chart::Chart
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GridLines {
+    style: Style,
+    x_divisions: u16,
+    y_divisions: u16,
+    dashed: bool,
+}
+
+impl Default for GridLines {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridLines {
+    /// Creates gridlines dividing each axis in 4 parts, drawn with solid dark gray lines.
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new().fg(Color::DarkGray),
+            x_divisions: 4,
+            y_divisions: 4,
+            dashed: false,
+        }
+    }
+
+    /// Sets the style of the gridlines.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the number of parts the horizontal and vertical axes are divided into.
+    ///
+    /// `x` parts are separated by `x - 1` vertical lines, and `y` parts by `y - 1` horizontal
+    /// lines. A value of 0 or 1 draws no lines along that axis.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn divisions(mut self, x: u16, y: u16) -> Self {
+        self.x_divisions = x;
+        self.y_divisions = y;
+        self
+    }
+
+    /// Sets whether the gridlines are dashed instead of solid.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn dashed(mut self, dashed: bool) -> Self {
+        self.dashed = dashed;
+        self
+    }
+
+    /// Draws the gridlines in the given area.
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (vertical, horizontal) = if self.dashed {
+            (
+                line::LIGHT_DOUBLE_DASH_VERTICAL,
+                line::LIGHT_DOUBLE_DASH_HORIZONTAL,
+            )
+        } else {
+            (line::VERTICAL, line::HORIZONTAL)
+        };
+        let columns = Self::offsets(area.width, self.x_divisions);
+        let rows = Self::offsets(area.height, self.y_divisions);
+        for y in rows.clone() {
+            for x in area.left()..area.right() {
+                buf[(x, area.y + y)]
+                    .set_symbol(horizontal)
+                    .set_style(self.style);
+            }
+        }
+        for x in columns {
+            for y in area.top()..area.bottom() {
+                let symbol = if rows.clone().any(|row| area.y + row == y) {
+                    line::CROSS
+                } else {
+                    vertical
+                };
+                buf[(area.x + x, y)]
+                    .set_symbol(symbol)
+                    .set_style(self.style);
+            }
+        }
+    }
+
+    /// Returns the offsets of the lines dividing `length` cells into `divisions` parts.
+    fn offsets(length: u16, divisions: u16) -> impl Iterator<Item = u16> + Clone {
+        let span = u32::from(length.saturating_sub(1));
+        let divisions = u32::from(divisions);
+        (1..divisions).filter_map(move |i| {
+            let offset = (i * span + divisions / 2) / divisions;
+            u16::try_from(offset).ok().filter(|&offset| offset > 0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+        GridLines::new()
+            .divisions(2, 2)
+            .style(Style::new())
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "    │    ",
+                "    │    ",
+                "────┼────",
+                "    │    ",
+                "    │    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_dashed() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        GridLines::new()
+            .divisions(3, 0)
+            .dashed(true)
+            .style(Style::new())
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  ╎ ╎  "; 3]));
+    }
+}
@@ -33,10 +33,86 @@ impl MapResolution {
     }
 }
 
+/// Defines how the longitudes and latitudes of a [`Map`] are converted to canvas coordinates.
+///
+/// Use [`MapProjection::bounds`] as the bounds of the [`Canvas`] to show the whole world, and
+/// [`MapProjection::project`] to place other shapes at a given location on the map.
+///
+/// [`Canvas`]: crate::canvas::Canvas
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MapProjection {
+    /// Longitudes and latitudes are used as `x` and `y` coordinates as is.
+    ///
+    /// The world spans `[-180.0, 180.0]` horizontally and `[-90.0, 90.0]` vertically.
+    #[default]
+    Equirectangular,
+    /// The [Web Mercator projection], which preserves angles but enlarges areas far from the
+    /// equator.
+    ///
+    /// Latitudes are limited to about ±85.05°, so that the world spans `[-180.0, 180.0]` on both
+    /// axes.
+    ///
+    /// [Web Mercator projection]: https://en.wikipedia.org/wiki/Web_Mercator_projection
+    Mercator,
+}
+
+impl MapProjection {
+    /// The largest latitude shown by the [`Mercator`](Self::Mercator) projection, in degrees.
+    pub const MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+    /// Converts a longitude and a latitude, in degrees, to `(x, y)` canvas coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::canvas::MapProjection;
+    ///
+    /// let (x, y) = MapProjection::Mercator.project(2.35, 48.86);
+    /// assert!((x - 2.35).abs() < 1e-9);
+    /// assert!((y - 56.1).abs() < 0.1);
+    /// ```
+    pub fn project(self, longitude: f64, latitude: f64) -> (f64, f64) {
+        match self {
+            Self::Equirectangular => (longitude, latitude),
+            Self::Mercator => {
+                let latitude = latitude
+                    .clamp(-Self::MERCATOR_MAX_LATITUDE, Self::MERCATOR_MAX_LATITUDE)
+                    .to_radians();
+                let y = (std::f64::consts::FRAC_PI_4 + latitude / 2.0).tan().ln();
+                (longitude, y.to_degrees())
+            }
+        }
+    }
+
+    /// Returns the `x` and `y` bounds of the canvas containing the whole world.
+    pub const fn bounds(self) -> ([f64; 2], [f64; 2]) {
+        match self {
+            Self::Equirectangular => ([-180.0, 180.0], [-90.0, 90.0]),
+            Self::Mercator => ([-180.0, 180.0], [-180.0, 180.0]),
+        }
+    }
+}
+
 /// A world map
 ///
-/// A world map can be rendered with different [resolutions](MapResolution) and [colors](Color).
+/// A world map can be rendered with different [resolutions](MapResolution),
+/// [projections](MapProjection) and [colors](Color).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     style::Color,
+///     widgets::canvas::{Map, MapProjection, MapResolution},
+/// };
+///
+/// let map = Map::default()
+///     .resolution(MapResolution::High)
+///     .color(Color::Green)
+///     .projection(MapProjection::Mercator);
+/// ```
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub struct Map {
     /// The resolution of the map.
     ///
@@ -46,12 +122,40 @@ pub struct Map {
     ///
     /// This is the color of the points of the map.
     pub color: Color,
+    /// The projection of the map.
+    ///
+    /// This defines where each point of the map is drawn on the canvas.
+    pub projection: MapProjection,
+}
+
+impl Map {
+    /// Sets the resolution of the map.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn resolution(mut self, resolution: MapResolution) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Sets the color of the points of the map.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the projection of the map.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn projection(mut self, projection: MapProjection) -> Self {
+        self.projection = projection;
+        self
+    }
 }
 
 impl Shape for Map {
     fn draw(&self, painter: &mut Painter) {
-        for (x, y) in self.resolution.data() {
-            if let Some((x, y)) = painter.get_point(*x, *y) {
+        for &(longitude, latitude) in self.resolution.data() {
+            let (x, y) = self.projection.project(longitude, latitude);
+            if let Some((x, y)) = painter.get_point(x, y) {
                 painter.paint(x, y, self.color);
             }
         }
@@ -87,6 +191,49 @@ mod tests {
         let map = Map::default();
         assert_eq!(map.resolution, MapResolution::Low);
         assert_eq!(map.color, Color::Reset);
+        assert_eq!(map.projection, MapProjection::Equirectangular);
+    }
+
+    #[test]
+    fn map_projection_from_str() {
+        assert_eq!("Mercator".parse(), Ok(MapProjection::Mercator));
+        assert_eq!(
+            "Equirectangular".parse(),
+            Ok(MapProjection::Equirectangular)
+        );
+    }
+
+    #[test]
+    fn project() {
+        assert_eq!(
+            MapProjection::Equirectangular.project(-73.9, 40.7),
+            (-73.9, 40.7)
+        );
+        let (x, equator) = MapProjection::Mercator.project(10.0, 0.0);
+        assert!((x - 10.0).abs() < 1e-9);
+        assert!(equator.abs() < 1e-9);
+        let (_, top) = MapProjection::Mercator.project(0.0, 90.0);
+        let (_, bottom) = MapProjection::Mercator.project(0.0, -90.0);
+        assert!((top - 180.0).abs() < 1e-9);
+        assert!((bottom + 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn draw_mercator() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let projection = MapProjection::Mercator;
+        let (x_bounds, y_bounds) = projection.bounds();
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
+            .paint(|context| {
+                context.draw(&Map::default().projection(projection));
+            })
+            .render(buffer.area, &mut buffer);
+        // Antarctica is on the bottom row, while the Arctic islands are on the top row
+        assert_eq!(buffer[(1, 2)].symbol(), "•");
+        assert_eq!(buffer[(1, 0)].symbol(), "•");
     }
 
     #[test]
@@ -153,10 +300,7 @@ mod tests {
             .x_bounds([-180.0, 180.0])
             .y_bounds([-90.0, 90.0])
             .paint(|context| {
-                context.draw(&Map {
-                    resolution: MapResolution::High,
-                    ..Default::default()
-                });
+                context.draw(&Map::default().resolution(MapResolution::High));
             });
         canvas.render(buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
@@ -0,0 +1,213 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Line, Painter, Shape};
+
+/// A sequence of points joined by straight lines, with a given color
+///
+/// Unlike [`Points`](crate::canvas::Points), the segments between consecutive points are drawn,
+/// and they are clipped to the bounds of the canvas: a segment with one or both ends outside of
+/// the canvas is still drawn where it crosses it. This makes polylines suitable for outlines,
+/// routes or borders that are only partially visible while the canvas is panned or zoomed.
+///
+/// Points closer than [`tolerance`](Self::tolerance) to the simplified outline are dropped before
+/// drawing (see [`simplify`]). A tolerance around the size of a dot of the canvas, in canvas
+/// units, keeps large polylines cheap to draw without visibly changing them.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     style::Color,
+///     widgets::canvas::{Canvas, Polyline},
+/// };
+///
+/// let route = [(0.0, 0.0), (40.0, 10.0), (60.0, 80.0), (150.0, 90.0)];
+/// let canvas = Canvas::default()
+///     .x_bounds([0.0, 100.0])
+///     .y_bounds([0.0, 100.0])
+///     .paint(|ctx| {
+///         ctx.draw(&Polyline::new(&route, Color::Yellow).tolerance(0.5));
+///     });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polyline<'a> {
+    /// List of points to join
+    pub coords: &'a [(f64, f64)],
+    /// Color of the lines
+    pub color: Color,
+    /// Maximum distance between the dropped points and the simplified polyline
+    ///
+    /// A tolerance of 0 (the default) draws every point.
+    pub tolerance: f64,
+}
+
+impl<'a> Polyline<'a> {
+    /// Create a new polyline joining the given coordinates with the given color
+    pub const fn new(coords: &'a [(f64, f64)], color: Color) -> Self {
+        Self {
+            coords,
+            color,
+            tolerance: 0.0,
+        }
+    }
+
+    /// Sets the tolerance used to simplify the polyline before drawing it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+impl Shape for Polyline<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let simplified;
+        let coords = if self.tolerance > 0.0 {
+            simplified = simplify(self.coords, self.tolerance);
+            &simplified
+        } else {
+            self.coords
+        };
+        if let [(x, y)] = coords {
+            if let Some((x, y)) = painter.get_point(*x, *y) {
+                painter.paint(x, y, self.color);
+            }
+        }
+        for segment in coords.windows(2) {
+            let [(x1, y1), (x2, y2)] = [segment[0], segment[1]];
+            Line::new(x1, y1, x2, y2, self.color).draw(painter);
+        }
+    }
+}
+
+/// Simplifies a polyline with the Ramer-Douglas-Peucker algorithm.
+///
+/// The first and last points are always kept. Between them, the point furthest from the segment
+/// joining them is kept if it is further than `tolerance`, and the same is repeated on both sides
+/// of it. The returned points are a subset of the given ones, in the same order, and every dropped
+/// point is within `tolerance` of the simplified polyline.
+///
+/// The points are returned unchanged if `tolerance` is not positive.
+///
+/// See [Ramer-Douglas-Peucker algorithm] for details.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::canvas::simplify;
+///
+/// let points = [(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0)];
+/// assert_eq!(
+///     simplify(&points, 0.5),
+///     [(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0)]
+/// );
+/// ```
+///
+/// [Ramer-Douglas-Peucker algorithm]: https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm
+pub fn simplify(coords: &[(f64, f64)], tolerance: f64) -> Vec<(f64, f64)> {
+    if coords.len() < 3 || tolerance.is_nan() || tolerance <= 0.0 {
+        return coords.to_vec();
+    }
+    let mut keep = vec![false; coords.len()];
+    keep[0] = true;
+    keep[coords.len() - 1] = true;
+    // ranges are processed with an explicit stack, as long polylines would overflow the call stack
+    let mut ranges = vec![(0, coords.len() - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let furthest = (first + 1..last)
+            .map(|i| {
+                (
+                    i,
+                    distance_to_segment(coords[i], coords[first], coords[last]),
+                )
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        if let Some((i, distance)) = furthest {
+            if distance > tolerance {
+                keep[i] = true;
+                ranges.push((first, i));
+                ranges.push((i, last));
+            }
+        }
+    }
+    coords
+        .iter()
+        .zip(keep)
+        .filter_map(|(&point, keep)| keep.then_some(point))
+        .collect()
+}
+
+/// Returns the distance between `point` and the segment from `start` to `end`.
+fn distance_to_segment(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = dx.mul_add(dx, dy * dy);
+    let t = if length_squared > 0.0 {
+        ((point.0 - start.0).mul_add(dx, (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (x, y) = (t.mul_add(dx, start.0), t.mul_add(dy, start.1));
+    (point.0 - x).hypot(point.1 - y)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{buffer::Buffer, layout::Rect, symbols::Marker, widgets::Widget};
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn simplify_drops_points_within_tolerance() {
+        let points = [(0.0, 0.0), (1.0, 0.2), (2.0, 0.0), (3.0, 3.0), (4.0, 0.0)];
+        assert_eq!(
+            simplify(&points, 0.5),
+            [(0.0, 0.0), (2.0, 0.0), (3.0, 3.0), (4.0, 0.0)]
+        );
+        assert_eq!(simplify(&points, 10.0), [(0.0, 0.0), (4.0, 0.0)]);
+        assert_eq!(simplify(&points, 0.0), points);
+    }
+
+    #[test]
+    fn simplify_closed_polyline() {
+        let square = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)];
+        assert_eq!(simplify(&square, 0.1), square);
+    }
+
+    #[test]
+    fn simplify_long_polyline() {
+        let points: Vec<_> = (0..100_000)
+            .map(|i| (f64::from(i), f64::from(i % 2)))
+            .collect();
+        assert_eq!(simplify(&points, 1.0), [(0.0, 0.0), (99_999.0, 1.0)]);
+        let zigzag = &points[..1_000];
+        assert_eq!(simplify(zigzag, 0.25), zigzag);
+    }
+
+    #[test]
+    fn draws_segments_crossing_the_bounds() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let coords = [(-10.0, 1.0), (10.0, 1.0), (2.0, 10.0), (2.0, -10.0)];
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 2.0])
+            .paint(|ctx| ctx.draw(&Polyline::new(&coords, Color::Reset)))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  •  ", "•••••", "  •  "]));
+    }
+
+    #[test]
+    fn draws_single_point() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| ctx.draw(&Polyline::new(&[(1.0, 0.0)], Color::Reset)))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" • "]));
+    }
+}
@@ -0,0 +1,409 @@
+//! The [`CardGrid`] widget lays out cards of different heights in columns, like a masonry wall.
+use std::fmt;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Position, Rect},
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A widget that lays out cards of different heights into columns and scrolls vertically.
+///
+/// Each [`Card`] wraps any [`Widget`] together with its height. The cards are placed in the order
+/// they were added, each one at the bottom of the shortest column (the leftmost one if several
+/// columns have the same height), which keeps the columns balanced. This suits dashboards made of
+/// heterogeneous panels. The columns share the width of the area equally.
+///
+/// When rendered as a stateful widget, the grid is scrolled vertically by the offset of the
+/// [`CardGridState`]. Cards that are partially scrolled out of the area are clipped.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{Block, Card, CardGrid, CardGridState, Paragraph},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, state: &mut CardGridState) {
+/// let grid = CardGrid::new()
+///     .columns(3)
+///     .spacing(1, 1)
+///     .card(Block::bordered().title("CPU"), 8)
+///     .card(Block::bordered().title("Memory"), 5)
+///     .push(Card::new(Paragraph::new("Uptime: 3 days"), 1));
+/// frame.render_stateful_widget(grid, frame.area(), state);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CardGrid<'a> {
+    cards: Vec<Card<'a>>,
+    columns: u16,
+    column_spacing: u16,
+    row_spacing: u16,
+}
+
+type RenderFn<'a> = Box<dyn FnOnce(Rect, &mut Buffer) + 'a>;
+
+/// A single card of a [`CardGrid`].
+///
+/// A card wraps any [`Widget`] together with the height it occupies in its column.
+pub struct Card<'a> {
+    render: RenderFn<'a>,
+    height: u16,
+}
+
+/// State of a [`CardGrid`] widget
+///
+/// This state keeps track of the vertical scroll offset of the grid. The offset is clamped when
+/// the grid is rendered, so that the bottom of the tallest column is never scrolled above the
+/// bottom of the area.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardGridState {
+    offset: u16,
+    max_scroll: u16,
+}
+
+impl Default for CardGrid<'_> {
+    fn default() -> Self {
+        Self {
+            cards: Vec::new(),
+            columns: 2,
+            column_spacing: 1,
+            row_spacing: 0,
+        }
+    }
+}
+
+impl<'a> CardGrid<'a> {
+    /// Creates a new empty `CardGrid` with two columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of columns.
+    ///
+    /// A grid has at least one column, so `0` is treated as `1`. Defaults to 2.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the space between the columns and between the cards of a column.
+    ///
+    /// Defaults to one cell between the columns and no space between the cards.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, column_spacing: u16, row_spacing: u16) -> Self {
+        self.column_spacing = column_spacing;
+        self.row_spacing = row_spacing;
+        self
+    }
+
+    /// Adds a widget as a new card with the given height.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn card<W: Widget + 'a>(self, widget: W, height: u16) -> Self {
+        self.push(Card::new(widget, height))
+    }
+
+    /// Adds a [`Card`] to the grid.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn push(mut self, card: Card<'a>) -> Self {
+        self.cards.push(card);
+        self
+    }
+
+    /// Returns the number of cards in the grid.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns `true` if the grid has no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the areas of the cards when the grid is laid out in the given area, without
+    /// scrolling, and the height of the content (the height of the tallest column).
+    ///
+    /// The areas are in the order the cards were added, and extend below the area when the
+    /// content is taller than it.
+    pub fn layout(&self, area: Rect) -> (Vec<Rect>, u16) {
+        let columns =
+            Layout::horizontal(vec![Constraint::Fill(1); usize::from(self.columns.max(1))])
+                .spacing(self.column_spacing)
+                .split(area);
+        let mut bottoms = vec![0_u16; columns.len()];
+        let areas = self
+            .cards
+            .iter()
+            .map(|card| {
+                let (index, top) = bottoms
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .min_by_key(|&(index, bottom)| (bottom, index))
+                    .unwrap_or_default();
+                let top = if top == 0 {
+                    0
+                } else {
+                    top.saturating_add(self.row_spacing)
+                };
+                bottoms[index] = top.saturating_add(card.height);
+                Rect {
+                    y: area.y.saturating_add(top),
+                    height: card.height,
+                    ..columns[index]
+                }
+            })
+            .collect();
+        (areas, bottoms.into_iter().max().unwrap_or_default())
+    }
+}
+
+impl<'a> FromIterator<Card<'a>> for CardGrid<'a> {
+    fn from_iter<T: IntoIterator<Item = Card<'a>>>(iter: T) -> Self {
+        Self {
+            cards: iter.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+}
+
+impl<'a> Card<'a> {
+    /// Creates a new card rendering the given widget with the given height.
+    pub fn new<W: Widget + 'a>(widget: W, height: u16) -> Self {
+        Self {
+            render: Box::new(move |area, buf| widget.render(area, buf)),
+            height,
+        }
+    }
+
+    /// Returns the height of the card.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Renders the card into the visible area, skipping the given number of rows at its top.
+    fn render(self, visible: Rect, hidden: u16, buf: &mut Buffer) {
+        if hidden == 0 && visible.height == self.height {
+            (self.render)(visible, buf);
+            return;
+        }
+        // render the whole card off-screen and copy the visible rows
+        let mut card_buf = Buffer::empty(Rect::new(0, 0, visible.width, self.height));
+        (self.render)(card_buf.area, &mut card_buf);
+        for position in visible.positions() {
+            let source = Position::new(position.x - visible.x, position.y - visible.y + hidden);
+            buf[position] = card_buf[source].clone();
+        }
+    }
+}
+
+impl fmt::Debug for Card<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Card")
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CardGridState {
+    /// Sets the number of rows the grid is scrolled by
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::CardGridState;
+    ///
+    /// let state = CardGridState::default().with_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: u16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Number of rows the grid is scrolled by
+    ///
+    /// The offset is clamped to [`CardGridState::max_scroll`] when the grid is rendered.
+    pub const fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// The largest offset at which the bottom of the tallest column is still visible
+    ///
+    /// This depends on the size of the area, so it is only known after the grid has been
+    /// rendered, and is `0` before.
+    pub const fn max_scroll(&self) -> u16 {
+        self.max_scroll
+    }
+
+    /// Scrolls up by the given number of rows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::CardGridState;
+    ///
+    /// let mut state = CardGridState::default();
+    /// state.scroll_up_by(4);
+    /// ```
+    pub fn scroll_up_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    /// Scrolls down by the given number of rows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::CardGridState;
+    ///
+    /// let mut state = CardGridState::default();
+    /// state.scroll_down_by(4);
+    /// ```
+    pub fn scroll_down_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+
+    /// Scrolls to the top of the grid
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scrolls to the bottom of the grid
+    ///
+    /// Note: until the grid is rendered, the height of the content is not known, so the offset is
+    /// set to `u16::MAX` and will be corrected when the grid is rendered
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = u16::MAX;
+    }
+}
+
+impl Widget for CardGrid<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = CardGridState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for CardGrid<'_> {
+    type State = CardGridState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        let (areas, content_height) = self.layout(area);
+        state.max_scroll = content_height.saturating_sub(area.height);
+        state.offset = state.offset.min(state.max_scroll);
+        if area.is_empty() {
+            return;
+        }
+        let (first_row, last_row) = (state.offset, state.offset.saturating_add(area.height));
+        for (card, card_area) in self.cards.into_iter().zip(areas) {
+            let top = card_area.y - area.y;
+            let bottom = top.saturating_add(card_area.height);
+            let (start, end) = (top.max(first_row), bottom.min(last_row));
+            if start >= end {
+                continue;
+            }
+            let visible = Rect {
+                y: area.y + (start - first_row),
+                height: end - start,
+                ..card_area
+            };
+            card.render(visible, start - top, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paragraph::Paragraph;
+
+    fn card(c: char, height: u16) -> Card<'static> {
+        let lines = (0..height)
+            .map(|row| format!("{c}{row}"))
+            .collect::<Vec<_>>();
+        Card::new(Paragraph::new(lines.join("\n")), height)
+    }
+
+    #[test]
+    fn layout_shortest_column_first() {
+        let grid = CardGrid::new()
+            .push(card('a', 3))
+            .push(card('b', 1))
+            .push(card('c', 1))
+            .push(card('d', 2));
+        let (areas, height) = grid.layout(Rect::new(0, 0, 9, 10));
+        assert_eq!(
+            areas,
+            [
+                Rect::new(0, 0, 4, 3),
+                Rect::new(5, 0, 4, 1),
+                Rect::new(5, 1, 4, 1),
+                Rect::new(5, 2, 4, 2),
+            ]
+        );
+        assert_eq!(height, 4);
+    }
+
+    #[test]
+    fn layout_with_spacing() {
+        let grid = CardGrid::new()
+            .columns(3)
+            .spacing(0, 1)
+            .push(card('a', 2))
+            .push(card('b', 1))
+            .push(card('c', 1))
+            .push(card('d', 1));
+        let (areas, height) = grid.layout(Rect::new(0, 0, 6, 10));
+        assert_eq!(areas[3], Rect::new(2, 2, 2, 1));
+        assert_eq!(height, 3);
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let grid = CardGrid::new()
+            .push(card('a', 3))
+            .push(card('b', 1))
+            .push(card('c', 1));
+        Widget::render(grid, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["a0 b0", "a1 c0", "a2   "]));
+    }
+
+    #[test]
+    fn render_scrolled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let grid = || {
+            CardGrid::new()
+                .push(card('a', 3))
+                .push(card('b', 1))
+                .push(card('c', 2))
+        };
+        let mut state = CardGridState::default().with_offset(1);
+        StatefulWidget::render(grid(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["a1 c0", "a2 c1"]));
+        assert_eq!(state.max_scroll(), 1);
+
+        state.scroll_to_bottom();
+        StatefulWidget::render(grid(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 1);
+
+        state.scroll_up_by(5);
+        StatefulWidget::render(grid(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["a0 b0", "a1 c0"]));
+    }
+
+    #[test]
+    fn from_iter() {
+        let grid: CardGrid = [card('a', 1), card('b', 2)].into_iter().collect();
+        assert_eq!(grid.len(), 2);
+        assert!(!grid.is_empty());
+    }
+}
@@ -1,4 +1,7 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
+//!
+//! Large datasets can be reduced to the width of the plot with the functions of the
+//! [`downsample`] module.
 use std::{cmp::max, ops::Not};
 
 use ratatui_core::{
@@ -13,9 +16,11 @@ use strum::{Display, EnumString};
 
 use crate::{
     block::{Block, BlockExt},
-    canvas::{Canvas, Line as CanvasLine, Points},
+    canvas::{Canvas, GridLines, Line as CanvasLine, Points},
 };
 
+pub mod downsample;
+
 /// An X or Y axis for the [`Chart`] widget
 ///
 /// An axis can have a [title](Axis::title) which will be displayed at the end of the axis. For an
@@ -528,6 +533,8 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// Gridlines drawn behind the datasets
+    grid: Option<GridLines>,
 }
 
 impl<'a> Chart<'a> {
@@ -567,6 +574,7 @@ impl<'a> Chart<'a> {
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            grid: None,
         }
     }
 
@@ -729,6 +737,30 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Draws gridlines in the plot area, behind the datasets.
+    ///
+    /// Gridlines make it easier to read values off dense plots. Their style, density and whether
+    /// they are dashed are configured with [`GridLines`]. To line them up with the labels of an
+    /// axis, use one division less than the number of labels.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{canvas::GridLines, Axis, Chart};
+    ///
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().labels(["0", "5", "10"]))
+    ///     .y_axis(Axis::default().labels(["0", "50", "100"]))
+    ///     .grid(GridLines::new().divisions(2, 2).dashed(true));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn grid(mut self, grid: GridLines) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
@@ -1019,6 +1051,10 @@ impl Widget for &Chart<'_> {
             }
         }
 
+        if let Some(grid) = &self.grid {
+            grid.render(graph_area, buf);
+        }
+
         for dataset in &self.datasets {
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
@@ -1544,4 +1580,20 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn grid_is_drawn_behind_datasets() {
+        let data = [(5.0, 5.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)])
+        .x_axis(Axis::default().bounds([0.0, 10.0]))
+        .y_axis(Axis::default().bounds([0.0, 10.0]))
+        .grid(GridLines::new().divisions(2, 2).style(Style::new()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["  │  ", "  │  ", "──•──", "  │  ", "  │  "]);
+        assert_eq!(buffer, expected);
+    }
 }
@@ -0,0 +1,188 @@
+//! Functions reducing the number of points of large datasets before they are plotted.
+//!
+//! A [`Chart`] can only show as many distinct points as there are cells (or dots, depending on the
+//! marker) along its X axis, but it still draws every point it is given. With very large series,
+//! reducing the data to a few points per column beforehand keeps rendering fast without visibly
+//! changing the plot.
+//!
+//! Both functions expect the points to be sorted by their X coordinate, and always keep the first
+//! and last points.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::widgets::{chart::downsample, Chart, Dataset};
+//!
+//! let data: Vec<(f64, f64)> = (0..1_000_000)
+//!     .map(|x| (f64::from(x), f64::from(x).sin()))
+//!     .collect();
+//! let plot_width = 200;
+//! let reduced = downsample::lttb(&data, plot_width * 2);
+//! assert_eq!(reduced.len(), 400);
+//! let chart = Chart::new(vec![Dataset::default().data(&reduced)]);
+//! ```
+//!
+//! [`Chart`]: crate::chart::Chart
+
+/// Reduces the data to `threshold` points with the Largest-Triangle-Three-Buckets algorithm.
+///
+/// The points between the first and the last ones are split into `threshold - 2` buckets, and the
+/// point of each bucket forming the largest triangle with the point selected in the previous
+/// bucket and the average of the next bucket is kept. This preserves the overall shape of the
+/// series, including its peaks, better than keeping every n-th point.
+///
+/// The data is returned unchanged if it has no more than `threshold` points, or if `threshold`
+/// is less than 3.
+///
+/// See [Downsampling Time Series for Visual Representation] for details.
+///
+/// [Downsampling Time Series for Visual Representation]: https://hdl.handle.net/1946/15343
+#[allow(clippy::cast_precision_loss)]
+pub fn lttb(data: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+    if threshold >= data.len() || threshold < 3 {
+        return data.to_vec();
+    }
+    let last = data.len() - 1;
+    let bucket = |i: usize| {
+        let start = 1 + i * (data.len() - 2) / (threshold - 2);
+        let end = 1 + (i + 1) * (data.len() - 2) / (threshold - 2);
+        start..end
+    };
+
+    let mut sampled = Vec::with_capacity(threshold);
+    let mut previous = data[0];
+    sampled.push(previous);
+    for i in 0..threshold - 2 {
+        let next = if i + 1 < threshold - 2 {
+            &data[bucket(i + 1)]
+        } else {
+            &data[last..]
+        };
+        let count = next.len() as f64;
+        let (sum_x, sum_y) = next
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        let average = (sum_x / count, sum_y / count);
+
+        let area = |&(x, y): &(f64, f64)| {
+            ((previous.0 - average.0) * (y - previous.1)
+                - (previous.0 - x) * (average.1 - previous.1))
+                .abs()
+        };
+        let selected = data[bucket(i)]
+            .iter()
+            .copied()
+            .max_by(|a, b| area(a).total_cmp(&area(b)))
+            .expect("buckets are not empty");
+        sampled.push(selected);
+        previous = selected;
+    }
+    sampled.push(data[last]);
+    sampled
+}
+
+/// Reduces the data to at most `2 * buckets` points by keeping the minimum and maximum of each
+/// bucket.
+///
+/// The data is split into `buckets` buckets of consecutive points, and the points with the
+/// lowest and highest Y coordinates of each bucket are kept, in their original order. Unlike
+/// [`lttb`], this keeps every extreme value, which is what matters for signals such as audio
+/// waveforms or latency measurements, at the cost of twice as many points.
+///
+/// The data is returned unchanged if it has no more than `2 * buckets` points, or if `buckets`
+/// is 0.
+pub fn min_max(data: &[(f64, f64)], buckets: usize) -> Vec<(f64, f64)> {
+    if buckets == 0 || data.len() <= buckets.saturating_mul(2) {
+        return data.to_vec();
+    }
+    let mut sampled = Vec::with_capacity(buckets * 2);
+    for i in 0..buckets {
+        let start = i * data.len() / buckets;
+        let end = (i + 1) * data.len() / buckets;
+        let bucket = data[start..end].iter().enumerate();
+        let (min, _) = bucket
+            .clone()
+            .min_by(|(_, a), (_, b)| a.1.total_cmp(&b.1))
+            .expect("buckets are not empty");
+        let (max, _) = bucket
+            .max_by(|(_, a), (_, b)| a.1.total_cmp(&b.1))
+            .expect("buckets are not empty");
+        let (first, second) = (min.min(max), min.max(max));
+        sampled.push(data[start + first]);
+        if second != first {
+            sampled.push(data[start + second]);
+        }
+    }
+    if sampled.first() != data.first() {
+        sampled.insert(0, data[0]);
+    }
+    if sampled.last() != data.last() {
+        sampled.push(data[data.len() - 1]);
+    }
+    sampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(len: u32) -> Vec<(f64, f64)> {
+        (0..len).map(|x| (f64::from(x), f64::from(x % 7))).collect()
+    }
+
+    #[test]
+    fn lttb_small_data_unchanged() {
+        let data = series(5);
+        assert_eq!(lttb(&data, 5), data);
+        assert_eq!(lttb(&data, 2), data);
+    }
+
+    #[test]
+    fn lttb_keeps_peak() {
+        let data = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (3.0, 9.0),
+            (4.0, 0.0),
+            (5.0, 0.0),
+            (6.0, 0.0),
+        ];
+        assert_eq!(lttb(&data, 3), [(0.0, 0.0), (3.0, 9.0), (6.0, 0.0)]);
+    }
+
+    #[test]
+    fn lttb_threshold() {
+        let data = series(1000);
+        let sampled = lttb(&data, 100);
+        assert_eq!(sampled.len(), 100);
+        assert_eq!(sampled.first(), data.first());
+        assert_eq!(sampled.last(), data.last());
+        assert!(sampled.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn min_max_keeps_extremes() {
+        let data = [
+            (0.0, 1.0),
+            (1.0, 5.0),
+            (2.0, -3.0),
+            (3.0, 2.0),
+            (4.0, 2.0),
+            (5.0, 8.0),
+            (6.0, 1.0),
+            (7.0, 0.0),
+        ];
+        assert_eq!(
+            min_max(&data, 2),
+            [(0.0, 1.0), (1.0, 5.0), (2.0, -3.0), (5.0, 8.0), (7.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn min_max_small_data_unchanged() {
+        let data = series(6);
+        assert_eq!(min_max(&data, 3), data);
+        assert_eq!(min_max(&data, 0), data);
+    }
+}
@@ -0,0 +1,457 @@
+//! The [`ChatView`] widget displays a conversation as message bubbles.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Margin, Position, Rect},
+    style::{Modifier, Style, Styled},
+    text::Line,
+    widgets::{StatefulWidget, Widget},
+};
+
+pub use self::{
+    history::ChatHistory,
+    message::{ChatMessage, ChatSide},
+    state::ChatViewState,
+};
+use crate::{
+    block::{Block, BlockExt},
+    paragraph::{Paragraph, Wrap},
+};
+
+mod history;
+mod message;
+mod state;
+
+/// A widget that displays a conversation as message bubbles, e.g. for chat clients.
+///
+/// The messages are taken from a [`ChatHistory`], a ring buffer stored in the application state.
+/// Each [`ChatMessage`] is shown in a bubble aligned on its [`ChatSide`], with its sender and
+/// timestamp above its content, which is wrapped to the width of the bubble. A day separator is
+/// shown before each message whose day differs from the day of the previous message.
+///
+/// Like a chat client, the conversation is anchored to the bottom of the area, and the
+/// [`ChatViewState`] follows the newest message until the user scrolls up.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::{ChatHistory, ChatMessage, ChatSide, ChatView, ChatViewState},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, history: &ChatHistory, state: &mut ChatViewState) {
+/// let view = ChatView::new(history)
+///     .bubble_style(ChatSide::Left, Style::new().on_dark_gray())
+///     .bubble_style(ChatSide::Right, Style::new().black().on_cyan());
+/// frame.render_stateful_widget(view, frame.area(), state);
+/// # }
+/// # let mut history = ChatHistory::default();
+/// history.push(
+///     ChatMessage::new(ChatSide::Left, "Are we still on for lunch?")
+///         .sender("Alice".magenta())
+///         .timestamp("12:04")
+///         .day("Today"),
+/// );
+/// history.push(ChatMessage::new(ChatSide::Right, "Yes, see you at one!").timestamp("12:05"));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ChatView<'a> {
+    history: &'a ChatHistory<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    left_style: Style,
+    right_style: Style,
+    timestamp_style: Style,
+    separator_style: Style,
+    max_width_percent: u16,
+    message_spacing: u16,
+}
+
+/// A row-aligned item of the conversation: a day separator or a message bubble.
+enum Entry<'h, 'a> {
+    Separator(&'h Line<'a>),
+    Message {
+        message: &'h ChatMessage<'a>,
+        header: Option<Line<'a>>,
+        width: u16,
+    },
+}
+
+impl<'a> ChatView<'a> {
+    /// Creates a new view of the given history.
+    pub const fn new(history: &'a ChatHistory<'a>) -> Self {
+        Self {
+            history,
+            block: None,
+            style: Style::new(),
+            left_style: Style::new(),
+            right_style: Style::new(),
+            timestamp_style: Style::new().add_modifier(Modifier::DIM),
+            separator_style: Style::new().add_modifier(Modifier::DIM),
+            max_width_percent: 75,
+            message_spacing: 1,
+        }
+    }
+
+    /// Wraps the view in a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the bubbles of the messages on the given side.
+    ///
+    /// The style of each message, set with [`ChatMessage::style`], is patched over it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bubble_style<S: Into<Style>>(mut self, side: ChatSide, style: S) -> Self {
+        match side {
+            ChatSide::Left => self.left_style = style.into(),
+            ChatSide::Right => self.right_style = style.into(),
+        }
+        self
+    }
+
+    /// Sets the style added to the timestamps.
+    ///
+    /// Defaults to dim.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn timestamp_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.timestamp_style = style.into();
+        self
+    }
+
+    /// Sets the style of the day separators.
+    ///
+    /// Defaults to dim.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.separator_style = style.into();
+        self
+    }
+
+    /// Sets the maximum width of a bubble, as a percentage of the width of the view.
+    ///
+    /// Values above 100 are treated as 100. Defaults to 75.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_width_percent(mut self, percent: u16) -> Self {
+        self.max_width_percent = percent;
+        self
+    }
+
+    /// Sets the number of empty rows between the messages and separators.
+    ///
+    /// Defaults to 1.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn message_spacing(mut self, spacing: u16) -> Self {
+        self.message_spacing = spacing;
+        self
+    }
+
+    /// Returns the entries of the conversation, with the width of the bubbles limited to
+    /// `max_width`.
+    fn entries(&self, max_width: u16) -> Vec<Entry<'_, 'a>> {
+        let mut entries = Vec::new();
+        let mut day = None;
+        for message in self.history {
+            if let Some(message_day) = &message.day {
+                if day != Some(message_day) {
+                    entries.push(Entry::Separator(message_day));
+                }
+                day = Some(message_day);
+            }
+            let header = message.header(self.timestamp_style);
+            let content_width = header
+                .iter()
+                .map(Line::width)
+                .chain(message.content.iter().map(Line::width))
+                .max()
+                .unwrap_or_default();
+            // one cell of padding on each side of the content
+            let width = u16::try_from(content_width)
+                .unwrap_or(u16::MAX)
+                .saturating_add(2)
+                .min(max_width);
+            entries.push(Entry::Message {
+                message,
+                header,
+                width,
+            });
+        }
+        entries
+    }
+
+    fn message_style(&self, message: &ChatMessage) -> Style {
+        let style = match message.side {
+            ChatSide::Left => self.left_style,
+            ChatSide::Right => self.right_style,
+        };
+        style.patch(message.style)
+    }
+}
+
+impl Entry<'_, '_> {
+    /// Returns the number of rows taken by the entry.
+    fn height(&self) -> u16 {
+        match self {
+            Self::Separator(_) => 1,
+            Self::Message {
+                message,
+                header,
+                width,
+            } => {
+                let content = Paragraph::new(message.content.clone()).wrap(Wrap { trim: false });
+                let lines = content.text_line_count(width.saturating_sub(2).max(1));
+                u16::try_from(lines)
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(u16::from(header.is_some()))
+            }
+        }
+    }
+
+    /// Renders the entry, with the given width, into `area`, of which only the rows after the
+    /// first `hidden` rows of the entry are visible.
+    fn render(&self, view: &ChatView, area: Rect, height: u16, hidden: u16, buf: &mut Buffer) {
+        if hidden == 0 && area.height == height {
+            self.render_unclipped(view, area, buf);
+            return;
+        }
+        // render the whole entry off-screen and copy the visible rows
+        let mut entry_buf = Buffer::empty(Rect::new(0, 0, area.width, height));
+        self.render_unclipped(view, entry_buf.area, &mut entry_buf);
+        for position in area.positions() {
+            let source = Position::new(position.x - area.x, position.y - area.y + hidden);
+            buf[position] = entry_buf[source].clone();
+        }
+    }
+
+    fn render_unclipped(&self, view: &ChatView, area: Rect, buf: &mut Buffer) {
+        match self {
+            Self::Separator(day) => {
+                buf.set_style(area, view.separator_style);
+                for position in area.positions() {
+                    buf[position].set_symbol("─");
+                }
+                let label_width = u16::try_from(day.width())
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(2)
+                    .min(area.width);
+                let label_area = Rect {
+                    x: area.x + (area.width - label_width) / 2,
+                    width: label_width,
+                    ..area
+                };
+                buf.set_string(
+                    label_area.x,
+                    label_area.y,
+                    " ".repeat(label_width.into()),
+                    Style::new(),
+                );
+                let label_area = label_area.inner(Margin::new(1, 0));
+                buf.set_line(label_area.x, label_area.y, day, label_area.width);
+            }
+            Self::Message {
+                message, header, ..
+            } => {
+                buf.set_style(area, view.message_style(message));
+                let mut inner = area.inner(Margin::new(1, 0));
+                if let Some(header) = header {
+                    buf.set_line(inner.x, inner.y, header, inner.width);
+                    inner.y += 1;
+                    inner.height = inner.height.saturating_sub(1);
+                }
+                let content = Paragraph::new(message.content.clone()).wrap(Wrap { trim: false });
+                Widget::render(content, inner, buf);
+            }
+        }
+    }
+}
+
+impl Widget for ChatView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &ChatView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ChatViewState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for ChatView<'_> {
+    type State = ChatViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &ChatView<'_> {
+    type State = ChatViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            state.update(0);
+            return;
+        }
+
+        let max_width = u32::from(inner.width) * u32::from(self.max_width_percent.min(100)) / 100;
+        let max_width = u16::try_from(max_width).unwrap_or(inner.width).max(1);
+        let entries = self.entries(max_width);
+        let heights = entries.iter().map(Entry::height).collect::<Vec<_>>();
+        let spacing = self.message_spacing;
+        let content_height = heights
+            .iter()
+            .fold(0_u16, |total, height| total.saturating_add(*height))
+            .saturating_add(spacing.saturating_mul(
+                u16::try_from(heights.len().saturating_sub(1)).unwrap_or(u16::MAX),
+            ));
+        state.update(content_height.saturating_sub(inner.height));
+
+        // anchor the conversation to the bottom of the area when it is shorter than the area
+        let first_row = state.offset;
+        let last_row = first_row.saturating_add(inner.height);
+        let mut top = 0_u16;
+        let padding = inner.height.saturating_sub(content_height);
+        for (entry, height) in entries.iter().zip(heights) {
+            let bottom = top.saturating_add(height);
+            let (start, end) = (top.max(first_row), bottom.min(last_row));
+            if start < end {
+                let width = match entry {
+                    Entry::Separator(_) => inner.width,
+                    Entry::Message { width, .. } => *width,
+                };
+                let x = match entry {
+                    Entry::Message { message, .. } if message.side == ChatSide::Right => {
+                        inner.right() - width
+                    }
+                    _ => inner.x,
+                };
+                let visible = Rect::new(
+                    x,
+                    inner.y + padding + (start - first_row),
+                    width,
+                    end - start,
+                );
+                entry.render(self, visible, height, start - top, buf);
+            }
+            top = bottom.saturating_add(spacing);
+        }
+    }
+}
+
+impl Styled for ChatView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    fn history() -> ChatHistory<'static> {
+        let mut history = ChatHistory::default();
+        history.push(
+            ChatMessage::new(ChatSide::Left, "hi there")
+                .sender("ann")
+                .day("Mon"),
+        );
+        history.push(ChatMessage::new(ChatSide::Right, "hello").day("Mon"));
+        history.push(ChatMessage::new(ChatSide::Right, "bye").day("Tue"));
+        history
+    }
+
+    fn view<'a>(history: &'a ChatHistory<'a>) -> ChatView<'a> {
+        ChatView::new(history)
+            .timestamp_style(Style::new())
+            .separator_style(Style::new())
+            .message_spacing(0)
+    }
+
+    #[test]
+    fn render() {
+        let history = history();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 9));
+        Widget::render(view(&history), buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "            ",
+                "            ",
+                "─── Mon ────",
+                " ann        ",
+                " hi         ",
+                " there      ",
+                "      hello ",
+                "─── Tue ────",
+                "        bye ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_scrolled() {
+        let history = history();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        let mut state = ChatViewState::default();
+        StatefulWidget::render(view(&history), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["─── Tue ────", "        bye "]));
+        assert_eq!((state.offset(), state.max_scroll()), (5, 5));
+
+        // the bubble of the first message is clipped at the top
+        state.scroll_up_by(3);
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(view(&history), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([" hi         ", " there      "]));
+        assert!(!state.is_following());
+    }
+
+    #[test]
+    fn render_styles() {
+        let mut history = ChatHistory::default();
+        history.push(
+            ChatMessage::new(ChatSide::Left, "a")
+                .timestamp("1")
+                .style(Style::new().bold()),
+        );
+        history.push(ChatMessage::new(ChatSide::Right, "b"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let view = ChatView::new(&history)
+            .bubble_style(ChatSide::Left, Style::new().red())
+            .bubble_style(ChatSide::Right, Style::new().blue());
+        Widget::render(view, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" 1    ", " a    ", "      ", "    b "]);
+        expected.set_style(Rect::new(0, 0, 3, 2), Style::new().red().bold());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().dim());
+        expected.set_style(Rect::new(3, 3, 3, 1), Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+}
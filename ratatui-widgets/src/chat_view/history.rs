@@ -0,0 +1,133 @@
+use std::collections::{vec_deque, VecDeque};
+
+use crate::chat_view::ChatMessage;
+
+/// The messages of a [`ChatView`], kept in a ring buffer.
+///
+/// The history holds at most [`capacity`](ChatHistory::capacity) messages: pushing a message
+/// into a full history drops the oldest one, so long conversations use a bounded amount of memory.
+/// The history is stored in the application state and borrowed by the [`ChatView`] when it is
+/// rendered.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{ChatHistory, ChatMessage, ChatSide};
+///
+/// let mut history = ChatHistory::new(2);
+/// history.push(ChatMessage::new(ChatSide::Left, "one"));
+/// history.push(ChatMessage::new(ChatSide::Right, "two"));
+/// history.push(ChatMessage::new(ChatSide::Left, "three"));
+/// assert_eq!(history.len(), 2);
+/// ```
+///
+/// [`ChatView`]: crate::chat_view::ChatView
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ChatHistory<'a> {
+    messages: VecDeque<ChatMessage<'a>>,
+    capacity: usize,
+}
+
+impl Default for ChatHistory<'_> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<'a> ChatHistory<'a> {
+    /// The capacity of a default history.
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    /// Creates an empty history that holds at most `capacity` messages.
+    ///
+    /// A history holds at least one message, so `0` is treated as `1`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            messages: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the maximum number of messages of the history.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Appends a message, dropping the oldest message if the history is full.
+    pub fn push(&mut self, message: ChatMessage<'a>) {
+        if self.messages.len() == self.capacity {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(message);
+    }
+
+    /// Returns the number of messages in the history.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns `true` if the history has no messages.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Removes all the messages.
+    pub fn clear(&mut self) {
+        self.messages.clear();
+    }
+
+    /// Returns the last message, e.g. to update it while it is being received.
+    pub fn last_mut(&mut self) -> Option<&mut ChatMessage<'a>> {
+        self.messages.back_mut()
+    }
+
+    /// Returns an iterator over the messages, from the oldest to the newest.
+    pub fn iter(&self) -> vec_deque::Iter<'_, ChatMessage<'a>> {
+        self.messages.iter()
+    }
+}
+
+impl<'a> Extend<ChatMessage<'a>> for ChatHistory<'a> {
+    fn extend<T: IntoIterator<Item = ChatMessage<'a>>>(&mut self, iter: T) {
+        for message in iter {
+            self.push(message);
+        }
+    }
+}
+
+impl<'a, 'h> IntoIterator for &'h ChatHistory<'a> {
+    type Item = &'h ChatMessage<'a>;
+    type IntoIter = vec_deque::Iter<'h, ChatMessage<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat_view::ChatSide;
+
+    #[test]
+    fn push_drops_oldest() {
+        let mut history = ChatHistory::new(2);
+        history.extend(["a", "b", "c"].map(|text| ChatMessage::new(ChatSide::Left, text)));
+        let texts = history
+            .iter()
+            .map(|message| message.content().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, ["b", "c"]);
+    }
+
+    #[test]
+    fn capacity_is_at_least_one() {
+        let mut history = ChatHistory::new(0);
+        assert_eq!(history.capacity(), 1);
+        history.push(ChatMessage::new(ChatSide::Left, "a"));
+        history.push(ChatMessage::new(ChatSide::Left, "b"));
+        assert_eq!(history.len(), 1);
+        history.clear();
+        assert!(history.is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+use ratatui_core::{
+    style::{Style, Styled},
+    text::{Line, Text},
+};
+use strum::{Display, EnumString};
+
+/// The side of a [`ChatView`] on which a message bubble is aligned.
+///
+/// By convention, the messages of the other participants are on the left and the messages of the
+/// user are on the right.
+///
+/// [`ChatView`]: crate::chat_view::ChatView
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatSide {
+    /// The bubble is aligned to the left edge of the view.
+    #[default]
+    Left,
+    /// The bubble is aligned to the right edge of the view.
+    Right,
+}
+
+/// A message of a [`ChatView`].
+///
+/// A message has some content, which is wrapped to the width of its bubble, and optionally the
+/// name of its sender and a timestamp, which are shown above the content. The sender is a
+/// [`Line`], so each sender can have its own style.
+///
+/// The timestamp is shown as given, so it can use any format. Similarly, the day of the message
+/// is a label such as `"Today"` or `"Monday, 3 June"`: a day separator is shown before each
+/// message whose day differs from the day of the previous message.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::Stylize,
+///     widgets::{ChatMessage, ChatSide},
+/// };
+///
+/// let message = ChatMessage::new(ChatSide::Left, "Are we still on for lunch?")
+///     .sender("Alice".magenta().bold())
+///     .timestamp("12:04")
+///     .day("Today");
+/// ```
+///
+/// [`ChatView`]: crate::chat_view::ChatView
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ChatMessage<'a> {
+    pub(crate) side: ChatSide,
+    pub(crate) content: Text<'a>,
+    pub(crate) sender: Option<Line<'a>>,
+    pub(crate) timestamp: Option<Line<'a>>,
+    pub(crate) day: Option<Line<'a>>,
+    pub(crate) style: Style,
+}
+
+impl<'a> ChatMessage<'a> {
+    /// Creates a new message with the given content, aligned on the given side.
+    pub fn new<T: Into<Text<'a>>>(side: ChatSide, content: T) -> Self {
+        Self {
+            side,
+            content: content.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the sender shown above the content.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sender<T: Into<Line<'a>>>(mut self, sender: T) -> Self {
+        self.sender = Some(sender.into());
+        self
+    }
+
+    /// Sets the timestamp shown above the content, after the sender.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn timestamp<T: Into<Line<'a>>>(mut self, timestamp: T) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets the label of the day of the message, shown in a separator when the day changes.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn day<T: Into<Line<'a>>>(mut self, day: T) -> Self {
+        self.day = Some(day.into());
+        self
+    }
+
+    /// Sets the style of the bubble of the message.
+    ///
+    /// The style is patched over the style of the side of the message set with
+    /// [`ChatView::bubble_style`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`ChatView::bubble_style`]: crate::chat_view::ChatView::bubble_style
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the side on which the message is aligned.
+    pub const fn side(&self) -> ChatSide {
+        self.side
+    }
+
+    /// Returns the content of the message.
+    pub const fn content(&self) -> &Text<'a> {
+        &self.content
+    }
+
+    /// Returns the line shown above the content: the sender and the timestamp, if any.
+    pub(crate) fn header(&self, timestamp_style: Style) -> Option<Line<'a>> {
+        let timestamp = self
+            .timestamp
+            .clone()
+            .map(|timestamp| timestamp.patch_style(timestamp_style));
+        match (self.sender.clone(), timestamp) {
+            (Some(mut sender), Some(timestamp)) => {
+                sender.push_span(" ");
+                sender.spans.extend(timestamp.spans);
+                Some(sender)
+            }
+            (sender, timestamp) => sender.or(timestamp),
+        }
+    }
+}
+
+impl Styled for ChatMessage<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
@@ -0,0 +1,118 @@
+/// State of a [`ChatView`] widget
+///
+/// This state keeps track of the vertical scroll offset of the conversation. By default, the
+/// state follows the bottom of the conversation: the view is scrolled to the newest message every
+/// time it is rendered. Scrolling up stops following, and scrolling back down to the bottom
+/// follows again, so that new messages stay visible without overriding the user while they read
+/// older ones.
+///
+/// [`ChatView`]: super::ChatView
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatViewState {
+    pub(crate) offset: u16,
+    pub(crate) follow: bool,
+    pub(crate) max_scroll: u16,
+}
+
+impl Default for ChatViewState {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            follow: true,
+            max_scroll: 0,
+        }
+    }
+}
+
+impl ChatViewState {
+    /// Index of the first row of the conversation to be displayed
+    ///
+    /// The offset is clamped to [`ChatViewState::max_scroll`] when the view is rendered.
+    pub const fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// Returns whether the view follows the bottom of the conversation
+    pub const fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// The largest offset, at which the newest message is at the bottom of the view
+    ///
+    /// This is only known after the view has been rendered, and is `0` before.
+    pub const fn max_scroll(&self) -> u16 {
+        self.max_scroll
+    }
+
+    /// Scrolls up by the given number of rows
+    ///
+    /// This stops following the bottom of the conversation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ChatViewState;
+    ///
+    /// let mut state = ChatViewState::default();
+    /// state.scroll_up_by(4);
+    /// assert!(!state.is_following());
+    /// ```
+    pub fn scroll_up_by(&mut self, amount: u16) {
+        if amount > 0 {
+            self.offset = self.offset.saturating_sub(amount);
+            self.follow = false;
+        }
+    }
+
+    /// Scrolls down by the given number of rows
+    ///
+    /// Reaching the bottom of the conversation follows it again when the view is rendered.
+    pub fn scroll_down_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+
+    /// Scrolls to the bottom of the conversation and follows it
+    pub fn scroll_to_bottom(&mut self) {
+        self.follow = true;
+    }
+
+    /// Updates the scroll bounds after the view is laid out
+    pub(crate) fn update(&mut self, max_scroll: u16) {
+        self.max_scroll = max_scroll;
+        if self.offset >= max_scroll {
+            self.follow = true;
+        }
+        if self.follow {
+            self.offset = max_scroll;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_by_default() {
+        let mut state = ChatViewState::default();
+        state.update(10);
+        assert_eq!(state.offset(), 10);
+        assert!(state.is_following());
+    }
+
+    #[test]
+    fn scrolling_back_to_bottom_follows_again() {
+        let mut state = ChatViewState::default();
+        state.update(10);
+        state.scroll_up_by(3);
+        state.update(12);
+        assert_eq!(state.offset(), 7);
+        assert!(!state.is_following());
+
+        state.scroll_down_by(20);
+        state.update(12);
+        assert_eq!(state.offset(), 12);
+        assert!(state.is_following());
+    }
+}
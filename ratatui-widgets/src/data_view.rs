@@ -0,0 +1,541 @@
+//! The [`DataView`] widget displays a JSON or YAML value as a collapsible tree.
+use std::collections::HashSet;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    text::{Line, Span, Text},
+    widgets::{StatefulWidget, Widget},
+};
+use serde_json::Value;
+
+pub use self::state::DataViewState;
+use crate::{
+    block::{Block, BlockExt},
+    search_bar::SearchState,
+};
+
+mod state;
+
+/// A widget that displays a [`serde_json::Value`] as a collapsible, syntax-colored tree, e.g. to
+/// inspect the response of an API or a configuration file.
+///
+/// Each line of the tree shows a key (or an index in an array) and its value. Objects and arrays
+/// show the number of their children and can be collapsed and expanded with the
+/// [`DataViewState`], which also keeps the selected line. The [path](DataViewState::selected_path)
+/// of the selected value, e.g. `$.servers[0].port`, can be shown on the last line of the area
+/// with [`DataView::show_path`].
+///
+/// YAML documents can be displayed by deserializing them into a [`serde_json::Value`], e.g. with
+/// `serde_yaml::from_str::<serde_json::Value>`.
+///
+/// The keys and values can be searched with a [`SearchState`]: pass it to
+/// [`DataViewState::search`] to find the matches and select the first one, and to
+/// [`DataView::search`] to highlight them.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{DataView, DataViewState},
+///     Frame,
+/// };
+/// use serde_json::json;
+///
+/// # fn draw(frame: &mut Frame, state: &mut DataViewState) {
+/// let value = json!({
+///     "name": "ratatui",
+///     "keywords": ["tui", "terminal"],
+///     "license": null,
+/// });
+/// let data_view = DataView::new(&value).show_path(true);
+/// frame.render_stateful_widget(data_view, frame.area(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DataView<'a> {
+    value: &'a Value,
+    search: Option<&'a SearchState>,
+    block: Option<Block<'a>>,
+    style: Style,
+    key_style: Style,
+    string_style: Style,
+    number_style: Style,
+    bool_style: Style,
+    null_style: Style,
+    highlight_style: Style,
+    match_style: Style,
+    path_style: Style,
+    indent: u16,
+    show_path: bool,
+}
+
+impl<'a> DataView<'a> {
+    /// Creates a new `DataView` displaying the given value.
+    pub const fn new(value: &'a Value) -> Self {
+        Self {
+            value,
+            search: None,
+            block: None,
+            style: Style::new(),
+            key_style: Style::new().fg(Color::Blue),
+            string_style: Style::new().fg(Color::Green),
+            number_style: Style::new().fg(Color::Cyan),
+            bool_style: Style::new().fg(Color::Yellow),
+            null_style: Style::new().fg(Color::DarkGray),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            match_style: Style::new().fg(Color::Black).bg(Color::Yellow),
+            path_style: Style::new().add_modifier(Modifier::DIM),
+            indent: 2,
+            show_path: false,
+        }
+    }
+
+    /// Highlights the matches of the query of the given [`SearchState`].
+    ///
+    /// See [`DataViewState::search`] to find the matches and select them.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn search(mut self, search: &'a SearchState) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Wraps the tree with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the keys and of the indices of the arrays.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    /// Sets the style of the strings.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn string_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.string_style = style.into();
+        self
+    }
+
+    /// Sets the style of the numbers.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn number_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.number_style = style.into();
+        self
+    }
+
+    /// Sets the style of `true` and `false`.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bool_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.bool_style = style.into();
+        self
+    }
+
+    /// Sets the style of `null`.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn null_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.null_style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected line.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style patched onto the matches of the [search](DataView::search).
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.match_style = style.into();
+        self
+    }
+
+    /// Sets the style of the path of the selected value.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn path_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.path_style = style.into();
+        self
+    }
+
+    /// Sets the number of columns each level of the tree is indented by.
+    ///
+    /// The default indentation is `2`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Shows the path of the selected value, e.g. `$.servers[0].port`, on the last line of the
+    /// area.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_path(mut self, show_path: bool) -> Self {
+        self.show_path = show_path;
+        self
+    }
+
+    /// Returns the content of a line of the tree, without the indentation.
+    fn content(&self, node: &Node) -> Line<'static> {
+        let mut spans = Vec::new();
+        match node.key {
+            Some(Key::Name(name)) => spans.push(Span::styled(name.to_string(), self.key_style)),
+            Some(Key::Index(index)) => spans.push(Span::styled(index.to_string(), self.key_style)),
+            None => {}
+        }
+        if node.key.is_some() {
+            spans.push(Span::raw(": "));
+        }
+        spans.push(match node.value {
+            Value::Null => Span::styled("null", self.null_style),
+            Value::Bool(value) => Span::styled(value.to_string(), self.bool_style),
+            Value::Number(number) => Span::styled(number.to_string(), self.number_style),
+            Value::String(_) => Span::styled(node.value.to_string(), self.string_style),
+            Value::Array(values) => Span::raw(format!("[{}]", values.len())),
+            Value::Object(values) => Span::raw(format!("{{{}}}", values.len())),
+        });
+        Line::from(spans)
+    }
+}
+
+impl Styled for DataView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for DataView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &DataView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = DataViewState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for DataView<'_> {
+    type State = DataViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &DataView<'_> {
+    type State = DataViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        let nodes = nodes(self.value, Some(&state.collapsed));
+        state.paths = nodes.iter().map(|node| node.path.clone()).collect();
+        state.selected = state.selected.map(|index| index.min(nodes.len() - 1));
+        if area.is_empty() {
+            return;
+        }
+
+        let (path_area, area) = if self.show_path {
+            area.split_bottom(1)
+        } else {
+            (Rect::ZERO, area)
+        };
+        let height = usize::from(area.height);
+        if let Some(selected) = state.selected {
+            state.offset = state
+                .offset
+                .min(selected)
+                .max((selected + 1).saturating_sub(height));
+        }
+        state.offset = state.offset.min(nodes.len().saturating_sub(height));
+
+        for (index, node) in nodes.iter().enumerate().skip(state.offset).take(height) {
+            let y = area.y + u16::try_from(index - state.offset).unwrap_or(u16::MAX);
+            let row = Rect::new(area.x, y, area.width, 1);
+            let indent = u16::try_from(node.depth)
+                .unwrap_or(u16::MAX)
+                .saturating_mul(self.indent);
+            let (marker_area, content_area) = row.split_left(indent.saturating_add(2));
+            let marker = match node.value {
+                Value::Array(values) if !values.is_empty() => Some(&node.path),
+                Value::Object(values) if !values.is_empty() => Some(&node.path),
+                _ => None,
+            }
+            .map(|path| {
+                if state.collapsed.contains(path) {
+                    "▸"
+                } else {
+                    "▾"
+                }
+            });
+            if let Some(marker) = marker {
+                let (_, marker_area) = marker_area.split_left(indent);
+                Span::raw(marker).render(marker_area, buf);
+            }
+            let content = self.content(node);
+            match self.search {
+                Some(search) => search
+                    .highlight(&Text::from(content), self.match_style)
+                    .render(content_area, buf),
+                None => content.render(content_area, buf),
+            }
+            if state.selected == Some(index) {
+                buf.set_style(row, self.highlight_style);
+            }
+        }
+
+        if let Some(path) = state.selected_path() {
+            Span::styled(path, self.path_style).render(path_area, buf);
+        }
+    }
+}
+
+/// A line of the tree: a value and the key or index it is found at.
+#[derive(Debug)]
+struct Node<'v> {
+    path: String,
+    depth: usize,
+    key: Option<Key<'v>>,
+    value: &'v Value,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Key<'v> {
+    Name(&'v str),
+    Index(usize),
+}
+
+/// Returns the lines of the tree, skipping the children of the collapsed values.
+///
+/// All the lines are returned when `collapsed` is `None`.
+fn nodes<'v>(value: &'v Value, collapsed: Option<&HashSet<String>>) -> Vec<Node<'v>> {
+    let mut nodes = Vec::new();
+    push_nodes(&mut nodes, "$", 0, None, value, collapsed);
+    nodes
+}
+
+fn push_nodes<'v>(
+    nodes: &mut Vec<Node<'v>>,
+    path: &str,
+    depth: usize,
+    key: Option<Key<'v>>,
+    value: &'v Value,
+    collapsed: Option<&HashSet<String>>,
+) {
+    let is_collapsed = collapsed.is_some_and(|collapsed| collapsed.contains(path));
+    nodes.push(Node {
+        path: path.to_string(),
+        depth,
+        key,
+        value,
+    });
+    if is_collapsed {
+        return;
+    }
+    match value {
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let path = format!("{path}[{index}]");
+                let key = Some(Key::Index(index));
+                push_nodes(nodes, &path, depth + 1, key, value, collapsed);
+            }
+        }
+        Value::Object(values) => {
+            for (name, value) in values {
+                let path = child_path(path, name);
+                let key = Some(Key::Name(name));
+                push_nodes(nodes, &path, depth + 1, key, value, collapsed);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the path of a member of an object, e.g. `$.name` or `$["first name"]`.
+fn child_path(parent: &str, name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        format!("{parent}.{name}")
+    } else {
+        format!("{parent}[{}]", Value::from(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+    use serde_json::json;
+
+    use super::*;
+
+    fn plain(value: &Value) -> DataView<'_> {
+        DataView::new(value)
+            .key_style(Style::new())
+            .string_style(Style::new())
+            .number_style(Style::new())
+            .bool_style(Style::new())
+            .null_style(Style::new())
+            .highlight_style(Style::new())
+    }
+
+    fn render(data_view: &DataView, state: &mut DataViewState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(data_view, buf.area, &mut buf, state);
+        buf
+    }
+
+    fn value() -> Value {
+        json!({
+            "name": "ratatui",
+            "tags": ["tui", 1.5],
+            "first name": null,
+            "empty": {},
+            "ok": true,
+        })
+    }
+
+    #[test]
+    fn render_tree() {
+        let value = value();
+        let mut state = DataViewState::new();
+        assert_eq!(
+            render(&plain(&value), &mut state, 20, 9),
+            Buffer::with_lines([
+                "▾ {5}               ",
+                "    empty: {0}      ",
+                "    first name: null",
+                "    name: \"ratatui\" ",
+                "    ok: true        ",
+                "  ▾ tags: [2]       ",
+                "      0: \"tui\"      ",
+                "      1: 1.5        ",
+                "                    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn collapse_and_path() {
+        let value = value();
+        let data_view = plain(&value).show_path(true).path_style(Style::new());
+        let mut state = DataViewState::new().with_selected(Some(5));
+        render(&data_view, &mut state, 20, 4);
+        assert_eq!(state.selected_path(), Some("$.tags"));
+
+        state.toggle_selected();
+        assert!(state.is_collapsed("$.tags"));
+        assert_eq!(
+            render(&data_view, &mut state, 20, 4),
+            Buffer::with_lines([
+                "    name: \"ratatui\" ",
+                "    ok: true        ",
+                "  ▸ tags: [2]       ",
+                "$.tags              ",
+            ])
+        );
+        state.select_last();
+        render(&data_view, &mut state, 20, 4);
+        assert_eq!(state.selected(), Some(5));
+        state.select(Some(2));
+        render(&data_view, &mut state, 20, 4);
+        assert_eq!(state.selected_path(), Some("$[\"first name\"]"));
+    }
+
+    #[test]
+    fn render_styles() {
+        let value = json!({"a": [true, null]});
+        let data_view = DataView::new(&value).indent(1);
+        let mut state = DataViewState::new().with_selected(Some(1));
+        let mut expected =
+            Buffer::with_lines(["▾ {1}      ", " ▾ a: [2]  ", "    0: true", "    1: null"]);
+        expected.set_style(Rect::new(3, 1, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 1, 11, 1), Style::new().reversed());
+        expected.set_style(Rect::new(4, 2, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(7, 2, 4, 1), Style::new().yellow());
+        expected.set_style(Rect::new(4, 3, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(7, 3, 4, 1), Style::new().dark_gray());
+        assert_eq!(render(&data_view, &mut state, 11, 4), expected);
+    }
+
+    #[test]
+    fn search() {
+        let value = value();
+        let mut search = SearchState::new().with_query("tui");
+        let mut state = DataViewState::new();
+        state.collapse("$.tags");
+        state.search(&value, &mut search);
+        assert_eq!(search.match_count(), 2);
+        render(&plain(&value), &mut state, 20, 3);
+        assert_eq!(state.selected_path(), Some("$.name"));
+
+        // selecting the match inside the collapsed array expands it
+        search.next_match();
+        state.select_match(&value, &search);
+        assert!(!state.is_collapsed("$.tags"));
+        let data_view = plain(&value)
+            .search(&search)
+            .match_style(Style::new().bold());
+        let mut expected = Buffer::with_lines([
+            "    ok: true        ",
+            "  ▾ tags: [2]       ",
+            "      0: \"tui\"      ",
+        ]);
+        expected.set_style(Rect::new(10, 2, 3, 1), Style::new().bold());
+        assert_eq!(render(&data_view, &mut state, 20, 3), expected);
+        assert_eq!(state.selected_path(), Some("$.tags[0]"));
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::{nodes, DataView};
+use crate::search_bar::SearchState;
+
+/// State of the [`DataView`] widget.
+///
+/// The state keeps the selected line, the index of the first line to be displayed and the paths
+/// of the collapsed values, e.g. `$.servers[0]`. All the values are expanded by default. When the
+/// tree is rendered, the selected line is kept in view, and the paths of the displayed lines are
+/// stored so that [`selected_path`](Self::selected_path) and
+/// [`toggle_selected`](Self::toggle_selected) work without the value.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::DataViewState;
+///
+/// let mut state = DataViewState::new().with_selected(Some(0));
+/// state.collapse("$.dependencies");
+/// state.select_next();
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct DataViewState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<usize>,
+    pub(crate) collapsed: HashSet<String>,
+    pub(crate) paths: Vec<String>,
+}
+
+impl DataViewState {
+    /// Creates a new `DataViewState` with no selected line and all the values expanded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the index of the selected line.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Returns the index of the first line to be displayed.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the selected line, or `None` if no line is selected.
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Sets the index of the selected line.
+    ///
+    /// Set to `None` if no line is selected. This will also reset the offset to `0`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        if index.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Selects the next line or the first one if no line is selected.
+    ///
+    /// The index is corrected when the tree is rendered if it is out of range.
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        self.select(Some(next));
+    }
+
+    /// Selects the previous line or the last one if no line is selected.
+    ///
+    /// The index is corrected when the tree is rendered if it is out of range.
+    pub fn select_previous(&mut self) {
+        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select(Some(previous));
+    }
+
+    /// Selects the first line.
+    pub fn select_first(&mut self) {
+        self.select(Some(0));
+    }
+
+    /// Selects the last line.
+    ///
+    /// The index is corrected when the tree is rendered.
+    pub fn select_last(&mut self) {
+        self.select(Some(usize::MAX));
+    }
+
+    /// Returns the path of the selected value, e.g. `$.servers[0].port`, as of the last render.
+    pub fn selected_path(&self) -> Option<&str> {
+        self.selected
+            .and_then(|index| self.paths.get(index))
+            .map(String::as_str)
+    }
+
+    /// Returns whether the value at the given path is collapsed.
+    pub fn is_collapsed(&self, path: &str) -> bool {
+        self.collapsed.contains(path)
+    }
+
+    /// Collapses the value at the given path, hiding its children.
+    pub fn collapse<T: Into<String>>(&mut self, path: T) {
+        self.collapsed.insert(path.into());
+    }
+
+    /// Expands the value at the given path, showing its children.
+    pub fn expand(&mut self, path: &str) {
+        self.collapsed.remove(path);
+    }
+
+    /// Collapses the value at the given path if it is expanded, and expands it otherwise.
+    pub fn toggle(&mut self, path: &str) {
+        if !self.collapsed.remove(path) {
+            self.collapsed.insert(path.to_string());
+        }
+    }
+
+    /// Collapses or expands the selected value.
+    pub fn toggle_selected(&mut self) {
+        if let Some(path) = self.selected_path().map(str::to_string) {
+            self.toggle(&path);
+        }
+    }
+
+    /// Expands all the values.
+    pub fn expand_all(&mut self) {
+        self.collapsed.clear();
+    }
+
+    /// Finds the matches of the query of `search` in the keys and values of `value`, including
+    /// the collapsed ones, and selects the current match.
+    ///
+    /// Each line of the tree is searched as it is displayed, e.g. `port: 8080` or
+    /// `name: "ratatui"`.
+    pub fn search(&mut self, value: &Value, search: &mut SearchState) {
+        let data_view = DataView::new(value);
+        let contents = nodes(value, None)
+            .iter()
+            .map(|node| data_view.content(node).to_string())
+            .collect::<Vec<_>>();
+        search.search(contents.iter().map(String::as_str));
+        self.select_match(value, search);
+    }
+
+    /// Selects the line of the current match of `search`, expanding the values that contain it.
+    ///
+    /// Call this after [`SearchState::next_match`] and [`SearchState::previous_match`]. The
+    /// matches must have been found with [`DataViewState::search`].
+    pub fn select_match(&mut self, value: &Value, search: &SearchState) {
+        let Some(found) = search.current_match() else {
+            return;
+        };
+        let all_nodes = nodes(value, None);
+        let Some(path) = all_nodes.get(found.line).map(|node| node.path.as_str()) else {
+            return;
+        };
+        // the paths of the parents are prefixes of the path, followed by a member or an index
+        self.collapsed.retain(|parent| {
+            !path
+                .strip_prefix(parent.as_str())
+                .is_some_and(|rest| rest.starts_with(['.', '[']))
+        });
+        self.selected = nodes(value, Some(&self.collapsed))
+            .iter()
+            .position(|node| node.path == path);
+    }
+}
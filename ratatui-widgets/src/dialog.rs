@@ -0,0 +1,192 @@
+//! The [`ConfirmDialog`] and [`PromptDialog`] widgets ask the user to confirm an action or to
+//! enter a value.
+//!
+//! Both dialogs are rendered as a bordered popup centered in the given area, on top of what was
+//! rendered before. They show a message and two buttons, and [`PromptDialog`] adds an input field.
+//! The focus, the entered value and the position of the buttons are kept in the [`ConfirmState`]
+//! and [`PromptState`], which the application updates from its input events and which turn a key
+//! press or a click into a [`DialogResult`].
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::Widget,
+};
+
+pub(crate) use self::prompt::render_value;
+pub use self::{
+    confirm::{ConfirmDialog, ConfirmState},
+    prompt::{PromptDialog, PromptState},
+};
+use crate::{
+    block::{Block, Padding},
+    clear::Clear,
+    interact::Button,
+    paragraph::{Paragraph, Wrap},
+};
+
+mod confirm;
+mod prompt;
+
+/// How the user closed a dialog.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DialogResult {
+    /// The user accepted the dialog, e.g. with the confirm button.
+    Confirmed,
+    /// The user dismissed the dialog, e.g. with the cancel button.
+    Cancelled,
+}
+
+/// One of the two buttons of a dialog.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DialogButton {
+    /// The button that accepts the dialog.
+    Confirm,
+    /// The button that dismisses the dialog.
+    ///
+    /// This is focused by default, so that accepting a dialog is a deliberate choice.
+    #[default]
+    Cancel,
+}
+
+impl DialogButton {
+    /// Returns the result of pressing this button.
+    pub const fn result(self) -> DialogResult {
+        match self {
+            Self::Confirm => DialogResult::Confirmed,
+            Self::Cancel => DialogResult::Cancelled,
+        }
+    }
+
+    /// Returns the other button.
+    #[must_use]
+    pub const fn other(self) -> Self {
+        match self {
+            Self::Confirm => Self::Cancel,
+            Self::Cancel => Self::Confirm,
+        }
+    }
+}
+
+/// The parts shared by all dialogs: the popup with its title and message, and the buttons.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct DialogBase<'a> {
+    title: Line<'a>,
+    message: Text<'a>,
+    confirm_label: Line<'a>,
+    cancel_label: Line<'a>,
+    width: u16,
+    style: Style,
+    focused_style: Style,
+}
+
+impl<'a> DialogBase<'a> {
+    fn new(message: Text<'a>, confirm_label: Line<'a>, cancel_label: Line<'a>) -> Self {
+        Self {
+            title: Line::default(),
+            message,
+            confirm_label,
+            cancel_label,
+            width: 50,
+            style: Style::new(),
+            focused_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    fn message_paragraph(&self) -> Paragraph<'a> {
+        Paragraph::new(self.message.clone()).wrap(Wrap { trim: true })
+    }
+
+    /// Renders the popup and returns the area between the message and the buttons, which is
+    /// `body_height` rows high, and the area of the buttons.
+    fn render(&self, area: Rect, buf: &mut Buffer, body_height: u16) -> (Rect, Rect) {
+        let block = Block::bordered()
+            .title(self.title.clone())
+            .padding(Padding::horizontal(1))
+            .style(self.style);
+        let width = self.width.min(area.width);
+        let text_width = block.inner(Rect::new(0, 0, width, u16::MAX)).width;
+        let message_height =
+            u16::try_from(self.message_paragraph().line_count(text_width)).unwrap_or(u16::MAX);
+        let body_rows = if body_height > 0 { body_height + 1 } else { 0 };
+        let height = message_height
+            .saturating_add(body_rows)
+            .saturating_add(4)
+            .min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        Clear.render(popup, buf);
+        let inner = block.inner(popup);
+        block.render(popup, buf);
+        let mut constraints = vec![Constraint::Fill(1), Constraint::Length(1)];
+        if body_height > 0 {
+            constraints.insert(1, Constraint::Length(body_height));
+        }
+        let areas = Layout::vertical(constraints).spacing(1).split(inner);
+        self.message_paragraph().render(areas[0], buf);
+        let body_area = if body_height > 0 {
+            areas[1]
+        } else {
+            Rect::default()
+        };
+        let buttons_area = areas[areas.len() - 1];
+        (body_area, buttons_area)
+    }
+
+    /// Renders the buttons right-aligned in the area, and returns the areas of the confirm and
+    /// cancel buttons.
+    fn render_buttons(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        focus: Option<DialogButton>,
+    ) -> (Rect, Rect) {
+        let confirm = button_label(&self.confirm_label);
+        let cancel = button_label(&self.cancel_label);
+        let [_, confirm_area, cancel_area] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(u16::try_from(confirm.width()).unwrap_or(u16::MAX)),
+            Constraint::Length(u16::try_from(cancel.width()).unwrap_or(u16::MAX)),
+        ])
+        .spacing(1)
+        .areas(area);
+        for (label, button_area, button) in [
+            (confirm, confirm_area, DialogButton::Confirm),
+            (cancel, cancel_area, DialogButton::Cancel),
+        ] {
+            let style = if focus == Some(button) {
+                self.focused_style
+            } else {
+                Style::new()
+            };
+            Button::new(label).style(style).render(button_area, buf);
+        }
+        (confirm_area, cancel_area)
+    }
+}
+
+/// Returns the label of a button surrounded by brackets, e.g. `[ Yes ]`.
+fn button_label<'a>(label: &Line<'a>) -> Line<'a> {
+    let mut line = label.clone();
+    line.spans.insert(0, Span::raw("[ "));
+    line.spans.push(Span::raw(" ]"));
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dialog_button() {
+        assert_eq!(DialogButton::default(), DialogButton::Cancel);
+        assert_eq!(DialogButton::Cancel.other(), DialogButton::Confirm);
+        assert_eq!(DialogButton::Confirm.result(), DialogResult::Confirmed);
+        assert_eq!(DialogButton::Cancel.result(), DialogResult::Cancelled);
+    }
+}
@@ -0,0 +1,255 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Style, Styled},
+    text::{Line, Text},
+    widgets::StatefulWidget,
+};
+
+use crate::dialog::{DialogBase, DialogButton, DialogResult};
+
+/// A dialog that asks the user to confirm an action, e.g. "Are you sure? [y/N]".
+///
+/// The dialog shows a message and a confirm and a cancel button (`Yes` and `No` by default). The
+/// focused button is kept in a [`ConfirmState`], which also turns key presses and clicks into a
+/// [`DialogResult`]. The cancel button is focused by default.
+///
+/// See the [`dialog`](crate::dialog) module for more details.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{ConfirmDialog, ConfirmState, DialogResult},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, state: &mut ConfirmState) {
+/// let dialog = ConfirmDialog::new("Delete 3 files?").title("Confirm");
+/// frame.render_stateful_widget(dialog, frame.area(), state);
+/// # }
+/// # fn handle_key(state: &mut ConfirmState, key: char) -> Option<DialogResult> {
+/// match key {
+///     'y' => Some(DialogResult::Confirmed),
+///     'n' => Some(DialogResult::Cancelled),
+///     '\t' => {
+///         state.focus_next();
+///         None
+///     }
+///     '\n' => Some(state.result()),
+///     _ => None,
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ConfirmDialog<'a> {
+    base: DialogBase<'a>,
+}
+
+impl<'a> ConfirmDialog<'a> {
+    /// Creates a new dialog with the given message.
+    pub fn new<T: Into<Text<'a>>>(message: T) -> Self {
+        Self {
+            base: DialogBase::new(message.into(), Line::raw("Yes"), Line::raw("No")),
+        }
+    }
+
+    /// Sets the title shown in the border of the dialog.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        self.base.title = title.into();
+        self
+    }
+
+    /// Sets the labels of the confirm and cancel buttons.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn buttons<C, N>(mut self, confirm: C, cancel: N) -> Self
+    where
+        C: Into<Line<'a>>,
+        N: Into<Line<'a>>,
+    {
+        self.base.confirm_label = confirm.into();
+        self.base.cancel_label = cancel.into();
+        self
+    }
+
+    /// Sets the maximum width of the dialog, including its borders.
+    ///
+    /// The message is wrapped to fit. Defaults to 50.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.base.width = width;
+        self
+    }
+
+    /// Sets the style of the dialog.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.base.style = style.into();
+        self
+    }
+
+    /// Sets the style of the focused button.
+    ///
+    /// Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.base.focused_style = style.into();
+        self
+    }
+}
+
+impl StatefulWidget for ConfirmDialog<'_> {
+    type State = ConfirmState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &ConfirmDialog<'_> {
+    type State = ConfirmState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (_, buttons_area) = self.base.render(area, buf, 0);
+        (state.confirm_area, state.cancel_area) =
+            self.base
+                .render_buttons(buttons_area, buf, Some(state.focus));
+    }
+}
+
+impl Styled for ConfirmDialog<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.base.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`ConfirmDialog`]: the focused button and where the buttons were rendered.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ConfirmState {
+    focus: DialogButton,
+    confirm_area: Rect,
+    cancel_area: Rect,
+}
+
+impl ConfirmState {
+    /// Creates a new state with the cancel button focused.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the focused button.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_focus(mut self, button: DialogButton) -> Self {
+        self.focus = button;
+        self
+    }
+
+    /// Returns the focused button.
+    pub const fn focus(&self) -> DialogButton {
+        self.focus
+    }
+
+    /// Focuses the given button.
+    pub fn set_focus(&mut self, button: DialogButton) {
+        self.focus = button;
+    }
+
+    /// Moves the focus to the other button.
+    pub fn focus_next(&mut self) {
+        self.focus = self.focus.other();
+    }
+
+    /// Returns the result of pressing the focused button, e.g. when `Enter` is pressed.
+    pub const fn result(&self) -> DialogResult {
+        self.focus.result()
+    }
+
+    /// Handles a click at the given position, returning the result of the clicked button.
+    ///
+    /// The clicked button is focused. Returns `None` if the click is not on a button, or if the
+    /// dialog was not rendered yet.
+    pub fn click<P: Into<Position>>(&mut self, position: P) -> Option<DialogResult> {
+        let position = position.into();
+        let button = if self.confirm_area.contains(position) {
+            DialogButton::Confirm
+        } else if self.cancel_area.contains(position) {
+            DialogButton::Cancel
+        } else {
+            return None;
+        };
+        self.focus = button;
+        Some(button.result())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Modifier, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::with_lines(["xxxxxxxxxxxxxxxxxxxxxxxx"; 7]);
+        let mut state = ConfirmState::new();
+        let dialog = ConfirmDialog::new("Are you sure?").title("Quit").width(22);
+        StatefulWidget::render(&dialog, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "xxxxxxxxxxxxxxxxxxxxxxxx",
+            "x┌Quit────────────────┐x",
+            "x│ Are you sure?      │x",
+            "x│                    │x",
+            "x│     [ Yes ] [ No ] │x",
+            "x└────────────────────┘x",
+            "xxxxxxxxxxxxxxxxxxxxxxxx",
+        ]);
+        expected.set_style(Rect::new(15, 4, 6, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn focus_and_result() {
+        let mut state = ConfirmState::new();
+        assert_eq!(state.result(), DialogResult::Cancelled);
+        state.focus_next();
+        assert_eq!(state.focus(), DialogButton::Confirm);
+        assert_eq!(state.result(), DialogResult::Confirmed);
+        state.set_focus(DialogButton::Cancel);
+        assert_eq!(state.result(), DialogResult::Cancelled);
+    }
+
+    #[test]
+    fn click() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+        let mut state = ConfirmState::new();
+        assert_eq!(state.click((0, 0)), None);
+        ConfirmDialog::new("Delete?")
+            .buttons("Delete", "Keep")
+            .width(30)
+            .focused_style(Modifier::BOLD)
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(9, 5)].symbol(), "[");
+        assert_eq!(state.click((9, 5)), Some(DialogResult::Confirmed));
+        assert_eq!(state.focus(), DialogButton::Confirm);
+        assert_eq!(state.click((28, 5)), None);
+        assert_eq!(state.click((27, 5)), Some(DialogResult::Cancelled));
+    }
+}
@@ -0,0 +1,504 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Modifier, Style, Styled},
+    text::{Line, Text},
+    widgets::{HasCursor, StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::dialog::{DialogBase, DialogButton, DialogResult};
+
+/// A dialog that asks the user to enter a value, e.g. the name of a new file.
+///
+/// The dialog shows a message, a single line input field, and a confirm and a cancel button (`OK`
+/// and `Cancel` by default). The entered value, the position of the cursor and the focus are
+/// kept in a [`PromptState`], which provides the usual editing operations and turns key presses
+/// and clicks into a [`DialogResult`]. The input field is focused by default.
+///
+/// When the input field is focused, [`PromptState::cursor_position`] returns where the terminal
+/// cursor should be shown after rendering, which [`Frame::set_cursor_from`] uses to place it.
+///
+/// See the [`dialog`](crate::dialog) module for more details.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{DialogResult, PromptDialog, PromptState},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, state: &mut PromptState) {
+/// let dialog = PromptDialog::new("Name of the new file:")
+///     .title("New file")
+///     .placeholder("untitled.txt");
+/// frame.render_stateful_widget(dialog, frame.area(), state);
+/// frame.set_cursor_from(state);
+/// # }
+/// # fn handle_key(state: &mut PromptState, key: char) -> Option<DialogResult> {
+/// match key {
+///     '\n' => return Some(state.result()),
+///     '\t' => state.focus_next(),
+///     '\x08' => state.delete_backward(),
+///     c => state.insert_char(c),
+/// }
+/// None
+/// # }
+/// ```
+///
+/// [`Frame::set_cursor_from`]: ratatui_core::terminal::Frame::set_cursor_from
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PromptDialog<'a> {
+    base: DialogBase<'a>,
+    placeholder: Line<'a>,
+    input_style: Style,
+    placeholder_style: Style,
+}
+
+impl<'a> PromptDialog<'a> {
+    /// Creates a new dialog with the given message.
+    pub fn new<T: Into<Text<'a>>>(message: T) -> Self {
+        Self {
+            base: DialogBase::new(message.into(), Line::raw("OK"), Line::raw("Cancel")),
+            placeholder: Line::default(),
+            input_style: Style::new().add_modifier(Modifier::UNDERLINED),
+            placeholder_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Sets the title shown in the border of the dialog.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        self.base.title = title.into();
+        self
+    }
+
+    /// Sets the text shown in the input field while it is empty.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the labels of the confirm and cancel buttons.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn buttons<C, N>(mut self, confirm: C, cancel: N) -> Self
+    where
+        C: Into<Line<'a>>,
+        N: Into<Line<'a>>,
+    {
+        self.base.confirm_label = confirm.into();
+        self.base.cancel_label = cancel.into();
+        self
+    }
+
+    /// Sets the maximum width of the dialog, including its borders.
+    ///
+    /// The message is wrapped to fit. Defaults to 50.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.base.width = width;
+        self
+    }
+
+    /// Sets the style of the dialog.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.base.style = style.into();
+        self
+    }
+
+    /// Sets the style of the focused button.
+    ///
+    /// Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.base.focused_style = style.into();
+        self
+    }
+
+    /// Sets the style of the input field.
+    ///
+    /// Defaults to underlined.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    /// Sets the style added to the placeholder.
+    ///
+    /// Defaults to dim.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.placeholder_style = style.into();
+        self
+    }
+
+    /// Renders the value of the input field, scrolled so that the cursor is visible, and returns
+    /// the position of the cursor.
+    fn render_input(&self, area: Rect, buf: &mut Buffer, state: &PromptState) -> Position {
+        buf.set_style(area, self.input_style);
+        if state.value.is_empty() {
+            buf.set_style(area, self.placeholder_style);
+            (&self.placeholder).render(area, buf);
+            return area.as_position();
+        }
+        render_value(&state.value, state.cursor, area, buf)
+    }
+}
+
+/// Renders a value being edited on the first line of the area, scrolled so that the cursor is
+/// visible, and returns the position of the cursor.
+///
+/// The cursor is an index in the characters of the value.
+pub(crate) fn render_value(value: &str, cursor: usize, area: Rect, buf: &mut Buffer) -> Position {
+    let widths = value
+        .chars()
+        .map(|c| c.width().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let cursor_column: usize = widths[..cursor].iter().sum();
+    // skip the characters at the start of the value until the cursor fits in the field
+    let mut skipped = 0;
+    let mut skipped_width = 0;
+    while cursor_column - skipped_width >= usize::from(area.width) && skipped < cursor {
+        skipped_width += widths[skipped];
+        skipped += 1;
+    }
+    let visible = value.chars().skip(skipped).collect::<String>();
+    buf.set_stringn(
+        area.x,
+        area.y,
+        visible,
+        usize::from(area.width),
+        Style::new(),
+    );
+    let column = u16::try_from(cursor_column - skipped_width).unwrap_or(u16::MAX);
+    Position::new(area.x + column.min(area.width.saturating_sub(1)), area.y)
+}
+
+impl StatefulWidget for PromptDialog<'_> {
+    type State = PromptState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &PromptDialog<'_> {
+    type State = PromptState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        state.cursor_position = None;
+        if area.is_empty() {
+            return;
+        }
+        let (input_area, buttons_area) = self.base.render(area, buf, 1);
+        state.input_area = input_area;
+        if !input_area.is_empty() {
+            let cursor = self.render_input(input_area, buf, state);
+            if state.focused_button.is_none() {
+                state.cursor_position = Some(cursor);
+            }
+        }
+        (state.confirm_area, state.cancel_area) =
+            self.base
+                .render_buttons(buttons_area, buf, state.focused_button);
+    }
+}
+
+impl Styled for PromptDialog<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.base.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`PromptDialog`]: the entered value, the cursor and the focus.
+///
+/// The cursor is an index in the characters of the value, between `0` and the number of
+/// characters.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct PromptState {
+    value: String,
+    cursor: usize,
+    focused_button: Option<DialogButton>,
+    input_area: Rect,
+    confirm_area: Rect,
+    cancel_area: Rect,
+    cursor_position: Option<Position>,
+}
+
+impl PromptState {
+    /// Creates a new state with an empty value and the input field focused.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initial value, with the cursor at its end.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Returns the entered value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value, moving the cursor to its end.
+    pub fn set_value<T: Into<String>>(&mut self, value: T) {
+        self.value = value.into();
+        self.cursor = self.value.chars().count();
+    }
+
+    /// Returns the position of the cursor, as an index in the characters of the value.
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the focused button, or `None` if the input field is focused.
+    pub const fn focused_button(&self) -> Option<DialogButton> {
+        self.focused_button
+    }
+
+    /// Focuses the given button, or the input field if `None`.
+    pub fn set_focus(&mut self, button: Option<DialogButton>) {
+        self.focused_button = button;
+    }
+
+    /// Moves the focus from the input field to the confirm and cancel buttons, and back.
+    pub fn focus_next(&mut self) {
+        self.focused_button = match self.focused_button {
+            None => Some(DialogButton::Confirm),
+            Some(DialogButton::Confirm) => Some(DialogButton::Cancel),
+            Some(DialogButton::Cancel) => None,
+        };
+    }
+
+    /// Moves the focus in the opposite order of [`PromptState::focus_next`].
+    pub fn focus_previous(&mut self) {
+        self.focused_button = match self.focused_button {
+            None => Some(DialogButton::Cancel),
+            Some(DialogButton::Cancel) => Some(DialogButton::Confirm),
+            Some(DialogButton::Confirm) => None,
+        };
+    }
+
+    /// Returns the result of pressing `Enter`: cancelled if the cancel button is focused, and
+    /// confirmed otherwise.
+    pub fn result(&self) -> DialogResult {
+        self.focused_button
+            .unwrap_or(DialogButton::Confirm)
+            .result()
+    }
+
+    /// Returns where the terminal cursor should be shown, if the input field is focused.
+    ///
+    /// This is updated when the dialog is rendered.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Inserts a character at the cursor.
+    pub fn insert_char(&mut self, c: char) {
+        let index = self.byte_index(self.cursor);
+        self.value.insert(index, c);
+        self.cursor += 1;
+    }
+
+    /// Deletes the character before the cursor, like `Backspace`.
+    pub fn delete_backward(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.value.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    /// Deletes the character under the cursor, like `Delete`.
+    pub fn delete_forward(&mut self) {
+        if self.cursor < self.value.chars().count() {
+            self.value.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    /// Moves the cursor one character to the left.
+    pub fn move_cursor_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one character to the right.
+    pub fn move_cursor_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.value.chars().count());
+    }
+
+    /// Moves the cursor to the start of the value.
+    pub fn move_cursor_to_start(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the value.
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor = self.value.chars().count();
+    }
+
+    /// Handles a click at the given position, returning the result of the clicked button.
+    ///
+    /// The clicked button or input field is focused. Returns `None` if the click is not on a
+    /// button, or if the dialog was not rendered yet.
+    pub fn click<P: Into<Position>>(&mut self, position: P) -> Option<DialogResult> {
+        let position = position.into();
+        if self.input_area.contains(position) {
+            self.focused_button = None;
+            return None;
+        }
+        let button = if self.confirm_area.contains(position) {
+            DialogButton::Confirm
+        } else if self.cancel_area.contains(position) {
+            DialogButton::Cancel
+        } else {
+            return None;
+        };
+        self.focused_button = Some(button);
+        Some(button.result())
+    }
+
+    /// Returns the byte index of the character at the given index.
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(index)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+}
+
+impl HasCursor for PromptState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 7));
+        let mut state = PromptState::new().with_value("notes");
+        PromptDialog::new("File name:")
+            .title("Save")
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "┌Save────────────────┐",
+            "│ File name:         │",
+            "│                    │",
+            "│ notes              │",
+            "│                    │",
+            "│  [ OK ] [ Cancel ] │",
+            "└────────────────────┘",
+        ]);
+        expected.set_style(Rect::new(2, 3, 18, 1), Style::new().underlined());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(7, 3)));
+
+        state.focus_next();
+        PromptDialog::new("File name:").render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+        assert!(buf[(4, 5)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 7));
+        let mut state = PromptState::new();
+        PromptDialog::new("Name")
+            .placeholder("none")
+            .buttons("Y", "N")
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(2, 3)].symbol(), "n");
+        assert_eq!(buf[(2, 3)].modifier, Modifier::UNDERLINED | Modifier::DIM);
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 3)));
+    }
+
+    #[test]
+    fn render_scrolls_to_cursor() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 7));
+        let mut state = PromptState::new().with_value("abcdefghijklmnop");
+        let dialog = PromptDialog::new("").buttons("Y", "N");
+        StatefulWidget::render(&dialog, buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(2, 3)].symbol(), "f");
+        assert_eq!(state.cursor_position(), Some(Position::new(13, 3)));
+
+        state.move_cursor_to_start();
+        StatefulWidget::render(&dialog, buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(2, 3)].symbol(), "a");
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 3)));
+    }
+
+    #[test]
+    fn edit() {
+        let mut state = PromptState::new();
+        for c in "héllo".chars() {
+            state.insert_char(c);
+        }
+        state.move_cursor_left();
+        state.move_cursor_left();
+        state.delete_backward();
+        assert_eq!(state.value(), "hélo");
+        assert_eq!(state.cursor(), 2);
+        state.delete_forward();
+        state.move_cursor_to_start();
+        state.insert_char('_');
+        assert_eq!(state.value(), "_héo");
+        state.move_cursor_to_end();
+        state.move_cursor_right();
+        state.delete_forward();
+        assert_eq!(state.cursor(), 4);
+        state.set_value("new");
+        assert_eq!(state.cursor(), 3);
+    }
+
+    #[test]
+    fn focus_and_result() {
+        let mut state = PromptState::new();
+        assert_eq!(state.result(), DialogResult::Confirmed);
+        state.focus_next();
+        assert_eq!(state.focused_button(), Some(DialogButton::Confirm));
+        state.focus_next();
+        assert_eq!(state.result(), DialogResult::Cancelled);
+        state.focus_next();
+        assert_eq!(state.focused_button(), None);
+        state.focus_previous();
+        assert_eq!(state.focused_button(), Some(DialogButton::Cancel));
+    }
+
+    #[test]
+    fn click() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 22, 7));
+        let mut state = PromptState::new();
+        PromptDialog::new("File name:").render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.click((6, 5)), Some(DialogResult::Confirmed));
+        assert_eq!(state.click((12, 5)), Some(DialogResult::Cancelled));
+        assert_eq!(state.focused_button(), Some(DialogButton::Cancel));
+        assert_eq!(state.click((5, 3)), None);
+        assert_eq!(state.focused_button(), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! The [`Either`] widget renders one of two widgets of different types.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A widget that is either one of two widgets of different types.
+///
+/// This makes it possible to choose between two widgets at runtime without boxing them. Nest
+/// `Either` values to choose between more than two widget types, or use [`Switch`] when all the
+/// choices have the same type. `Option<W>` also implements [`Widget`] and renders nothing when it
+/// is `None`.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{Either, List, Paragraph},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, items: Vec<&str>) {
+/// let widget = if items.is_empty() {
+///     Either::Left(Paragraph::new("No items"))
+/// } else {
+///     Either::Right(List::new(items))
+/// };
+/// frame.render_widget(widget, frame.area());
+/// # }
+/// ```
+///
+/// [`Switch`]: crate::switch::Switch
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Either<L, R> {
+    /// The left widget
+    Left(L),
+    /// The right widget
+    Right(R),
+}
+
+impl<L: Widget, R: Widget> Widget for Either<L, R> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Self::Left(widget) => widget.render(area, buf),
+            Self::Right(widget) => widget.render(area, buf),
+        }
+    }
+}
+
+impl<L, R> Widget for &Either<L, R>
+where
+    for<'a> &'a L: Widget,
+    for<'a> &'a R: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Either::Left(widget) => widget.render(area, buf),
+            Either::Right(widget) => widget.render(area, buf),
+        }
+    }
+}
+
+impl<L, R, S> StatefulWidget for Either<L, R>
+where
+    L: StatefulWidget<State = S>,
+    R: StatefulWidget<State = S>,
+    S: ?Sized,
+{
+    type State = S;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        match self {
+            Self::Left(widget) => widget.render(area, buf, state),
+            Self::Right(widget) => widget.render(area, buf, state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::Line;
+
+    use super::*;
+    use crate::list::{List, ListState};
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Either::<_, Line>::Left("left").render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["left "]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Either::<&str, _>::Right(Line::from("right")).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["right"]));
+    }
+
+    #[test]
+    fn render_ref() {
+        let either = Either::<Line, Line>::Left(Line::from("ref"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        (&either).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ref  "]));
+    }
+
+    #[test]
+    fn render_stateful() {
+        let mut state = ListState::default().with_selected(Some(1));
+        let either = Either::<_, List>::Left(List::new(["a", "b"]).highlight_symbol(">"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        StatefulWidget::render(either, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([" a ", ">b "]));
+    }
+}
@@ -0,0 +1,329 @@
+//! The [`HintBar`] widget shows the actions that are available in the current context.
+use std::borrow::Cow;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style, Styled},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::block::{Block, BlockExt};
+
+/// A key and the action it triggers, shown in a [`HintBar`].
+///
+/// Hints with a higher priority are kept when the hints do not all fit in the bar. The default
+/// priority is `0`.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::Hint;
+///
+/// let hint = Hint::new("q", "Quit").priority(10);
+/// let hint = Hint::from(("/", "Search"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Hint<'a> {
+    key: Cow<'a, str>,
+    label: Cow<'a, str>,
+    priority: u8,
+}
+
+impl<'a> Hint<'a> {
+    /// Creates a new hint for the given key and label.
+    pub fn new<K, L>(key: K, label: L) -> Self
+    where
+        K: Into<Cow<'a, str>>,
+        L: Into<Cow<'a, str>>,
+    {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            priority: 0,
+        }
+    }
+
+    /// Sets the priority of the hint.
+    ///
+    /// When the hints do not all fit in the bar, the hints with the lowest priority are left out
+    /// first.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the width of the hint: the key, a space and the label.
+    fn width(&self) -> usize {
+        self.key.width() + 1 + self.label.width()
+    }
+}
+
+impl<'a, K, L> From<(K, L)> for Hint<'a>
+where
+    K: Into<Cow<'a, str>>,
+    L: Into<Cow<'a, str>>,
+{
+    fn from((key, label): (K, L)) -> Self {
+        Self::new(key, label)
+    }
+}
+
+/// A widget that shows the actions that are available in the current context, e.g.
+/// `q Quit ‧ / Search ‧ ⏎ Open`.
+///
+/// Each [`Hint`] is a key followed by a label, and the hints are separated by a separator. The
+/// key and the label have their own styles. When the hints do not all fit in the area, the hints
+/// with the lowest [`priority`](Hint::priority) are left out, starting from the last one, and the
+/// others keep their order. The hints are shown on the first line of the area.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Color, Style},
+///     widgets::{Hint, HintBar},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, editing: bool) {
+/// let mut hints = vec![Hint::new("q", "Quit").priority(1), Hint::new("/", "Search")];
+/// if !editing {
+///     hints.push(Hint::new("⏎", "Open"));
+/// }
+/// let hint_bar = HintBar::new(hints).key_style(Style::new().fg(Color::Yellow));
+/// frame.render_widget(hint_bar, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct HintBar<'a> {
+    hints: Vec<Hint<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    key_style: Style,
+    label_style: Style,
+    separator: Span<'a>,
+    alignment: Alignment,
+}
+
+impl Default for HintBar<'_> {
+    fn default() -> Self {
+        Self {
+            hints: Vec::new(),
+            block: None,
+            style: Style::new(),
+            key_style: Style::new().add_modifier(Modifier::BOLD),
+            label_style: Style::new(),
+            separator: Span::raw(" ‧ "),
+            alignment: Alignment::Left,
+        }
+    }
+}
+
+impl<'a> HintBar<'a> {
+    /// Creates a new `HintBar` with the given hints.
+    ///
+    /// The hints can be [`Hint`]s or `(key, label)` tuples. The keys are bold by default.
+    pub fn new<I>(hints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Hint<'a>>,
+    {
+        Self {
+            hints: hints.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Wraps the hints with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the keys.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    /// Sets the style of the labels.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Sets the separator shown between the hints.
+    ///
+    /// The default separator is `" ‧ "`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator<T: Into<Span<'a>>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the alignment of the hints within the area.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the hints that fit in the given width, in their original order.
+    ///
+    /// The hints with the lowest priority are left out first, and of hints with the same priority
+    /// the last one is left out first. A single hint that does not fit is kept, and is truncated
+    /// when rendered.
+    fn visible_hints(&self, width: u16) -> Vec<&Hint<'a>> {
+        let separator_width = self.separator.width();
+        let total_width = |hints: &[&Hint]| {
+            hints.iter().map(|hint| hint.width()).sum::<usize>()
+                + separator_width * hints.len().saturating_sub(1)
+        };
+        let mut hints = self.hints.iter().collect::<Vec<_>>();
+        while hints.len() > 1 && total_width(&hints) > usize::from(width) {
+            let lowest = hints
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, hint)| hint.priority)
+                .map(|(index, _)| index);
+            if let Some(index) = lowest {
+                hints.remove(index);
+            }
+        }
+        hints
+    }
+}
+
+impl Styled for HintBar<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for HintBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &HintBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return;
+        }
+        let mut spans = Vec::new();
+        for (index, hint) in self.visible_hints(area.width).into_iter().enumerate() {
+            if index > 0 {
+                spans.push(self.separator.clone());
+            }
+            spans.push(Span::styled(hint.key.as_ref(), self.key_style));
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(hint.label.as_ref(), self.label_style));
+        }
+        Line::from(spans)
+            .alignment(self.alignment)
+            .render(Rect { height: 1, ..area }, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    fn render(hint_bar: &HintBar, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        Widget::render(hint_bar, buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn render_hints() {
+        let hint_bar =
+            HintBar::new([("q", "Quit"), ("/", "Search"), ("⏎", "Open")]).key_style(Style::new());
+        assert_eq!(
+            render(&hint_bar, 28),
+            Buffer::with_lines(["q Quit ‧ / Search ‧ ⏎ Open  "])
+        );
+    }
+
+    #[test]
+    fn render_styles() {
+        let hint_bar = HintBar::new([("q", "Quit")])
+            .key_style(Style::new().yellow())
+            .label_style(Style::new().italic());
+        let mut expected = Buffer::with_lines(["q Quit"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().yellow());
+        expected.set_style(Rect::new(2, 0, 4, 1), Style::new().italic());
+        assert_eq!(render(&hint_bar, 6), expected);
+    }
+
+    #[test]
+    fn truncates_by_priority() {
+        let hint_bar = HintBar::new([
+            Hint::new("q", "Quit").priority(2),
+            Hint::new("/", "Search"),
+            Hint::new("⏎", "Open"),
+            Hint::new("?", "Help").priority(1),
+        ])
+        .key_style(Style::new())
+        .separator(" ");
+        assert_eq!(
+            render(&hint_bar, 24),
+            Buffer::with_lines(["q Quit / Search ? Help  "])
+        );
+        assert_eq!(
+            render(&hint_bar, 14),
+            Buffer::with_lines(["q Quit ? Help "])
+        );
+        assert_eq!(render(&hint_bar, 4), Buffer::with_lines(["q Qu"]));
+    }
+
+    #[test]
+    fn render_right_aligned() {
+        let hint_bar = HintBar::new([("q", "Quit")])
+            .key_style(Style::new())
+            .alignment(Alignment::Right);
+        assert_eq!(render(&hint_bar, 8), Buffer::with_lines(["  q Quit"]));
+    }
+}
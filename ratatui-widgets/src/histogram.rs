@@ -0,0 +1,318 @@
+//! The [`Histogram`] helper counts raw samples into bins and turns them into a [`BarChart`] or a
+//! [`Chart`].
+//!
+//! [`BarChart`]: crate::barchart::BarChart
+//! [`Chart`]: crate::chart::Chart
+use ratatui_core::text::Line;
+
+use crate::{
+    barchart::{Bar, BarChart},
+    chart::{Axis, Chart, Dataset, GraphType},
+};
+
+/// How the samples of a [`Histogram`] are divided into bins.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Binning {
+    /// Bins of the given width, starting at the smallest sample.
+    ///
+    /// A width that is not positive and finite results in a single bin. When the width would
+    /// result in more than [`Histogram::MAX_BINS`] bins, it is increased to span the samples with
+    /// that many bins.
+    Width(f64),
+    /// The given number of bins of equal width, spanning the samples.
+    ///
+    /// The number of bins is limited to [`Histogram::MAX_BINS`].
+    Count(usize),
+    /// `⌈log₂ n⌉ + 1` bins of equal width for `n` samples, following [Sturges' rule].
+    ///
+    /// [Sturges' rule]: https://en.wikipedia.org/wiki/Histogram#Sturges'_formula
+    #[default]
+    Sturges,
+    /// Bins between consecutive edges.
+    ///
+    /// The edges are sorted in ascending order, and the edges that are not finite or that appear
+    /// more than once are ignored. Samples outside of the first and last edges are not counted.
+    Edges(Vec<f64>),
+}
+
+/// The distribution of a set of samples, counted into bins.
+///
+/// Each bin contains the samples greater or equal to its lower edge and less than its upper edge,
+/// except for the last bin which also contains the samples equal to its upper edge. `NaN` and
+/// infinite samples are ignored.
+///
+/// A histogram is not a widget itself, it is rendered with [`Histogram::bar_chart`], which
+/// labels each bar with the lower edge of its bin, or with [`Histogram::chart`], which labels the
+/// X axis with the edges of the bins.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::histogram::{Binning, Histogram};
+///
+/// let samples = [1.0, 2.0, 2.5, 3.0, 7.5, 9.0];
+/// let histogram = Histogram::new(&samples, Binning::Width(2.0));
+/// assert_eq!(histogram.edges(), [1.0, 3.0, 5.0, 7.0, 9.0]);
+/// assert_eq!(histogram.counts(), [3, 1, 0, 2]);
+///
+/// let bar_chart = histogram.bar_chart().bar_width(3);
+/// let chart = histogram.chart();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<u64>,
+    /// The center of each bin and its count, used as the data of the [`Dataset`]
+    points: Vec<(f64, f64)>,
+    precision: usize,
+}
+
+impl Histogram {
+    /// The largest number of bins computed from [`Binning::Width`] and [`Binning::Count`].
+    ///
+    /// Each bin needs at least one column to be rendered, so a terminal cannot show more bins.
+    pub const MAX_BINS: usize = u16::MAX as usize;
+
+    /// Counts the samples into bins chosen with the given strategy.
+    ///
+    /// Without any sample, the histogram has no bins except with [`Binning::Edges`]. When all the
+    /// samples are equal, the bins span a width of 1 from that value.
+    pub fn new(samples: &[f64], binning: Binning) -> Self {
+        let samples = samples.iter().copied().filter(|s| s.is_finite());
+        let edges = Self::bin_edges(samples.clone(), binning);
+        let mut counts = vec![0; edges.len().saturating_sub(1)];
+        for sample in samples {
+            if let Some(bin) = Self::bin_of(&edges, sample) {
+                counts[bin] += 1;
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let points = edges
+            .windows(2)
+            .zip(&counts)
+            .map(|(edges, &count)| ((edges[0] + edges[1]) / 2.0, count as f64))
+            .collect();
+        Self {
+            edges,
+            counts,
+            points,
+            precision: 1,
+        }
+    }
+
+    /// Sets the number of decimals of the edges in the labels.
+    ///
+    /// Defaults to 1.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the edges of the bins, one more than the number of bins.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// Returns the number of samples in each bin.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns one bar per bin, labelled with the lower edge of the bin.
+    pub fn bars(&self) -> Vec<Bar<'static>> {
+        self.edges
+            .iter()
+            .zip(&self.counts)
+            .map(|(&edge, &count)| Bar::with_label(self.label(edge), count))
+            .collect()
+    }
+
+    /// Returns a vertical [`BarChart`] with one bar per bin.
+    ///
+    /// The bar chart can be customized further with its builder methods.
+    pub fn bar_chart(&self) -> BarChart<'static> {
+        BarChart::vertical(self.bars())
+    }
+
+    /// Returns a [`Dataset`] with a vertical bar at the center of each bin, whose height is the
+    /// number of samples in the bin.
+    pub fn dataset(&self) -> Dataset<'_> {
+        Dataset::default()
+            .data(&self.points)
+            .graph_type(GraphType::Bar)
+    }
+
+    /// Returns an X [`Axis`] spanning the bins, labelled with their edges.
+    ///
+    /// The labels are spread evenly along the axis, so they only line up with the edges when the
+    /// bins have the same width.
+    pub fn x_axis(&self) -> Axis<'static> {
+        let bounds = match (self.edges.first(), self.edges.last()) {
+            (Some(&first), Some(&last)) => [first, last],
+            _ => [0.0, 0.0],
+        };
+        Axis::default()
+            .bounds(bounds)
+            .labels(self.edges.iter().map(|&edge| self.label(edge)))
+    }
+
+    /// Returns a Y [`Axis`] from 0 to the largest count, labelled with these values.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn y_axis(&self) -> Axis<'static> {
+        let max = self.counts.iter().copied().max().unwrap_or_default();
+        Axis::default()
+            .bounds([0.0, max as f64])
+            .labels(["0".to_string(), max.to_string()])
+    }
+
+    /// Returns a [`Chart`] of the [`dataset`](Self::dataset) with the [`x_axis`](Self::x_axis)
+    /// and [`y_axis`](Self::y_axis) of the histogram.
+    pub fn chart(&self) -> Chart<'_> {
+        Chart::new(vec![self.dataset()])
+            .x_axis(self.x_axis())
+            .y_axis(self.y_axis())
+    }
+
+    fn label(&self, edge: f64) -> Line<'static> {
+        Line::from(format!("{edge:.*}", self.precision))
+    }
+
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn bin_edges(samples: impl Iterator<Item = f64>, binning: Binning) -> Vec<f64> {
+        let (count, min, max) = samples
+            .fold((0_usize, f64::INFINITY, f64::NEG_INFINITY), |acc, s| {
+                (acc.0 + 1, acc.1.min(s), acc.2.max(s))
+            });
+        if count == 0 && !matches!(binning, Binning::Edges(_)) {
+            return Vec::new();
+        }
+        let span = if max > min { max - min } else { 1.0 };
+        let (bins, width) = match binning {
+            Binning::Width(width) if width > 0.0 && width.is_finite() => {
+                let bins = (span / width).ceil().max(1.0);
+                if bins > Self::MAX_BINS as f64 {
+                    (Self::MAX_BINS, span / Self::MAX_BINS as f64)
+                } else {
+                    (bins as usize, width)
+                }
+            }
+            Binning::Width(_) => (1, span),
+            Binning::Count(bins) => {
+                let bins = bins.clamp(1, Self::MAX_BINS);
+                (bins, span / bins as f64)
+            }
+            Binning::Sturges => {
+                let bins = (count as f64).log2().ceil() as usize + 1;
+                (bins, span / bins as f64)
+            }
+            Binning::Edges(mut edges) => {
+                edges.retain(|edge| edge.is_finite());
+                edges.sort_by(f64::total_cmp);
+                edges.dedup();
+                return edges;
+            }
+        };
+        (0..=bins).map(|i| min + width * i as f64).collect()
+    }
+
+    /// Returns the index of the bin containing the sample, if any.
+    fn bin_of(edges: &[f64], sample: f64) -> Option<usize> {
+        let (&first, &last) = (edges.first()?, edges.last()?);
+        if edges.len() < 2 || sample < first || sample > last {
+            return None;
+        }
+        let upper = edges.partition_point(|&edge| edge <= sample);
+        Some(upper.clamp(1, edges.len() - 1) - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{buffer::Buffer, layout::Rect, widgets::Widget};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::width(Binning::Width(2.0), &[1.0, 3.0, 5.0, 7.0, 9.0], &[3, 1, 0, 2])]
+    #[case::count(Binning::Count(2), &[1.0, 5.0, 9.0], &[4, 2])]
+    #[case::sturges(Binning::Sturges, &[1.0, 3.0, 5.0, 7.0, 9.0], &[3, 1, 0, 2])]
+    #[case::edges(Binning::Edges(vec![0.0, 2.0, 8.0]), &[0.0, 2.0, 8.0], &[1, 4])]
+    fn bins(#[case] binning: Binning, #[case] edges: &[f64], #[case] counts: &[u64]) {
+        let histogram = Histogram::new(&[1.0, 2.0, 2.5, 3.0, 7.5, 9.0], binning);
+        assert_eq!(histogram.edges(), edges);
+        assert_eq!(histogram.counts(), counts);
+    }
+
+    #[test]
+    fn equal_samples() {
+        let histogram = Histogram::new(&[2.0, 2.0, f64::NAN], Binning::Count(2));
+        assert_eq!(histogram.edges(), [2.0, 2.5, 3.0]);
+        assert_eq!(histogram.counts(), [2, 0]);
+    }
+
+    #[rstest]
+    #[case::tiny_width(Binning::Width(1e-12))]
+    #[case::huge_count(Binning::Count(usize::MAX))]
+    fn limits_the_number_of_bins(#[case] binning: Binning) {
+        let histogram = Histogram::new(&[0.0, 1.0], binning);
+        assert_eq!(histogram.counts().len(), Histogram::MAX_BINS);
+        assert_eq!(histogram.edges().first(), Some(&0.0));
+        assert!((histogram.edges().last().unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(histogram.counts().iter().sum::<u64>(), 2);
+    }
+
+    #[rstest]
+    #[case::zero(0.0)]
+    #[case::negative(-1.0)]
+    #[case::nan(f64::NAN)]
+    #[case::infinite(f64::INFINITY)]
+    fn invalid_width(#[case] width: f64) {
+        let histogram = Histogram::new(&[1.0, 3.0], Binning::Width(width));
+        assert_eq!(histogram.edges(), [1.0, 3.0]);
+        assert_eq!(histogram.counts(), [2]);
+    }
+
+    #[test]
+    fn unsorted_edges() {
+        let edges = vec![8.0, f64::NAN, 0.0, 2.0, f64::INFINITY, 2.0];
+        let histogram = Histogram::new(&[1.0, 2.0, 2.5, 3.0, 7.5, 9.0], Binning::Edges(edges));
+        assert_eq!(histogram.edges(), [0.0, 2.0, 8.0]);
+        assert_eq!(histogram.counts(), [1, 4]);
+    }
+
+    #[test]
+    fn infinite_samples() {
+        let histogram = Histogram::new(&[1.0, f64::INFINITY, 3.0], Binning::Count(2));
+        assert_eq!(histogram.edges(), [1.0, 2.0, 3.0]);
+        assert_eq!(histogram.counts(), [1, 1]);
+    }
+
+    #[test]
+    fn no_samples() {
+        let histogram = Histogram::new(&[], Binning::Sturges);
+        assert!(histogram.edges().is_empty());
+        assert!(histogram.counts().is_empty());
+    }
+
+    #[test]
+    fn bars() {
+        let histogram = Histogram::new(&[0.0, 0.5, 1.0], Binning::Count(2)).precision(2);
+        assert_eq!(
+            histogram.bars(),
+            [Bar::with_label("0.00", 1), Bar::with_label("0.50", 2)]
+        );
+    }
+
+    #[test]
+    fn render_bar_chart() {
+        let histogram = Histogram::new(&[0.0, 1.0, 1.0, 2.0, 3.0], Binning::Count(3)).precision(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        histogram.bar_chart().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  █ █", "1 2 2", "0 1 2"]));
+    }
+}
@@ -0,0 +1,201 @@
+//! Immediate-mode interaction with the [`Button`], [`Focusable`], [`Link`] and [`Toggle`] widgets.
+//!
+//! Instead of routing events to widgets, the application collects the state of the input for the
+//! current frame in an [`InputSnapshot`] (the position of the mouse, where it was clicked, which
+//! widget has the keyboard focus and whether it was activated), and renders the interactive
+//! widgets with it. Rendering returns a [`Response`] that tells whether the widget was clicked,
+//! hovered or focused, so the application can react right where the widget is drawn.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{
+//!     layout::{Position, Rect},
+//!     widgets::{Button, InputSnapshot, InteractiveWidget, Toggle},
+//!     Frame,
+//! };
+//!
+//! # fn draw(frame: &mut Frame, dark_mode: &mut bool, count: &mut u32) {
+//! // usually built from the events received since the last frame
+//! let input = InputSnapshot::new().click(Position::new(2, 0));
+//!
+//! let button = Button::new("Increment").id("increment");
+//! if button
+//!     .render_interactive(Rect::new(0, 0, 11, 1), frame.buffer_mut(), &input)
+//!     .clicked
+//! {
+//!     *count += 1;
+//! }
+//!
+//! let toggle = Toggle::new("Dark mode", *dark_mode).id("dark-mode");
+//! if toggle
+//!     .render_interactive(Rect::new(0, 1, 20, 1), frame.buffer_mut(), &input)
+//!     .clicked
+//! {
+//!     *dark_mode = !*dark_mode;
+//! }
+//! # }
+//! ```
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    terminal::WidgetId,
+};
+
+pub use self::{button::Button, focusable::Focusable, link::Link, toggle::Toggle};
+
+mod button;
+mod focusable;
+mod link;
+mod toggle;
+
+/// The state of the input for the current frame, used to render [`InteractiveWidget`]s.
+///
+/// The snapshot is usually rebuilt for every frame from the events received since the previous
+/// one: the mouse position is kept from frame to frame, while clicks and activations only last
+/// for the frame in which they happened.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct InputSnapshot {
+    /// The position of the mouse, if known.
+    pub mouse: Option<Position>,
+    /// The position of the mouse click that happened during this frame, if any.
+    pub click: Option<Position>,
+    /// The id of the widget that has the keyboard focus, if any.
+    pub focus: Option<WidgetId>,
+    /// Whether the focused widget was activated from the keyboard (e.g. with `Enter`) during this
+    /// frame.
+    pub activate: bool,
+}
+
+impl InputSnapshot {
+    /// Creates a snapshot with no mouse position, no click and no focus.
+    pub const fn new() -> Self {
+        Self {
+            mouse: None,
+            click: None,
+            focus: None,
+            activate: false,
+        }
+    }
+
+    /// Sets the position of the mouse.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn mouse<P: Into<Position>>(mut self, position: P) -> Self {
+        self.mouse = Some(position.into());
+        self
+    }
+
+    /// Sets the position of the mouse click that happened during this frame.
+    ///
+    /// The mouse is also considered to be at that position.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn click<P: Into<Position>>(mut self, position: P) -> Self {
+        let position = position.into();
+        self.click = Some(position);
+        self.mouse = Some(position);
+        self
+    }
+
+    /// Sets the id of the widget that has the keyboard focus.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focus<I: Into<WidgetId>>(mut self, id: I) -> Self {
+        self.focus = Some(id.into());
+        self
+    }
+
+    /// Sets whether the focused widget was activated during this frame.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn activate(mut self, activate: bool) -> Self {
+        self.activate = activate;
+        self
+    }
+
+    /// Returns the response of a widget with the given id that reacts to the mouse in `area`.
+    ///
+    /// This can be used to implement [`InteractiveWidget`] for other widgets.
+    pub fn response(&self, id: Option<WidgetId>, area: Rect) -> Response {
+        let focused = id.is_some() && id == self.focus;
+        Response {
+            clicked: self.click.is_some_and(|click| area.contains(click))
+                || (focused && self.activate),
+            hovered: self.mouse.is_some_and(|mouse| area.contains(mouse)),
+            focused,
+        }
+    }
+}
+
+/// How the user interacted with a widget during the current frame.
+///
+/// This is returned by [`InteractiveWidget::render_interactive`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Response {
+    /// Whether the widget was clicked, or activated from the keyboard while focused.
+    pub clicked: bool,
+    /// Whether the mouse is over the widget.
+    pub hovered: bool,
+    /// Whether the widget has the keyboard focus.
+    pub focused: bool,
+}
+
+/// A widget that is rendered with the state of the input and reports how the user interacted
+/// with it.
+///
+/// See the [module documentation](self) for more details.
+pub trait InteractiveWidget {
+    /// Draws the widget in the given area, styled according to the input, and returns how the
+    /// user interacted with it.
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response() {
+        let area = Rect::new(2, 2, 4, 1);
+        let id = WidgetId::from("ok");
+        assert_eq!(
+            InputSnapshot::new().response(Some(id), area),
+            Response::default()
+        );
+        assert_eq!(
+            InputSnapshot::new().mouse((3, 2)).response(Some(id), area),
+            Response {
+                hovered: true,
+                ..Response::default()
+            }
+        );
+        assert_eq!(
+            InputSnapshot::new().click((5, 2)).response(None, area),
+            Response {
+                clicked: true,
+                hovered: true,
+                focused: false,
+            }
+        );
+        assert_eq!(
+            InputSnapshot::new().click((6, 2)).response(None, area),
+            Response::default()
+        );
+    }
+
+    #[test]
+    fn response_focus() {
+        let area = Rect::new(0, 0, 4, 1);
+        let input = InputSnapshot::new().focus("ok").activate(true);
+        assert_eq!(
+            input.response(Some(WidgetId::from("ok")), area),
+            Response {
+                clicked: true,
+                hovered: false,
+                focused: true,
+            }
+        );
+        assert_eq!(
+            input.response(Some(WidgetId::from("cancel")), area),
+            Response::default()
+        );
+        assert_eq!(input.response(None, area), Response::default());
+    }
+}
@@ -0,0 +1,211 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    terminal::WidgetId,
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::interact::{InputSnapshot, InteractiveWidget, Response};
+
+/// A button that reports when it is clicked.
+///
+/// The label is centered in the area of the button, which is filled with the style of the
+/// button. When the mouse is over the button or when it has the keyboard focus, the
+/// [`hovered_style`](Button::hovered_style) or [`focused_style`](Button::focused_style) is added
+/// on top of it. The whole area reacts to the mouse.
+///
+/// See the [`interact`](crate::interact) module for how to use it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::{Button, InputSnapshot, InteractiveWidget},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, input: &InputSnapshot) {
+/// let button = Button::new("Quit").id("quit").style(Style::new().on_red());
+/// if button
+///     .render_interactive(frame.area(), frame.buffer_mut(), input)
+///     .clicked
+/// {
+///     // quit the application
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Button<'a> {
+    label: Line<'a>,
+    id: Option<WidgetId>,
+    style: Style,
+    hovered_style: Style,
+    focused_style: Style,
+}
+
+impl<'a> Button<'a> {
+    /// Creates a new button with the given label.
+    ///
+    /// By default the button is shown in bold when hovered and reversed when focused.
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            id: None,
+            style: Style::new(),
+            hovered_style: Style::new().add_modifier(Modifier::BOLD),
+            focused_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Sets the id of the button, which is used to tell whether it has the keyboard focus.
+    ///
+    /// A button without an id can only be clicked with the mouse.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn id<I: Into<WidgetId>>(mut self, id: I) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the style of the button.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style added to the button when the mouse is over it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hovered_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.hovered_style = style.into();
+        self
+    }
+
+    /// Sets the style added to the button when it has the keyboard focus.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_style = style.into();
+        self
+    }
+
+    fn render_with_response(&self, area: Rect, buf: &mut Buffer, response: Response) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let mut style = self.style;
+        if response.hovered {
+            style = style.patch(self.hovered_style);
+        }
+        if response.focused {
+            style = style.patch(self.focused_style);
+        }
+        buf.set_style(area, style);
+        let width = u16::try_from(self.label.width())
+            .unwrap_or(u16::MAX)
+            .min(area.width);
+        let label_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + area.height.saturating_sub(1) / 2,
+            width,
+            height: 1,
+        };
+        (&self.label).render(label_area, buf);
+    }
+}
+
+impl Widget for Button<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Button<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_response(area, buf, Response::default());
+    }
+}
+
+impl InteractiveWidget for Button<'_> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        (&self).render_interactive(area, buf, input)
+    }
+}
+
+impl InteractiveWidget for &Button<'_> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        let response = input.response(self.id, area);
+        self.render_with_response(area, buf, response);
+        response
+    }
+}
+
+impl Styled for Button<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        Button::new("OK").on_blue().render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["        ", "   OK   ", "        "]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_interactive() {
+        let button = Button::new("OK").id("ok");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+
+        let input = InputSnapshot::new().mouse((0, 0));
+        let response = button
+            .clone()
+            .render_interactive(buf.area, &mut buf, &input);
+        assert_eq!(
+            response,
+            Response {
+                hovered: true,
+                ..Response::default()
+            }
+        );
+        let mut expected = Buffer::with_lines(["  OK  "]);
+        expected.set_style(expected.area, Style::new().bold());
+        assert_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let input = InputSnapshot::new().focus("ok").activate(true);
+        let response = (&button).render_interactive(buf.area, &mut buf, &input);
+        assert!(response.clicked);
+        assert!(response.focused);
+        assert_eq!(buf[(2, 0)].modifier, Modifier::REVERSED);
+    }
+
+    #[test]
+    fn render_truncated() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Button::new("Cancel").render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["Can"]));
+    }
+}
@@ -0,0 +1,217 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    terminal::WidgetId,
+    widgets::Widget,
+};
+
+use crate::{
+    block::Block,
+    borders::BorderType,
+    interact::{InputSnapshot, InteractiveWidget, Response},
+};
+
+/// A pane made of a [`Block`] around another widget, whose borders change when it has the
+/// keyboard focus.
+///
+/// When the id of the pane is the [`focus`](InputSnapshot::focus) of the input, the
+/// [`focused_border_style`](Focusable::focused_border_style) is added on top of the border style
+/// of the block and its borders are drawn with the
+/// [`focused_border_type`](Focusable::focused_border_type). The widget is rendered inside the
+/// block. Clicking anywhere in the pane is reported in the [`Response`], e.g. to move the focus
+/// to it.
+///
+/// See the [`interact`](crate::interact) module for how to use it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::{Constraint, Layout},
+///     widgets::{Block, Focusable, InputSnapshot, InteractiveWidget, Paragraph},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, input: &InputSnapshot) {
+/// let [left, right] = Layout::horizontal([Constraint::Fill(1); 2]).areas(frame.area());
+/// let files =
+///     Focusable::new(Block::bordered().title("Files"), Paragraph::new("a.txt")).id("files");
+/// let preview =
+///     Focusable::new(Block::bordered().title("Preview"), Paragraph::new("...")).id("preview");
+/// if files
+///     .render_interactive(left, frame.buffer_mut(), input)
+///     .clicked
+/// {
+///     // move the focus to the files pane
+/// }
+/// preview.render_interactive(right, frame.buffer_mut(), input);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Focusable<'a, W> {
+    block: Block<'a>,
+    widget: W,
+    id: Option<WidgetId>,
+    focused_border_style: Style,
+    focused_border_type: BorderType,
+}
+
+impl<W: Default> Default for Focusable<'_, W> {
+    fn default() -> Self {
+        Self::new(Block::default(), W::default())
+    }
+}
+
+impl<'a, W> Focusable<'a, W> {
+    /// Creates a new pane rendering the widget inside the block.
+    ///
+    /// By default the borders of a focused pane are thick and bold.
+    pub const fn new(block: Block<'a>, widget: W) -> Self {
+        Self {
+            block,
+            widget,
+            id: None,
+            focused_border_style: Style::new().add_modifier(Modifier::BOLD),
+            focused_border_type: BorderType::Thick,
+        }
+    }
+
+    /// Sets the id of the pane, which is used to tell whether it has the keyboard focus.
+    ///
+    /// A pane without an id is never focused.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn id<I: Into<WidgetId>>(mut self, id: I) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the style added to the borders when the pane has the keyboard focus.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_border_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_border_style = style.into();
+        self
+    }
+
+    /// Sets the type of the borders when the pane has the keyboard focus.
+    ///
+    /// Use the border type of the block to only change the style of the borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused_border_type(mut self, border_type: BorderType) -> Self {
+        self.focused_border_type = border_type;
+        self
+    }
+
+    /// Returns the area that the wrapped widget is rendered into.
+    pub fn inner(&self, area: Rect) -> Rect {
+        self.block.inner(area)
+    }
+
+    /// Returns the block drawn around the widget for the given response.
+    fn block(&self, response: Response) -> Block<'a> {
+        if response.focused {
+            self.block
+                .clone()
+                .border_type(self.focused_border_type)
+                .patch_border_style(self.focused_border_style)
+        } else {
+            self.block.clone()
+        }
+    }
+}
+
+impl<W: Widget> Widget for Focusable<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_interactive(area, buf, &InputSnapshot::new());
+    }
+}
+
+impl<W> Widget for &Focusable<'_, W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_interactive(area, buf, &InputSnapshot::new());
+    }
+}
+
+impl<W: Widget> InteractiveWidget for Focusable<'_, W> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        let response = input.response(self.id, area);
+        self.block(response).render(area, buf);
+        self.widget.render(self.block.inner(area), buf);
+        response
+    }
+}
+
+impl<W> InteractiveWidget for &Focusable<'_, W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        let response = input.response(self.id, area);
+        self.block(response).render(area, buf);
+        self.widget.render(self.block.inner(area), buf);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{style::Stylize, text::Line};
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        Focusable::new(Block::bordered(), Line::raw("text"))
+            .id("pane")
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["┌────┐", "│text│", "└────┘"]));
+    }
+
+    #[test]
+    fn render_focused() {
+        let pane = Focusable::new(Block::bordered().red(), Line::raw("text"))
+            .id("pane")
+            .focused_border_style(Style::new().yellow());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let input = InputSnapshot::new().focus("pane").click((2, 1));
+        let response = (&pane).render_interactive(buf.area, &mut buf, &input);
+        assert_eq!(
+            response,
+            Response {
+                clicked: true,
+                hovered: true,
+                focused: true,
+            }
+        );
+        let mut expected = Buffer::with_lines(["┏━━━━┓", "┃text┃", "┗━━━━┛"]);
+        expected.set_style(expected.area, Style::new().red());
+        for position in [(0, 0), (5, 0), (0, 1), (5, 1), (0, 2), (5, 2)] {
+            expected[position].set_style(Style::new().yellow());
+        }
+        for x in 1..5 {
+            expected[(x, 0)].set_style(Style::new().yellow());
+            expected[(x, 2)].set_style(Style::new().yellow());
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_other_focused() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let input = InputSnapshot::new().focus("other");
+        let response = Focusable::new(Block::bordered(), Line::raw("text"))
+            .id("pane")
+            .render_interactive(buf.area, &mut buf, &input);
+        assert!(!response.focused);
+        assert_eq!(buf, Buffer::with_lines(["┌────┐", "│text│", "└────┘"]));
+    }
+}
@@ -0,0 +1,187 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    terminal::WidgetId,
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::interact::{InputSnapshot, InteractiveWidget, Response};
+
+/// A piece of underlined text that reports when it is clicked.
+///
+/// The text is rendered on the first row of the area. Only the cells covered by the text react
+/// to the mouse, so a link can be given the rest of a line without making it clickable.
+///
+/// See the [`interact`](crate::interact) module for how to use it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{InputSnapshot, InteractiveWidget, Link},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, input: &InputSnapshot) {
+/// let link = Link::new("Show details").id("details");
+/// if link
+///     .render_interactive(frame.area(), frame.buffer_mut(), input)
+///     .clicked
+/// {
+///     // open the details view
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Link<'a> {
+    text: Line<'a>,
+    id: Option<WidgetId>,
+    style: Style,
+    hovered_style: Style,
+    focused_style: Style,
+}
+
+impl<'a> Link<'a> {
+    /// Creates a new link with the given text.
+    ///
+    /// By default the link is underlined, shown in bold when hovered and reversed when focused.
+    pub fn new<T: Into<Line<'a>>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            id: None,
+            style: Style::new().add_modifier(Modifier::UNDERLINED),
+            hovered_style: Style::new().add_modifier(Modifier::BOLD),
+            focused_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Sets the id of the link, which is used to tell whether it has the keyboard focus.
+    ///
+    /// A link without an id can only be clicked with the mouse.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn id<I: Into<WidgetId>>(mut self, id: I) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the style of the link.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style added to the link when the mouse is over it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hovered_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.hovered_style = style.into();
+        self
+    }
+
+    /// Sets the style added to the link when it has the keyboard focus.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_style = style.into();
+        self
+    }
+
+    /// Returns the cells covered by the text of the link in the given area.
+    fn text_area(&self, area: Rect) -> Rect {
+        let width = u16::try_from(self.text.width()).unwrap_or(u16::MAX);
+        Rect {
+            width: width.min(area.width),
+            height: area.height.min(1),
+            ..area
+        }
+    }
+
+    fn render_with_response(&self, area: Rect, buf: &mut Buffer, response: Response) {
+        let area = self.text_area(area).intersection(buf.area);
+        let mut style = self.style;
+        if response.hovered {
+            style = style.patch(self.hovered_style);
+        }
+        if response.focused {
+            style = style.patch(self.focused_style);
+        }
+        buf.set_style(area, style);
+        (&self.text).render(area, buf);
+    }
+}
+
+impl Widget for Link<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Link<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_response(area, buf, Response::default());
+    }
+}
+
+impl InteractiveWidget for Link<'_> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        (&self).render_interactive(area, buf, input)
+    }
+}
+
+impl InteractiveWidget for &Link<'_> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        let response = input.response(self.id, self.text_area(area));
+        self.render_with_response(area, buf, response);
+        response
+    }
+}
+
+impl Styled for Link<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        Link::new("docs").render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["docs  ", "      "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().underlined());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_interactive() {
+        let link = Link::new("docs");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+
+        let input = InputSnapshot::new().click((3, 0));
+        let response = link.clone().render_interactive(buf.area, &mut buf, &input);
+        assert!(response.clicked);
+        assert!(response.hovered);
+        assert_eq!(buf[(0, 0)].modifier, Modifier::UNDERLINED | Modifier::BOLD);
+
+        let input = InputSnapshot::new().click((4, 0));
+        let response = link.render_interactive(buf.area, &mut buf, &input);
+        assert_eq!(response, Response::default());
+    }
+}
@@ -0,0 +1,255 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    terminal::WidgetId,
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::interact::{InputSnapshot, InteractiveWidget, Response};
+
+/// A checkbox with a label that reports when it is clicked.
+///
+/// The toggle is rendered on the first row of the area as its symbol (`[x]` when on, `[ ]` when
+/// off) followed by the label. The toggle does not change its own value, the application flips
+/// it when the [`Response`] is clicked. Only the cells covered by the symbol and the label react
+/// to the mouse.
+///
+/// See the [`interact`](crate::interact) module for how to use it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{InputSnapshot, InteractiveWidget, Toggle},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, input: &InputSnapshot, wrap: &mut bool) {
+/// let toggle = Toggle::new("Wrap lines", *wrap).id("wrap");
+/// if toggle
+///     .render_interactive(frame.area(), frame.buffer_mut(), input)
+///     .clicked
+/// {
+///     *wrap = !*wrap;
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Toggle<'a> {
+    label: Line<'a>,
+    on: bool,
+    id: Option<WidgetId>,
+    on_symbol: &'a str,
+    off_symbol: &'a str,
+    style: Style,
+    hovered_style: Style,
+    focused_style: Style,
+}
+
+impl Default for Toggle<'_> {
+    fn default() -> Self {
+        Self::new("", false)
+    }
+}
+
+impl<'a> Toggle<'a> {
+    /// Creates a new toggle with the given label and value.
+    ///
+    /// By default the toggle is shown in bold when hovered and reversed when focused.
+    pub fn new<T: Into<Line<'a>>>(label: T, on: bool) -> Self {
+        Self {
+            label: label.into(),
+            on,
+            id: None,
+            on_symbol: "[x]",
+            off_symbol: "[ ]",
+            style: Style::new(),
+            hovered_style: Style::new().add_modifier(Modifier::BOLD),
+            focused_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Sets the value shown by the toggle.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn on(mut self, on: bool) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Sets the id of the toggle, which is used to tell whether it has the keyboard focus.
+    ///
+    /// A toggle without an id can only be clicked with the mouse.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn id<I: Into<WidgetId>>(mut self, id: I) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the symbols shown when the toggle is on and off, e.g. `"◉"` and `"○"`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbols(mut self, on: &'a str, off: &'a str) -> Self {
+        self.on_symbol = on;
+        self.off_symbol = off;
+        self
+    }
+
+    /// Sets the style of the toggle.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style added to the toggle when the mouse is over it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hovered_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.hovered_style = style.into();
+        self
+    }
+
+    /// Sets the style added to the toggle when it has the keyboard focus.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_style = style.into();
+        self
+    }
+
+    const fn symbol(&self) -> &'a str {
+        if self.on {
+            self.on_symbol
+        } else {
+            self.off_symbol
+        }
+    }
+
+    /// Returns the cells covered by the symbol and the label in the given area.
+    fn content_area(&self, area: Rect) -> Rect {
+        let width = Line::raw(self.symbol()).width() + 1 + self.label.width();
+        Rect {
+            width: u16::try_from(width).unwrap_or(u16::MAX).min(area.width),
+            height: area.height.min(1),
+            ..area
+        }
+    }
+
+    fn render_with_response(&self, area: Rect, buf: &mut Buffer, response: Response) {
+        let area = self.content_area(area).intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let mut style = self.style;
+        if response.hovered {
+            style = style.patch(self.hovered_style);
+        }
+        if response.focused {
+            style = style.patch(self.focused_style);
+        }
+        buf.set_style(area, style);
+        let (x, _) = buf.set_stringn(
+            area.x,
+            area.y,
+            self.symbol(),
+            area.width as usize,
+            Style::new(),
+        );
+        let label_area = Rect {
+            x: x + 1,
+            width: area.right().saturating_sub(x + 1),
+            ..area
+        };
+        (&self.label).render(label_area, buf);
+    }
+}
+
+impl Widget for Toggle<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Toggle<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_response(area, buf, Response::default());
+    }
+}
+
+impl InteractiveWidget for Toggle<'_> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        (&self).render_interactive(area, buf, input)
+    }
+}
+
+impl InteractiveWidget for &Toggle<'_> {
+    fn render_interactive(self, area: Rect, buf: &mut Buffer, input: &InputSnapshot) -> Response {
+        let response = input.response(self.id, self.content_area(area));
+        self.render_with_response(area, buf, response);
+        response
+    }
+}
+
+impl Styled for Toggle<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Toggle::new("Wrap", false).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["[ ] Wrap    "]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Toggle::new("Wrap", true)
+            .symbols("◉", "○")
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["◉ Wrap      "]));
+    }
+
+    #[test]
+    fn render_truncated() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Toggle::new("Wrap", true).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["[x] Wr"]));
+    }
+
+    #[test]
+    fn render_interactive() {
+        let toggle = Toggle::new("Wrap", false).id("wrap");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+
+        let input = InputSnapshot::new().click((9, 0));
+        let response = (&toggle).render_interactive(buf.area, &mut buf, &input);
+        assert_eq!(response, Response::default());
+
+        let input = InputSnapshot::new().click((7, 0)).focus("wrap");
+        let response = toggle.render_interactive(buf.area, &mut buf, &input);
+        assert_eq!(
+            response,
+            Response {
+                clicked: true,
+                hovered: true,
+                focused: true,
+            }
+        );
+        assert_eq!(buf[(0, 0)].modifier, Modifier::BOLD | Modifier::REVERSED);
+        assert_eq!(buf[(8, 0)].modifier, Modifier::empty());
+    }
+}
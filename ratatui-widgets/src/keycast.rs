@@ -0,0 +1,417 @@
+//! The [`Keycast`] widget shows the keys that were recently pressed.
+use std::{collections::VecDeque, time::Duration};
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style, Styled},
+    widgets::{StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::block::{Block, BlockExt};
+
+/// A widget that shows the keys that were recently pressed, e.g. for demos, screencasts and
+/// teaching tools.
+///
+/// The keys are stored in a [`KeycastState`]: push the label of each key that is pressed, and
+/// advance the state by the time that elapsed between frames. Each key is shown for the
+/// [`hold`](Keycast::hold) duration, then fades out over the [`fade`](Keycast::fade) duration and
+/// is removed. Pressing the same key several times in a row shows it once with a count, e.g.
+/// `j ×3`.
+///
+/// The keys are shown on the first line of the area, the newest key last. When they do not all
+/// fit, the oldest keys are left out.
+///
+/// With the `crossterm` feature of the `ratatui` crate, `Event::key_label` returns the label of
+/// the key pressed in an event.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     widgets::{Keycast, KeycastState},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, state: &mut KeycastState) {
+/// // in the event handler
+/// state.push("Ctrl+S");
+///
+/// // on every frame
+/// state.advance(Duration::from_millis(16));
+/// let keycast = Keycast::new().hold(Duration::from_secs(2));
+/// frame.render_stateful_widget(keycast, frame.area(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Keycast<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    key_style: Style,
+    alignment: Alignment,
+    hold: Duration,
+    fade: Duration,
+}
+
+impl Default for Keycast<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            key_style: Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            alignment: Alignment::Right,
+            hold: Duration::from_secs(1),
+            fade: Duration::from_millis(500),
+        }
+    }
+}
+
+impl<'a> Keycast<'a> {
+    /// Creates a new `Keycast` with the default settings.
+    ///
+    /// The keys are bold and reversed, aligned to the right, shown for one second and faded out
+    /// over half a second.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps the keys with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the keys.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    /// Sets the alignment of the keys within the area.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets how long a key is shown before it starts fading out.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hold(mut self, hold: Duration) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    /// Sets how long a key takes to fade out once its [`hold`](Keycast::hold) duration is over.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fade(mut self, fade: Duration) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Returns how far a key of the given age has faded out, from `0.0` to `1.0`.
+    fn fade_factor(&self, age: Duration) -> f64 {
+        let Some(fading) = age.checked_sub(self.hold) else {
+            return 0.0;
+        };
+        if self.fade.is_zero() {
+            1.0
+        } else {
+            (fading.as_secs_f64() / self.fade.as_secs_f64()).min(1.0)
+        }
+    }
+}
+
+impl Styled for Keycast<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl StatefulWidget for Keycast<'_> {
+    type State = KeycastState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Keycast<'_> {
+    type State = KeycastState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        state.prune(self.hold.saturating_add(self.fade));
+        if area.is_empty() {
+            return;
+        }
+
+        // take the newest keys that fit, separated by a space
+        let mut keys = Vec::new();
+        let mut width = 0;
+        for key in state.keys.iter().rev() {
+            let label = key.label();
+            let key_width = u16::try_from(label.width()).unwrap_or(u16::MAX);
+            let needed = if keys.is_empty() {
+                key_width
+            } else {
+                key_width.saturating_add(1)
+            };
+            if width + u32::from(needed) > u32::from(area.width) {
+                break;
+            }
+            width += u32::from(needed);
+            keys.push((label, key_width, key.age));
+        }
+
+        let width = u16::try_from(width).unwrap_or(area.width);
+        let mut x = area.x
+            + match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (area.width - width) / 2,
+                Alignment::Right => area.width - width,
+            };
+        for (label, key_width, age) in keys.into_iter().rev() {
+            buf.set_stringn(x, area.y, label, usize::from(key_width), self.key_style);
+            buf.dim(Rect::new(x, area.y, key_width, 1), self.fade_factor(age));
+            x = x.saturating_add(key_width + 1);
+        }
+    }
+}
+
+/// State of a [`Keycast`] widget
+///
+/// The state holds the keys that were recently pressed, at most
+/// [`capacity`](KeycastState::capacity) of them. The keys are labels such as `"q"` or `"Ctrl+C"`.
+/// Keys are removed when the [`Keycast`] is rendered after they have faded out.
+///
+/// The state does not read the clock: call [`advance`](KeycastState::advance) with the time that
+/// elapsed since the previous frame, e.g. the tick rate of the application.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeycastState {
+    keys: VecDeque<Keystroke>,
+    capacity: usize,
+}
+
+/// A key shown by a [`Keycast`], with the number of times it was pressed in a row.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Keystroke {
+    label: String,
+    count: usize,
+    age: Duration,
+}
+
+impl Keystroke {
+    /// Returns the text shown for the key, padded with a space on each side.
+    fn label(&self) -> String {
+        if self.count > 1 {
+            format!(" {} ×{} ", self.label, self.count)
+        } else {
+            format!(" {} ", self.label)
+        }
+    }
+}
+
+impl Default for KeycastState {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl KeycastState {
+    /// The capacity of a default state.
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// Creates an empty state that holds at most `capacity` keys.
+    ///
+    /// A state holds at least one key, so `0` is treated as `1`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            keys: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the maximum number of keys of the state.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds a key that was just pressed, dropping the oldest key if the state is full.
+    ///
+    /// If the newest key has the same label, its count is incremented and it is shown again from
+    /// the start instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::KeycastState;
+    ///
+    /// let mut state = KeycastState::default();
+    /// state.push("j");
+    /// state.push("j");
+    /// state.push("k");
+    /// assert_eq!(state.len(), 2);
+    /// ```
+    pub fn push<S: Into<String>>(&mut self, label: S) {
+        let label = label.into();
+        if let Some(last) = self.keys.back_mut().filter(|key| key.label == label) {
+            last.count += 1;
+            last.age = Duration::ZERO;
+            return;
+        }
+        if self.keys.len() == self.capacity {
+            self.keys.pop_front();
+        }
+        self.keys.push_back(Keystroke {
+            label,
+            count: 1,
+            age: Duration::ZERO,
+        });
+    }
+
+    /// Ages all the keys by the given duration.
+    pub fn advance(&mut self, elapsed: Duration) {
+        for key in &mut self.keys {
+            key.age = key.age.saturating_add(elapsed);
+        }
+    }
+
+    /// Returns the number of keys in the state.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the state has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Removes all the keys.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    /// Removes the keys that are older than the given lifetime.
+    fn prune(&mut self, lifetime: Duration) {
+        self.keys.retain(|key| key.age < lifetime);
+    }
+}
+
+impl<S: Into<String>> Extend<S> for KeycastState {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        for label in iter {
+            self.push(label);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Color;
+
+    use super::*;
+
+    fn render(keycast: &Keycast, state: &mut KeycastState, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(keycast, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_keys() {
+        let keycast = Keycast::new().key_style(Style::new());
+        let mut state = KeycastState::default();
+        state.extend(["Ctrl+S", "j", "j", "j"]);
+        assert_eq!(
+            render(&keycast, &mut state, 20),
+            Buffer::with_lines(["      Ctrl+S   j ×3 "])
+        );
+
+        let keycast = keycast.alignment(Alignment::Left);
+        assert_eq!(
+            render(&keycast, &mut state, 20),
+            Buffer::with_lines([" Ctrl+S   j ×3      "])
+        );
+    }
+
+    #[test]
+    fn render_leaves_out_oldest_keys() {
+        let keycast = Keycast::new().key_style(Style::new());
+        let mut state = KeycastState::default();
+        state.extend(["Esc", "a", "b"]);
+        assert_eq!(
+            render(&keycast, &mut state, 8),
+            Buffer::with_lines(["  a   b "])
+        );
+        assert_eq!(state.len(), 3);
+    }
+
+    #[test]
+    fn fade_out() {
+        let keycast = Keycast::new()
+            .key_style(Color::Rgb(200, 200, 200))
+            .hold(Duration::from_secs(1))
+            .fade(Duration::from_secs(1));
+        let mut state = KeycastState::default();
+        state.push("q");
+
+        state.advance(Duration::from_secs(1));
+        let buf = render(&keycast, &mut state, 3);
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(200, 200, 200));
+
+        state.advance(Duration::from_millis(500));
+        let buf = render(&keycast, &mut state, 3);
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(100, 100, 100));
+
+        state.advance(Duration::from_millis(500));
+        let buf = render(&keycast, &mut state, 3);
+        assert_eq!(buf, Buffer::with_lines(["   "]));
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn repeated_key_restarts() {
+        let mut state = KeycastState::new(2);
+        state.push("a");
+        state.advance(Duration::from_secs(1));
+        state.push("a");
+        assert_eq!(state.keys[0].age, Duration::ZERO);
+        assert_eq!(state.keys[0].count, 2);
+
+        state.extend(["b", "c"]);
+        assert_eq!(state.len(), 2);
+        assert_eq!(state.keys[0].label, "b");
+    }
+}
@@ -33,39 +33,87 @@
 //!
 //! # Available Widgets
 //!
+//! - [`Align`]: positions another widget of a given size within a larger area.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles, and styles.
+//! - [`Button`]: a button that reports when it is clicked.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
+//! - [`CardGrid`]: lays out cards of different heights in columns, like a masonry wall.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
+//! - [`ChatView`]: displays a conversation as message bubbles.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`ConfirmDialog`]: asks the user to confirm an action.
+//! - [`CpuBars`]: displays the usage of each CPU core as a bar, like `htop`.
+//! - [`DataView`]: displays a JSON or YAML value as a collapsible tree.
+//! - [`Either`]: renders one of two widgets of different types.
+//! - [`Focusable`]: a block around another widget whose borders change when it is focused.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`HintBar`]: shows the actions that are available in the current context.
+//! - [`Keycast`]: shows the keys that were recently pressed, fading them out.
 //! - [`LineGauge`]: displays progress as a line.
+//! - [`Link`]: underlined text that reports when it is clicked.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`MemoryGauge`]: displays the memory in use as a gauge.
+//! - [`NetworkSparklines`]: displays the received and transmitted network traffic as sparklines.
+//! - [`Padded`]: renders another widget inside a padded area.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PromptDialog`]: asks the user to enter a value.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`Scrollbars`]: displays a vertical and a horizontal scrollbar sharing a corner.
+//! - [`SearchBar`]: an input field to search the content of other widgets.
+//! - [`Skeleton`]: displays shimmering placeholder lines while content is loading.
+//! - [`Spacer`]: fills the space between other widgets, optionally drawing a divider.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
+//! - [`Stack`]: renders several widgets on top of each other in the same area.
+//! - [`Switch`]: renders one of several widgets of the same type, chosen by index.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Toggle`]: a checkbox with a label that reports when it is clicked.
+//! - [`Transition`]: animates the change from one widget to another.
 //!
+//! [`Align`]: crate::align::Align
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
+//! [`Button`]: crate::interact::Button
 //! [`calendar::Monthly`]: crate::calendar::Monthly
 //! [`Canvas`]: crate::canvas::Canvas
+//! [`CardGrid`]: crate::card_grid::CardGrid
 //! [`Chart`]: crate::chart::Chart
+//! [`ChatView`]: crate::chat_view::ChatView
 //! [`Clear`]: crate::clear::Clear
+//! [`ConfirmDialog`]: crate::dialog::ConfirmDialog
+//! [`CpuBars`]: crate::metrics::CpuBars
+//! [`DataView`]: crate::data_view::DataView
+//! [`Either`]: crate::either::Either
+//! [`Focusable`]: crate::interact::Focusable
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`HintBar`]: crate::hint_bar::HintBar
+//! [`Keycast`]: crate::keycast::Keycast
 //! [`LineGauge`]: crate::gauge::LineGauge
+//! [`Link`]: crate::interact::Link
 //! [`List`]: crate::list::List
+//! [`MemoryGauge`]: crate::metrics::MemoryGauge
+//! [`NetworkSparklines`]: crate::metrics::NetworkSparklines
+//! [`Padded`]: crate::padded::Padded
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
 //! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`PromptDialog`]: crate::dialog::PromptDialog
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
+//! [`Scrollbars`]: crate::scrollbar::Scrollbars
+//! [`SearchBar`]: crate::search_bar::SearchBar
+//! [`Skeleton`]: crate::skeleton::Skeleton
+//! [`Spacer`]: crate::spacer::Spacer
 //! [`Sparkline`]: crate::sparkline::Sparkline
+//! [`Stack`]: crate::stack::Stack
+//! [`Switch`]: crate::switch::Switch
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`Toggle`]: crate::interact::Toggle
+//! [`Transition`]: crate::transition::Transition
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
@@ -79,23 +127,52 @@
 //! # License
 //!
 //! This project is licensed under the MIT License. See the [LICENSE](../LICENSE) file for details.
-pub mod barchart;
+pub mod align;
 pub mod block;
 pub mod borders;
-pub mod canvas;
-pub mod chart;
+pub mod card_grid;
+pub mod chat_view;
 pub mod clear;
-pub mod gauge;
+pub mod dialog;
+pub mod either;
+pub mod hint_bar;
+pub mod interact;
+pub mod keycast;
 pub mod list;
 pub mod logo;
 pub mod mascot;
+pub mod padded;
 pub mod paragraph;
 pub mod scrollbar;
-pub mod sparkline;
-pub mod table;
-pub mod tabs;
+pub mod search_bar;
+pub mod skeleton;
+pub mod spacer;
+pub mod stack;
+pub mod switch;
+pub mod transition;
 
+mod highlight_spacing;
 mod reflow;
 
+#[cfg(feature = "barchart")]
+pub mod barchart;
 #[cfg(feature = "calendar")]
 pub mod calendar;
+#[cfg(feature = "canvas")]
+pub mod canvas;
+#[cfg(feature = "chart")]
+pub mod chart;
+#[cfg(feature = "data-view")]
+pub mod data_view;
+#[cfg(feature = "gauge")]
+pub mod gauge;
+#[cfg(feature = "histogram")]
+pub mod histogram;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "sparkline")]
+pub mod sparkline;
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "tabs")]
+pub mod tabs;
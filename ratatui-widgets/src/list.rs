@@ -1,10 +1,14 @@
 //! The [`List`] widget is used to display a list of items and allows selecting one or multiple
 //! items.
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::{
+    style::{Style, Styled},
+    text::Text,
+};
 use strum::{Display, EnumString};
 
 pub use self::{item::ListItem, state::ListState};
-use crate::{block::Block, table::HighlightSpacing};
+use crate::block::Block;
+pub use crate::highlight_spacing::HighlightSpacing;
 
 mod item;
 mod rendering;
@@ -123,6 +127,10 @@ pub struct List<'a> {
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// Text displayed when the list has no items
+    pub(crate) empty_text: Option<Text<'a>>,
+    /// Whether to display a skeleton instead of the items
+    pub(crate) loading: bool,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -419,6 +427,49 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets the text displayed when the list has no items
+    ///
+    /// The text is centered in the list area, so that messages such as "No results" don't require
+    /// a separate branch in the application's rendering code.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new(Vec::<ListItem>::new()).empty_text("No results");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn empty_text<T: Into<Text<'a>>>(mut self, empty_text: T) -> Self {
+        self.empty_text = Some(empty_text.into());
+        self
+    }
+
+    /// Sets whether the list is loading
+    ///
+    /// While loading, a [`Skeleton`] is rendered in place of the items. Render a [`Skeleton`]
+    /// directly to animate it or to customize its appearance.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// # let is_fetching = true;
+    /// let list = List::new(Vec::<ListItem>::new()).loading(is_fetching);
+    /// ```
+    ///
+    /// [`Skeleton`]: crate::skeleton::Skeleton
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
@@ -6,14 +6,20 @@ use ratatui_core::{
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    block::BlockExt,
-    list::{List, ListDirection, ListState},
+    align::Align,
+    block::{Block, BlockExt},
+    list::{List, ListDirection, ListItem, ListState},
+    skeleton::Skeleton,
 };
 
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
     }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        Widget::size_hint(&self, area)
+    }
 }
 
 impl Widget for &List<'_> {
@@ -21,6 +27,42 @@ impl Widget for &List<'_> {
         let mut state = ListState::default();
         StatefulWidget::render(self, area, buf, &mut state);
     }
+
+    /// Returns the width of the widest item and the total height of the items, including the
+    /// block and, if it is always shown, the space for the highlight symbol.
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        let (left, right) = self
+            .block
+            .as_ref()
+            .map(Block::horizontal_space)
+            .unwrap_or_default();
+        let (top, bottom) = self
+            .block
+            .as_ref()
+            .map(Block::vertical_space)
+            .unwrap_or_default();
+        let symbol_width = if self.highlight_spacing.should_add(false) {
+            self.highlight_symbol.unwrap_or("").width()
+        } else {
+            0
+        };
+        let width = self
+            .items
+            .iter()
+            .map(ListItem::width)
+            .max()
+            .unwrap_or_default()
+            + symbol_width
+            + usize::from(left)
+            + usize::from(right);
+        let height = self.items.iter().map(ListItem::height).sum::<usize>()
+            + usize::from(top)
+            + usize::from(bottom);
+        (
+            u16::try_from(width).unwrap_or(u16::MAX).min(area.width),
+            u16::try_from(height).unwrap_or(u16::MAX).min(area.height),
+        )
+    }
 }
 
 impl StatefulWidget for List<'_> {
@@ -35,6 +77,7 @@ impl StatefulWidget for &List<'_> {
     type State = ListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.near_end = false;
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let list_area = self.block.inner_if_some(area);
@@ -43,8 +86,21 @@ impl StatefulWidget for &List<'_> {
             return;
         }
 
+        if self.loading {
+            Skeleton::new().render(list_area, buf);
+            return;
+        }
+
         if self.items.is_empty() {
             state.select(None);
+            state.near_end = state.near_end_threshold.is_some();
+            if let Some(empty_text) = &self.empty_text {
+                Align::new(empty_text)
+                    .width(u16::try_from(empty_text.width()).unwrap_or(u16::MAX))
+                    .height(u16::try_from(empty_text.height()).unwrap_or(u16::MAX))
+                    .centered()
+                    .render(list_area, buf);
+            }
             return;
         }
 
@@ -60,6 +116,9 @@ impl StatefulWidget for &List<'_> {
 
         // Important: this changes the state's offset to be the beginning of the now viewable items
         state.offset = first_visible_index;
+        state.near_end = state
+            .near_end_threshold
+            .is_some_and(|threshold| last_visible_index + threshold >= self.items.len());
 
         // Get our set highlighted symbol (if one was set)
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
@@ -275,7 +334,10 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
-    use crate::{block::Block, list::ListItem, table::HighlightSpacing};
+    use crate::{
+        block::Block,
+        list::{HighlightSpacing, ListItem},
+    };
 
     #[fixture]
     fn single_line_buf() -> Buffer {
@@ -293,6 +355,69 @@ mod tests {
         assert_eq!(state.selected, None);
     }
 
+    #[test]
+    fn size_hint() {
+        let list = List::new([ListItem::new("one"), ListItem::new("two\nthree")])
+            .highlight_symbol(">>")
+            .highlight_spacing(HighlightSpacing::Always)
+            .block(Block::bordered());
+        assert_eq!(list.size_hint(Rect::new(0, 0, 20, 20)), (9, 5));
+        assert_eq!(list.size_hint(Rect::new(0, 0, 5, 4)), (5, 4));
+    }
+
+    #[test]
+    fn empty_list_with_empty_text() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        let list = List::new(Vec::<ListItem>::new()).empty_text("Nothing");
+        Widget::render(list, buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["          ", " Nothing  ", "          "])
+        );
+    }
+
+    #[test]
+    fn loading() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let list = List::new(["Item 1", "Item 2"]).loading(true);
+        Widget::render(list, buf.area, &mut buf);
+        let mut expected = Buffer::empty(buf.area);
+        Skeleton::new().render(expected.area, &mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::top(None, Some(2), false)]
+    #[case::near_end(Some(5), Some(2), false)]
+    #[case::within_threshold(Some(7), Some(2), true)]
+    #[case::last(Some(9), Some(0), true)]
+    #[case::no_threshold(Some(9), None, false)]
+    fn near_end(
+        #[case] selected: Option<usize>,
+        #[case] threshold: Option<usize>,
+        #[case] expected: bool,
+    ) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let list = List::new((0..10).map(|i| i.to_string()));
+        let mut state = ListState::default()
+            .with_selected(selected)
+            .with_near_end_threshold(threshold);
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.is_near_end(), expected);
+    }
+
+    #[test]
+    fn near_end_empty_and_loading() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let mut state = ListState::default().with_near_end_threshold(Some(2));
+        StatefulWidget::render(List::default(), buf.area, &mut buf, &mut state);
+        assert!(state.is_near_end());
+
+        let list = List::new(["Item 1"]).loading(true);
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert!(!state.is_near_end());
+    }
+
     #[rstest]
     fn single_item(mut single_line_buf: Buffer) {
         let mut state = ListState::default();
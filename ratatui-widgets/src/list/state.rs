@@ -12,6 +12,14 @@
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
 ///
+/// For lists that load their items lazily (e.g. one page of search results at a time), a
+/// [`near_end_threshold`] can be set. Rendering then records whether the last visible item is
+/// within that many items of the end of the list, which the application checks with
+/// [`is_near_end`] to fetch the next page before the user reaches the end.
+///
+/// [`near_end_threshold`]: ListState::with_near_end_threshold()
+/// [`is_near_end`]: ListState::is_near_end()
+///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
 ///
@@ -47,6 +55,10 @@
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) near_end_threshold: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) near_end: bool,
 }
 
 impl ListState {
@@ -270,6 +282,83 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Sets the number of items before the end of the list at which the list is near its end
+    ///
+    /// See [`ListState::is_near_end`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_near_end_threshold(Some(5));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_near_end_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.near_end_threshold = threshold;
+        self
+    }
+
+    /// Sets the number of items before the end of the list at which the list is near its end
+    ///
+    /// Set to `None` to disable the check. See [`ListState::is_near_end`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_near_end_threshold(Some(5));
+    /// ```
+    pub fn set_near_end_threshold(&mut self, threshold: Option<usize>) {
+        self.near_end_threshold = threshold;
+    }
+
+    /// Number of items before the end of the list at which the list is near its end
+    ///
+    /// Returns `None` if no threshold is set.
+    pub const fn near_end_threshold(&self) -> Option<usize> {
+        self.near_end_threshold
+    }
+
+    /// Whether the last rendered item was within the threshold of the end of the list
+    ///
+    /// This is updated when the list is rendered: it is `true` if a [`near_end_threshold`] is set
+    /// and at most that many items follow the last visible item (or if the list is empty), and
+    /// `false` otherwise, including while the list is [loading]. Applications use it to load the
+    /// next items lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{List, ListState, StatefulWidget},
+    /// };
+    ///
+    /// let mut items: Vec<String> = (0..10).map(|i| format!("Item {i}")).collect();
+    /// let mut state = ListState::default().with_near_end_threshold(Some(2));
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let mut buf = Buffer::empty(area);
+    ///
+    /// state.select(Some(7));
+    /// List::new(items.clone()).render(area, &mut buf, &mut state);
+    /// if state.is_near_end() {
+    ///     items.extend((10..20).map(|i| format!("Item {i}")));
+    /// }
+    /// assert_eq!(items.len(), 20);
+    /// ```
+    ///
+    /// [`near_end_threshold`]: ListState::with_near_end_threshold
+    /// [loading]: crate::list::List::loading
+    pub const fn is_near_end(&self) -> bool {
+        self.near_end
+    }
 }
 
 #[cfg(test)]
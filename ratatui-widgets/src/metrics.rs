@@ -0,0 +1,79 @@
+//! The [`CpuBars`], [`MemoryGauge`] and [`NetworkSparklines`] widgets display the usage of the
+//! resources of a system, to build dashboards like `htop`.
+//!
+//! The widgets take plain numbers, e.g. percentages, byte counts and byte rates, so they work with
+//! whichever crate or file the metrics are read from. They are styled out of the box, and their
+//! styles can be changed with their builder methods.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{
+//!     layout::{Constraint, Layout},
+//!     widgets::metrics::{CpuBars, MemoryGauge, NetworkSparklines},
+//!     Frame,
+//! };
+//!
+//! # fn draw(frame: &mut Frame) {
+//! let [cpu, memory, network] = Layout::vertical([
+//!     Constraint::Length(4),
+//!     Constraint::Length(1),
+//!     Constraint::Fill(1),
+//! ])
+//! .areas(frame.area());
+//! frame.render_widget(CpuBars::new([12.5, 80.0, 47.3, 3.0]), cpu);
+//! frame.render_widget(MemoryGauge::new(3 << 30, 16 << 30), memory);
+//! frame.render_widget(
+//!     NetworkSparklines::new(&[800, 1200, 300], &[100, 90, 2000]),
+//!     network,
+//! );
+//! # }
+//! ```
+pub use self::{cpu::CpuBars, memory::MemoryGauge, network::NetworkSparklines};
+
+mod cpu;
+mod memory;
+mod network;
+
+/// Formats a number of bytes with a binary unit and one decimal, e.g. `512B`, `1.5K` or `3.2G`.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::metrics::format_bytes;
+///
+/// assert_eq!(format_bytes(512), "512B");
+/// assert_eq!(format_bytes(1536), "1.5K");
+/// assert_eq!(format_bytes(3 << 30), "3.0G");
+/// ```
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1}{}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero(0, "0B")]
+    #[case::bytes(1023, "1023B")]
+    #[case::kibibytes(1024, "1.0K")]
+    #[case::mebibytes(5 << 20 | 1 << 19, "5.5M")]
+    #[case::max(u64::MAX, "16.0E")]
+    fn format(#[case] bytes: u64, #[case] expected: &str) {
+        assert_eq!(format_bytes(bytes), expected);
+    }
+}
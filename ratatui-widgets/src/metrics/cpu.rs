@@ -0,0 +1,275 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Styled},
+    widgets::Widget,
+};
+
+use crate::block::{Block, BlockExt};
+
+/// The usage from which a core is drawn with the medium style, in percent.
+const MEDIUM_USAGE: f64 = 50.0;
+
+/// The usage from which a core is drawn with the high style, in percent.
+const HIGH_USAGE: f64 = 80.0;
+
+/// A widget that displays the usage of each CPU core as a bar, like `htop`.
+///
+/// Each core takes one line, with its index, a bar filled in proportion to its usage and the
+/// usage in percent, e.g. `0 [|||   37.5%]`. The bar is green below 50%, yellow below 80% and red
+/// above, see [`low_style`](Self::low_style), [`medium_style`](Self::medium_style) and
+/// [`high_style`](Self::high_style). The usages are clamped between 0 and 100, and `NaN` is
+/// displayed as 0.
+///
+/// The cores can be laid out in several [`columns`](Self::columns) for machines with many cores.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{metrics::CpuBars, Block},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame) {
+/// let usages = [12.5, 80.0, 47.3, 3.0];
+/// let cpu = CpuBars::new(usages)
+///     .columns(2)
+///     .block(Block::bordered().title("CPU"));
+/// frame.render_widget(cpu, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuBars<'a> {
+    usages: Vec<f64>,
+    block: Option<Block<'a>>,
+    columns: u16,
+    symbol: &'a str,
+    style: Style,
+    label_style: Style,
+    low_style: Style,
+    medium_style: Style,
+    high_style: Style,
+}
+
+impl<'a> CpuBars<'a> {
+    /// Creates new `CpuBars` from the usage of each core, in percent.
+    pub fn new<I>(usages: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        Self {
+            usages: usages.into_iter().collect(),
+            block: None,
+            columns: 1,
+            symbol: "|",
+            style: Style::new(),
+            label_style: Style::new().fg(Color::Cyan),
+            low_style: Style::new().fg(Color::Green),
+            medium_style: Style::new().fg(Color::Yellow),
+            high_style: Style::new().fg(Color::Red),
+        }
+    }
+
+    /// Surrounds the bars with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the number of columns the cores are laid out in.
+    ///
+    /// The cores fill the first column from top to bottom before the next one. Defaults to 1.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.columns = if columns == 0 { 1 } else { columns };
+        self
+    }
+
+    /// Sets the symbol used to fill the bars.
+    ///
+    /// Defaults to `|`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the index of each core.
+    ///
+    /// Defaults to cyan.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Sets the style of the bars of the cores used less than 50%.
+    ///
+    /// Defaults to green.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn low_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.low_style = style.into();
+        self
+    }
+
+    /// Sets the style of the bars of the cores used from 50% and less than 80%.
+    ///
+    /// Defaults to yellow.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn medium_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.medium_style = style.into();
+        self
+    }
+
+    /// Sets the style of the bars of the cores used from 80%.
+    ///
+    /// Defaults to red.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn high_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.high_style = style.into();
+        self
+    }
+
+    fn usage_style(&self, usage: f64) -> Style {
+        if usage >= HIGH_USAGE {
+            self.high_style
+        } else if usage >= MEDIUM_USAGE {
+            self.medium_style
+        } else {
+            self.low_style
+        }
+    }
+
+    fn render_core(&self, index: usize, usage: f64, digits: usize, area: Rect, buf: &mut Buffer) {
+        let usage = if usage.is_nan() {
+            0.0
+        } else {
+            usage.clamp(0.0, 100.0)
+        };
+        let label = format!("{index:>digits$} [");
+        let (x, _) = buf.set_stringn(area.x, area.y, &label, area.width.into(), Style::new());
+        let label_width = u16::try_from(digits).unwrap_or(u16::MAX);
+        buf.set_style(Rect::new(area.x, area.y, label_width, 1), self.label_style);
+        // the bar is between the brackets
+        let width = area.right().saturating_sub(x).saturating_sub(1);
+        if width == 0 {
+            return;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let filled = (usage / 100.0 * f64::from(width)).round() as u16;
+        let style = self.usage_style(usage);
+        for offset in 0..filled {
+            buf[(x + offset, area.y)]
+                .set_symbol(self.symbol)
+                .set_style(style);
+        }
+        let percent = format!("{usage:.1}%");
+        if let Ok(len) = u16::try_from(percent.len()) {
+            if len <= width {
+                buf.set_string(x + width - len, area.y, percent, Style::new());
+            }
+        }
+        buf.set_string(x + width, area.y, "]", Style::new());
+    }
+}
+
+impl Widget for CpuBars<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &CpuBars<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() || self.usages.is_empty() {
+            return;
+        }
+        let digits = (self.usages.len() - 1).to_string().len();
+        let rows = self.usages.len().div_ceil(usize::from(self.columns));
+        let columns = Layout::horizontal(vec![Constraint::Fill(1); usize::from(self.columns)])
+            .spacing(1)
+            .split(area);
+        for (index, &usage) in self.usages.iter().enumerate() {
+            let column = columns[index / rows];
+            let y = column
+                .y
+                .saturating_add(u16::try_from(index % rows).unwrap_or(u16::MAX));
+            if y < column.bottom() {
+                let row = Rect::new(column.x, y, column.width, 1);
+                self.render_core(index, usage, digits, row, buf);
+            }
+        }
+    }
+}
+
+impl Styled for CpuBars<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        Widget::render(CpuBars::new([25.0, 100.0]), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["0 [|| 25.0%]", "1 [||100.0%]"]);
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().cyan());
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().green());
+        expected.set_style(Rect::new(3, 1, 8, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_columns() {
+        let cpu = CpuBars::new([60.0, f64::NAN, 150.0])
+            .columns(2)
+            .label_style(Style::new())
+            .medium_style(Style::new().blue())
+            .high_style(Style::new().bold());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 2));
+        Widget::render(&cpu, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["0 [|60.0%] 2 [100.0%]", "1 [  0.0%]           "]);
+        expected.set_style(Rect::new(3, 0, 4, 1), Style::new().blue());
+        expected.set_style(Rect::new(14, 0, 6, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_narrow() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Widget::render(CpuBars::new([100.0]).style(Color::Gray), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["0 [|]"]);
+        expected.set_style(buf.area, Style::new().gray());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().cyan());
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,187 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Styled},
+    text::Span,
+    widgets::Widget,
+};
+
+use super::format_bytes;
+use crate::{
+    block::{Block, BlockExt},
+    gauge::Gauge,
+};
+
+/// A widget that displays the memory in use as a [`Gauge`].
+///
+/// The gauge is filled in proportion to the used bytes over the total bytes, and labelled with
+/// both amounts, e.g. `3.2G/15.6G`, unless another [`label`](Self::label) is set. The used bytes
+/// are capped at the total. The same widget can display the usage of the swap or of a disk.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{widgets::metrics::MemoryGauge, Frame};
+///
+/// # fn draw(frame: &mut Frame) {
+/// let memory = MemoryGauge::new(3 << 30, 16 << 30);
+/// frame.render_widget(memory, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryGauge<'a> {
+    used: u64,
+    total: u64,
+    label: Option<Span<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    gauge_style: Style,
+}
+
+impl<'a> MemoryGauge<'a> {
+    /// Creates a new `MemoryGauge` from the used and total bytes.
+    pub const fn new(used: u64, total: u64) -> Self {
+        Self {
+            used,
+            total,
+            label: None,
+            block: None,
+            style: Style::new(),
+            gauge_style: Style::new().fg(Color::Green),
+        }
+    }
+
+    /// Sets the label of the gauge, in place of the used and total bytes.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Surrounds the gauge with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the bar of the gauge.
+    ///
+    /// Defaults to green.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gauge_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.gauge_style = style.into();
+        self
+    }
+
+    /// Returns the ratio of the used bytes over the total bytes, between 0 and 1.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            (self.used as f64 / self.total as f64).min(1.0)
+        }
+    }
+}
+
+impl Widget for MemoryGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &MemoryGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        let label = self.label.clone().unwrap_or_else(|| {
+            let used = self.used.min(self.total);
+            Span::raw(format!(
+                "{}/{}",
+                format_bytes(used),
+                format_bytes(self.total)
+            ))
+        });
+        Gauge::default()
+            .ratio(self.ratio())
+            .label(label)
+            .use_unicode(true)
+            .gauge_style(self.gauge_style)
+            .render(area, buf);
+    }
+}
+
+impl Styled for MemoryGauge<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty(0, 0, 0.0)]
+    #[case::quarter(1, 4, 0.25)]
+    #[case::overflow(5, 4, 1.0)]
+    fn ratio(#[case] used: u64, #[case] total: u64, #[case] expected: f64) {
+        assert!((MemoryGauge::new(used, total).ratio() - expected).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 1));
+        Widget::render(MemoryGauge::new(3 << 30, 12 << 30), buf.area, &mut buf);
+        let mut expected = Buffer::empty(buf.area);
+        Gauge::default()
+            .ratio(0.25)
+            .label("3.0G/12.0G")
+            .use_unicode(true)
+            .gauge_style(Color::Green)
+            .render(expected.area, &mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_label() {
+        let memory = MemoryGauge::new(1, 2)
+            .label("swap")
+            .block(Block::bordered())
+            .gauge_style(Color::Blue);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Widget::render(&memory, buf.area, &mut buf);
+        let mut expected = Buffer::empty(buf.area);
+        Block::bordered().render(expected.area, &mut expected);
+        Gauge::default()
+            .ratio(0.5)
+            .label("swap")
+            .use_unicode(true)
+            .gauge_style(Color::Blue)
+            .render(Rect::new(1, 1, 8, 1), &mut expected);
+        assert_eq!(buf, expected);
+    }
+}
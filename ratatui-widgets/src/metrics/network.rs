@@ -0,0 +1,198 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Styled},
+    widgets::Widget,
+};
+
+use super::format_bytes;
+use crate::{
+    block::{Block, BlockExt},
+    sparkline::{RenderDirection, Sparkline},
+};
+
+/// A widget that displays the received and transmitted network traffic as a pair of
+/// [`Sparkline`]s.
+///
+/// The traffic is given as two histories of rates in bytes per second, from the oldest to the most
+/// recent sample. The area is split between the received traffic on top and the transmitted
+/// traffic below. Each half starts with a line showing the most recent rate, e.g. `RX 1.2K/s`,
+/// followed by the sparkline, with the most recent sample on the right. Both sparklines share the
+/// same scale, the largest rate of both histories unless a [`max`](Self::max) is set, so that
+/// they can be compared.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{widgets::metrics::NetworkSparklines, Frame};
+///
+/// # fn draw(frame: &mut Frame) {
+/// let received = [800, 1200, 300, 4000];
+/// let transmitted = [100, 90, 2000, 150];
+/// let network = NetworkSparklines::new(&received, &transmitted);
+/// frame.render_widget(network, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NetworkSparklines<'a> {
+    received: &'a [u64],
+    transmitted: &'a [u64],
+    max: Option<u64>,
+    block: Option<Block<'a>>,
+    style: Style,
+    received_style: Style,
+    transmitted_style: Style,
+}
+
+impl<'a> NetworkSparklines<'a> {
+    /// Creates new `NetworkSparklines` from the histories of the received and transmitted rates,
+    /// in bytes per second.
+    pub const fn new(received: &'a [u64], transmitted: &'a [u64]) -> Self {
+        Self {
+            received,
+            transmitted,
+            max: None,
+            block: None,
+            style: Style::new(),
+            received_style: Style::new().fg(Color::Green),
+            transmitted_style: Style::new().fg(Color::Blue),
+        }
+    }
+
+    /// Sets the rate at the top of both sparklines, in bytes per second.
+    ///
+    /// Defaults to the largest rate of both histories.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max(mut self, max: u64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Surrounds the sparklines with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the received traffic.
+    ///
+    /// Defaults to green.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn received_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.received_style = style.into();
+        self
+    }
+
+    /// Sets the style of the transmitted traffic.
+    ///
+    /// Defaults to blue.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn transmitted_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.transmitted_style = style.into();
+        self
+    }
+
+    fn render_rates(&self, name: &str, rates: &[u64], style: Style, area: Rect, buf: &mut Buffer) {
+        let [label, sparkline] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+        let current = rates.last().copied().unwrap_or_default();
+        let text = format!("{name} {}/s", format_bytes(current));
+        buf.set_stringn(label.x, label.y, text, label.width.into(), style);
+        let max = self.max.unwrap_or_else(|| {
+            let all = self.received.iter().chain(self.transmitted);
+            all.copied().max().unwrap_or_default().max(1)
+        });
+        // the sparkline is drawn from the right, so that the most recent rate is on the right
+        Sparkline::default()
+            .data(rates.iter().rev().copied())
+            .direction(RenderDirection::RightToLeft)
+            .max(max)
+            .style(style)
+            .render(sparkline, buf);
+    }
+}
+
+impl Widget for NetworkSparklines<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &NetworkSparklines<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return;
+        }
+        let [received, transmitted] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+        self.render_rates("RX", self.received, self.received_style, received, buf);
+        let transmitted_style = self.transmitted_style;
+        self.render_rates("TX", self.transmitted, transmitted_style, transmitted, buf);
+    }
+}
+
+impl Styled for NetworkSparklines<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let network = NetworkSparklines::new(&[8, 0, 4, 2048], &[16, 8]).max(16);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        Widget::render(network, buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["RX 2.0K/s ", "      ▄ ▂█", "TX 8B/s   ", "        █▄"]);
+        expected.set_style(Rect::new(0, 0, 9, 1), Style::new().green());
+        expected.set_style(Rect::new(6, 1, 4, 1), Style::new().green());
+        expected.set_style(Rect::new(0, 2, 7, 1), Style::new().blue());
+        expected.set_style(Rect::new(8, 3, 2, 1), Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_shared_scale() {
+        let network = NetworkSparklines::new(&[1, 2], &[4])
+            .received_style(Style::new())
+            .transmitted_style(Style::new());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 6));
+        Widget::render(&network, buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "RX 2B/s ",
+            "        ",
+            "      ▄█",
+            "TX 4B/s ",
+            "       █",
+            "       █",
+        ]);
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,174 @@
+//! The [`Padded`] widget adds padding and an optional background style around another widget.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    widgets::Widget,
+};
+
+use crate::block::Padding;
+
+/// A widget that renders another widget inside a padded area.
+///
+/// `Padded` is a lightweight alternative to wrapping a widget in a borderless [`Block`] just to
+/// add some space around it. The [`style`](Padded::style) is applied to the whole area (including
+/// the padding) before the child is rendered into the remaining inner area. If the padding is
+/// larger than the area, the child is rendered into an empty area.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::{Padded, Padding, Paragraph},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame) {
+/// let paragraph = Paragraph::new("Hello, world!");
+/// let padded = Padded::new(paragraph)
+///     .padding(Padding::horizontal(2))
+///     .style(Style::new().on_blue());
+/// frame.render_widget(padded, frame.area());
+/// # }
+/// ```
+///
+/// [`Block`]: crate::block::Block
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Padded<W> {
+    widget: W,
+    padding: Padding,
+    style: Style,
+}
+
+impl<W> Padded<W> {
+    /// Creates a new `Padded` widget wrapping the given widget with no padding.
+    pub const fn new(widget: W) -> Self {
+        Self {
+            widget,
+            padding: Padding::ZERO,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the padding around the wrapped widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the style of the whole area, including the padding.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns a reference to the wrapped widget.
+    pub const fn widget(&self) -> &W {
+        &self.widget
+    }
+
+    /// Consumes the `Padded` widget and returns the wrapped widget.
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+
+    /// Computes the area that the wrapped widget is rendered into.
+    ///
+    /// This is the same area as inside a [`Block`] with the same padding and no borders, see
+    /// [`Rect::inner_padded`].
+    ///
+    /// [`Block`]: crate::block::Block
+    pub const fn inner(&self, area: Rect) -> Rect {
+        area.inner_padded(self.padding)
+    }
+}
+
+impl<W: Widget> Widget for Padded<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        let inner = self.inner(area);
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<W> Widget for &Padded<W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        let inner = self.inner(area);
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<W> Styled for Padded<W> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::paragraph::Paragraph;
+
+    #[rstest]
+    #[case::zero(Padding::ZERO, Rect::new(1, 2, 10, 5))]
+    #[case::uniform(Padding::uniform(1), Rect::new(2, 3, 8, 3))]
+    #[case::asymmetric(Padding::new(1, 2, 3, 1), Rect::new(2, 5, 7, 1))]
+    #[case::too_wide(Padding::horizontal(6), Rect::new(7, 2, 0, 5))]
+    #[case::too_tall(Padding::vertical(3), Rect::new(1, 5, 10, 0))]
+    fn inner(#[case] padding: Padding, #[case] expected: Rect) {
+        let padded = Padded::new(Paragraph::new("")).padding(padding);
+        assert_eq!(padded.inner(Rect::new(1, 2, 10, 5)), expected);
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Padded::new(Paragraph::new("abcd\nef"))
+            .padding(Padding::new(2, 1, 1, 0))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["       ", "  abcd ", "  ef   "]));
+    }
+
+    #[test]
+    fn render_ref() {
+        let padded = Padded::new(Paragraph::new("abc")).padding(Padding::left(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        (&padded).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" abc "]));
+    }
+
+    #[test]
+    fn render_style() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Padded::new(Paragraph::new("ab"))
+            .padding(Padding::uniform(1))
+            .on_blue()
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["    ", " ab ", "    "]);
+        expected.set_style(expected.area, Style::new().bg(Color::Blue));
+        assert_eq!(buf, expected);
+    }
+}
@@ -5,15 +5,20 @@ use ratatui_core::{
     layout::{Alignment, Position, Rect},
     style::{Style, Styled},
     text::{Line, StyledGrapheme, Text},
-    widgets::Widget,
+    widgets::{StatefulWidget, Widget},
 };
 use unicode_width::UnicodeWidthStr;
 
+pub use self::{gutter::Gutter, state::ParagraphState};
 use crate::{
     block::{Block, BlockExt},
     reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
 };
 
+mod cache;
+mod gutter;
+mod state;
+
 /// A widget to display some text.
 ///
 /// It is used to display a block of text. The text can be styled and aligned. It can also be
@@ -30,7 +35,8 @@ use crate::{
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
 ///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
-/// the [`scroll`] method.
+/// the [`scroll`] method. Alternatively, render the paragraph returned by [`scrollable`] with a
+/// [`ParagraphState`] to scroll to a given line or keep following the bottom of the text.
 ///
 /// The text can be surrounded by a [`Block`] with a title and borders. The block can be configured
 /// with the [`block`] method.
@@ -49,6 +55,7 @@ use crate::{
 /// [`right_aligned`]: Self::right_aligned
 /// [`centered`]: Self::centered
 /// [`scroll`]: Self::scroll
+/// [`scrollable`]: Self::scrollable
 /// [`block`]: Self::block
 /// [`style`]: Self::style
 ///
@@ -93,6 +100,8 @@ pub struct Paragraph<'a> {
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// The gutter to the left of the text
+    gutter: Option<Gutter<'a>>,
 }
 
 /// Describes how to wrap text across lines.
@@ -168,6 +177,7 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            gutter: None,
         }
     }
 
@@ -249,6 +259,47 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the gutter rendered to the left of the text.
+    ///
+    /// The gutter can display line numbers, wrap markers and signs for each line of the text. See
+    /// [`Gutter`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Gutter, Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("first\nsecond").gutter(Gutter::new().line_numbers(true));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn gutter(mut self, gutter: Gutter<'a>) -> Self {
+        self.gutter = Some(gutter);
+        self
+    }
+
+    /// Highlights the matches of the rules of a [`Highlighter`] in the text of the paragraph.
+    ///
+    /// The rules are applied once to each line of the text, and not each time the paragraph is
+    /// rendered. This is useful to color the severity of log lines, for example.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Color, text::Highlighter, widgets::Paragraph};
+    /// use regex::Regex;
+    ///
+    /// let highlighter = Highlighter::new()
+    ///     .rule(Regex::new(r"\bERROR\b").unwrap(), Color::Red)
+    ///     .rule(Regex::new(r"\bWARN\b").unwrap(), Color::Yellow);
+    /// let paragraph = Paragraph::new("ERROR disk full\nWARN low memory").highlight(&highlighter);
+    /// ```
+    #[cfg(feature = "regex")]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight(mut self, highlighter: &ratatui_core::text::Highlighter) -> Self {
+        self.text = highlighter.highlight(&self.text);
+        self
+    }
+
     /// Set the text alignment for the given paragraph
     ///
     /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right, or
@@ -350,26 +401,7 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
-            }
-            count
-        } else {
-            self.text.height()
-        };
-
-        count
+        self.text_line_count(width.saturating_sub(self.gutter_width()))
             .saturating_add(top as usize)
             .saturating_add(bottom as usize)
     }
@@ -409,10 +441,85 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+impl Paragraph<'_> {
+    /// Returns the largest vertical scroll offset at which the last line is still visible.
+    ///
+    /// This accounts for the wrapping of the text at the width of the given area and for the
+    /// [`Block`] if one is set through [`Self::block`]. It is the offset that
+    /// [`ParagraphState::scroll_to_bottom`] scrolls to when the paragraph is rendered in `area`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     widgets::{Paragraph, Wrap},
+    /// };
+    ///
+    /// let paragraph = Paragraph::new("one two three four").wrap(Wrap { trim: true });
+    /// assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 8, 2)), 1);
+    /// ```
+    pub fn max_scroll(&self, area: Rect) -> u16 {
+        let inner = self.block.inner_if_some(area);
+        let width = inner.width.saturating_sub(self.gutter_width());
+        if width == 0 || inner.height == 0 {
+            return 0;
+        }
+        let count = self.text_line_count(width);
+        u16::try_from(count)
+            .unwrap_or(u16::MAX)
+            .saturating_sub(inner.height)
+    }
+
+    /// Returns the number of lines of the text when wrapped at the given width.
+    pub(crate) fn text_line_count(&self, width: u16) -> usize {
+        if self.wrap.is_none() {
+            return self.text.height();
+        }
+        self.wrapped_heights(width).sum()
+    }
+
+    /// Returns the number of rows taken by each line of the text when wrapped at the given width.
+    ///
+    /// The heights are memoized, so only the lines that changed since the last call are wrapped.
+    fn wrapped_heights(&self, width: u16) -> impl Iterator<Item = usize> + '_ {
+        self.text.iter().map(move |line| {
+            let Some(Wrap { trim }) = self.wrap else {
+                return 1;
+            };
+            cache::wrapped_height(line, width, trim, || {
+                let graphemes = line
+                    .spans
+                    .iter()
+                    .flat_map(|span| span.styled_graphemes(self.style));
+                let alignment = line.alignment.unwrap_or(self.alignment);
+                let mut line_composer =
+                    WordWrapper::new(std::iter::once((graphemes, alignment)), width, trim);
+                let mut count = 0;
+                while line_composer.next_line().is_some() {
+                    count += 1;
+                }
+                count
+            })
+        })
+    }
+
+    /// Returns the width of the gutter, or `0` if there is none.
+    fn gutter_width(&self) -> u16 {
+        self.gutter
+            .as_ref()
+            .map_or(0, |gutter| gutter.width(self.text.lines.len()))
+    }
+}
+
 impl Widget for Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
     }
+
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        Widget::size_hint(&self, area)
+    }
 }
 
 impl Widget for &Paragraph<'_> {
@@ -420,27 +527,120 @@ impl Widget for &Paragraph<'_> {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        self.render_paragraph(inner, buf, self.scroll);
+    }
+
+    /// Returns the width of the longest line and the height of the text once wrapped at the width
+    /// of the area, including the block and the gutter.
+    fn size_hint(&self, area: Rect) -> (u16, u16) {
+        let width = self
+            .line_width()
+            .saturating_add(self.gutter_width() as usize);
+        let height = self.line_count(area.width);
+        (
+            u16::try_from(width).unwrap_or(u16::MAX).min(area.width),
+            u16::try_from(height).unwrap_or(u16::MAX).min(area.height),
+        )
+    }
+}
+
+/// A [`Paragraph`] rendered as a [`StatefulWidget`] with a [`ParagraphState`], which sets its
+/// vertical scroll offset.
+///
+/// This is created with [`Paragraph::scrollable`]. See [`ParagraphState`] for an example.
+///
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollableParagraph<'a> {
+    paragraph: Paragraph<'a>,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Returns a widget rendering this paragraph with a [`ParagraphState`], to scroll to a given
+    /// line or keep following the bottom of the text.
+    ///
+    /// The vertical offset set with [`Paragraph::scroll`] is replaced by the offset of the state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{Paragraph, ParagraphState, StatefulWidget},
+    /// };
+    ///
+    /// let mut state = ParagraphState::default().with_follow(true);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+    /// Paragraph::new("a\nb\nc")
+    ///     .scrollable()
+    ///     .render(buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(["b  ", "c  "]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scrollable(self) -> ScrollableParagraph<'a> {
+        ScrollableParagraph { paragraph: self }
+    }
+}
+
+impl StatefulWidget for ScrollableParagraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &ScrollableParagraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let paragraph = &self.paragraph;
+        buf.set_style(area, paragraph.style);
+        paragraph.block.as_ref().render(area, buf);
+        state.update(paragraph.max_scroll(area));
+        let inner = paragraph.block.inner_if_some(area);
+        let scroll = Position::new(paragraph.scroll.x, state.offset);
+        paragraph.render_paragraph(inner, buf, scroll);
     }
 }
 
 impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer, scroll: Position) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
-            let alignment = line.alignment.unwrap_or(self.alignment);
-            (graphemes, alignment)
-        });
+        let text_area = self.render_gutter(text_area, buf, scroll.y);
+        if text_area.is_empty() {
+            return;
+        }
+        let styled = |lines: usize| {
+            self.text.iter().skip(lines).map(|line| {
+                let graphemes = line.styled_graphemes(self.text.style);
+                let alignment = line.alignment.unwrap_or(self.alignment);
+                (graphemes, alignment)
+            })
+        };
 
         if let Some(Wrap { trim }) = self.wrap {
-            let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
-            // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
+            // skip the lines that are entirely scrolled out of view using their memoized heights,
+            // so that they don't need to be wrapped again
+            let mut rows = usize::from(scroll.y);
+            let skipped = self
+                .wrapped_heights(text_area.width)
+                .take_while(|&height| {
+                    let is_hidden = height <= rows;
+                    if is_hidden {
+                        rows -= height;
+                    }
+                    is_hidden
+                })
+                .count();
+            let mut line_composer = WordWrapper::new(styled(skipped), text_area.width, trim);
+            // compute the remaining rows iteratively until we reach the desired scroll offset.
+            for _ in 0..rows {
                 if line_composer.next_line().is_none() {
                     return;
                 }
@@ -448,14 +648,32 @@ impl Paragraph<'_> {
             render_lines(line_composer, text_area, buf);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
-            let lines = styled.skip(self.scroll.y as usize);
+            let lines = styled(scroll.y as usize);
             let mut line_composer = LineTruncator::new(lines, text_area.width);
-            line_composer.set_horizontal_offset(self.scroll.x);
+            line_composer.set_horizontal_offset(scroll.x);
             render_lines(line_composer, text_area, buf);
         }
     }
 }
 
+impl Paragraph<'_> {
+    /// Renders the gutter on the left of the area and returns the remaining area for the text.
+    fn render_gutter(&self, area: Rect, buf: &mut Buffer, scroll: u16) -> Rect {
+        let Some(gutter) = &self.gutter else {
+            return area;
+        };
+        let line_count = self.text.lines.len();
+        let (gutter_area, text_area) = area.split_left(gutter.width(line_count));
+        let rows = self
+            .wrapped_heights(text_area.width)
+            .enumerate()
+            .flat_map(|(line, height)| (0..height).map(move |row| (line, row == 0)))
+            .skip(usize::from(scroll));
+        gutter.render(gutter_area, buf, line_count, rows);
+        text_area
+    }
+}
+
 fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
@@ -511,6 +729,7 @@ mod tests {
         text::{Line, Span, Text},
         widgets::Widget,
     };
+    use rstest::rstest;
 
     use super::*;
     use crate::{block::Position, borders::Borders};
@@ -1123,6 +1342,16 @@ mod tests {
         assert_eq!(paragraph.line_width(), 1200);
     }
 
+    #[test]
+    fn size_hint() {
+        let paragraph = Paragraph::new("Hello World\nhi")
+            .block(Block::bordered())
+            .wrap(Wrap { trim: true });
+        assert_eq!(paragraph.size_hint(Rect::new(0, 0, 20, 10)), (13, 4));
+        assert_eq!(paragraph.size_hint(Rect::new(0, 0, 9, 10)), (9, 5));
+        assert_eq!(paragraph.size_hint(Rect::new(0, 0, 9, 3)), (9, 3));
+    }
+
     #[test]
     fn widgets_paragraph_line_width_accounts_for_block() {
         let block = Block::bordered();
@@ -1184,4 +1413,123 @@ mod tests {
         expected.set_style(Rect::new(1, 1, 11, 1), Style::default().fg(Color::Green));
         assert_eq!(buf, expected);
     }
+
+    #[test]
+    fn max_scroll() {
+        let paragraph = Paragraph::new("one two three four five").wrap(Wrap { trim: true });
+        assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 8, 2)), 2);
+        assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 30, 2)), 0);
+        let paragraph = paragraph.block(Block::bordered());
+        assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 10, 4)), 2);
+    }
+
+    #[rstest]
+    #[case::whole_lines(3, ["ee ff", "gg   "])]
+    #[case::inside_line(2, ["dd   ", "ee ff"])]
+    #[case::past_end(9, ["     ", "     "])]
+    fn render_wrapped_scroll_skips_lines(#[case] offset: u16, #[case] expected: [&str; 2]) {
+        let paragraph = Paragraph::new("aa\nbb cc dd\nee ff gg")
+            .wrap(Wrap { trim: true })
+            .scroll((offset, 0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_stateful_follow() {
+        let mut state = ParagraphState::default().with_follow(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        let paragraph = Paragraph::new("a\nb\nc");
+        paragraph
+            .scrollable()
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["b     ", "c     "]));
+        assert_eq!(state.offset(), 1);
+        assert_eq!(state.max_scroll(), 1);
+
+        let paragraph = Paragraph::new("a\nb\nc\nd");
+        paragraph
+            .scrollable()
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["c     ", "d     "]));
+    }
+
+    #[test]
+    fn render_stateful_scroll_to_line() {
+        let mut state = ParagraphState::default();
+        state.scroll_to_line(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        let paragraph = Paragraph::new("aa bb cc").wrap(Wrap { trim: true });
+        paragraph
+            .scrollable()
+            .render(Rect::new(0, 0, 3, 2), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["bb    ", "cc    "]));
+
+        state.scroll_to_line(5);
+        let paragraph = Paragraph::new("aa bb cc").wrap(Wrap { trim: true });
+        paragraph
+            .scrollable()
+            .render(Rect::new(0, 0, 3, 2), &mut buf, &mut state);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_gutter_line_numbers() {
+        let paragraph = Paragraph::new(
+            (1..=10)
+                .map(|i| Line::from(i.to_string()))
+                .collect::<Vec<_>>(),
+        )
+        .gutter(Gutter::new().line_numbers(true).separator("│"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let mut state = ParagraphState::default().with_offset(8);
+        paragraph
+            .scrollable()
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([" 8│8  ", " 9│9  ", "10│10 "]));
+    }
+
+    #[test]
+    fn render_gutter_wrapped() {
+        let gutter = Gutter::new()
+            .line_numbers(true)
+            .wrap_marker("~")
+            .signs(|line| (line == 1).then(|| Span::raw("!")));
+        let paragraph = Paragraph::new("aa bb\ncc")
+            .wrap(Wrap { trim: true })
+            .gutter(gutter);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        (&paragraph).render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([" 1 aa", " ~ bb", "!2 cc", "     "])
+        );
+        assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 5, 2)), 1);
+    }
+
+    #[test]
+    fn render_gutter_style() {
+        let paragraph = Paragraph::new("a").gutter(Gutter::new().line_numbers(true).red());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["1 a"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn render_highlighted() {
+        let highlighter = ratatui_core::text::Highlighter::new()
+            .rule(regex::Regex::new("ERR").unwrap(), Color::Red)
+            .rule(regex::Regex::new("[A-Z]+").unwrap(), Color::Yellow);
+        let paragraph = Paragraph::new("ERR a\nOK b").highlight(&highlighter);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["ERR a", "OK b "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().yellow());
+        assert_eq!(buf, expected);
+    }
 }
@@ -0,0 +1,53 @@
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    num::NonZeroUsize,
+};
+
+use lru::LruCache;
+use ratatui_core::text::Line;
+
+/// The maximum number of wrapped line heights kept in the cache.
+///
+/// This is large enough to hold every line of a long log or document, so that re-rendering it
+/// after appending a line only wraps the new line.
+const CACHE_SIZE: usize = 16_384;
+
+// The key is the hash of the line combined with the wrapping width and trim option, rather than
+// the line itself, so that lines don't need to be cloned into the cache.
+type Cache = LruCache<u64, usize>;
+
+thread_local! {
+    static WRAP_CACHE: RefCell<Cache> = RefCell::new(Cache::new(
+        NonZeroUsize::new(CACHE_SIZE).unwrap(),
+    ));
+}
+
+/// Returns the number of rows taken by the line when wrapped at the given width.
+///
+/// The height is memoized by the hash of the line, so that lines that haven't changed since the
+/// previous frame are not wrapped again. `wrap` is only called when the height is not cached.
+pub(super) fn wrapped_height<F>(line: &Line, width: u16, trim: bool, wrap: F) -> usize
+where
+    F: FnOnce() -> usize,
+{
+    let mut hasher = DefaultHasher::new();
+    (line, width, trim).hash(&mut hasher);
+    let key = hasher.finish();
+    WRAP_CACHE.with_borrow_mut(|cache| *cache.get_or_insert(key, wrap))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memoizes_height() {
+        let line = Line::from("a line that is only wrapped once");
+        assert_eq!(wrapped_height(&line, 7, true, || 5), 5);
+        assert_eq!(wrapped_height(&line, 7, true, || unreachable!()), 5);
+        assert_eq!(wrapped_height(&line, 8, true, || 4), 4);
+        assert_eq!(wrapped_height(&line, 7, false, || 6), 6);
+    }
+}
@@ -0,0 +1,231 @@
+use std::hash::{Hash, Hasher};
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    text::Span,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// A column rendered to the left of the content of a [`Paragraph`].
+///
+/// The gutter can show the line numbers of the text, a marker on the rows that continue a wrapped
+/// line, and a sign for each line (e.g. a diagnostic or a change marker) returned by a callback.
+/// Each row of the gutter is aligned with the row of the paragraph it annotates, taking wrapping
+/// and scrolling into account. A separator is drawn between the gutter and the content.
+///
+/// Each row of the gutter is made of the sign column (if a sign callback is set), the line number
+/// column (if line numbers or a wrap marker are shown) and the separator.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::Stylize,
+///     text::Span,
+///     widgets::{Gutter, Paragraph, Wrap},
+/// };
+///
+/// fn sign(line: usize) -> Option<Span<'static>> {
+///     (line == 2).then(|| "●".red())
+/// }
+///
+/// let gutter = Gutter::new()
+///     .line_numbers(true)
+///     .wrap_marker("↪")
+///     .signs(sign)
+///     .dark_gray();
+/// let paragraph = Paragraph::new("fn main() {\n    println!(\"hello\");\n}")
+///     .wrap(Wrap { trim: false })
+///     .gutter(gutter);
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+#[derive(Debug, Clone)]
+pub struct Gutter<'a> {
+    line_numbers: bool,
+    wrap_marker: &'a str,
+    signs: Option<fn(usize) -> Option<Span<'static>>>,
+    separator: &'a str,
+    style: Style,
+}
+
+impl Default for Gutter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Gutter<'a> {
+    /// Creates a new empty `Gutter`, which only renders the separator.
+    pub const fn new() -> Self {
+        Self {
+            line_numbers: false,
+            wrap_marker: "",
+            signs: None,
+            separator: " ",
+            style: Style::new(),
+        }
+    }
+
+    /// Sets whether the gutter shows the line numbers of the text, starting at 1.
+    ///
+    /// The line number is displayed on the first row of each line of the text only.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the marker displayed on the rows that continue a wrapped line.
+    ///
+    /// The marker is right-aligned in the line number column.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_marker(mut self, marker: &'a str) -> Self {
+        self.wrap_marker = marker;
+        self
+    }
+
+    /// Sets a callback that returns the sign displayed for a line.
+    ///
+    /// The callback is called with the zero-based index of the line in the text. Only the first
+    /// cell of the returned span is displayed, on the first row of the line. The style of the
+    /// span is patched onto the style of the gutter.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signs(mut self, signs: fn(usize) -> Option<Span<'static>>) -> Self {
+        self.signs = Some(signs);
+        self
+    }
+
+    /// Sets the separator drawn between the gutter and the content.
+    ///
+    /// Defaults to a single space.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the style of the gutter.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the width of the gutter for a text with the given number of lines.
+    pub fn width(&self, line_count: usize) -> u16 {
+        let sign_width = u16::from(self.signs.is_some());
+        sign_width + self.number_width(line_count) + self.separator.width() as u16
+    }
+
+    /// Returns the width of the line number column.
+    fn number_width(&self, line_count: usize) -> u16 {
+        let digits = if self.line_numbers {
+            line_count.max(1).ilog10() as u16 + 1
+        } else {
+            0
+        };
+        digits.max(self.wrap_marker.width() as u16)
+    }
+
+    /// Renders the gutter for the given rows.
+    ///
+    /// Each row is the index of the line of the text, and whether the row is the first row of
+    /// that line.
+    pub(crate) fn render<I>(&self, area: Rect, buf: &mut Buffer, line_count: usize, rows: I)
+    where
+        I: IntoIterator<Item = (usize, bool)>,
+    {
+        buf.set_style(area, self.style);
+        let sign_width = u16::from(self.signs.is_some());
+        let number_width = self.number_width(line_count);
+        let rows = (area.top()..area.bottom()).zip(rows);
+        for (y, (line, is_first_row)) in rows {
+            let mut x = area.x;
+            if let Some(signs) = self.signs.filter(|_| is_first_row) {
+                if let Some(sign) = signs(line) {
+                    let style = self.style.patch(sign.style);
+                    buf.set_stringn(x, y, sign.content, 1, style);
+                }
+            }
+            x += sign_width;
+            let label = if !is_first_row {
+                self.wrap_marker.to_string()
+            } else if self.line_numbers {
+                (line + 1).to_string()
+            } else {
+                String::new()
+            };
+            let label = format!("{label:>width$}", width = usize::from(number_width));
+            buf.set_stringn(x, y, label, usize::from(number_width), self.style);
+            x += number_width;
+            let width = usize::from(area.right().saturating_sub(x));
+            buf.set_stringn(x, y, self.separator, width, self.style);
+        }
+    }
+}
+
+// The sign callbacks are compared by address, as the derived implementations would do
+#[allow(clippy::fn_to_numeric_cast_any)]
+impl PartialEq for Gutter<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.line_numbers == other.line_numbers
+            && self.wrap_marker == other.wrap_marker
+            && self.signs.map(|signs| signs as usize) == other.signs.map(|signs| signs as usize)
+            && self.separator == other.separator
+            && self.style == other.style
+    }
+}
+
+impl Eq for Gutter<'_> {}
+
+#[allow(clippy::fn_to_numeric_cast_any)]
+impl Hash for Gutter<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.line_numbers.hash(state);
+        self.wrap_marker.hash(state);
+        self.signs.map(|signs| signs as usize).hash(state);
+        self.separator.hash(state);
+        self.style.hash(state);
+    }
+}
+
+impl Styled for Gutter<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn width() {
+        assert_eq!(Gutter::new().width(100), 1);
+        assert_eq!(Gutter::new().line_numbers(true).width(9), 2);
+        assert_eq!(Gutter::new().line_numbers(true).width(100), 4);
+        assert_eq!(Gutter::new().wrap_marker("->").width(1), 3);
+        assert_eq!(
+            Gutter::new()
+                .line_numbers(true)
+                .signs(|_| None)
+                .separator(" │ ")
+                .width(10),
+            6
+        );
+    }
+}
@@ -0,0 +1,243 @@
+/// State of a [`Paragraph`] widget
+///
+/// This state keeps track of the vertical scroll offset of a paragraph that is rendered with
+/// [`Paragraph::scrollable`], so that panes such as chat or log views don't need to duplicate the scroll
+/// computations. The offset is clamped when the paragraph is rendered, so that the last line of
+/// the text is never scrolled above the bottom of the area.
+///
+/// When the state is following, the paragraph is scrolled to the bottom every time it is rendered,
+/// so that new lines appended to the text are always visible. Scrolling up stops following.
+///
+/// The horizontal scroll offset is still set with [`Paragraph::scroll`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     widgets::{Paragraph, ParagraphState, Wrap},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame, log: &str) {
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ParagraphState::default().with_follow(true);
+///
+/// let paragraph = Paragraph::new(log).wrap(Wrap { trim: false });
+/// frame.render_stateful_widget(paragraph.scrollable(), frame.area(), &mut state);
+/// # }
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`Paragraph::scroll`]: super::Paragraph::scroll
+/// [`Paragraph::scrollable`]: super::Paragraph::scrollable
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphState {
+    pub(crate) offset: u16,
+    pub(crate) follow: bool,
+    pub(crate) max_scroll: u16,
+}
+
+impl ParagraphState {
+    /// Sets the index of the first line to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default().with_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: u16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether the paragraph follows the bottom of the text
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default().with_follow(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Index of the first line to be displayed
+    ///
+    /// The offset is in lines after wrapping, and is clamped to [`ParagraphState::max_scroll`]
+    /// when the paragraph is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default();
+    /// assert_eq!(state.offset(), 0);
+    /// ```
+    pub const fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// Returns whether the paragraph follows the bottom of the text
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default();
+    /// assert!(!state.is_following());
+    /// ```
+    pub const fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// The largest offset at which the last line of the text is still visible
+    ///
+    /// This depends on the wrapping width and the height of the area, so it is only known after
+    /// the paragraph has been rendered, and is `0` before. Use [`Paragraph::max_scroll`] to
+    /// compute it for a given area beforehand.
+    ///
+    /// [`Paragraph::max_scroll`]: super::Paragraph::max_scroll
+    pub const fn max_scroll(&self) -> u16 {
+        self.max_scroll
+    }
+
+    /// Sets whether the paragraph follows the bottom of the text
+    ///
+    /// While following, the paragraph is scrolled to the bottom every time it is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.follow(true);
+    /// ```
+    pub fn follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// Scrolls so that the given line is the first line displayed
+    ///
+    /// The line is an index in the wrapped text. This stops following the bottom of the text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.scroll_to_line(10);
+    /// ```
+    pub fn scroll_to_line(&mut self, line: u16) {
+        self.offset = line;
+        self.follow = false;
+    }
+
+    /// Scrolls to the bottom of the text
+    ///
+    /// Note: until the paragraph is rendered, the number of lines is not known, so the offset is
+    /// set to `u16::MAX` and will be corrected when the paragraph is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.scroll_to_bottom();
+    /// ```
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = u16::MAX;
+    }
+
+    /// Scrolls up by the given number of lines
+    ///
+    /// This stops following the bottom of the text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.scroll_up_by(4);
+    /// ```
+    pub fn scroll_up_by(&mut self, amount: u16) {
+        self.scroll_to_line(self.offset.saturating_sub(amount));
+    }
+
+    /// Scrolls down by the given number of lines
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.scroll_down_by(4);
+    /// ```
+    pub fn scroll_down_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+
+    /// Updates the scroll bounds after the paragraph is laid out
+    pub(crate) fn update(&mut self, max_scroll: u16) {
+        self.max_scroll = max_scroll;
+        if self.follow {
+            self.offset = max_scroll;
+        }
+        self.offset = self.offset.min(max_scroll);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_to_line_stops_following() {
+        let mut state = ParagraphState::default().with_follow(true);
+        state.scroll_to_line(3);
+        assert_eq!(state.offset(), 3);
+        assert!(!state.is_following());
+    }
+
+    #[test]
+    fn scroll_up_and_down() {
+        let mut state = ParagraphState::default().with_offset(5);
+        state.scroll_down_by(2);
+        assert_eq!(state.offset(), 7);
+        state.scroll_up_by(10);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn update() {
+        let mut state = ParagraphState::default();
+        state.scroll_to_bottom();
+        state.update(4);
+        assert_eq!(state.offset(), 4);
+        assert_eq!(state.max_scroll(), 4);
+
+        let mut state = ParagraphState::default().with_follow(true);
+        state.update(2);
+        assert_eq!(state.offset(), 2);
+        state.update(6);
+        assert_eq!(state.offset(), 6);
+    }
+}
@@ -1,4 +1,5 @@
-//! The [`Scrollbar`] widget is used to display a scrollbar alongside other widgets.
+//! The [`Scrollbar`] widget is used to display a scrollbar alongside other widgets, and
+//! [`Scrollbars`] displays a vertical and a horizontal scrollbar together.
 #![warn(clippy::pedantic)]
 #![allow(
     clippy::cast_possible_truncation,
@@ -19,6 +20,10 @@ use ratatui_core::{
 use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
+pub use self::scrollbars::{Scrollbars, ScrollbarsState};
+
+mod scrollbars;
+
 /// A widget to display a scrollbar
 ///
 /// The following components of the scrollbar are customizable in symbol and style. Note the
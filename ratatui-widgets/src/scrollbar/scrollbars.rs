@@ -0,0 +1,335 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    widgets::StatefulWidget,
+};
+
+use crate::scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+
+/// A pair of vertical and horizontal [`Scrollbar`]s sharing a corner.
+///
+/// Rendering two scrollbars in the same area makes them overlap in the corner, and the last one
+/// rendered overwrites the end of the other. `Scrollbars` shortens both scrollbars so that they
+/// meet in a corner cell, which is filled with the [`corner_symbol`](Self::corner_symbol). When
+/// only one of the scrollbars is displayed (because the content has no length along the other
+/// axis), it spans the whole side of the area and no corner is drawn.
+///
+/// The scrollbars are rendered with a [`ScrollbarsState`], which holds the state of both axes.
+/// Use [`Scrollbars::inner`] to get the area left for the content.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Scrollbar, ScrollbarOrientation, Scrollbars, ScrollbarsState},
+///     Frame,
+/// };
+///
+/// # fn render(frame: &mut Frame, area: Rect) {
+/// let scrollbars = Scrollbars::new()
+///     .vertical(Scrollbar::new(ScrollbarOrientation::VerticalLeft))
+///     .corner_symbol("+");
+/// let mut state = ScrollbarsState::new(200, 1000).position(10, 50);
+///
+/// let content_area = scrollbars.inner(area, &state);
+/// // render the content in `content_area`
+/// frame.render_stateful_widget(scrollbars, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Scrollbars<'a> {
+    vertical: Scrollbar<'a>,
+    horizontal: Scrollbar<'a>,
+    corner_symbol: &'a str,
+    corner_style: Style,
+}
+
+/// The state of [`Scrollbars`], made of the [`ScrollbarState`] of each axis.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollbarsState {
+    horizontal: ScrollbarState,
+    vertical: ScrollbarState,
+}
+
+impl Default for Scrollbars<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Scrollbars<'a> {
+    /// Creates a vertical scrollbar on the right and a horizontal scrollbar at the bottom, with
+    /// the default symbols and an empty corner.
+    #[must_use = "creates the Scrollbars"]
+    pub const fn new() -> Self {
+        Self {
+            vertical: Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            horizontal: Scrollbar::new(ScrollbarOrientation::HorizontalBottom),
+            corner_symbol: " ",
+            corner_style: Style::new(),
+        }
+    }
+
+    /// Sets the vertical scrollbar.
+    ///
+    /// The scrollbar must have a vertical orientation, which decides on which side of the area it
+    /// is rendered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scrollbar has a horizontal orientation.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn vertical(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        assert!(
+            scrollbar.orientation.is_vertical(),
+            "the vertical scrollbar must have a vertical orientation"
+        );
+        self.vertical = scrollbar;
+        self
+    }
+
+    /// Sets the horizontal scrollbar.
+    ///
+    /// The scrollbar must have a horizontal orientation, which decides on which side of the area
+    /// it is rendered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scrollbar has a vertical orientation.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn horizontal(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        assert!(
+            scrollbar.orientation.is_horizontal(),
+            "the horizontal scrollbar must have a horizontal orientation"
+        );
+        self.horizontal = scrollbar;
+        self
+    }
+
+    /// Sets the symbol drawn in the corner where the scrollbars meet.
+    ///
+    /// Defaults to a space.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn corner_symbol(mut self, corner_symbol: &'a str) -> Self {
+        self.corner_symbol = corner_symbol;
+        self
+    }
+
+    /// Sets the style of the corner where the scrollbars meet.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn corner_style<S: Into<Style>>(mut self, corner_style: S) -> Self {
+        self.corner_style = corner_style.into();
+        self
+    }
+
+    /// Sets the style of both scrollbars and of the corner.
+    ///
+    /// This overrides the styles of the scrollbars set with [`Scrollbars::vertical`] and
+    /// [`Scrollbars::horizontal`], see [`Scrollbar::style`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        let style = style.into();
+        self.vertical = self.vertical.style(style);
+        self.horizontal = self.horizontal.style(style);
+        self.corner_style = style;
+        self
+    }
+
+    /// Returns the area left for the content once the scrollbars displayed for the given state
+    /// are rendered in `area`.
+    #[must_use = "returns the area of the content"]
+    pub fn inner(&self, area: Rect, state: &ScrollbarsState) -> Rect {
+        let (vertical, horizontal) = state.visible();
+        let mut inner = area;
+        if vertical {
+            inner.width = inner.width.saturating_sub(1);
+            if self.vertical.orientation == ScrollbarOrientation::VerticalLeft {
+                inner.x = area.right().min(area.x.saturating_add(1));
+            }
+        }
+        if horizontal {
+            inner.height = inner.height.saturating_sub(1);
+            if self.horizontal.orientation == ScrollbarOrientation::HorizontalTop {
+                inner.y = area.bottom().min(area.y.saturating_add(1));
+            }
+        }
+        inner
+    }
+}
+
+impl StatefulWidget for Scrollbars<'_> {
+    type State = ScrollbarsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (vertical, horizontal) = state.visible();
+        let (Some(column), Some(row)) = (
+            self.vertical.scrollbar_area(area),
+            self.horizontal.scrollbar_area(area),
+        ) else {
+            return;
+        };
+        let inner = self.inner(area, state);
+        if vertical {
+            let track = Rect {
+                y: inner.y,
+                height: inner.height,
+                ..column
+            };
+            self.vertical.render(track, buf, &mut state.vertical);
+        }
+        if horizontal {
+            let track = Rect {
+                x: inner.x,
+                width: inner.width,
+                ..row
+            };
+            self.horizontal.render(track, buf, &mut state.horizontal);
+        }
+        if vertical && horizontal {
+            buf.set_string(column.x, row.y, self.corner_symbol, self.corner_style);
+        }
+    }
+}
+
+impl Styled for Scrollbars<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.corner_style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl ScrollbarsState {
+    /// Creates a state for content of the given width and height.
+    #[must_use = "creates the ScrollbarsState"]
+    pub const fn new(content_width: usize, content_height: usize) -> Self {
+        Self {
+            horizontal: ScrollbarState::new(content_width),
+            vertical: ScrollbarState::new(content_height),
+        }
+    }
+
+    /// Sets the horizontal and vertical positions within the content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn position(mut self, x: usize, y: usize) -> Self {
+        self.horizontal = self.horizontal.position(x);
+        self.vertical = self.vertical.position(y);
+        self
+    }
+
+    /// Sets the width and height of the content displayed in the viewport.
+    ///
+    /// See [`ScrollbarState::viewport_content_length`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn viewport_content_size(mut self, width: usize, height: usize) -> Self {
+        self.horizontal = self.horizontal.viewport_content_length(width);
+        self.vertical = self.vertical.viewport_content_length(height);
+        self
+    }
+
+    /// Returns the horizontal and vertical positions within the content.
+    #[must_use = "returns the current position within the scrollable content"]
+    pub const fn get_position(&self) -> (usize, usize) {
+        (self.horizontal.get_position(), self.vertical.get_position())
+    }
+
+    /// Returns the state of the horizontal scrollbar.
+    #[must_use = "returns the state of the horizontal scrollbar"]
+    pub const fn horizontal(&self) -> &ScrollbarState {
+        &self.horizontal
+    }
+
+    /// Returns a mutable reference to the state of the horizontal scrollbar.
+    pub fn horizontal_mut(&mut self) -> &mut ScrollbarState {
+        &mut self.horizontal
+    }
+
+    /// Returns the state of the vertical scrollbar.
+    #[must_use = "returns the state of the vertical scrollbar"]
+    pub const fn vertical(&self) -> &ScrollbarState {
+        &self.vertical
+    }
+
+    /// Returns a mutable reference to the state of the vertical scrollbar.
+    pub fn vertical_mut(&mut self) -> &mut ScrollbarState {
+        &mut self.vertical
+    }
+
+    /// Returns whether the vertical and horizontal scrollbars are displayed.
+    const fn visible(&self) -> (bool, bool) {
+        (
+            self.vertical.content_length > 0,
+            self.horizontal.content_length > 0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::both(ScrollbarsState::new(10, 10), ["▲    ", "█    ", "║    ", "▼    ", "+◄█═►"])]
+    #[case::vertical_only(ScrollbarsState::new(0, 10), ["▲    ", "█    ", "║    ", "║    ", "▼    "])]
+    #[case::horizontal_only(ScrollbarsState::new(10, 0), ["     ", "     ", "     ", "     ", "◄█══►"])]
+    fn render(#[case] mut state: ScrollbarsState, #[case] expected: [&str; 5]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Scrollbars::new()
+            .vertical(Scrollbar::new(ScrollbarOrientation::VerticalLeft))
+            .corner_symbol("+")
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn inner() {
+        let area = Rect::new(0, 0, 10, 5);
+        let scrollbars =
+            Scrollbars::new().horizontal(Scrollbar::new(ScrollbarOrientation::HorizontalTop));
+        let state = ScrollbarsState::new(20, 20);
+        assert_eq!(scrollbars.inner(area, &state), Rect::new(0, 1, 9, 4));
+        let state = ScrollbarsState::new(20, 0);
+        assert_eq!(scrollbars.inner(area, &state), Rect::new(0, 1, 10, 4));
+    }
+
+    #[test]
+    #[should_panic = "the vertical scrollbar must have a vertical orientation"]
+    fn vertical_with_horizontal_orientation() {
+        let _ = Scrollbars::new().vertical(Scrollbar::new(ScrollbarOrientation::HorizontalTop));
+    }
+
+    #[test]
+    fn state() {
+        let mut state = ScrollbarsState::new(100, 50).position(3, 4);
+        state.vertical_mut().next();
+        assert_eq!(state.get_position(), (3, 5));
+        assert_eq!(state.horizontal(), &ScrollbarState::new(100).position(3));
+    }
+}
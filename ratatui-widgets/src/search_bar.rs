@@ -0,0 +1,329 @@
+//! The [`SearchBar`] widget is an input field to search the content of other widgets.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+pub use self::state::{SearchControl, SearchState};
+use crate::{
+    block::{Block, BlockExt},
+    dialog::render_value,
+};
+
+mod state;
+
+/// A search bar: an input field for the query, a match counter, toggles for the search options
+/// and controls to go to the previous and next match.
+///
+/// The bar is rendered on the first line of the area, e.g. `/ query   2/5 Aa .* ↑ ↓`, with the
+/// toggle of the case-sensitive search (`Aa`) and, with the `regex` feature, the toggle of the
+/// regular expression search (`.*`). Active toggles are reversed by default.
+///
+/// The query, the options and the matches are kept in a [`SearchState`], which also finds the
+/// matches in the content of the widget being searched, highlights them, and selects the next
+/// and previous match. Forward the key presses to the state while the search bar is focused, and
+/// clicks to [`SearchState::click`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::{Constraint, Layout},
+///     style::{Style, Stylize},
+///     widgets::{List, ListState, SearchBar, SearchState},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, items: &[&str], search: &mut SearchState, list: &mut ListState) {
+/// search.search(items.iter().copied());
+/// if let Some(found) = search.current_match() {
+///     list.select(Some(found.line));
+/// }
+/// let highlight = Style::new().on_yellow();
+/// let list_items = items
+///     .iter()
+///     .map(|item| search.highlight(&(*item).into(), highlight));
+///
+/// let [list_area, search_area] =
+///     Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+/// frame.render_stateful_widget(List::new(list_items), list_area, list);
+/// frame.render_stateful_widget(SearchBar::new(), search_area, search);
+/// frame.set_cursor_from(&*search);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SearchBar<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    prompt: Span<'a>,
+    placeholder: Line<'a>,
+    placeholder_style: Style,
+    invalid_style: Style,
+    counter_style: Style,
+    toggle_style: Style,
+}
+
+impl Default for SearchBar<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            prompt: Span::raw("/"),
+            placeholder: Line::raw("Search"),
+            placeholder_style: Style::new().add_modifier(Modifier::DIM),
+            invalid_style: Style::new().add_modifier(Modifier::CROSSED_OUT),
+            counter_style: Style::new().add_modifier(Modifier::DIM),
+            toggle_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+impl<'a> SearchBar<'a> {
+    /// Creates a new `SearchBar` with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps the search bar with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the prompt shown before the query.
+    ///
+    /// The default prompt is `/`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn prompt<T: Into<Span<'a>>>(mut self, prompt: T) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the text shown while the query is empty.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the style of the placeholder.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.placeholder_style = style.into();
+        self
+    }
+
+    /// Sets the style of the query when it is an invalid regular expression.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn invalid_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.invalid_style = style.into();
+        self
+    }
+
+    /// Sets the style of the match counter.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn counter_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.counter_style = style.into();
+        self
+    }
+
+    /// Sets the style of the toggles of the active search options.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn toggle_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.toggle_style = style.into();
+        self
+    }
+
+    /// Returns the controls at the end of the bar, with their labels and whether they are active.
+    fn controls(state: &SearchState) -> Vec<(SearchControl, &'static str, bool)> {
+        let mut controls = vec![(
+            SearchControl::CaseSensitive,
+            "Aa",
+            state.is_case_sensitive(),
+        )];
+        #[cfg(feature = "regex")]
+        controls.push((SearchControl::Regex, ".*", state.is_regex()));
+        controls.push((SearchControl::Previous, "↑", false));
+        controls.push((SearchControl::Next, "↓", false));
+        controls
+    }
+}
+
+impl Styled for SearchBar<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl StatefulWidget for SearchBar<'_> {
+    type State = SearchState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &SearchBar<'_> {
+    type State = SearchState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        state.controls.clear();
+        state.cursor_position = None;
+        if area.is_empty() {
+            return;
+        }
+        let mut rest = Rect { height: 1, ..area };
+
+        // the controls are laid out from the right, each followed by a space
+        let controls = SearchBar::controls(state);
+        let mut control_areas = Vec::new();
+        for (control, label, active) in controls.into_iter().rev() {
+            let width = u16::try_from(label.width()).unwrap_or(u16::MAX);
+            let (slot, remaining) = rest.split_right(width.saturating_add(1));
+            let (control_area, _) = slot.split_left(width);
+            let style = if active {
+                self.toggle_style
+            } else {
+                Style::new()
+            };
+            buf.set_stringn(
+                control_area.x,
+                control_area.y,
+                label,
+                usize::from(control_area.width),
+                style,
+            );
+            control_areas.push((control, control_area));
+            rest = remaining;
+        }
+        control_areas.reverse();
+        state.controls = control_areas;
+
+        let counter = state.counter();
+        let counter_width = u16::try_from(counter.width()).unwrap_or(u16::MAX);
+        let (counter_area, rest) = rest.split_right(counter_width.saturating_add(1));
+        // keep a space between the input field and the counter
+        let (_, rest) = rest.split_right(1);
+        buf.set_stringn(
+            counter_area.x,
+            counter_area.y,
+            &counter,
+            usize::from(counter_area.width),
+            self.counter_style,
+        );
+
+        let prompt_width = u16::try_from(self.prompt.width()).unwrap_or(u16::MAX);
+        let (prompt_area, input_area) = rest.split_left(prompt_width.saturating_add(1));
+        (&self.prompt).render(prompt_area, buf);
+        if input_area.is_empty() {
+            return;
+        }
+        let cursor = if state.query().is_empty() {
+            buf.set_style(input_area, self.placeholder_style);
+            (&self.placeholder).render(input_area, buf);
+            input_area.as_position()
+        } else {
+            if state.is_invalid() {
+                buf.set_style(input_area, self.invalid_style);
+            }
+            render_value(state.query(), state.cursor(), input_area, buf)
+        };
+        state.cursor_position = Some(cursor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::layout::Position;
+
+    use super::*;
+
+    fn render(state: &mut SearchState, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(SearchBar::new(), buf.area, &mut buf, state);
+        buf
+    }
+
+    #[cfg(not(feature = "regex"))]
+    #[test]
+    fn render_bar() {
+        use ratatui_core::style::Stylize;
+
+        let mut state = SearchState::new();
+        let mut expected = Buffer::with_lines(["/ Search       Aa ↑ ↓ "]);
+        expected.set_style(Rect::new(2, 0, 11, 1), Style::new().dim());
+        assert_eq!(render(&mut state, 22), expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 0)));
+
+        state.set_query("ab");
+        state.search(["ab", "xaby"]);
+        state.toggle_case_sensitive();
+        state.search(["ab", "xaby"]);
+        let mut expected = Buffer::with_lines(["/ ab       1/2 Aa ↑ ↓ "]);
+        expected.set_style(Rect::new(11, 0, 3, 1), Style::new().dim());
+        expected.set_style(Rect::new(15, 0, 2, 1), Style::new().reversed());
+        assert_eq!(render(&mut state, 22), expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(4, 0)));
+    }
+
+    #[test]
+    fn click_controls() {
+        let mut state = SearchState::new().with_query("a");
+        state.search(["a", "a", "a"]);
+        render(&mut state, 30);
+        let (_, next_area) = state.controls[state.controls.len() - 1];
+        let (_, case_area) = state.controls[0];
+
+        assert_eq!(state.click(next_area), Some(SearchControl::Next));
+        assert_eq!(state.current_match_index(), Some(1));
+        assert_eq!(state.click(case_area), Some(SearchControl::CaseSensitive));
+        assert!(state.is_case_sensitive());
+        assert_eq!(state.click(Position::new(0, 0)), None);
+    }
+}
@@ -0,0 +1,479 @@
+use std::ops::Range;
+
+use ratatui_core::{
+    layout::{Position, Rect},
+    style::Style,
+    text::{Text, TextMatch},
+    widgets::HasCursor,
+};
+use strum::{Display, EnumString};
+
+/// A control of a [`SearchBar`] that can be clicked.
+///
+/// [`SearchBar`]: crate::search_bar::SearchBar
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SearchControl {
+    /// The toggle between a case-sensitive and a case-insensitive search.
+    CaseSensitive,
+    /// The toggle between a search for regular expressions and a search for the query as is.
+    #[cfg(feature = "regex")]
+    Regex,
+    /// The control that selects the previous match.
+    Previous,
+    /// The control that selects the next match.
+    Next,
+}
+
+/// The state of a [`SearchBar`]: the query being edited, the search options and the matches.
+///
+/// The state finds the matches of the query in the content of other widgets, e.g. the items of a
+/// [`List`], the rows of a [`Table`] or the lines of a [`Paragraph`]:
+///
+/// - [`SearchState::find`] returns the byte ranges of the matches in a string,
+/// - [`SearchState::highlight`] highlights the matches in a [`Text`],
+/// - [`SearchState::search`] finds the matches in a sequence of strings (one per item, row or line)
+///   and keeps them for navigation with [`SearchState::next_match`] and
+///   [`SearchState::previous_match`]. The [`line`](TextMatch::line) of each match is the index of
+///   the string it was found in, e.g. the index of the item to select.
+///
+/// The search is case-insensitive by default. With the `regex` feature, the query can be a
+/// regular expression; an invalid regular expression matches nothing and is reported by
+/// [`SearchState::is_invalid`].
+///
+/// The cursor is an index in the characters of the query, between `0` and the number of
+/// characters.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::SearchState;
+///
+/// let items = ["Apples", "Bananas", "Pineapples"];
+/// let mut state = SearchState::new().with_query("apple");
+/// state.search(items);
+/// assert_eq!(state.match_count(), 2);
+/// assert_eq!(state.current_match().map(|m| m.line), Some(0));
+///
+/// state.next_match();
+/// assert_eq!(state.current_match().map(|m| m.line), Some(2));
+/// ```
+///
+/// [`SearchBar`]: crate::search_bar::SearchBar
+/// [`List`]: crate::list::List
+/// [`Table`]: crate::table::Table
+/// [`Paragraph`]: crate::paragraph::Paragraph
+#[derive(Debug, Default, Clone)]
+pub struct SearchState {
+    query: String,
+    cursor: usize,
+    case_sensitive: bool,
+    #[cfg(feature = "regex")]
+    regex: bool,
+    #[cfg(feature = "regex")]
+    compiled: Option<regex::Regex>,
+    invalid: bool,
+    matches: Vec<TextMatch>,
+    current: Option<usize>,
+    pub(crate) controls: Vec<(SearchControl, Rect)>,
+    pub(crate) cursor_position: Option<Position>,
+}
+
+impl SearchState {
+    /// Creates a new state with an empty query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initial query, with the cursor at its end.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_query<T: Into<String>>(mut self, query: T) -> Self {
+        self.set_query(query);
+        self
+    }
+
+    /// Returns the query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Replaces the query, moving the cursor to its end.
+    pub fn set_query<T: Into<String>>(&mut self, query: T) {
+        self.query = query.into();
+        self.cursor = self.query.chars().count();
+        self.query_changed();
+    }
+
+    /// Returns the position of the cursor, as an index in the characters of the query.
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Inserts a character at the cursor.
+    pub fn insert_char(&mut self, c: char) {
+        let index = self.byte_index(self.cursor);
+        self.query.insert(index, c);
+        self.cursor += 1;
+        self.query_changed();
+    }
+
+    /// Deletes the character before the cursor, like `Backspace`.
+    pub fn delete_backward(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.query.remove(self.byte_index(self.cursor));
+            self.query_changed();
+        }
+    }
+
+    /// Deletes the character under the cursor, like `Delete`.
+    pub fn delete_forward(&mut self) {
+        if self.cursor < self.query.chars().count() {
+            self.query.remove(self.byte_index(self.cursor));
+            self.query_changed();
+        }
+    }
+
+    /// Moves the cursor one character to the left.
+    pub fn move_cursor_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one character to the right.
+    pub fn move_cursor_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.query.chars().count());
+    }
+
+    /// Moves the cursor to the start of the query.
+    pub fn move_cursor_to_start(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the query.
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor = self.query.chars().count();
+    }
+
+    /// Returns whether the search is case-sensitive.
+    pub const fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Sets whether the search is case-sensitive.
+    pub fn set_case_sensitive(&mut self, case_sensitive: bool) {
+        self.case_sensitive = case_sensitive;
+        self.query_changed();
+    }
+
+    /// Switches between a case-sensitive and a case-insensitive search.
+    pub fn toggle_case_sensitive(&mut self) {
+        self.set_case_sensitive(!self.case_sensitive);
+    }
+
+    /// Returns whether the query is a regular expression.
+    #[cfg(feature = "regex")]
+    pub const fn is_regex(&self) -> bool {
+        self.regex
+    }
+
+    /// Sets whether the query is a regular expression.
+    #[cfg(feature = "regex")]
+    pub fn set_regex(&mut self, regex: bool) {
+        self.regex = regex;
+        self.query_changed();
+    }
+
+    /// Switches between a search for regular expressions and a search for the query as is.
+    #[cfg(feature = "regex")]
+    pub fn toggle_regex(&mut self) {
+        self.set_regex(!self.regex);
+    }
+
+    /// Returns whether the query is an invalid regular expression.
+    ///
+    /// An invalid query matches nothing. This is always `false` without the `regex` feature.
+    pub const fn is_invalid(&self) -> bool {
+        self.invalid
+    }
+
+    /// Returns the byte ranges of the matches of the query in the given content, in order.
+    ///
+    /// The matches don't overlap, and an empty query matches nothing.
+    pub fn find(&self, content: &str) -> Vec<Range<usize>> {
+        if self.query.is_empty() {
+            return Vec::new();
+        }
+        #[cfg(feature = "regex")]
+        if self.regex {
+            return self.compiled.as_ref().map_or_else(Vec::new, |regex| {
+                regex
+                    .find_iter(content)
+                    .filter(|m| !m.is_empty())
+                    .map(|m| m.range())
+                    .collect()
+            });
+        }
+        if self.case_sensitive {
+            content
+                .match_indices(&self.query)
+                .map(|(start, matched)| start..start + matched.len())
+                .collect()
+        } else {
+            find_ignoring_case(content, &self.query)
+        }
+    }
+
+    /// Returns a copy of the text with the matches of the query highlighted.
+    ///
+    /// `style` is patched onto the style of the matched parts of the text, see
+    /// [`Text::highlight_with`].
+    pub fn highlight<'a, S: Into<Style>>(&self, text: &Text<'a>, style: S) -> Text<'a> {
+        text.highlight_with(style, |content| self.find(content)).0
+    }
+
+    /// Finds the matches of the query in the given strings, e.g. the items of a list, and keeps
+    /// them for navigation.
+    ///
+    /// The [`line`](TextMatch::line) of each match is the index of the string it was found in.
+    /// The current match is kept if it is still in range, and is the first match otherwise.
+    pub fn search<'s, I>(&mut self, contents: I)
+    where
+        I: IntoIterator<Item = &'s str>,
+    {
+        let matches = contents
+            .into_iter()
+            .enumerate()
+            .flat_map(|(line, content)| {
+                self.find(content)
+                    .into_iter()
+                    .map(move |range| TextMatch { line, range })
+            })
+            .collect();
+        self.set_matches(matches);
+    }
+
+    /// Replaces the matches used for navigation, e.g. with the matches returned by
+    /// [`Text::highlight_with`].
+    ///
+    /// The current match is kept if it is still in range, and is the first match otherwise.
+    pub fn set_matches(&mut self, matches: Vec<TextMatch>) {
+        self.current = if matches.is_empty() {
+            None
+        } else {
+            Some(self.current.unwrap_or(0).min(matches.len() - 1))
+        };
+        self.matches = matches;
+    }
+
+    /// Returns the matches found by the last [`search`](SearchState::search).
+    pub fn matches(&self) -> &[TextMatch] {
+        &self.matches
+    }
+
+    /// Returns the number of matches.
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Returns the index of the current match in the matches.
+    pub const fn current_match_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns the current match.
+    pub fn current_match(&self) -> Option<&TextMatch> {
+        self.current.and_then(|index| self.matches.get(index))
+    }
+
+    /// Selects the next match, wrapping around to the first one.
+    pub fn next_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = Some(
+                self.current
+                    .map_or(0, |index| (index + 1) % self.matches.len()),
+            );
+        }
+    }
+
+    /// Selects the previous match, wrapping around to the last one.
+    pub fn previous_match(&mut self) {
+        if let Some(last) = self.matches.len().checked_sub(1) {
+            self.current = Some(
+                self.current
+                    .map_or(last, |index| index.checked_sub(1).unwrap_or(last)),
+            );
+        }
+    }
+
+    /// Returns where the terminal cursor should be shown in the input field.
+    ///
+    /// This is updated when the search bar is rendered.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Handles a click at the given position, returning the clicked control.
+    ///
+    /// Clicking a toggle switches its option and clicking the previous or next control selects
+    /// the previous or next match. Returns `None` if the click is not on a control, or if the
+    /// search bar was not rendered yet.
+    pub fn click<P: Into<Position>>(&mut self, position: P) -> Option<SearchControl> {
+        let position = position.into();
+        let control = self
+            .controls
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(control, _)| *control)?;
+        match control {
+            SearchControl::CaseSensitive => self.toggle_case_sensitive(),
+            #[cfg(feature = "regex")]
+            SearchControl::Regex => self.toggle_regex(),
+            SearchControl::Previous => self.previous_match(),
+            SearchControl::Next => self.next_match(),
+        }
+        Some(control)
+    }
+
+    /// Returns the label of the match counter, e.g. `2/5`, or an empty label without a query.
+    pub(crate) fn counter(&self) -> String {
+        if self.query.is_empty() {
+            String::new()
+        } else if self.is_invalid() {
+            "invalid".to_string()
+        } else {
+            let current = self.current.map_or(0, |index| index + 1);
+            format!("{current}/{}", self.matches.len())
+        }
+    }
+
+    /// Forgets the matches of the previous query, and compiles the regular expression.
+    fn query_changed(&mut self) {
+        self.matches.clear();
+        self.current = None;
+        #[cfg(feature = "regex")]
+        {
+            self.compiled = if self.regex {
+                regex::RegexBuilder::new(&self.query)
+                    .case_insensitive(!self.case_sensitive)
+                    .build()
+                    .ok()
+            } else {
+                None
+            };
+            self.invalid = self.regex && !self.query.is_empty() && self.compiled.is_none();
+        }
+    }
+
+    /// Returns the byte index of the character at the given index.
+    fn byte_index(&self, index: usize) -> usize {
+        self.query
+            .char_indices()
+            .nth(index)
+            .map_or(self.query.len(), |(i, _)| i)
+    }
+}
+
+impl HasCursor for SearchState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+}
+
+/// Returns the byte ranges of the non-overlapping occurrences of `query` in `content`, comparing
+/// the characters by their lowercase form.
+fn find_ignoring_case(content: &str, query: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while let Some(rest) = content.get(start..).filter(|rest| !rest.is_empty()) {
+        if let Some(len) = match_len_ignoring_case(rest, query) {
+            ranges.push(start..start + len);
+            start += len;
+        } else {
+            start += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    ranges
+}
+
+/// Returns the byte length of the match if `content` starts with `query`, ignoring case.
+fn match_len_ignoring_case(content: &str, query: &str) -> Option<usize> {
+    let mut chars = content.char_indices();
+    for expected in query.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(content.len(), |(index, _)| index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_ignores_case_by_default() {
+        let mut state = SearchState::new().with_query("ab");
+        assert_eq!(state.find("Ab ab éAB ab"), [0..2, 3..5, 8..10, 11..13]);
+        state.toggle_case_sensitive();
+        assert_eq!(state.find("Ab ab éAB ab"), [3..5, 11..13]);
+        state.set_query("");
+        assert!(state.find("Ab ab").is_empty());
+    }
+
+    #[test]
+    fn editing() {
+        let mut state = SearchState::new().with_query("ac");
+        state.move_cursor_left();
+        state.insert_char('b');
+        assert_eq!(state.query(), "abc");
+        state.move_cursor_to_start();
+        state.delete_forward();
+        state.move_cursor_to_end();
+        state.delete_backward();
+        assert_eq!(state.query(), "b");
+        assert_eq!(state.cursor(), 1);
+    }
+
+    #[test]
+    fn navigation() {
+        let mut state = SearchState::new().with_query("a");
+        state.search(["a", "b", "aa"]);
+        assert_eq!(state.match_count(), 3);
+        assert_eq!(state.counter(), "1/3");
+
+        state.previous_match();
+        assert_eq!(
+            state.current_match(),
+            Some(&TextMatch {
+                line: 2,
+                range: 1..2
+            })
+        );
+        state.next_match();
+        assert_eq!(state.current_match_index(), Some(0));
+
+        state.next_match();
+        state.search(["a"]);
+        assert_eq!(state.current_match_index(), Some(0));
+
+        state.insert_char('x');
+        assert_eq!(state.current_match(), None);
+        assert_eq!(state.counter(), "0/0");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex() {
+        let mut state = SearchState::new().with_query(r"\d+");
+        assert!(state.find("a1 b22").is_empty());
+        state.toggle_regex();
+        assert_eq!(state.find("a1 b22"), [1..2, 4..6]);
+
+        state.set_query("(");
+        assert!(state.is_invalid());
+        assert!(state.find("(").is_empty());
+        assert_eq!(state.counter(), "invalid");
+    }
+}
@@ -0,0 +1,196 @@
+//! The [`Skeleton`] widget displays placeholder blocks while content is loading.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Styled},
+    symbols::shade,
+    widgets::Widget,
+};
+
+/// The width of each line of the skeleton, in percent of the area, cycling from the top.
+const LINE_WIDTHS: [u16; 4] = [100, 80, 90, 60];
+
+/// The width of the shimmer band, in cells.
+const SHIMMER_WIDTH: u16 = 4;
+
+/// A widget that displays shimmering placeholder lines while content is loading.
+///
+/// Each line of the area is filled with a bar of placeholder symbols of varying width, to suggest
+/// the shape of text that hasn't arrived yet. A band of highlighted symbols sweeps across the bars
+/// from left to right as the [`tick`] increases, so incrementing it on every frame animates the
+/// skeleton.
+///
+/// [`List::loading`] and [`Table::loading`] render a skeleton in place of their items.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{widgets::Skeleton, Frame};
+///
+/// # fn draw(frame: &mut Frame, frame_count: usize) {
+/// frame.render_widget(Skeleton::new().tick(frame_count), frame.area());
+/// # }
+/// ```
+///
+/// [`tick`]: Skeleton::tick
+/// [`List::loading`]: crate::list::List::loading
+/// [`Table::loading`]: crate::table::Table::loading
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Skeleton {
+    tick: usize,
+    symbol: &'static str,
+    style: Style,
+    shimmer_style: Style,
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Skeleton {
+    /// Creates a new `Skeleton` with dark gray bars and a gray shimmer.
+    pub const fn new() -> Self {
+        Self {
+            tick: 0,
+            symbol: shade::MEDIUM,
+            style: Style::new().fg(Color::DarkGray),
+            shimmer_style: Style::new().fg(Color::Gray),
+        }
+    }
+
+    /// Sets the animation tick, which controls the position of the shimmer.
+    ///
+    /// The shimmer moves one cell to the right for each tick and starts over once it has crossed
+    /// the whole area. It is hidden when the tick is `0`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tick(mut self, tick: usize) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Sets the symbol used to draw the bars.
+    ///
+    /// Defaults to [`shade::MEDIUM`] (`▒`).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the style of the bars.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the shimmer band, which is patched over the style of the bars.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shimmer_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.shimmer_style = style.into();
+        self
+    }
+}
+
+impl Widget for Skeleton {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Skeleton {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let period = usize::from(area.width + SHIMMER_WIDTH);
+        // the end of the shimmer band, relative to the left of the area
+        let shimmer_end = (self.tick % period) as u16;
+        let shimmer_start = shimmer_end.saturating_sub(SHIMMER_WIDTH);
+        for (index, y) in (area.top()..area.bottom()).enumerate() {
+            let percent = LINE_WIDTHS[index % LINE_WIDTHS.len()];
+            let width = (u32::from(area.width) * u32::from(percent) / 100).max(1) as u16;
+            for offset in 0..width {
+                let cell = &mut buf[(area.x + offset, y)];
+                cell.set_symbol(self.symbol).set_style(self.style);
+                if (shimmer_start..shimmer_end).contains(&offset) {
+                    cell.set_style(self.shimmer_style);
+                }
+            }
+        }
+    }
+}
+
+impl Styled for Skeleton {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Skeleton::new().render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "▒▒▒▒▒▒▒▒▒▒",
+            "▒▒▒▒▒▒▒▒  ",
+            "▒▒▒▒▒▒▒▒▒ ",
+            "▒▒▒▒▒▒    ",
+            "▒▒▒▒▒▒▒▒▒▒",
+        ]);
+        for (y, width) in [10, 8, 9, 6, 10].into_iter().enumerate() {
+            expected.set_style(Rect::new(0, y as u16, width, 1), Style::new().dark_gray());
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_shimmer() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Skeleton::new().tick(6).render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▒▒▒▒▒▒▒▒"]);
+        expected.set_style(expected.area, Style::new().dark_gray());
+        expected.set_style(Rect::new(2, 0, 4, 1), Style::new().gray());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn shimmer_wraps_around() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Skeleton::new().tick(12).render(buf.area, &mut buf);
+        let mut expected = Buffer::empty(buf.area);
+        Skeleton::new().render(expected.area, &mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_custom_symbol() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Skeleton::new()
+            .symbol("#")
+            .style(Style::new())
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["###"]));
+    }
+}
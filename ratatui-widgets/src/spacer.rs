@@ -0,0 +1,185 @@
+//! The [`Spacer`] widget fills the space between other widgets, optionally drawing a divider.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Style, Styled},
+    symbols::line,
+    widgets::Widget,
+};
+
+/// A widget that fills the gap between two panes, optionally drawing a divider line.
+///
+/// A `Spacer` is intended to be rendered into the spacer areas returned by
+/// [`Layout::split_with_spacers`], so that visual separators between panes do not need to be
+/// drawn with borders of [`Block`]s. By default it only applies its style to the area. When a
+/// line is set, the line is drawn through the middle of the area, vertically when the area is
+/// taller than it is wide and horizontally otherwise. The direction of the line can also be set
+/// explicitly with [`Spacer::direction`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::{Constraint, Layout},
+///     style::Stylize,
+///     widgets::{Paragraph, Spacer},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame) {
+/// let layout = Layout::horizontal([Constraint::Fill(1); 2]).spacing(1);
+/// let (panes, spacers) = layout.split_with_spacers(frame.area());
+/// frame.render_widget(Paragraph::new("left"), panes[0]);
+/// frame.render_widget(Paragraph::new("right"), panes[1]);
+/// frame.render_widget(Spacer::divider().dark_gray(), spacers[1]);
+/// # }
+/// ```
+///
+/// [`Layout::split_with_spacers`]: ratatui_core::layout::Layout::split_with_spacers
+/// [`Block`]: crate::block::Block
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Spacer {
+    line: Option<line::Set>,
+    direction: Option<Direction>,
+    style: Style,
+}
+
+impl Spacer {
+    /// Creates a new blank `Spacer`.
+    pub const fn new() -> Self {
+        Self {
+            line: None,
+            direction: None,
+            style: Style::new(),
+        }
+    }
+
+    /// Creates a new `Spacer` that draws a solid divider line.
+    pub const fn divider() -> Self {
+        Self::new().line(line::NORMAL)
+    }
+
+    /// Creates a new `Spacer` that draws a dotted rule.
+    pub const fn dotted() -> Self {
+        Self::new().line(line::LIGHT_QUADRUPLE_DASHED)
+    }
+
+    /// Sets the line symbols used to draw the divider.
+    ///
+    /// Only the `vertical` and `horizontal` symbols of the set are used.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line(mut self, line: line::Set) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets the direction of the divider line.
+    ///
+    /// [`Direction::Vertical`] draws a vertical line, which separates panes laid out horizontally.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style of the spacer.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for Spacer {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Spacer {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let Some(line) = self.line else {
+            return;
+        };
+        let direction = self.direction.unwrap_or(if area.height > area.width {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        });
+        match direction {
+            Direction::Vertical => {
+                let x = area.x + area.width.saturating_sub(1) / 2;
+                for y in area.top()..area.bottom() {
+                    buf[(x, y)].set_symbol(line.vertical);
+                }
+            }
+            Direction::Horizontal => {
+                let y = area.y + area.height.saturating_sub(1) / 2;
+                for x in area.left()..area.right() {
+                    buf[(x, y)].set_symbol(line.horizontal);
+                }
+            }
+        }
+    }
+}
+
+impl Styled for Spacer {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn render_blank() {
+        let mut buf = Buffer::with_lines(["abc"]);
+        Spacer::new().on_blue().render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["abc"]);
+        expected.set_style(expected.area, Style::new().bg(Color::Blue));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_vertical_divider() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Spacer::divider().render(buf.area.split_left(1).1, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" │ ", " │ ", " │ "]));
+    }
+
+    #[test]
+    fn render_horizontal_dotted() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Spacer::dotted().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["    ", "┈┈┈┈", "    "]));
+    }
+
+    #[test]
+    fn render_explicit_direction() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Spacer::divider()
+            .direction(Direction::Vertical)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["│"]));
+    }
+}
@@ -0,0 +1,208 @@
+//! The [`Stack`] widget renders several widgets on top of each other in the same area.
+use std::fmt;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Offset, Rect},
+    widgets::Widget,
+};
+
+use crate::clear::Clear;
+
+/// A widget that renders multiple layers into the same area, in the order they were added.
+///
+/// Later layers are drawn over earlier ones, so the last layer is on top. Each [`StackLayer`] can
+/// be moved by an [`Offset`] relative to the render area and can be marked as
+/// [transparent](StackLayer::transparent), in which case the cells it does not draw to keep the
+/// content of the layers below it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::Offset,
+///     widgets::{Block, Paragraph, Stack, StackLayer},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame) {
+/// let stack = Stack::new()
+///     .layer(Block::bordered().title("Background"))
+///     .push(
+///         StackLayer::new(Paragraph::new("Overlay"))
+///             .offset(Offset::new(2, 1))
+///             .transparent(true),
+///     );
+/// frame.render_widget(stack, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Stack<'a> {
+    layers: Vec<StackLayer<'a>>,
+}
+
+type RenderFn<'a> = Box<dyn FnOnce(Rect, &mut Buffer) + 'a>;
+
+/// A single layer of a [`Stack`].
+///
+/// A layer wraps any [`Widget`] together with the offset and transparency used when rendering it
+/// as part of the stack.
+pub struct StackLayer<'a> {
+    render: RenderFn<'a>,
+    offset: Offset,
+    transparent: bool,
+}
+
+impl<'a> Stack<'a> {
+    /// Creates a new empty `Stack`.
+    pub const fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Adds a widget as a new opaque layer on top of the stack.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn layer<W: Widget + 'a>(self, widget: W) -> Self {
+        self.push(StackLayer::new(widget))
+    }
+
+    /// Adds a configured [`StackLayer`] on top of the stack.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn push(mut self, layer: StackLayer<'a>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Returns the number of layers in the stack.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` if the stack has no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+impl<'a> FromIterator<StackLayer<'a>> for Stack<'a> {
+    fn from_iter<T: IntoIterator<Item = StackLayer<'a>>>(iter: T) -> Self {
+        Self {
+            layers: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> StackLayer<'a> {
+    /// Creates a new opaque layer rendering the given widget with no offset.
+    pub fn new<W: Widget + 'a>(widget: W) -> Self {
+        Self {
+            render: Box::new(move |area, buf| widget.render(area, buf)),
+            offset: Offset::ZERO,
+            transparent: false,
+        }
+    }
+
+    /// Moves the layer by the given offset relative to the area of the stack.
+    ///
+    /// The part of the layer that ends up outside the area of the stack is not rendered.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn offset(mut self, offset: Offset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether the layers below show through the cells that this layer does not draw to.
+    ///
+    /// Opaque layers (the default) [clear](crate::clear::Clear) their area before rendering, so
+    /// nothing from the layers below is visible within it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let layer_area = area.offset(self.offset).intersection(area);
+        if layer_area.is_empty() {
+            return;
+        }
+        if !self.transparent {
+            Clear.render(layer_area, buf);
+        }
+        (self.render)(layer_area, buf);
+    }
+}
+
+impl fmt::Debug for StackLayer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StackLayer")
+            .field("offset", &self.offset)
+            .field("transparent", &self.transparent)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Widget for Stack<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for layer in self.layers {
+            layer.render(area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paragraph::Paragraph;
+
+    #[test]
+    fn render_in_order() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        Stack::new()
+            .layer(Paragraph::new("aaaaa\naaaaa"))
+            .push(StackLayer::new(Paragraph::new("bb")).offset(Offset::new(0, 1)))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["aaaaa", "bb   "]));
+    }
+
+    #[test]
+    fn render_offset() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        Stack::new()
+            .push(StackLayer::new(Paragraph::new("abcd")).offset(Offset::new(3, 1)))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["     ", "   ab"]));
+    }
+
+    #[test]
+    fn render_opaque_overwrites_below() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Stack::new()
+            .layer(Paragraph::new("aaaaa"))
+            .layer(Paragraph::new("b b"))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["b b  "]));
+    }
+
+    #[test]
+    fn render_transparent_keeps_empty_cells_below() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Stack::new()
+            .layer(Paragraph::new("aaaaa"))
+            .push(StackLayer::new(Paragraph::new("b b")).transparent(true))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["b baa"]));
+    }
+
+    #[test]
+    fn from_iter() {
+        let stack: Stack = [
+            StackLayer::new(Paragraph::new("a")),
+            StackLayer::new(Paragraph::new("b")),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(stack.len(), 2);
+        assert!(!stack.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! The [`Switch`] widget renders one of several widgets of the same type, chosen by index.
+use ratatui_core::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// A widget that renders one of several widgets, chosen by index.
+///
+/// This is useful for views that show one of a fixed set of pages (e.g. the content of the
+/// selected tab). Nothing is rendered when the index is out of range. Use [`Either`] to choose
+/// between widgets of different types.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     widgets::{Paragraph, Switch},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, selected_tab: usize) {
+/// let pages = [
+///     Paragraph::new("Home"),
+///     Paragraph::new("Settings"),
+///     Paragraph::new("About"),
+/// ];
+/// frame.render_widget(Switch::new(selected_tab, pages), frame.area());
+/// # }
+/// ```
+///
+/// [`Either`]: crate::either::Either
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Switch<W> {
+    index: usize,
+    children: Vec<W>,
+}
+
+impl<W> Switch<W> {
+    /// Creates a new `Switch` that renders the child at the given index.
+    pub fn new<I>(index: usize, children: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+    {
+        Self {
+            index,
+            children: children.into_iter().collect(),
+        }
+    }
+
+    /// Sets the index of the child to render.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn index(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Returns the child that will be rendered, if the index is in range.
+    pub fn selected(&self) -> Option<&W> {
+        self.children.get(self.index)
+    }
+}
+
+impl<W: Widget> Widget for Switch<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.children.into_iter().nth(self.index).render(area, buf);
+    }
+}
+
+impl<W> Widget for &Switch<W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.selected().render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::Line;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Switch::new(1, ["one", "two", "six"]).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["two"]));
+    }
+
+    #[test]
+    fn render_out_of_range() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Switch::new(3, ["one", "two", "six"]).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["   "]));
+    }
+
+    #[test]
+    fn render_ref() {
+        let switch = Switch::new(0, [Line::from("one"), Line::from("two")]).index(1);
+        assert_eq!(switch.selected(), Some(&Line::from("two")));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        (&switch).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["two"]));
+    }
+}
@@ -4,17 +4,32 @@
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Constraint, Flex, Layout, Rect},
-    style::{Style, Styled},
+    layout::{self, Constraint, Flex, Layout, Position, Rect},
+    style::{Modifier, Style, Styled},
     text::Text,
     widgets::{StatefulWidget, Widget},
 };
-
-pub use self::{cell::Cell, highlight_spacing::HighlightSpacing, row::Row, state::TableState};
-use crate::block::{Block, BlockExt};
+use unicode_width::UnicodeWidthChar;
+
+#[cfg(feature = "csv")]
+pub use self::data::TableData;
+pub use self::{
+    cell::Cell,
+    editor::{CellEdit, CellEditor},
+    row::Row,
+    state::TableState,
+};
+pub use crate::highlight_spacing::HighlightSpacing;
+use crate::{
+    align::Align,
+    block::{Block, BlockExt},
+    skeleton::Skeleton,
+};
 
 mod cell;
-mod highlight_spacing;
+#[cfg(feature = "csv")]
+mod data;
+mod editor;
 mod row;
 mod state;
 
@@ -40,6 +55,10 @@ mod state;
 /// Note: if the `widths` field is empty, the table will be rendered with equal widths.
 /// Note: Highlight styles are applied in the following order: Row, Column, Cell.
 ///
+/// A cell can also be edited in place: [`TableState::start_editing`] starts editing the selected
+/// cell, which is then rendered as an input field with the [`Table::edit_style`] until the edit is
+/// committed or cancelled. See [`CellEditor`] for more details.
+///
 /// See the table example and the recipe and traceroute tabs in the demo2 example in the [Examples]
 /// directory for a more in depth example of the various configuration options and for how to handle
 /// state.
@@ -67,6 +86,7 @@ mod state;
 /// - [`Table::cell_highlight_style`] sets the style of the selected cell.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::edit_style`] sets the style of the cell being edited.
 ///
 /// # Example
 ///
@@ -277,6 +297,15 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Text displayed when the table has no rows
+    empty_text: Option<Text<'a>>,
+
+    /// Whether to display a skeleton instead of the rows
+    loading: bool,
+
+    /// Style used to render the cell being edited
+    edit_style: Style,
 }
 
 impl Default for Table<'_> {
@@ -295,6 +324,9 @@ impl Default for Table<'_> {
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            empty_text: None,
+            loading: false,
+            edit_style: Style::new().add_modifier(Modifier::UNDERLINED),
         }
     }
 }
@@ -748,6 +780,155 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Set the text displayed when the table has no rows
+    ///
+    /// The text is centered in the area below the header, so that messages such as "No results"
+    /// don't require a separate branch in the application's rendering code. The header and footer
+    /// are still rendered.
+    ///
+    /// `empty_text` accepts any value that can be converted to [`Text`] (e.g. [`&str`],
+    /// [`String`], [`Line`] or [`Span`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let table = Table::new(Vec::<Row>::new(), [10, 10])
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .empty_text("No files found");
+    /// ```
+    ///
+    /// [`Line`]: ratatui_core::text::Line
+    /// [`Span`]: ratatui_core::text::Span
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn empty_text<T: Into<Text<'a>>>(mut self, empty_text: T) -> Self {
+        self.empty_text = Some(empty_text.into());
+        self
+    }
+
+    /// Set whether the table is loading
+    ///
+    /// While loading, a [`Skeleton`] is rendered in place of the rows. The header and footer are
+    /// still rendered. Render a [`Skeleton`] directly to animate it or to customize its
+    /// appearance.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let is_fetching = true;
+    /// let table = Table::new(Vec::<Row>::new(), [10, 10])
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .loading(is_fetching);
+    /// ```
+    ///
+    /// [`Skeleton`]: crate::skeleton::Skeleton
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the style of the cell being edited
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style is applied on top of the highlight styles. Defaults to underlined.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{Row, Table},
+    /// };
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [5, 5];
+    /// let table = Table::new(rows, widths).edit_style(Style::new().black().on_yellow());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn edit_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.edit_style = style.into();
+        self
+    }
+
+    /// Returns the text of the current selection as tab-separated values.
+    ///
+    /// This is useful to copy the selection to the clipboard so that it can be pasted into a
+    /// spreadsheet. The content of the selection depends on what is selected in the `state`:
+    ///
+    /// - a cell (both a row and a column): the text of that cell
+    /// - a row only: the cells of the row separated by tabs
+    /// - a column only: the cells of the column in every row, one per line
+    ///
+    /// Tabs and line breaks inside the cells are replaced by spaces. Returns `None` if nothing is
+    /// selected or if the selection is out of the bounds of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let table = Table::default().rows([Row::new(["a", "b"]), Row::new(["c", "d"])]);
+    /// let state = TableState::default().with_selected(1);
+    /// assert_eq!(table.selection_to_tsv(&state), Some("c\td".to_string()));
+    /// ```
+    pub fn selection_to_tsv(&self, state: &TableState) -> Option<String> {
+        let values = self.selected_values(state)?;
+        let mut lines = values.iter().map(|row| {
+            row.iter()
+                .map(|value| value.replace(['\t', '\n', '\r'], " "))
+                .join("\t")
+        });
+        Some(lines.join("\n"))
+    }
+
+    /// Returns the text of the current selection as comma-separated values.
+    ///
+    /// The selection is extracted in the same way as [`Table::selection_to_tsv`]. Fields that
+    /// contain commas, quotes or line breaks are quoted as described in [RFC 4180].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let table = Table::default().rows([Row::new(["a", "b"]), Row::new(["1,5", "d"])]);
+    /// let state = TableState::default().with_selected_column(0);
+    /// assert_eq!(
+    ///     table.selection_to_csv(&state),
+    ///     Some("a\n\"1,5\"".to_string())
+    /// );
+    /// ```
+    ///
+    /// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+    pub fn selection_to_csv(&self, state: &TableState) -> Option<String> {
+        let values = self.selected_values(state)?;
+        let mut lines = values.iter().map(|row| {
+            row.iter()
+                .map(|value| {
+                    if value.contains([',', '"', '\n', '\r']) {
+                        format!("\"{}\"", value.replace('"', "\"\""))
+                    } else {
+                        value.clone()
+                    }
+                })
+                .join(",")
+        });
+        Some(lines.join("\n"))
+    }
 }
 
 impl Widget for Table<'_> {
@@ -775,6 +956,8 @@ impl StatefulWidget for &Table<'_> {
     type State = TableState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.cursor_position = None;
+        state.near_end = false;
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let table_area = self.block.inner_if_some(area);
@@ -860,16 +1043,33 @@ impl Table<'_> {
         selection_width: u16,
         columns_widths: &[(u16, u16)],
     ) {
+        if self.loading {
+            Skeleton::new().render(area, buf);
+            return;
+        }
+
         if self.rows.is_empty() {
+            state.near_end = state.near_end_threshold.is_some();
+            if let Some(empty_text) = &self.empty_text {
+                Align::new(empty_text)
+                    .width(u16::try_from(empty_text.width()).unwrap_or(u16::MAX))
+                    .height(u16::try_from(empty_text.height()).unwrap_or(u16::MAX))
+                    .centered()
+                    .render(area, buf);
+            }
             return;
         }
 
         let (start_index, end_index) = self.visible_rows(state, area);
         state.offset = start_index;
+        state.near_end = state
+            .near_end_threshold
+            .is_some_and(|threshold| end_index + threshold >= self.rows.len());
 
         let mut y_offset = 0;
 
         let mut selected_row_area = None;
+        let mut edited_row_area = None;
         for (i, row) in self
             .rows
             .iter()
@@ -900,6 +1100,13 @@ impl Table<'_> {
             if is_selected {
                 selected_row_area = Some(row_area);
             }
+            if state
+                .editor
+                .as_ref()
+                .is_some_and(|editor| editor.row() == i)
+            {
+                edited_row_area = Some(row_area);
+            }
             y_offset += row.height_with_margin();
         }
 
@@ -928,6 +1135,52 @@ impl Table<'_> {
             }
             (None, None) => (),
         }
+
+        if let (Some(row_area), Some(editor)) = (edited_row_area, state.editor.as_ref()) {
+            if let Some((x, width)) = columns_widths.get(editor.column()) {
+                let cell_area = Rect::new(row_area.x + x, row_area.y, *width, row_area.height);
+                state.cursor_position = self.render_editor(cell_area, buf, editor);
+            }
+        }
+    }
+
+    /// Renders the edited value in place of the cell, scrolled so that the cursor is visible, and
+    /// returns the position of the cursor.
+    fn render_editor(&self, area: Rect, buf: &mut Buffer, editor: &CellEditor) -> Option<Position> {
+        if area.is_empty() {
+            return None;
+        }
+        for position in area.positions() {
+            buf[position].set_symbol(" ");
+        }
+        buf.set_style(area, self.edit_style);
+        let widths = editor
+            .value()
+            .chars()
+            .map(|c| c.width().unwrap_or(0))
+            .collect_vec();
+        let cursor_column: usize = widths[..editor.cursor()].iter().sum();
+        // skip the characters at the start of the value until the cursor fits in the cell
+        let mut skipped = 0;
+        let mut skipped_width = 0;
+        while cursor_column - skipped_width >= usize::from(area.width) && skipped < editor.cursor()
+        {
+            skipped_width += widths[skipped];
+            skipped += 1;
+        }
+        let visible = editor.value().chars().skip(skipped).collect::<String>();
+        buf.set_stringn(
+            area.x,
+            area.y,
+            visible,
+            usize::from(area.width),
+            Style::new(),
+        );
+        let column = u16::try_from(cursor_column - skipped_width).unwrap_or(u16::MAX);
+        Some(Position::new(
+            area.x + column.min(area.width.saturating_sub(1)),
+            area.y,
+        ))
     }
 
     /// Return the indexes of the visible rows.
@@ -1001,14 +1254,52 @@ impl Table<'_> {
             self.widths.clone()
         };
         // this will always allocate a selection area
-        let [_selection_area, columns_area] =
-            Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
-                .areas(Rect::new(0, 0, max_width, 1));
-        let rects = Layout::horizontal(widths)
-            .flex(self.flex)
-            .spacing(self.column_spacing)
-            .split(columns_area);
-        rects.iter().map(|c| (c.x, c.width)).collect()
+        let selection_width = selection_width.min(max_width);
+        layout::solve(
+            max_width - selection_width,
+            &widths,
+            self.flex,
+            self.column_spacing,
+        )
+        .into_iter()
+        .map(|(x, width)| (x + selection_width, width))
+        .collect()
+    }
+
+    /// Returns the text of the selected cells, grouped by row.
+    fn selected_values(&self, state: &TableState) -> Option<Vec<Vec<String>>> {
+        let cell_text = |row: &Row, column: usize| {
+            row.cells
+                .get(column)
+                .map(|cell| cell.text().to_string())
+                .unwrap_or_default()
+        };
+        match (state.selected, state.selected_column) {
+            (Some(row), Some(column)) => {
+                let row = self.rows.get(row)?;
+                Some(vec![vec![cell_text(row, column)]])
+            }
+            (Some(row), None) => {
+                let row = self.rows.get(row)?;
+                Some(vec![row
+                    .cells
+                    .iter()
+                    .map(|cell| cell.text().to_string())
+                    .collect()])
+            }
+            (None, Some(column)) => {
+                if column >= self.column_count() {
+                    return None;
+                }
+                Some(
+                    self.rows
+                        .iter()
+                        .map(|row| vec![cell_text(row, column)])
+                        .collect(),
+                )
+            }
+            (None, None) => None,
+        }
     }
 
     fn column_count(&self) -> usize {
@@ -1035,11 +1326,16 @@ impl Table<'_> {
 
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     for w in widths {
-        if let Constraint::Percentage(p) = w {
-            assert!(
+        match w {
+            Constraint::Percentage(p) => assert!(
                 *p <= 100,
                 "Percentages should be between 0 and 100 inclusively."
-            );
+            ),
+            Constraint::Permille(p) => assert!(
+                *p <= 1000,
+                "Permilles should be between 0 and 1000 inclusively."
+            ),
+            _ => {}
         }
     }
 }
@@ -1100,6 +1396,8 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert_eq!(table.empty_text, None);
+        assert!(!table.loading);
     }
 
     #[test]
@@ -1116,6 +1414,8 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert_eq!(table.empty_text, None);
+        assert!(!table.loading);
     }
 
     #[test]
@@ -1235,6 +1535,65 @@ mod tests {
         assert_eq!(table.highlight_spacing, HighlightSpacing::Always);
     }
 
+    #[test]
+    fn empty_text() {
+        let table = Table::default().empty_text("Empty");
+        assert_eq!(table.empty_text, Some(Text::from("Empty")));
+    }
+
+    #[test]
+    fn loading() {
+        let table = Table::default().loading(true);
+        assert!(table.loading);
+    }
+
+    #[rstest]
+    #[case::nothing(None, None, None)]
+    #[case::cell(Some(1), Some(0), Some("1,5"))]
+    #[case::row(Some(0), None, Some("a\tb c"))]
+    #[case::column(None, Some(1), Some("b c\nd"))]
+    #[case::row_out_of_bounds(Some(2), None, None)]
+    #[case::column_out_of_bounds(None, Some(2), None)]
+    fn selection_to_tsv(
+        #[case] row: Option<usize>,
+        #[case] column: Option<usize>,
+        #[case] expected: Option<&str>,
+    ) {
+        let table = Table::default().rows([
+            Row::new(vec![Cell::from("a"), Cell::from("b\tc")]),
+            Row::new(vec!["1,5", "d"]),
+        ]);
+        let state = TableState::new().with_selected(row);
+        let state = state.with_selected_column(column);
+        assert_eq!(table.selection_to_tsv(&state).as_deref(), expected);
+    }
+
+    #[test]
+    fn selection_to_csv() {
+        let table = Table::default().rows([
+            Row::new(vec!["a", "say \"hi\""]),
+            Row::new(vec![Cell::from(Text::from(vec![
+                Line::from("1"),
+                Line::from("2"),
+            ]))]),
+        ]);
+        let state = TableState::new().with_selected(0);
+        assert_eq!(
+            table.selection_to_csv(&state).as_deref(),
+            Some("a,\"say \"\"hi\"\"\"")
+        );
+        let state = TableState::new().with_selected_column(1);
+        assert_eq!(
+            table.selection_to_csv(&state).as_deref(),
+            Some("\"say \"\"hi\"\"\"\n")
+        );
+        let state = TableState::new().with_selected_column(0);
+        assert_eq!(
+            table.selection_to_csv(&state).as_deref(),
+            Some("a\n\"1\n2\"")
+        );
+    }
+
     #[test]
     #[should_panic = "Percentages should be between 0 and 100 inclusively"]
     fn table_invalid_percentages() {
@@ -1369,6 +1728,35 @@ mod tests {
             assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 15, 3)));
         }
 
+        #[test]
+        fn render_empty_text() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .empty_text("No rows");
+            Widget::render(table, buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Head1 Head2    ",
+                "               ",
+                "    No rows    ",
+                "               ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_loading() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let table = Table::new([Row::new(["Cell1"])], [Constraint::Length(5)])
+                .header(Row::new(["Head1"]))
+                .loading(true);
+            Widget::render(table, buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["Head1", "", ""]);
+            Skeleton::new().render(Rect::new(0, 1, 5, 2), &mut expected);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_block() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1626,6 +2014,62 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_editor() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4", "Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .cell_highlight_style(Style::new().green())
+                .edit_style(Style::new().underlined());
+            let mut state = TableState::new().with_selected_cell((1, 1));
+            state.start_editing("ab");
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines::<[Line; 2]>([
+                Line::from("Cell1 Cell2 Cell3   "),
+                Line::from(vec![
+                    "Cell4 ".into(),
+                    "ab   ".green().underlined(),
+                    " Cell6   ".into(),
+                ]),
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.cursor_position(), Some(Position::new(8, 1)));
+
+            // the cursor stays visible when the value is wider than the cell
+            state.editor_mut().unwrap().set_value("abcdefgh");
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf[(6, 1)].symbol(), "e");
+            assert_eq!(state.cursor_position(), Some(Position::new(10, 1)));
+
+            state.commit_edit();
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf[(6, 1)].symbol(), "C");
+            assert_eq!(state.cursor_position(), None);
+        }
+
+        #[rstest]
+        #[case::top(None, Some(2), false)]
+        #[case::within_threshold(Some(7), Some(2), true)]
+        #[case::last(Some(9), Some(0), true)]
+        #[case::no_threshold(Some(9), None, false)]
+        fn render_near_end(
+            #[case] selected: Option<usize>,
+            #[case] threshold: Option<usize>,
+            #[case] expected: bool,
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let rows = (0..10).map(|i| Row::new([i.to_string()]));
+            let table = Table::new(rows, [5]);
+            let mut state = TableState::new()
+                .with_selected(selected)
+                .with_near_end_threshold(threshold);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.is_near_end(), expected);
+        }
+
         #[test]
         fn render_with_selected_row_and_column() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
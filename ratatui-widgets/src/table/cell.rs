@@ -165,6 +165,10 @@ impl Cell<'_> {
         buf.set_style(area, self.style);
         Widget::render(&self.content, area, buf);
     }
+
+    pub(crate) const fn text(&self) -> &Text<'_> {
+        &self.content
+    }
 }
 
 impl<'a, T> From<T> for Cell<'a>
@@ -0,0 +1,309 @@
+use std::io;
+
+use ratatui_core::{
+    layout::{Alignment, Constraint},
+    text::Text,
+};
+use serde::Serialize;
+use unicode_width::UnicodeWidthStr;
+
+use super::{Cell, Row, Table};
+
+/// Tabular data read from serializable records or CSV, ready to be displayed in a [`Table`].
+///
+/// `TableData` removes the boilerplate of mapping each field of a record to a [`Cell`]. The
+/// header is extracted from the field names of the records (or from the first line of a CSV
+/// file), and columns whose values are all numbers are right-aligned.
+///
+/// The data can be converted directly into a [`Table`], which sets the header, the rows and
+/// widths that fit the content of each column. Use [`TableData::header`], [`TableData::rows`] and
+/// [`TableData::widths`] to customize the table instead.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Table, TableData};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Process {
+///     name: &'static str,
+///     pid: u32,
+/// }
+///
+/// let processes = [
+///     Process {
+///         name: "init",
+///         pid: 1,
+///     },
+///     Process {
+///         name: "sshd",
+///         pid: 812,
+///     },
+/// ];
+/// let data = TableData::from_serialize(&processes)?;
+/// assert_eq!(data.headers(), ["name", "pid"]);
+/// let table = Table::from(data);
+/// # Ok::<(), csv::Error>(())
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TableData {
+    headers: Vec<String>,
+    records: Vec<Vec<String>>,
+    alignments: Vec<Alignment>,
+}
+
+impl TableData {
+    /// Creates table data from records that implement [`Serialize`].
+    ///
+    /// The records are serialized in the same way as [`csv::Writer::serialize`] does: structs
+    /// and maps produce a header from their field names, while tuples and sequences produce no
+    /// header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record cannot be serialized as a CSV record, for example if it
+    /// contains nested containers.
+    pub fn from_serialize<I>(records: I) -> Result<Self, csv::Error>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut records = records.into_iter();
+        let Some(first) = records.next() else {
+            return Ok(Self::default());
+        };
+        // the header is only written for records with named fields, in which case serializing
+        // the first record produces two lines
+        let mut values = serialize(Some(first), true)?;
+        let headers = if values.len() == 2 {
+            values.remove(0)
+        } else {
+            Vec::new()
+        };
+        values.extend(serialize(records, false)?);
+        Ok(Self::new(headers, values))
+    }
+
+    /// Creates table data from a CSV reader.
+    ///
+    /// If the reader is configured to read headers (which is the default), the first line of the
+    /// input is used as the header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be read or is not valid CSV.
+    pub fn from_csv<R: io::Read>(mut reader: csv::Reader<R>) -> Result<Self, csv::Error> {
+        let headers = if reader.has_headers() {
+            reader.headers()?.iter().map(String::from).collect()
+        } else {
+            Vec::new()
+        };
+        let records = reader
+            .records()
+            .map(|record| Ok(record?.iter().map(String::from).collect()))
+            .collect::<Result<_, csv::Error>>()?;
+        Ok(Self::new(headers, records))
+    }
+
+    /// Returns the names of the columns, or an empty slice if the data has no header.
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Returns the values of the records.
+    pub fn records(&self) -> &[Vec<String>] {
+        &self.records
+    }
+
+    /// Returns the alignment of each column.
+    ///
+    /// Columns that only contain numbers are right-aligned, other columns are left-aligned.
+    pub fn alignments(&self) -> &[Alignment] {
+        &self.alignments
+    }
+
+    /// Returns the header row, or `None` if the data has no header.
+    ///
+    /// The cells of the header are aligned like the values of their column.
+    pub fn header(&self) -> Option<Row<'static>> {
+        if self.headers.is_empty() {
+            return None;
+        }
+        Some(self.row(&self.headers))
+    }
+
+    /// Returns a row for each record.
+    pub fn rows(&self) -> Vec<Row<'static>> {
+        self.records.iter().map(|record| self.row(record)).collect()
+    }
+
+    /// Returns widths that fit the header and values of each column.
+    pub fn widths(&self) -> Vec<Constraint> {
+        (0..self.alignments.len())
+            .map(|column| {
+                let width = self
+                    .records
+                    .iter()
+                    .chain(Some(&self.headers))
+                    .filter_map(|record| record.get(column))
+                    .map(|value| value.width())
+                    .max()
+                    .unwrap_or_default();
+                Constraint::Length(u16::try_from(width).unwrap_or(u16::MAX))
+            })
+            .collect()
+    }
+
+    fn new(headers: Vec<String>, records: Vec<Vec<String>>) -> Self {
+        let mut data = Self {
+            headers,
+            records,
+            alignments: Vec::new(),
+        };
+        data.alignments = data.detect_alignments();
+        data
+    }
+
+    /// Right-aligns the columns whose values are all numbers.
+    fn detect_alignments(&self) -> Vec<Alignment> {
+        let columns = self
+            .records
+            .iter()
+            .chain(Some(&self.headers))
+            .map(Vec::len)
+            .max()
+            .unwrap_or_default();
+        (0..columns)
+            .map(|column| {
+                let mut values = self
+                    .records
+                    .iter()
+                    .filter_map(|record| record.get(column))
+                    .filter(|value| !value.is_empty())
+                    .peekable();
+                let is_numeric = values.peek().is_some()
+                    && values.all(|value| value.trim().parse::<f64>().is_ok());
+                if is_numeric {
+                    Alignment::Right
+                } else {
+                    Alignment::Left
+                }
+            })
+            .collect()
+    }
+
+    fn row(&self, values: &[String]) -> Row<'static> {
+        values
+            .iter()
+            .zip(&self.alignments)
+            .map(|(value, alignment)| Cell::new(Text::from(value.clone()).alignment(*alignment)))
+            .collect()
+    }
+}
+
+/// Serializes the records as CSV and reads back the values of each line.
+fn serialize<I>(records: I, has_headers: bool) -> Result<Vec<Vec<String>>, csv::Error>
+where
+    I: IntoIterator,
+    I::Item: Serialize,
+{
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(has_headers)
+        .from_writer(Vec::new());
+    for record in records {
+        writer.serialize(record)?;
+    }
+    let csv = writer
+        .into_inner()
+        .map_err(csv::IntoInnerError::into_error)?;
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(csv.as_slice())
+        .records()
+        .map(|record| Ok(record?.iter().map(String::from).collect()))
+        .collect()
+}
+
+impl From<TableData> for Table<'static> {
+    fn from(data: TableData) -> Self {
+        let table = Self::new(data.rows(), data.widths());
+        match data.header() {
+            Some(header) => table.header(header),
+            None => table,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Fruit {
+        name: &'static str,
+        price: f64,
+    }
+
+    #[test]
+    fn from_serialize_struct() {
+        let fruits = [
+            Fruit {
+                name: "apple",
+                price: 1.5,
+            },
+            Fruit {
+                name: "kiwi",
+                price: 12.0,
+            },
+        ];
+        let data = TableData::from_serialize(&fruits).unwrap();
+        assert_eq!(data.headers(), ["name", "price"]);
+        assert_eq!(data.records(), [["apple", "1.5"], ["kiwi", "12.0"]]);
+        assert_eq!(data.alignments(), [Alignment::Left, Alignment::Right]);
+        assert_eq!(
+            data.widths(),
+            [Constraint::Length(5), Constraint::Length(5)]
+        );
+    }
+
+    #[test]
+    fn from_serialize_tuple() {
+        let data = TableData::from_serialize([("a", 1), ("b", 2)]).unwrap();
+        assert!(data.headers().is_empty());
+        assert_eq!(data.header(), None);
+        assert_eq!(data.records(), [["a", "1"], ["b", "2"]]);
+    }
+
+    #[test]
+    fn from_serialize_empty() {
+        let data = TableData::from_serialize(Vec::<Fruit>::new()).unwrap();
+        assert_eq!(data, TableData::default());
+    }
+
+    #[test]
+    fn from_csv() {
+        let csv = "name,count\nfoo,1\nbar,\n";
+        let data = TableData::from_csv(csv::Reader::from_reader(csv.as_bytes())).unwrap();
+        assert_eq!(data.headers(), ["name", "count"]);
+        assert_eq!(data.records(), [["foo", "1"], ["bar", ""]]);
+        assert_eq!(data.alignments(), [Alignment::Left, Alignment::Right]);
+    }
+
+    #[test]
+    fn into_table() {
+        use ratatui_core::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+        let csv = "item,qty\nnuts,12\nbolts,3\n";
+        let data = TableData::from_csv(csv::Reader::from_reader(csv.as_bytes())).unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        Table::from(data).render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["item  qty", "nuts   12", "bolts   3"])
+        );
+    }
+}
@@ -0,0 +1,169 @@
+/// The value of a [`Table`] cell that is being edited.
+///
+/// An editor is created by [`TableState::start_editing`] with the current value of the selected
+/// cell, and rendered by the table in place of that cell. It provides the usual editing
+/// operations; key presses are mapped to them by the application. The edit ends with
+/// [`TableState::commit_edit`], which returns the new value as a [`CellEdit`], or with
+/// [`TableState::cancel_edit`].
+///
+/// The cursor is an index in the characters of the value, between `0` and the number of
+/// characters.
+///
+/// [`Table`]: crate::table::Table
+/// [`TableState::start_editing`]: crate::table::TableState::start_editing
+/// [`TableState::commit_edit`]: crate::table::TableState::commit_edit
+/// [`TableState::cancel_edit`]: crate::table::TableState::cancel_edit
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CellEditor {
+    row: usize,
+    column: usize,
+    value: String,
+    cursor: usize,
+}
+
+/// The result of committing the edit of a [`Table`] cell, to be applied to the underlying data.
+///
+/// Returned by [`TableState::commit_edit`].
+///
+/// [`Table`]: crate::table::Table
+/// [`TableState::commit_edit`]: crate::table::TableState::commit_edit
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CellEdit {
+    /// The index of the row of the edited cell.
+    pub row: usize,
+    /// The index of the column of the edited cell.
+    pub column: usize,
+    /// The new value of the cell.
+    pub value: String,
+}
+
+impl CellEditor {
+    /// Creates an editor for the cell at the given row and column, with the cursor at the end of
+    /// the value.
+    pub(crate) fn new(row: usize, column: usize, value: String) -> Self {
+        let cursor = value.chars().count();
+        Self {
+            row,
+            column,
+            value,
+            cursor,
+        }
+    }
+
+    /// Returns the index of the row of the edited cell.
+    pub const fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the index of the column of the edited cell.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the edited value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value, moving the cursor to its end.
+    pub fn set_value<T: Into<String>>(&mut self, value: T) {
+        self.value = value.into();
+        self.cursor = self.value.chars().count();
+    }
+
+    /// Returns the position of the cursor, as an index in the characters of the value.
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Inserts a character at the cursor.
+    pub fn insert_char(&mut self, c: char) {
+        let index = self.byte_index(self.cursor);
+        self.value.insert(index, c);
+        self.cursor += 1;
+    }
+
+    /// Deletes the character before the cursor, like `Backspace`.
+    pub fn delete_backward(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.value.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    /// Deletes the character under the cursor, like `Delete`.
+    pub fn delete_forward(&mut self) {
+        if self.cursor < self.value.chars().count() {
+            self.value.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    /// Moves the cursor one character to the left.
+    pub fn move_cursor_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one character to the right.
+    pub fn move_cursor_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.value.chars().count());
+    }
+
+    /// Moves the cursor to the start of the value.
+    pub fn move_cursor_to_start(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the value.
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor = self.value.chars().count();
+    }
+
+    /// Ends the edit, returning the new value of the cell.
+    pub(crate) fn into_edit(self) -> CellEdit {
+        CellEdit {
+            row: self.row,
+            column: self.column,
+            value: self.value,
+        }
+    }
+
+    /// Returns the byte index of the character at the given index.
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(index)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit() {
+        let mut editor = CellEditor::new(1, 2, "héllo".into());
+        assert_eq!(editor.cursor(), 5);
+        editor.move_cursor_left();
+        editor.move_cursor_left();
+        editor.delete_backward();
+        assert_eq!(editor.value(), "hélo");
+        assert_eq!(editor.cursor(), 2);
+        editor.delete_forward();
+        editor.insert_char('L');
+        assert_eq!(editor.value(), "héLo");
+        editor.move_cursor_to_start();
+        editor.insert_char('>');
+        editor.move_cursor_to_end();
+        editor.move_cursor_right();
+        assert_eq!(editor.cursor(), 5);
+        assert_eq!(
+            editor.into_edit(),
+            CellEdit {
+                row: 1,
+                column: 2,
+                value: ">héLo".into(),
+            }
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use ratatui_core::{layout::Position, widgets::HasCursor};
+
+use crate::table::{CellEdit, CellEditor};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -15,6 +19,13 @@
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
 ///
+/// For tables that load their rows lazily, a [`near_end_threshold`] can be set. Rendering then
+/// records whether the last visible row is within that many rows of the end of the table, which
+/// the application checks with [`is_near_end`] to fetch more rows.
+///
+/// [`near_end_threshold`]: TableState::with_near_end_threshold()
+/// [`is_near_end`]: TableState::is_near_end()
+///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
 /// how to handle state.
@@ -58,6 +69,14 @@ pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) near_end_threshold: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) near_end: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) editor: Option<CellEditor>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) cursor_position: Option<Position>,
 }
 
 impl TableState {
@@ -75,6 +94,10 @@ impl TableState {
             offset: 0,
             selected: None,
             selected_column: None,
+            near_end_threshold: None,
+            near_end: false,
+            editor: None,
+            cursor_position: None,
         }
     }
 
@@ -533,6 +556,159 @@ impl TableState {
         let selected = self.selected_column.unwrap_or_default();
         self.select_column(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Sets the number of rows before the end of the table at which the table is near its end
+    ///
+    /// See [`TableState::is_near_end`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_near_end_threshold(Some(5));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_near_end_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.near_end_threshold = threshold;
+        self
+    }
+
+    /// Sets the number of rows before the end of the table at which the table is near its end
+    ///
+    /// Set to `None` to disable the check. See [`TableState::is_near_end`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_near_end_threshold(Some(5));
+    /// ```
+    pub fn set_near_end_threshold(&mut self, threshold: Option<usize>) {
+        self.near_end_threshold = threshold;
+    }
+
+    /// Number of rows before the end of the table at which the table is near its end
+    ///
+    /// Returns `None` if no threshold is set.
+    pub const fn near_end_threshold(&self) -> Option<usize> {
+        self.near_end_threshold
+    }
+
+    /// Whether the last rendered row was within the threshold of the end of the table
+    ///
+    /// This is updated when the table is rendered: it is `true` if a [`near_end_threshold`] is set
+    /// and at most that many rows follow the last visible row (or if the table has no rows), and
+    /// `false` otherwise, including while the table is [loading]. Applications use it to load the
+    /// next rows lazily.
+    ///
+    /// [`near_end_threshold`]: TableState::with_near_end_threshold
+    /// [loading]: crate::table::Table::loading
+    pub const fn is_near_end(&self) -> bool {
+        self.near_end
+    }
+
+    /// Starts editing the selected cell, with the given value as its current value.
+    ///
+    /// While editing, the table renders a [`CellEditor`] in place of the edited cell, and the
+    /// editor follows the cell even if the selection changes. Key presses are applied to the
+    /// editor returned by [`TableState::editor_mut`], and the edit ends with
+    /// [`TableState::commit_edit`] or [`TableState::cancel_edit`]. Any edit in progress is
+    /// replaced.
+    ///
+    /// Returns `false`, and does not start editing, if no cell is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected_cell((1, 2));
+    /// assert!(state.start_editing("42"));
+    /// assert_eq!(state.editor().map(|e| e.value()), Some("42"));
+    /// ```
+    pub fn start_editing<T: Into<String>>(&mut self, value: T) -> bool {
+        let Some((row, column)) = self.selected_cell() else {
+            return false;
+        };
+        self.editor = Some(CellEditor::new(row, column, value.into()));
+        true
+    }
+
+    /// Returns whether a cell is being edited.
+    pub const fn is_editing(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    /// Returns the editor of the cell being edited, if any.
+    pub const fn editor(&self) -> Option<&CellEditor> {
+        self.editor.as_ref()
+    }
+
+    /// Returns the editor of the cell being edited, if any, to apply key presses to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected_cell((0, 0));
+    /// state.start_editing("4");
+    /// if let Some(editor) = state.editor_mut() {
+    ///     editor.insert_char('2');
+    /// }
+    /// ```
+    pub fn editor_mut(&mut self) -> Option<&mut CellEditor> {
+        self.editor.as_mut()
+    }
+
+    /// Ends the edit in progress and returns the new value of the cell.
+    ///
+    /// The table does not own the data it displays, so the application applies the returned
+    /// [`CellEdit`] to its own data. Returns `None` if no cell is being edited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut data = vec![vec!["a".to_string(), "b".to_string()]];
+    /// let mut state = TableState::default().with_selected_cell((0, 1));
+    /// state.start_editing(data[0][1].as_str());
+    /// state.editor_mut().unwrap().insert_char('c');
+    /// if let Some(edit) = state.commit_edit() {
+    ///     data[edit.row][edit.column] = edit.value;
+    /// }
+    /// assert_eq!(data[0][1], "bc");
+    /// ```
+    pub fn commit_edit(&mut self) -> Option<CellEdit> {
+        self.cursor_position = None;
+        self.editor.take().map(CellEditor::into_edit)
+    }
+
+    /// Ends the edit in progress and discards the edited value.
+    pub fn cancel_edit(&mut self) {
+        self.cursor_position = None;
+        self.editor = None;
+    }
+
+    /// Returns where the terminal cursor should be shown, if a cell is being edited and is
+    /// visible.
+    ///
+    /// This is updated when the table is rendered.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+}
+
+impl HasCursor for TableState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
 }
 
 #[cfg(test)]
@@ -547,6 +723,46 @@ mod tests {
         assert_eq!(state.selected_column, None);
     }
 
+    #[test]
+    fn start_editing_requires_selected_cell() {
+        let mut state = TableState::new().with_selected(1);
+        assert!(!state.start_editing("a"));
+        assert!(!state.is_editing());
+
+        state.select_column(Some(2));
+        assert!(state.start_editing("a"));
+        let editor = state.editor().unwrap();
+        assert_eq!((editor.row(), editor.column()), (1, 2));
+    }
+
+    #[test]
+    fn commit_edit() {
+        let mut state = TableState::new().with_selected_cell((0, 1));
+        assert_eq!(state.commit_edit(), None);
+        state.start_editing("ab");
+        state.editor_mut().unwrap().delete_backward();
+        // the edit follows the cell, not the selection
+        state.select_cell(Some((3, 3)));
+        assert_eq!(
+            state.commit_edit(),
+            Some(CellEdit {
+                row: 0,
+                column: 1,
+                value: "a".into(),
+            })
+        );
+        assert!(!state.is_editing());
+    }
+
+    #[test]
+    fn cancel_edit() {
+        let mut state = TableState::new().with_selected_cell((0, 1));
+        state.start_editing("ab");
+        state.cancel_edit();
+        assert!(!state.is_editing());
+        assert_eq!(state.commit_edit(), None);
+    }
+
     #[test]
     fn with_offset() {
         let state = TableState::new().with_offset(1);
@@ -0,0 +1,329 @@
+//! The [`Transition`] widget animates the change from one widget to another.
+use std::time::Duration;
+
+use ratatui_core::{buffer::Buffer, layout::Rect, widgets::Widget};
+use strum::{Display, EnumString};
+
+/// A widget that renders the transition from one widget to another, e.g. between two screens.
+///
+/// Both widgets are rendered into separate buffers, which are then composed into the rendered
+/// area according to the [`TransitionEffect`] and the [`progress`](Transition::progress) of the
+/// transition, from `0.0` (only the widget being replaced is visible) to `1.0` (only the new
+/// widget is visible). Rendering the transition on every frame while increasing the progress, for
+/// example with [`Transition::elapsed`], animates it.
+///
+/// Any widget can take part in a transition, including a [`Stack`] of layers or a [`Switch`]
+/// between screens.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::{
+///     widgets::{Paragraph, Transition, TransitionDirection, TransitionEffect},
+///     Frame,
+/// };
+///
+/// # fn draw(frame: &mut Frame, started: Instant) {
+/// let transition = Transition::new(Paragraph::new("Home"), Paragraph::new("Settings"))
+///     .effect(TransitionEffect::Slide(TransitionDirection::Left))
+///     .elapsed(started.elapsed(), Duration::from_millis(300));
+/// frame.render_widget(transition, frame.area());
+/// # }
+/// ```
+///
+/// [`Stack`]: crate::stack::Stack
+/// [`Switch`]: crate::switch::Switch
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Transition<F, T> {
+    from: F,
+    to: T,
+    effect: TransitionEffect,
+    progress: f64,
+}
+
+/// How a [`Transition`] replaces one widget with another.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TransitionEffect {
+    /// The old widget fades to black, then the new widget fades in from black.
+    ///
+    /// The colors are darkened with [`Buffer::dim`].
+    #[default]
+    Fade,
+    /// The old widget moves out of the area in the given direction, pushed by the new widget.
+    Slide(TransitionDirection),
+    /// The new widget is revealed over the old one by an edge moving in the given direction.
+    Wipe(TransitionDirection),
+}
+
+/// The direction in which a [`TransitionEffect`] moves.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TransitionDirection {
+    /// Towards the left of the area.
+    #[default]
+    Left,
+    /// Towards the right of the area.
+    Right,
+    /// Towards the top of the area.
+    Up,
+    /// Towards the bottom of the area.
+    Down,
+}
+
+impl<F, T> Transition<F, T> {
+    /// Creates a transition from the first widget to the second one, with the default
+    /// [`TransitionEffect::Fade`] effect and a progress of `0.0`.
+    pub const fn new(from: F, to: T) -> Self {
+        Self {
+            from,
+            to,
+            effect: TransitionEffect::Fade,
+            progress: 0.0,
+        }
+    }
+
+    /// Sets the effect of the transition.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn effect(mut self, effect: TransitionEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Sets the progress of the transition, from `0.0` to `1.0`.
+    ///
+    /// The progress is clamped to this range when rendering. Apply an easing function to the
+    /// progress to change the pace of the transition.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn progress(mut self, progress: f64) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Sets the progress of the transition from the time elapsed since it started and its total
+    /// duration.
+    ///
+    /// A transition with a zero duration is complete.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn elapsed(mut self, elapsed: Duration, duration: Duration) -> Self {
+        self.progress = if duration.is_zero() {
+            1.0
+        } else {
+            elapsed.as_secs_f64() / duration.as_secs_f64()
+        };
+        self
+    }
+}
+
+impl<F: Widget, T: Widget> Widget for Transition<F, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let mut from = Buffer::empty(area);
+        let mut to = Buffer::empty(area);
+        self.from.render(area, &mut from);
+        self.to.render(area, &mut to);
+        compose(&from, &to, self.effect, self.progress, buf);
+    }
+}
+
+impl<F, T> Widget for &Transition<F, T>
+where
+    for<'a> &'a F: Widget,
+    for<'a> &'a T: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let mut from = Buffer::empty(area);
+        let mut to = Buffer::empty(area);
+        self.from.render(area, &mut from);
+        self.to.render(area, &mut to);
+        compose(&from, &to, self.effect, self.progress, buf);
+    }
+}
+
+/// Composes the buffers of the old and new widgets into `buf`, over the area of the buffers.
+fn compose(from: &Buffer, to: &Buffer, effect: TransitionEffect, progress: f64, buf: &mut Buffer) {
+    let area = from.area;
+    let progress = if progress.is_nan() {
+        0.0
+    } else {
+        progress.clamp(0.0, 1.0)
+    };
+    match effect {
+        TransitionEffect::Fade => {
+            let (source, factor) = if progress < 0.5 {
+                (from, progress * 2.0)
+            } else {
+                (to, (1.0 - progress) * 2.0)
+            };
+            let mut faded = source.clone();
+            faded.dim(area, factor);
+            copy_cells(&faded, area, area, buf);
+        }
+        TransitionEffect::Slide(direction) => {
+            let shift = distance(area, direction, progress);
+            let (from_dest, to_dest) = match direction {
+                TransitionDirection::Left => (
+                    Rect::new(area.x, area.y, area.width - shift, area.height),
+                    Rect::new(area.right() - shift, area.y, shift, area.height),
+                ),
+                TransitionDirection::Right => (
+                    Rect::new(area.x + shift, area.y, area.width - shift, area.height),
+                    Rect::new(area.x, area.y, shift, area.height),
+                ),
+                TransitionDirection::Up => (
+                    Rect::new(area.x, area.y, area.width, area.height - shift),
+                    Rect::new(area.x, area.bottom() - shift, area.width, shift),
+                ),
+                TransitionDirection::Down => (
+                    Rect::new(area.x, area.y + shift, area.width, area.height - shift),
+                    Rect::new(area.x, area.y, area.width, shift),
+                ),
+            };
+            // the old widget keeps the part that stays visible, the new one shows its other end
+            let from_source = match direction {
+                TransitionDirection::Left | TransitionDirection::Up => Rect {
+                    x: area.right() - from_dest.width,
+                    y: area.bottom() - from_dest.height,
+                    ..from_dest
+                },
+                TransitionDirection::Right | TransitionDirection::Down => Rect {
+                    x: area.x,
+                    y: area.y,
+                    ..from_dest
+                },
+            };
+            let to_source = match direction {
+                TransitionDirection::Left | TransitionDirection::Up => Rect {
+                    x: area.x,
+                    y: area.y,
+                    ..to_dest
+                },
+                TransitionDirection::Right | TransitionDirection::Down => Rect {
+                    x: area.right() - to_dest.width,
+                    y: area.bottom() - to_dest.height,
+                    ..to_dest
+                },
+            };
+            copy_cells(from, from_source, from_dest, buf);
+            copy_cells(to, to_source, to_dest, buf);
+        }
+        TransitionEffect::Wipe(direction) => {
+            let shift = distance(area, direction, progress);
+            let revealed = match direction {
+                TransitionDirection::Left => {
+                    Rect::new(area.right() - shift, area.y, shift, area.height)
+                }
+                TransitionDirection::Right => Rect::new(area.x, area.y, shift, area.height),
+                TransitionDirection::Up => {
+                    Rect::new(area.x, area.bottom() - shift, area.width, shift)
+                }
+                TransitionDirection::Down => Rect::new(area.x, area.y, area.width, shift),
+            };
+            copy_cells(from, area, area, buf);
+            copy_cells(to, revealed, revealed, buf);
+        }
+    }
+}
+
+/// Returns how far the effect has moved across the area, in cells.
+fn distance(area: Rect, direction: TransitionDirection, progress: f64) -> u16 {
+    let length = match direction {
+        TransitionDirection::Left | TransitionDirection::Right => area.width,
+        TransitionDirection::Up | TransitionDirection::Down => area.height,
+    };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let distance = (f64::from(length) * progress).round() as u16;
+    distance.min(length)
+}
+
+/// Copies the cells of `source` in `source_area` to `buf` at `dest_area`, which has the same
+/// size.
+fn copy_cells(source: &Buffer, source_area: Rect, dest_area: Rect, buf: &mut Buffer) {
+    for (source_position, dest_position) in source_area.positions().zip(dest_area.positions()) {
+        if let Some(cell) = buf.cell_mut(dest_position) {
+            cell.clone_from(&source[source_position]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Modifier, Style, Stylize},
+        text::{Line, Text},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn transition(effect: TransitionEffect, progress: f64) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Transition::new(Line::raw("abcd"), Line::raw("wxyz"))
+            .effect(effect)
+            .progress(progress)
+            .render(buf.area, &mut buf);
+        buf
+    }
+
+    #[rstest]
+    #[case::start(TransitionDirection::Left, 0.0, "abcd")]
+    #[case::left(TransitionDirection::Left, 0.25, "bcdw")]
+    #[case::right(TransitionDirection::Right, 0.5, "yzab")]
+    #[case::end(TransitionDirection::Right, 1.0, "wxyz")]
+    fn slide(
+        #[case] direction: TransitionDirection,
+        #[case] progress: f64,
+        #[case] expected: &str,
+    ) {
+        let buf = transition(TransitionEffect::Slide(direction), progress);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::left(TransitionDirection::Left, 0.25, "abcz")]
+    #[case::right(TransitionDirection::Right, 0.75, "wxyd")]
+    #[case::clamped(TransitionDirection::Right, 2.0, "wxyz")]
+    fn wipe(#[case] direction: TransitionDirection, #[case] progress: f64, #[case] expected: &str) {
+        let buf = transition(TransitionEffect::Wipe(direction), progress);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn slide_vertical() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 3));
+        Transition::new(Text::raw("a\nb\nc"), Text::raw("x\ny\nz"))
+            .effect(TransitionEffect::Slide(TransitionDirection::Up))
+            .progress(1.0 / 3.0)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["b", "c", "x"]));
+    }
+
+    #[test]
+    fn fade() {
+        let buf = transition(TransitionEffect::Fade, 0.25);
+        let dim = Style::new().add_modifier(Modifier::DIM);
+        assert_eq!(buf, Buffer::with_lines([Line::styled("abcd", dim)]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Transition::new(
+            Line::raw("abcd"),
+            Line::raw("wxyz").bg(Color::Rgb(200, 0, 100)),
+        )
+        .progress(0.75)
+        .render(buf.area, &mut buf);
+        let expected = Style::new()
+            .bg(Color::Rgb(100, 0, 50))
+            .add_modifier(Modifier::DIM);
+        assert_eq!(buf, Buffer::with_lines([Line::styled("wxyz", expected)]));
+    }
+
+    #[test]
+    fn elapsed() {
+        let transition =
+            Transition::new((), ()).elapsed(Duration::from_millis(150), Duration::from_millis(600));
+        assert!((transition.progress - 0.25).abs() < f64::EPSILON);
+        let transition = Transition::new((), ()).elapsed(Duration::from_secs(1), Duration::ZERO);
+        assert!((transition.progress - 1.0).abs() < f64::EPSILON);
+    }
+}
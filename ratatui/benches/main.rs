@@ -8,6 +8,7 @@ pub mod main {
     pub mod rect;
     pub mod sparkline;
     pub mod table;
+    pub mod terminal;
 }
 pub use main::*;
 
@@ -21,4 +22,5 @@ criterion::criterion_main!(
     rect::benches,
     sparkline::benches,
     table::benches,
+    terminal::benches,
 );
@@ -0,0 +1,36 @@
+use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use ratatui::{
+    backend::TestBackend,
+    text::{Line, Text},
+    Terminal,
+};
+
+criterion_group!(benches, scroll);
+
+/// Benchmark for drawing a frame whose content moved up by one line since the previous frame.
+///
+/// With the `scrolling-regions` feature, this measures finding the rows that moved, which happens
+/// on every flush.
+fn scroll(c: &mut Criterion) {
+    let mut group = c.benchmark_group("terminal/scroll");
+    for (width, height) in [(80_u16, 24_u16), (200, 60), (255, 255)] {
+        let lines: Vec<Line> = (0..height * 2)
+            .map(|_| Line::from(fakeit::words::sentence(i64::from(width / 5))))
+            .collect();
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut offset = 0;
+        group.bench_function(
+            BenchmarkId::from_parameter(format!("{width}x{height}")),
+            |b| {
+                b.iter(|| {
+                    offset = (offset + 1) % usize::from(height);
+                    let text = Text::from(lines[offset..].to_vec());
+                    terminal
+                        .draw(|frame| frame.render_widget(black_box(text), frame.area()))
+                        .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
@@ -19,7 +19,7 @@ use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
     layout::{
-        Constraint::{self, Fill, Length, Max, Min, Percentage, Ratio},
+        Constraint::{self, Fill, Fit, Length, Max, Min, Percentage, Ratio},
         Flex, Layout, Rect,
     },
     style::{
@@ -148,10 +148,12 @@ impl App {
         };
         match constraint {
             Constraint::Length(v)
+            | Constraint::Fit(v)
             | Constraint::Min(v)
             | Constraint::Max(v)
             | Constraint::Fill(v)
-            | Constraint::Percentage(v) => *v = v.saturating_add(1),
+            | Constraint::Percentage(v)
+            | Constraint::Permille(v) => *v = v.saturating_add(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_add(1),
         };
     }
@@ -162,10 +164,12 @@ impl App {
         };
         match constraint {
             Constraint::Length(v)
+            | Constraint::Fit(v)
             | Constraint::Min(v)
             | Constraint::Max(v)
             | Constraint::Fill(v)
-            | Constraint::Percentage(v) => *v = v.saturating_sub(1),
+            | Constraint::Percentage(v)
+            | Constraint::Permille(v) => *v = v.saturating_sub(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_sub(1),
         };
     }
@@ -239,8 +243,8 @@ impl App {
 impl From<Constraint> for ConstraintName {
     fn from(constraint: Constraint) -> Self {
         match constraint {
-            Length(_) => Self::Length,
-            Percentage(_) => Self::Percentage,
+            Length(_) | Fit(_) => Self::Length,
+            Percentage(_) | Constraint::Permille(_) => Self::Percentage,
             Ratio(_, _) => Self::Ratio,
             Min(_) => Self::Min,
             Max(_) => Self::Max,
@@ -604,3 +608,20 @@ impl ConstraintName {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "constraint-explorer",
+            |frame| {
+                frame.render_widget(&App::default(), frame.area());
+            },
+        );
+    }
+}
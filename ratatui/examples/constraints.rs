@@ -385,8 +385,8 @@ impl Widget for Example {
 impl Example {
     fn illustration(constraint: Constraint, width: u16) -> impl Widget {
         let color = match constraint {
-            Constraint::Length(_) => LENGTH_COLOR,
-            Constraint::Percentage(_) => PERCENTAGE_COLOR,
+            Constraint::Length(_) | Constraint::Fit(_) => LENGTH_COLOR,
+            Constraint::Percentage(_) | Constraint::Permille(_) => PERCENTAGE_COLOR,
             Constraint::Ratio(_, _) => RATIO_COLOR,
             Constraint::Fill(_) => FILL_COLOR,
             Constraint::Min(_) => MIN_COLOR,
@@ -403,3 +403,20 @@ impl Example {
         Paragraph::new(text).centered().block(block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "constraints",
+            |frame| {
+                frame.render_widget(App::default(), frame.area());
+            },
+        );
+    }
+}
@@ -272,3 +272,20 @@ fn handle_mouse_event(
         _ => (),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::State;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "custom_widget",
+            |frame| {
+                super::draw(frame, [State::Selected, State::Normal, State::Normal]);
+            },
+        );
+    }
+}
@@ -125,3 +125,17 @@ fn styling(frame: &mut Frame) {
     // or using the short-hand syntax
     frame.render_widget(Paragraph::new("Hello World!").blue().on_yellow(), areas[3]);
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    #[test]
+    fn snapshots() {
+        let harness =
+            SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"));
+        harness.assert("docsrs_hello_world", super::hello_world);
+        harness.assert("docsrs_layout", super::layout);
+        harness.assert("docsrs_styling", super::styling);
+    }
+}
@@ -513,8 +513,8 @@ const fn color_for_constraint(constraint: Constraint) -> Color {
     match constraint {
         Constraint::Min(_) => BLUE.c900,
         Constraint::Max(_) => BLUE.c800,
-        Constraint::Length(_) => SLATE.c700,
-        Constraint::Percentage(_) => SLATE.c800,
+        Constraint::Length(_) | Constraint::Fit(_) => SLATE.c700,
+        Constraint::Percentage(_) | Constraint::Permille(_) => SLATE.c800,
         Constraint::Ratio(_, _) => SLATE.c900,
         Constraint::Fill(_) => SLATE.c950,
     }
@@ -528,3 +528,20 @@ fn get_description_height(s: &str) -> u16 {
         s.split('\n').count() as u16
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "flex",
+            |frame| {
+                frame.render_widget(App::default(), frame.area());
+            },
+        );
+    }
+}
@@ -203,3 +203,20 @@ fn title_block(title: &str) -> Block {
         .title(title)
         .fg(CUSTOM_LABEL_COLOR)
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "gauge-widget",
+            |frame| {
+                frame.render_widget(&App::default(), frame.area());
+            },
+        );
+    }
+}
@@ -99,3 +99,20 @@ impl Widget for &Hyperlink<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "hyperlink",
+            |frame| {
+                frame.render_widget(&App::new().hyperlink, frame.area());
+            },
+        );
+    }
+}
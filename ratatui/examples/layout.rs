@@ -183,6 +183,19 @@ fn constraint_label(constraint: Constraint) -> String {
         | Constraint::Min(n)
         | Constraint::Max(n)
         | Constraint::Percentage(n)
+        | Constraint::Permille(n)
+        | Constraint::Fit(n)
         | Constraint::Fill(n) => format!("{n}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"))
+            .assert("layout", super::draw);
+    }
+}
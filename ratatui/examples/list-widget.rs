@@ -285,3 +285,20 @@ impl From<&TodoItem> for ListItem<'_> {
         ListItem::new(line)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots")).assert(
+            "list-widget",
+            |frame| {
+                frame.render_widget(&mut App::default(), frame.area());
+            },
+        );
+    }
+}
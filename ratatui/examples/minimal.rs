@@ -38,3 +38,14 @@ fn draw(frame: &mut Frame) {
     let text = Text::raw("Hello World!");
     frame.render_widget(text, frame.area());
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"))
+            .assert("minimal", super::draw);
+    }
+}
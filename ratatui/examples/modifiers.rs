@@ -98,3 +98,14 @@ fn draw(frame: &mut Frame) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"))
+            .assert("modifiers", super::draw);
+    }
+}
@@ -108,3 +108,16 @@ impl App {
         frame.render_widget(paragraph, frame.area());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"))
+            .assert("panic", |frame| App::new().draw(frame));
+    }
+}
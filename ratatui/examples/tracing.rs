@@ -109,3 +109,14 @@ fn init_tracing() -> Result<WorkerGuard> {
         .init();
     Ok(guard)
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"))
+            .assert("tracing", |frame| super::draw(frame, &[]));
+    }
+}
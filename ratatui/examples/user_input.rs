@@ -236,3 +236,16 @@ impl App {
         frame.render_widget(messages, messages_area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::examples_harness::SnapshotHarness;
+
+    use super::App;
+
+    #[test]
+    fn snapshots() {
+        SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/snapshots"))
+            .assert("user_input", |frame| App::new().draw(frame));
+    }
+}
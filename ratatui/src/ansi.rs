@@ -0,0 +1,693 @@
+//! Parse the output of terminal programs into styled text.
+//!
+//! The [`Screen`] type consumes the raw bytes written by a program (e.g. the captured output of a
+//! build tool or of a subprocess) and maintains a grid of styled cells, the same way a terminal
+//! emulator would. The grid can be rendered as a widget or converted to [`Text`] to be displayed
+//! in a scrollable [`Paragraph`].
+//!
+//! Only the commonly used subset of the VT100 / xterm control sequences is supported: cursor
+//! movement, erasing, scrolling and the Select Graphic Rendition (SGR) sequences that set colors
+//! and modifiers. Other sequences are parsed and ignored.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{ansi::Screen, style::Stylize, text::Line};
+//!
+//! let mut screen = Screen::new(20, 2);
+//! screen.process(b"\x1b[1;32mok\x1b[0m build\r\n");
+//! let text = screen.to_text();
+//! assert_eq!(
+//!     text.lines[0],
+//!     Line::from(vec!["ok".green().bold(), " build".into()])
+//! );
+//! ```
+//!
+//! [`Paragraph`]: crate::widgets::Paragraph
+use std::{collections::VecDeque, mem};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect, Size},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::Widget,
+};
+
+/// A virtual terminal screen that interprets control sequences.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Screen {
+    size: Size,
+    rows: VecDeque<Vec<Cell>>,
+    scrollback: VecDeque<Vec<Cell>>,
+    scrollback_limit: usize,
+    cursor: Position,
+    saved_cursor: Position,
+    style: Style,
+    state: State,
+    pending: Vec<u8>,
+}
+
+/// The maximum number of parameters of a control sequence; further parameters are ignored, as in
+/// most terminals.
+const MAX_CSI_PARAMS: usize = 32;
+
+/// The state of the control sequence parser.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    Csi {
+        params: Vec<u16>,
+        current: Option<u16>,
+        private: bool,
+    },
+    Osc,
+    OscEscape,
+}
+
+impl Screen {
+    /// Creates a new empty screen with the given size and no scrollback.
+    pub fn new(width: u16, height: u16) -> Self {
+        let size = Size::new(width, height);
+        Self {
+            size,
+            rows: (0..height).map(|_| blank_row(width)).collect(),
+            scrollback: VecDeque::new(),
+            scrollback_limit: 0,
+            cursor: Position::ORIGIN,
+            saved_cursor: Position::ORIGIN,
+            style: Style::new(),
+            state: State::Ground,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Sets the maximum number of lines that are kept after they scroll off the top of the
+    /// screen.
+    ///
+    /// The lines of the scrollback are included in [`Screen::to_text`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scrollback(mut self, limit: usize) -> Self {
+        self.scrollback_limit = limit;
+        self.trim_scrollback();
+        self
+    }
+
+    /// Returns the size of the screen.
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the position of the cursor.
+    pub const fn cursor_position(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns the cell at the given position, or `None` if it is outside the screen.
+    pub fn cell<P: Into<Position>>(&self, position: P) -> Option<&Cell> {
+        let position = position.into();
+        self.rows
+            .get(usize::from(position.y))?
+            .get(usize::from(position.x))
+    }
+
+    /// Resizes the screen.
+    ///
+    /// Rows and columns that no longer fit are discarded and new ones are blank. The cursor is
+    /// clamped to the new size.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        for row in &mut self.rows {
+            row.resize(usize::from(width), Cell::EMPTY);
+        }
+        self.rows.resize(usize::from(height), blank_row(width));
+        self.size = Size::new(width, height);
+        self.cursor = self.clamp(self.cursor);
+        self.saved_cursor = self.clamp(self.saved_cursor);
+    }
+
+    /// Processes the bytes written by a program.
+    ///
+    /// The bytes are interpreted as UTF-8. Incomplete sequences at the end of `bytes` are kept
+    /// until the next call, so the output of a program can be processed in arbitrary chunks.
+    pub fn process(&mut self, bytes: &[u8]) {
+        let mut input = mem::take(&mut self.pending);
+        input.extend_from_slice(bytes);
+        let mut rest = input.as_slice();
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    valid.chars().for_each(|ch| self.process_char(ch));
+                    break;
+                }
+                Err(err) => {
+                    let (valid, after) = rest.split_at(err.valid_up_to());
+                    String::from_utf8_lossy(valid)
+                        .chars()
+                        .for_each(|ch| self.process_char(ch));
+                    let Some(len) = err.error_len() else {
+                        self.pending = after.to_vec();
+                        break;
+                    };
+                    self.process_char(char::REPLACEMENT_CHARACTER);
+                    rest = &after[len..];
+                }
+            }
+        }
+    }
+
+    /// Converts the content of the scrollback and of the screen to styled text.
+    ///
+    /// Trailing empty cells are removed from each line.
+    pub fn to_text(&self) -> Text<'static> {
+        self.scrollback
+            .iter()
+            .chain(self.rows.iter())
+            .map(|row| row_to_line(row))
+            .collect()
+    }
+
+    fn process_char(&mut self, ch: char) {
+        match mem::take(&mut self.state) {
+            State::Ground => self.ground(ch),
+            State::Escape => self.escape(ch),
+            State::Csi {
+                mut params,
+                mut current,
+                mut private,
+            } => match ch {
+                '0'..='9' => {
+                    let digit = ch as u16 - u16::from(b'0');
+                    current = Some(
+                        current
+                            .unwrap_or(0)
+                            .saturating_mul(10)
+                            .saturating_add(digit),
+                    );
+                    self.state = State::Csi {
+                        params,
+                        current,
+                        private,
+                    };
+                }
+                ';' | ':' => {
+                    if params.len() < MAX_CSI_PARAMS {
+                        params.push(current.unwrap_or(0));
+                    }
+                    self.state = State::Csi {
+                        params,
+                        current: None,
+                        private,
+                    };
+                }
+                '?' | '>' | '<' | '=' | ' '..='/' => {
+                    private |= matches!(ch, '?' | '>' | '<' | '=');
+                    self.state = State::Csi {
+                        params,
+                        current,
+                        private,
+                    };
+                }
+                '@'..='~' => {
+                    if let Some(current) = current.filter(|_| params.len() < MAX_CSI_PARAMS) {
+                        params.push(current);
+                    }
+                    if !private {
+                        self.csi(ch, &params);
+                    }
+                }
+                _ => {}
+            },
+            State::Osc => match ch {
+                '\x07' => {}
+                '\x1b' => self.state = State::OscEscape,
+                _ => self.state = State::Osc,
+            },
+            State::OscEscape => {}
+        }
+    }
+
+    fn ground(&mut self, ch: char) {
+        match ch {
+            '\x1b' => self.state = State::Escape,
+            '\r' => self.cursor.x = 0,
+            '\n' | '\x0b' | '\x0c' => self.line_feed(),
+            '\x08' => self.cursor.x = self.cursor.x.saturating_sub(1).min(self.last_column()),
+            '\t' => {
+                self.cursor.x = (self.cursor.x / 8 + 1)
+                    .saturating_mul(8)
+                    .min(self.last_column());
+            }
+            ch if ch.is_control() => {}
+            ch => self.print(ch),
+        }
+    }
+
+    fn escape(&mut self, ch: char) {
+        match ch {
+            '[' => {
+                self.state = State::Csi {
+                    params: Vec::new(),
+                    current: None,
+                    private: false,
+                };
+            }
+            ']' => self.state = State::Osc,
+            '7' => self.saved_cursor = self.cursor,
+            '8' => self.cursor = self.saved_cursor,
+            'D' => self.line_feed(),
+            'E' => {
+                self.cursor.x = 0;
+                self.line_feed();
+            }
+            'M' => self.reverse_line_feed(),
+            'c' => {
+                *self =
+                    Self::new(self.size.width, self.size.height).scrollback(self.scrollback_limit);
+            }
+            _ => {}
+        }
+    }
+
+    fn csi(&mut self, action: char, params: &[u16]) {
+        let param = |index: usize, default: u16| match params.get(index) {
+            Some(0) | None => default,
+            Some(&value) => value,
+        };
+        let Position { x, y } = self.cursor;
+        match action {
+            'A' => self.move_to(x, y.saturating_sub(param(0, 1))),
+            'B' => self.move_to(x, y.saturating_add(param(0, 1))),
+            'C' => self.move_to(x.saturating_add(param(0, 1)), y),
+            'D' => self.move_to(x.saturating_sub(param(0, 1)), y),
+            'E' => self.move_to(0, y.saturating_add(param(0, 1))),
+            'F' => self.move_to(0, y.saturating_sub(param(0, 1))),
+            'G' | '`' => self.move_to(param(0, 1) - 1, y),
+            'd' => self.move_to(x, param(0, 1) - 1),
+            'H' | 'f' => self.move_to(param(1, 1) - 1, param(0, 1) - 1),
+            'J' => self.erase_display(params.first().copied().unwrap_or(0)),
+            'K' => self.erase_line(params.first().copied().unwrap_or(0)),
+            'S' => (0..param(0, 1)).for_each(|_| self.scroll_up()),
+            'T' => (0..param(0, 1)).for_each(|_| self.scroll_down()),
+            'm' => self.select_graphic_rendition(params),
+            's' => self.saved_cursor = self.cursor,
+            'u' => self.cursor = self.saved_cursor,
+            _ => {}
+        }
+    }
+
+    fn print(&mut self, ch: char) {
+        let width = ch.width().unwrap_or(0);
+        if self.size.width == 0 || self.size.height == 0 {
+            return;
+        }
+        if width == 0 {
+            // combine zero-width characters with the previous cell
+            let x = self.cursor.x.saturating_sub(1).min(self.last_column());
+            let cell = &mut self.rows[usize::from(self.cursor.y)][usize::from(x)];
+            let symbol = format!("{}{ch}", cell.symbol());
+            cell.set_symbol(&symbol);
+            return;
+        }
+        if usize::from(self.cursor.x) + width > usize::from(self.size.width) {
+            self.cursor.x = 0;
+            self.line_feed();
+        }
+        let row = &mut self.rows[usize::from(self.cursor.y)];
+        let x = usize::from(self.cursor.x);
+        row[x].reset();
+        row[x].set_char(ch).set_style(self.style);
+        for cell in row.iter_mut().skip(x + 1).take(width - 1) {
+            cell.reset();
+            cell.set_symbol("");
+        }
+        // a character wider than the screen is cut off at its right edge
+        let width = u16::try_from(width).unwrap_or(u16::MAX);
+        self.cursor.x = self.cursor.x.saturating_add(width).min(self.size.width);
+    }
+
+    fn line_feed(&mut self) {
+        if self.cursor.y + 1 >= self.size.height {
+            self.scroll_up();
+        } else {
+            self.cursor.y += 1;
+        }
+    }
+
+    fn reverse_line_feed(&mut self) {
+        if self.cursor.y == 0 {
+            self.scroll_down();
+        } else {
+            self.cursor.y -= 1;
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(row) = self.rows.pop_front() {
+            self.scrollback.push_back(row);
+            self.trim_scrollback();
+            self.rows.push_back(blank_row(self.size.width));
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if self.rows.pop_back().is_some() {
+            self.rows.push_front(blank_row(self.size.width));
+        }
+    }
+
+    fn trim_scrollback(&mut self) {
+        while self.scrollback.len() > self.scrollback_limit {
+            self.scrollback.pop_front();
+        }
+    }
+
+    fn erase_display(&mut self, mode: u16) {
+        let y = usize::from(self.cursor.y);
+        match mode {
+            0 => {
+                self.erase_line(0);
+                self.rows.iter_mut().skip(y + 1).for_each(clear);
+            }
+            1 => {
+                self.erase_line(1);
+                self.rows.iter_mut().take(y).for_each(clear);
+            }
+            2 => self.rows.iter_mut().for_each(clear),
+            3 => {
+                self.rows.iter_mut().for_each(clear);
+                self.scrollback.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn erase_line(&mut self, mode: u16) {
+        let x = usize::from(self.cursor.x);
+        let Some(row) = self.rows.get_mut(usize::from(self.cursor.y)) else {
+            return;
+        };
+        match mode {
+            0 => clear(row.iter_mut().skip(x)),
+            1 => clear(row.iter_mut().take(x + 1)),
+            2 => clear(row),
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            self.style = Style::new();
+            return;
+        }
+        let mut params = params.iter().copied();
+        while let Some(param) = params.next() {
+            self.style = match param {
+                0 => Style::new(),
+                1 => self.style.add_modifier(Modifier::BOLD),
+                2 => self.style.add_modifier(Modifier::DIM),
+                3 => self.style.add_modifier(Modifier::ITALIC),
+                4 => self.style.add_modifier(Modifier::UNDERLINED),
+                5 => self.style.add_modifier(Modifier::SLOW_BLINK),
+                6 => self.style.add_modifier(Modifier::RAPID_BLINK),
+                7 => self.style.add_modifier(Modifier::REVERSED),
+                8 => self.style.add_modifier(Modifier::HIDDEN),
+                9 => self.style.add_modifier(Modifier::CROSSED_OUT),
+                22 => self.style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+                23 => self.style.remove_modifier(Modifier::ITALIC),
+                24 => self.style.remove_modifier(Modifier::UNDERLINED),
+                25 => self
+                    .style
+                    .remove_modifier(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+                27 => self.style.remove_modifier(Modifier::REVERSED),
+                28 => self.style.remove_modifier(Modifier::HIDDEN),
+                29 => self.style.remove_modifier(Modifier::CROSSED_OUT),
+                30..=37 => self.style.fg(ansi_color(param - 30)),
+                38 => match extended_color(&mut params) {
+                    Some(color) => self.style.fg(color),
+                    None => self.style,
+                },
+                39 => self.style.fg(Color::Reset),
+                40..=47 => self.style.bg(ansi_color(param - 40)),
+                48 => match extended_color(&mut params) {
+                    Some(color) => self.style.bg(color),
+                    None => self.style,
+                },
+                49 => self.style.bg(Color::Reset),
+                90..=97 => self.style.fg(ansi_color(param - 90 + 8)),
+                100..=107 => self.style.bg(ansi_color(param - 100 + 8)),
+                _ => self.style,
+            };
+        }
+    }
+
+    fn move_to(&mut self, x: u16, y: u16) {
+        self.cursor = self.clamp(Position::new(x, y));
+    }
+
+    fn clamp(&self, position: Position) -> Position {
+        Position::new(
+            position.x.min(self.last_column()),
+            position.y.min(self.size.height.saturating_sub(1)),
+        )
+    }
+
+    const fn last_column(&self) -> u16 {
+        self.size.width.saturating_sub(1)
+    }
+}
+
+impl Widget for &Screen {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for (row, y) in self.rows.iter().zip(area.rows()) {
+            for (cell, x) in row.iter().zip(area.left()..area.right()) {
+                buf[(x, y.y)] = cell.clone();
+            }
+        }
+    }
+}
+
+impl Widget for Screen {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+fn blank_row(width: u16) -> Vec<Cell> {
+    vec![Cell::EMPTY; usize::from(width)]
+}
+
+fn clear<'a>(cells: impl IntoIterator<Item = &'a mut Cell>) {
+    cells.into_iter().for_each(Cell::reset);
+}
+
+/// Converts a row of cells to a line, merging adjacent cells with the same style into one span.
+fn row_to_line(row: &[Cell]) -> Line<'static> {
+    let len = row
+        .iter()
+        .rposition(|cell| *cell != Cell::EMPTY)
+        .map_or(0, |index| index + 1);
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for cell in &row[..len] {
+        let style = cell_style(cell);
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push_str(cell.symbol()),
+            _ => spans.push(Span::styled(cell.symbol().to_string(), style)),
+        }
+    }
+    Line::from(spans)
+}
+
+/// Returns the style of a cell, leaving out the properties that are set to their defaults.
+fn cell_style(cell: &Cell) -> Style {
+    let mut style = Style::new();
+    if cell.fg != Color::Reset {
+        style = style.fg(cell.fg);
+    }
+    if cell.bg != Color::Reset {
+        style = style.bg(cell.bg);
+    }
+    style.add_modifier(cell.modifier)
+}
+
+/// Returns the color of one of the 16 standard ANSI colors.
+const fn ansi_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+/// Parses the parameters of an extended (256 colors or RGB) SGR color.
+fn extended_color(params: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    let mut next = || params.next().map(|value| value.min(255) as u8);
+    match next()? {
+        5 => Some(Color::Indexed(next()?)),
+        2 => Some(Color::Rgb(next()?, next()?, next()?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Stylize;
+
+    fn lines(screen: &Screen) -> Vec<String> {
+        screen
+            .to_text()
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn print_and_wrap() {
+        let mut screen = Screen::new(4, 3);
+        screen.process(b"abcdef");
+        assert_eq!(lines(&screen), ["abcd", "ef", ""]);
+        assert_eq!(screen.cursor_position(), Position::new(2, 1));
+    }
+
+    #[test]
+    fn wide_character_on_narrow_screen() {
+        let mut screen = Screen::new(1, 1);
+        screen.process("\u{3042}\u{301}".as_bytes());
+        assert_eq!(lines(&screen), ["\u{3042}\u{301}"]);
+        assert_eq!(screen.cursor_position(), Position::new(1, 0));
+    }
+
+    #[test]
+    fn carriage_return_and_line_feed() {
+        let mut screen = Screen::new(5, 3);
+        screen.process(b"hello\r\nab\rc\n");
+        assert_eq!(lines(&screen), ["hello", "cb", ""]);
+        assert_eq!(screen.cursor_position(), Position::new(1, 2));
+    }
+
+    #[test]
+    fn backspace_and_tab() {
+        let mut screen = Screen::new(12, 1);
+        screen.process(b"ab\x08c\td");
+        assert_eq!(lines(&screen), ["ac      d"]);
+    }
+
+    #[test]
+    fn tab_at_the_end_of_a_wide_screen() {
+        let mut screen = Screen::new(u16::MAX, 1);
+        screen.process(b"\x1b[65534G\t");
+        assert_eq!(screen.cursor_position(), Position::new(u16::MAX - 1, 0));
+    }
+
+    #[test]
+    fn ignores_parameters_beyond_the_limit() {
+        let mut screen = Screen::new(1, 1);
+        let sequence = format!("\x1b[{}1ma", "0;".repeat(MAX_CSI_PARAMS));
+        screen.process(sequence.as_bytes());
+        let cell = screen.cell((0, 0)).unwrap();
+        assert_eq!(cell.symbol(), "a");
+        assert!(!cell.modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn scroll_with_scrollback() {
+        let mut screen = Screen::new(3, 2).scrollback(1);
+        screen.process(b"1\r\n2\r\n3\r\n4");
+        assert_eq!(lines(&screen), ["2", "3", "4"]);
+    }
+
+    #[test]
+    fn cursor_movement() {
+        let mut screen = Screen::new(5, 3);
+        screen.process(b"\x1b[2;3Hx\x1b[Ay\x1b[2Bz\x1b[10D<\x1b[99C>");
+        assert_eq!(lines(&screen), ["   y", "  x", "<   >"]);
+    }
+
+    #[test]
+    fn erase() {
+        let mut screen = Screen::new(4, 3);
+        screen.process(b"aaaa\r\nbbbb\r\ncccc\x1b[2;3H\x1b[K");
+        assert_eq!(lines(&screen), ["aaaa", "bb", "cccc"]);
+        screen.process(b"\x1b[1K");
+        assert_eq!(lines(&screen), ["aaaa", "", "cccc"]);
+        screen.process(b"\x1b[J");
+        assert_eq!(lines(&screen), ["aaaa", "", ""]);
+        screen.process(b"\x1b[2J");
+        assert_eq!(lines(&screen), ["", "", ""]);
+    }
+
+    #[test]
+    fn select_graphic_rendition() {
+        let mut screen = Screen::new(20, 1);
+        screen.process(b"\x1b[31;1ma\x1b[22;44mb\x1b[38;5;42mc\x1b[38;2;1;2;3md\x1b[0me");
+        assert_eq!(
+            screen.to_text().lines[0],
+            Line::from(vec![
+                "a".red().bold(),
+                "b".red().on_blue(),
+                Span::styled("c", Style::new().fg(Color::Indexed(42)).on_blue()),
+                Span::styled("d", Style::new().fg(Color::Rgb(1, 2, 3)).on_blue()),
+                "e".into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn ignores_unsupported_sequences() {
+        let mut screen = Screen::new(10, 1);
+        screen.process(b"\x1b]0;title\x07a\x1b[?25lb\x1b]8;;url\x1b\\c");
+        assert_eq!(lines(&screen), ["abc"]);
+    }
+
+    #[test]
+    fn split_utf8() {
+        let mut screen = Screen::new(4, 1);
+        let bytes = "é😀".as_bytes();
+        screen.process(&bytes[..1]);
+        screen.process(&bytes[1..4]);
+        screen.process(&bytes[4..]);
+        screen.process(b"\xff");
+        assert_eq!(lines(&screen), ["é😀\u{fffd}"]);
+    }
+
+    #[test]
+    fn resize() {
+        let mut screen = Screen::new(4, 2);
+        screen.process(b"abcd\r\nefgh");
+        screen.resize(2, 3);
+        assert_eq!(lines(&screen), ["ab", "ef", ""]);
+        assert_eq!(screen.cursor_position(), Position::new(1, 1));
+    }
+
+    #[test]
+    fn render() {
+        let mut screen = Screen::new(3, 2);
+        screen.process(b"ab\r\n\x1b[32mc");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        screen.render(Rect::new(1, 0, 3, 2), &mut buf);
+        let mut expected = Buffer::with_lines([" ab ", " c  "]);
+        expected[(1, 1)].set_fg(Color::Green);
+        assert_eq!(buf, expected);
+    }
+}
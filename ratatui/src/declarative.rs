@@ -0,0 +1,265 @@
+//! Build user interfaces from a declarative description.
+//!
+//! This module contains types that describe a tree of nested layouts and stock widgets and that
+//! can be deserialized with [`serde`] from any format, such as TOML or JSON. This is useful for
+//! applications like status dashboards where users want to customize the arrangement of the
+//! panes without recompiling.
+//!
+//! The root of the description is a [`Node`]. A node optionally draws a [`Block`] (with borders
+//! and a title) and a [`Paragraph`] of text, and splits its remaining area between its children
+//! according to their constraints. A [`Node`] implements [`Widget`], so the whole tree can be
+//! rendered with a single call to [`Frame::render_widget`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{buffer::Buffer, declarative::Node, layout::Rect, widgets::Widget};
+//!
+//! let node: Node = serde_json::from_str(
+//!     r#"{
+//!         "direction": "horizontal",
+//!         "children": [
+//!             { "constraint": { "Length": 7 }, "title": "CPU", "borders": true, "text": "42%" },
+//!             { "constraint": { "Fill": 1 }, "text": "All systems nominal" }
+//!         ]
+//!     }"#,
+//! )?;
+//!
+//! let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+//! node.render(buf.area, &mut buf);
+//! assert_eq!(
+//!     buf,
+//!     Buffer::with_lines([
+//!         "┌CPU──┐All syste",
+//!         "│42%  │         ",
+//!         "└─────┘         ",
+//!     ])
+//! );
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The same description in TOML:
+//!
+//! ```toml
+//! direction = "horizontal"
+//!
+//! [[children]]
+//! constraint = { Length = 7 }
+//! title = "CPU"
+//! borders = true
+//! text = "42%"
+//!
+//! [[children]]
+//! text = "All systems nominal"
+//! ```
+//!
+//! [`Block`]: crate::widgets::Block
+//! [`Paragraph`]: crate::widgets::Paragraph
+//! [`Frame::render_widget`]: crate::Frame::render_widget
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// A node of a declarative user interface description.
+///
+/// All the fields are optional in the serialized representation.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Node {
+    /// The title of the block surrounding the node.
+    pub title: Option<String>,
+    /// Whether the block surrounding the node has borders.
+    pub borders: bool,
+    /// The foreground color of the node.
+    pub fg: Option<Color>,
+    /// The background color of the node.
+    pub bg: Option<Color>,
+    /// The text displayed inside the node.
+    pub text: Option<String>,
+    /// The direction in which the children are laid out.
+    pub direction: NodeDirection,
+    /// The margin between the inside of the block and the children.
+    pub margin: u16,
+    /// The children of the node.
+    pub children: Vec<Child>,
+}
+
+/// A child of a [`Node`] together with the constraint used to lay it out.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Child {
+    /// The constraint of the child in the layout of its parent.
+    ///
+    /// This is serialized as a single entry map, e.g. `{ "Length": 3 }`, `{ "Percentage": 50 }`
+    /// or `{ "Ratio": [1, 3] }`, and defaults to `Fill(1)`.
+    #[serde(default = "default_constraint")]
+    pub constraint: Constraint,
+    /// The child node.
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+/// The direction of a [`Node`] layout.
+///
+/// This is serialized as `"vertical"` or `"horizontal"`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeDirection {
+    /// Lay out the children from top to bottom.
+    #[default]
+    Vertical,
+    /// Lay out the children from left to right.
+    Horizontal,
+}
+
+impl Default for Child {
+    fn default() -> Self {
+        Self {
+            constraint: default_constraint(),
+            node: Node::default(),
+        }
+    }
+}
+
+/// The constraint of a [`Child`] that does not set one.
+const fn default_constraint() -> Constraint {
+    Constraint::Fill(1)
+}
+
+impl From<NodeDirection> for Direction {
+    fn from(direction: NodeDirection) -> Self {
+        match direction {
+            NodeDirection::Vertical => Self::Vertical,
+            NodeDirection::Horizontal => Self::Horizontal,
+        }
+    }
+}
+
+impl Node {
+    /// Returns the style of the node.
+    pub const fn style(&self) -> Style {
+        let mut style = Style::new();
+        style.fg = self.fg;
+        style.bg = self.bg;
+        style
+    }
+
+    /// Returns the block surrounding the node, if the node has borders or a title.
+    pub fn block(&self) -> Option<Block<'_>> {
+        if !self.borders && self.title.is_none() {
+            return None;
+        }
+        let mut block = if self.borders {
+            Block::bordered()
+        } else {
+            Block::new()
+        };
+        if let Some(title) = &self.title {
+            block = block.title(title.as_str());
+        }
+        Some(block)
+    }
+
+    /// Returns the layout used to split the area of the node between its children.
+    pub fn layout(&self) -> Layout {
+        Layout::new(
+            self.direction.into(),
+            self.children.iter().map(|child| child.constraint),
+        )
+        .margin(self.margin)
+    }
+}
+
+impl Widget for Node {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Node {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style());
+        let block = self.block();
+        let inner = block.as_ref().map_or(area, |block| block.inner(area));
+        block.render(area, buf);
+        if let Some(text) = &self.text {
+            Paragraph::new(text.as_str()).render(inner, buf);
+        }
+        if self.children.is_empty() {
+            return;
+        }
+        let areas = self.layout().split(inner);
+        for (child, area) in self.children.iter().zip(areas.iter()) {
+            Widget::render(&child.node, *area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_defaults() {
+        let node: Node = serde_json::from_str("{}").unwrap();
+        assert_eq!(node, Node::default());
+        let child: Child = serde_json::from_str("{}").unwrap();
+        assert_eq!(child.constraint, Constraint::Fill(1));
+    }
+
+    #[test]
+    fn deserialize_constraints() {
+        let node: Node = serde_json::from_str(
+            r#"{ "children": [
+                { "constraint": { "Length": 1 } },
+                { "constraint": { "Percentage": 50 } },
+                { "constraint": { "Ratio": [1, 3] } },
+                { "constraint": { "Permille": 333 } }
+            ] }"#,
+        )
+        .unwrap();
+        let constraints: Vec<Constraint> =
+            node.children.iter().map(|child| child.constraint).collect();
+        assert_eq!(
+            constraints,
+            [
+                Constraint::Length(1),
+                Constraint::Percentage(50),
+                Constraint::Ratio(1, 3),
+                Constraint::Permille(333)
+            ]
+        );
+    }
+
+    #[test]
+    fn render_nested() {
+        let node: Node = serde_json::from_str(
+            r#"{ "title": "Top", "borders": true, "children": [
+                { "constraint": { "Length": 1 }, "text": "a" },
+                { "direction": "horizontal", "children": [{ "text": "b" }, { "text": "c" }] }
+            ] }"#,
+        )
+        .unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        node.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌Top─┐", "│a   │", "│b c │", "└────┘"])
+        );
+    }
+
+    #[test]
+    fn render_style() {
+        let node: Node = serde_json::from_str(r#"{ "fg": "red", "text": "x" }"#).unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        node.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["x "]);
+        expected.set_style(expected.area, Style::new().fg(Color::Red));
+        assert_eq!(buf, expected);
+    }
+}
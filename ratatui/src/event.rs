@@ -0,0 +1,281 @@
+//! Events delivered to the update loop of an application.
+//!
+//! Ratatui does not read input by itself: applications read events from the library that provides
+//! their backend. This module provides a thin layer over the [`crossterm`] events so that the
+//! source of the events can be swapped, for example to replay a recording in a test.
+//!
+//! An [`EventSource`] produces [`Event`]s. [`TerminalEvents`] reads them from the terminal, and
+//! the other sources in this module wrap another source to record or replay its events
+//! ([`EventRecorder`] and [`EventPlayer`]), to add timer events to them ([`Ticker`]), to coalesce
+//! their resize events ([`DebouncedResize`]), or, on Unix, to add the signals sent to the process
+//! (`SignalEvents`).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use ratatui::{
+//!     crossterm::event::{Event as CrosstermEvent, KeyCode},
+//!     event::{Event, EventSource, TerminalEvents},
+//! };
+//!
+//! fn run(events: &mut impl EventSource) -> std::io::Result<()> {
+//!     loop {
+//!         match events.next_event(Duration::from_millis(250))? {
+//!             Some(Event::Terminal(CrosstermEvent::Key(key)))
+//!                 if key.code == KeyCode::Char('q') =>
+//!             {
+//!                 return Ok(());
+//!             }
+//!             Some(_event) => { /* update the application state */ }
+//!             None => { /* no event was received before the timeout */ }
+//!         }
+//!     }
+//! }
+//!
+//! run(&mut TerminalEvents)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`crossterm`]: ratatui_crossterm::crossterm
+use std::{io, time::Duration};
+
+use ratatui_core::layout::{Position, Size};
+use ratatui_crossterm::crossterm::event::{
+    self as crossterm_event, Event as CrosstermEvent, KeyCode, KeyEventKind, KeyModifiers,
+};
+
+mod debounce;
+mod record;
+mod signal;
+mod ticker;
+
+#[cfg(all(unix, feature = "signals"))]
+pub use self::signal::{suspend, SignalEvents};
+pub use self::{
+    debounce::DebouncedResize,
+    record::{EventPlayer, EventRecorder, RecordedEvent},
+    signal::Signal,
+    ticker::{Ticker, TimerId},
+};
+
+/// An event delivered to the update loop of an application.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Event {
+    /// An event read from the terminal, such as a key press, a mouse click or a resize.
+    Terminal(CrosstermEvent),
+    /// A tick delivered at the tick rate of a [`Ticker`].
+    Tick,
+    /// A one-shot timer started with [`Ticker::add_timer`] expired.
+    Timer(TimerId),
+    /// The terminal started being resized to the given size.
+    ///
+    /// This is delivered by [`DebouncedResize`] at the first resize event of a series. The final
+    /// size is delivered as a resize event once the resizing stops.
+    Resizing(Size),
+    /// A signal was sent to the process.
+    ///
+    /// This is delivered by `SignalEvents`, which is only available on Unix with the `signals`
+    /// feature.
+    Signal(Signal),
+}
+
+impl Event {
+    /// Returns a label for the key pressed in this event, e.g. `"q"`, `"Ctrl+C"` or `"Shift+←"`.
+    ///
+    /// This is meant to be shown to the user, e.g. in a [`Keycast`]. Returns `None` for events
+    /// other than key presses and repeats, and for key presses that only change the modifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    ///     event::Event,
+    /// };
+    ///
+    /// let event = Event::from(ratatui::crossterm::event::Event::Key(KeyEvent::new(
+    ///     KeyCode::Char('c'),
+    ///     KeyModifiers::CONTROL,
+    /// )));
+    /// assert_eq!(event.key_label().as_deref(), Some("Ctrl+C"));
+    /// ```
+    ///
+    /// [`Keycast`]: crate::widgets::Keycast
+    pub fn key_label(&self) -> Option<String> {
+        let Self::Terminal(CrosstermEvent::Key(key)) = self else {
+            return None;
+        };
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let mut modifiers = key.modifiers;
+        let code = match key.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            // the character of a shifted key already shows the shift
+            KeyCode::Char(c) if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                c.to_uppercase().to_string()
+            }
+            KeyCode::Char(c) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                c.to_string()
+            }
+            KeyCode::BackTab => {
+                modifiers.insert(KeyModifiers::SHIFT);
+                "Tab".to_string()
+            }
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            KeyCode::Null | KeyCode::Modifier(_) => return None,
+            code => format!("{code:?}"),
+        };
+        let mut label = String::new();
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl+"),
+            (KeyModifiers::ALT, "Alt+"),
+            (KeyModifiers::SUPER, "Super+"),
+            (KeyModifiers::SHIFT, "Shift+"),
+        ] {
+            if modifiers.contains(modifier) {
+                label.push_str(name);
+            }
+        }
+        label.push_str(&code);
+        Some(label)
+    }
+
+    /// Returns the position of the mouse on the screen for mouse events.
+    ///
+    /// Use [`Rect::relative_position`] to map the position into the area of a widget, which also
+    /// checks that the mouse is inside the area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{event::Event, layout::Rect};
+    ///
+    /// fn clicked_cell(event: &Event, area: Rect) -> Option<(u16, u16)> {
+    ///     let position = area.relative_position(event.mouse_position()?)?;
+    ///     Some((position.x, position.y))
+    /// }
+    /// ```
+    ///
+    /// [`Rect::relative_position`]: crate::layout::Rect::relative_position
+    pub const fn mouse_position(&self) -> Option<Position> {
+        match self {
+            Self::Terminal(CrosstermEvent::Mouse(mouse)) => {
+                Some(Position::new(mouse.column, mouse.row))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<CrosstermEvent> for Event {
+    fn from(event: CrosstermEvent) -> Self {
+        Self::Terminal(event)
+    }
+}
+
+/// A source of [`Event`]s.
+///
+/// Applications that read their events through this trait instead of reading them from the
+/// terminal directly can be driven by any source, such as an [`EventPlayer`] in tests.
+pub trait EventSource {
+    /// Waits for the next event for at most `timeout`.
+    ///
+    /// Returns `None` if no event is available before the timeout expires.
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>>;
+}
+
+impl<S: EventSource + ?Sized> EventSource for &mut S {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        (**self).next_event(timeout)
+    }
+}
+
+impl<S: EventSource + ?Sized> EventSource for Box<S> {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        (**self).next_event(timeout)
+    }
+}
+
+/// The events read from the terminal with [`crossterm`].
+///
+/// [`crossterm`]: ratatui_crossterm::crossterm
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TerminalEvents;
+
+impl EventSource for TerminalEvents {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        if crossterm_event::poll(timeout)? {
+            crossterm_event::read().map(|event| Some(Event::Terminal(event)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_crossterm::crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::char(KeyCode::Char('q'), KeyModifiers::NONE, Some("q"))]
+    #[case::shifted_char(KeyCode::Char('Q'), KeyModifiers::SHIFT, Some("Q"))]
+    #[case::control(KeyCode::Char('c'), KeyModifiers::CONTROL, Some("Ctrl+C"))]
+    #[case::space(KeyCode::Char(' '), KeyModifiers::NONE, Some("Space"))]
+    #[case::back_tab(KeyCode::BackTab, KeyModifiers::SHIFT, Some("Shift+Tab"))]
+    #[case::arrow(KeyCode::Left, KeyModifiers::SHIFT, Some("Shift+←"))]
+    #[case::function(KeyCode::F(5), KeyModifiers::ALT, Some("Alt+F5"))]
+    #[case::named(KeyCode::Enter, KeyModifiers::NONE, Some("Enter"))]
+    #[case::null(KeyCode::Null, KeyModifiers::NONE, None)]
+    fn key_label(
+        #[case] code: KeyCode,
+        #[case] modifiers: KeyModifiers,
+        #[case] expected: Option<&str>,
+    ) {
+        let event = Event::from(CrosstermEvent::Key(KeyEvent::new(code, modifiers)));
+        assert_eq!(event.key_label().as_deref(), expected);
+    }
+
+    #[test]
+    fn key_label_ignores_other_events() {
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        );
+        assert_eq!(Event::from(CrosstermEvent::Key(release)).key_label(), None);
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(Event::from(CrosstermEvent::Mouse(mouse)).key_label(), None);
+        assert_eq!(Event::Tick.key_label(), None);
+    }
+
+    #[test]
+    fn mouse_position() {
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 3,
+            row: 4,
+            modifiers: KeyModifiers::NONE,
+        };
+        let event = Event::from(CrosstermEvent::Mouse(mouse));
+        assert_eq!(event.mouse_position(), Some(Position::new(3, 4)));
+        assert_eq!(Event::Tick.mouse_position(), None);
+    }
+}
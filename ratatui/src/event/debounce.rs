@@ -0,0 +1,156 @@
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+use ratatui_core::layout::Size;
+use ratatui_crossterm::crossterm::event::Event as CrosstermEvent;
+
+use super::{Event, EventSource};
+
+/// An [`EventSource`] that coalesces the resize events of another source.
+///
+/// While the user drags the border of the terminal window, the terminal sends a resize event for
+/// every intermediate size, and laying out and drawing the interface for each of them is wasted
+/// work. This source delivers an [`Event::Resizing`] hint at the first resize event, swallows the
+/// following ones, and delivers the final size as a resize event once no resize event was received
+/// during the quiet period. The other events of the wrapped source are passed through unchanged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     crossterm::event::Event as CrosstermEvent,
+///     event::{DebouncedResize, Event, EventSource, TerminalEvents},
+/// };
+///
+/// let mut events = DebouncedResize::new(TerminalEvents, Duration::from_millis(100));
+/// loop {
+///     match events.next_event(Duration::from_secs(1))? {
+///         Some(Event::Resizing(_size)) => { /* e.g. skip expensive rendering */ }
+///         Some(Event::Terminal(CrosstermEvent::Resize(_width, _height))) => { /* redraw */ }
+///         _ => {}
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DebouncedResize<S> {
+    source: S,
+    quiet_period: Duration,
+    pending: Option<(u16, u16)>,
+    deadline: Option<Instant>,
+}
+
+impl<S> DebouncedResize<S> {
+    /// Creates a source that coalesces the resize events of the given source.
+    ///
+    /// The final size is delivered once no resize event was received for `quiet_period`.
+    pub const fn new(source: S, quiet_period: Duration) -> Self {
+        Self {
+            source,
+            quiet_period,
+            pending: None,
+            deadline: None,
+        }
+    }
+
+    /// Returns whether a resize is in progress, i.e. whether the final size was not delivered yet.
+    pub const fn is_resizing(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Consumes the source and returns the wrapped source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    /// Returns the final resize event if the quiet period has elapsed at `now`.
+    fn due_event(&mut self, now: Instant) -> Option<Event> {
+        if self.deadline.is_some_and(|deadline| deadline <= now) {
+            self.deadline = None;
+            let (width, height) = self.pending.take()?;
+            return Some(Event::Terminal(CrosstermEvent::Resize(width, height)));
+        }
+        None
+    }
+}
+
+impl<S: EventSource> EventSource for DebouncedResize<S> {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let end = Instant::now() + timeout;
+        loop {
+            let now = Instant::now();
+            if let Some(event) = self.due_event(now) {
+                return Ok(Some(event));
+            }
+            let wait = self
+                .deadline
+                .map_or(end, |deadline| deadline.min(end))
+                .saturating_duration_since(now);
+            match self.source.next_event(wait)? {
+                Some(Event::Terminal(CrosstermEvent::Resize(width, height))) => {
+                    let started = self.pending.replace((width, height)).is_none();
+                    self.deadline = Some(Instant::now() + self.quiet_period);
+                    if started {
+                        return Ok(Some(Event::Resizing(Size::new(width, height))));
+                    }
+                }
+                Some(event) => return Ok(Some(event)),
+                None => return Ok(self.due_event(Instant::now())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use ratatui_crossterm::crossterm::event::{KeyCode, KeyEvent};
+
+    use super::*;
+    use crate::event::{EventPlayer, RecordedEvent};
+
+    fn recorded(event: CrosstermEvent) -> RecordedEvent {
+        RecordedEvent {
+            time: Duration::ZERO,
+            event: Event::Terminal(event),
+        }
+    }
+
+    #[test]
+    fn coalesces_resize_events() {
+        let key = CrosstermEvent::Key(KeyEvent::from(KeyCode::Enter));
+        let player = EventPlayer::new([
+            recorded(CrosstermEvent::Resize(10, 5)),
+            recorded(CrosstermEvent::Resize(20, 6)),
+            recorded(CrosstermEvent::Resize(30, 7)),
+        ]);
+        let mut events = DebouncedResize::new(player, Duration::from_millis(10));
+        assert_eq!(
+            events.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Resizing(Size::new(10, 5)))
+        );
+        assert_eq!(events.next_event(Duration::ZERO).unwrap(), None);
+        assert!(events.is_resizing());
+
+        thread::sleep(Duration::from_millis(15));
+        assert_eq!(
+            events.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Terminal(CrosstermEvent::Resize(30, 7)))
+        );
+        assert!(!events.is_resizing());
+
+        let mut events = DebouncedResize::new(
+            EventPlayer::new([recorded(key.clone())]),
+            Duration::from_millis(10),
+        );
+        assert_eq!(
+            events.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Terminal(key))
+        );
+    }
+}
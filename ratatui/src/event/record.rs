@@ -0,0 +1,218 @@
+use std::{
+    collections::VecDeque,
+    io, thread,
+    time::{Duration, Instant},
+};
+
+use super::{Event, EventSource};
+
+/// An [`Event`] recorded by an [`EventRecorder`], with the time at which it was received.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEvent {
+    /// The time elapsed between the creation of the recorder and the event.
+    pub time: Duration,
+    /// The event.
+    pub event: Event,
+}
+
+/// An [`EventSource`] that records the events of another source.
+///
+/// The recorder passes the events of the wrapped source through unchanged, and keeps a copy of
+/// each of them with a timestamp. The recording can be replayed with an [`EventPlayer`], for
+/// example to reproduce a bug from a recording submitted by a user. With the `serde` feature, the
+/// recorded events can be serialized to any format, such as JSON.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::event::{EventRecorder, TerminalEvents};
+///
+/// let mut events = EventRecorder::new(TerminalEvents);
+/// // run the application with `&mut events` as its event source
+/// let recording = events.into_events();
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventRecorder<S> {
+    source: S,
+    start: Instant,
+    events: Vec<RecordedEvent>,
+}
+
+impl<S> EventRecorder<S> {
+    /// Creates a recorder of the events of the given source.
+    ///
+    /// The timestamps of the events are relative to the creation of the recorder.
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            start: Instant::now(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the events recorded so far.
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Consumes the recorder and returns the recorded events.
+    pub fn into_events(self) -> Vec<RecordedEvent> {
+        self.events
+    }
+
+    /// Consumes the recorder and returns the wrapped source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: EventSource> EventSource for EventRecorder<S> {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let event = self.source.next_event(timeout)?;
+        if let Some(event) = &event {
+            self.events.push(RecordedEvent {
+                time: self.start.elapsed(),
+                event: event.clone(),
+            });
+        }
+        Ok(event)
+    }
+}
+
+/// An [`EventSource`] that replays recorded events.
+///
+/// By default, the events are replayed as fast as they are requested, which makes tests fast and
+/// deterministic. Use [`EventPlayer::realtime`] to wait between the events as long as they were
+/// apart when they were recorded. Once all the events are replayed, the player returns `None`
+/// without waiting.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent},
+///     event::{Event, EventPlayer, EventSource, RecordedEvent},
+/// };
+///
+/// let key = Event::Terminal(CrosstermEvent::Key(KeyEvent::from(KeyCode::Char('q'))));
+/// let mut player = EventPlayer::new([RecordedEvent {
+///     time: Duration::from_secs(1),
+///     event: key.clone(),
+/// }]);
+/// assert_eq!(player.next_event(Duration::ZERO)?, Some(key));
+/// assert_eq!(player.next_event(Duration::ZERO)?, None);
+/// assert!(player.is_finished());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventPlayer {
+    events: VecDeque<RecordedEvent>,
+    realtime: bool,
+    start: Option<Instant>,
+}
+
+impl EventPlayer {
+    /// Creates a player of the given recorded events.
+    pub fn new<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = RecordedEvent>,
+    {
+        Self {
+            events: events.into_iter().collect(),
+            realtime: false,
+            start: None,
+        }
+    }
+
+    /// Sets whether the events are replayed with the timing of the recording.
+    ///
+    /// The time of the recording starts when the first event is requested.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn realtime(mut self, realtime: bool) -> Self {
+        self.realtime = realtime;
+        self
+    }
+
+    /// Returns whether all the events were replayed.
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the number of events that remain to be replayed.
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+}
+
+impl EventSource for EventPlayer {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let Some(next) = self.events.front() else {
+            return Ok(None);
+        };
+        if self.realtime {
+            let start = *self.start.get_or_insert_with(Instant::now);
+            let wait = next.time.saturating_sub(start.elapsed());
+            if wait > timeout {
+                thread::sleep(timeout);
+                return Ok(None);
+            }
+            thread::sleep(wait);
+        }
+        Ok(self.events.pop_front().map(|recorded| recorded.event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_crossterm::crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent};
+
+    use super::*;
+
+    fn key(c: char) -> Event {
+        Event::Terminal(CrosstermEvent::Key(KeyEvent::from(KeyCode::Char(c))))
+    }
+
+    fn recording() -> Vec<RecordedEvent> {
+        vec![
+            RecordedEvent {
+                time: Duration::ZERO,
+                event: key('a'),
+            },
+            RecordedEvent {
+                time: Duration::from_millis(20),
+                event: key('b'),
+            },
+        ]
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut recorder = EventRecorder::new(EventPlayer::new(recording()));
+        assert_eq!(recorder.next_event(Duration::ZERO).unwrap(), Some(key('a')));
+        assert_eq!(recorder.next_event(Duration::ZERO).unwrap(), Some(key('b')));
+        assert_eq!(recorder.next_event(Duration::ZERO).unwrap(), None);
+        let events: Vec<Event> = recorder
+            .into_events()
+            .into_iter()
+            .map(|recorded| recorded.event)
+            .collect();
+        assert_eq!(events, [key('a'), key('b')]);
+    }
+
+    #[test]
+    fn replay_realtime() {
+        let mut player = EventPlayer::new(recording()).realtime(true);
+        assert_eq!(player.next_event(Duration::ZERO).unwrap(), Some(key('a')));
+        // the second event is not due yet
+        assert_eq!(player.next_event(Duration::ZERO).unwrap(), None);
+        assert_eq!(player.remaining(), 1);
+        assert_eq!(
+            player.next_event(Duration::from_secs(1)).unwrap(),
+            Some(key('b'))
+        );
+        assert!(player.is_finished());
+    }
+}
@@ -0,0 +1,269 @@
+#[cfg(all(unix, feature = "signals"))]
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+#[cfg(all(unix, feature = "signals"))]
+use signal_hook::{
+    consts::{SIGCONT, SIGHUP, SIGTERM, SIGTSTP, SIGWINCH},
+    iterator::Signals,
+};
+
+#[cfg(all(unix, feature = "signals"))]
+use super::{Event, EventSource};
+
+/// A signal sent to the process, delivered by [`SignalEvents`].
+///
+/// Signals are only delivered on Unix.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Signal {
+    /// The terminal window was resized (`SIGWINCH`).
+    Resize,
+    /// The process was asked to terminate (`SIGTERM`), or the terminal was closed (`SIGHUP`).
+    Terminate,
+    /// The user asked to suspend the process, usually by pressing `Ctrl+Z` (`SIGTSTP`).
+    ///
+    /// The process is not suspended until the application calls [`suspend`].
+    Suspend,
+    /// The process was continued after being suspended (`SIGCONT`).
+    Continue,
+}
+
+/// An [`EventSource`] that adds the signals sent to the process to the events of another source.
+///
+/// Handling signals as events lets the application react to them in its update loop: save its
+/// state before terminating, or leave the alternate screen before being suspended and enter it
+/// again when continued. By default, the terminal is restored with [`restore`](crate::restore)
+/// before a [`Signal::Terminate`] is delivered, so that the terminal is usable again even if the
+/// application exits without restoring it.
+///
+/// While a [`SignalEvents`] exists, the default actions of the signals are replaced: in particular,
+/// `Ctrl+Z` no longer suspends the process until the application calls [`suspend`]. Once all the
+/// sources are dropped, `SIGTERM`, `SIGHUP` and `SIGTSTP` terminate or suspend the process again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use ratatui::event::{self, Event, EventSource, Signal, SignalEvents, TerminalEvents};
+///
+/// let mut terminal = ratatui::init();
+/// let mut events = SignalEvents::new(TerminalEvents)?;
+/// loop {
+///     match events.next_event(Duration::from_millis(250))? {
+///         Some(Event::Signal(Signal::Terminate)) => break,
+///         Some(Event::Signal(Signal::Suspend)) => {
+///             event::suspend()?;
+///             terminal = ratatui::init();
+///             terminal.clear()?;
+///         }
+///         _ => { /* handle the other events */ }
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(all(unix, feature = "signals"))]
+#[derive(Debug)]
+pub struct SignalEvents<S> {
+    source: S,
+    registration: Registration,
+    pending: VecDeque<Signal>,
+    restore_on_terminate: bool,
+}
+
+/// The signal handlers of a [`SignalEvents`], which stop delivering the signals and restore their
+/// default actions if no other source exists when dropped.
+#[cfg(all(unix, feature = "signals"))]
+#[derive(Debug)]
+struct Registration {
+    signals: Signals,
+}
+
+/// The signals whose default action is restored once no [`SignalEvents`] exists.
+#[cfg(all(unix, feature = "signals"))]
+const DEFAULT_SIGNALS: [i32; 3] = [SIGTERM, SIGHUP, SIGTSTP];
+
+/// The number of [`SignalEvents`] that exist, and whether the default actions are enabled.
+///
+/// `signal-hook` cannot restore the default disposition of a signal once it has handled it, so
+/// handlers that emulate the default actions are registered once, and enabled while no source
+/// exists.
+#[cfg(all(unix, feature = "signals"))]
+struct DefaultActions {
+    sources: Mutex<usize>,
+    enabled: Arc<AtomicBool>,
+}
+
+#[cfg(all(unix, feature = "signals"))]
+impl DefaultActions {
+    fn get() -> io::Result<&'static Self> {
+        static DEFAULT_ACTIONS: OnceLock<DefaultActions> = OnceLock::new();
+        if let Some(actions) = DEFAULT_ACTIONS.get() {
+            return Ok(actions);
+        }
+        let enabled = Arc::new(AtomicBool::new(true));
+        for signal in DEFAULT_SIGNALS {
+            signal_hook::flag::register_conditional_default(signal, Arc::clone(&enabled))?;
+        }
+        Ok(DEFAULT_ACTIONS.get_or_init(|| Self {
+            sources: Mutex::new(0),
+            enabled,
+        }))
+    }
+
+    /// Counts a new source, disabling the default actions.
+    fn acquire(&self) {
+        let mut sources = self.sources.lock().unwrap_or_else(PoisonError::into_inner);
+        *sources += 1;
+        self.enabled.store(false, Ordering::SeqCst);
+    }
+
+    /// Counts a dropped source, enabling the default actions if it was the last one.
+    fn release(&self) {
+        let mut sources = self.sources.lock().unwrap_or_else(PoisonError::into_inner);
+        *sources = sources.saturating_sub(1);
+        self.enabled.store(*sources == 0, Ordering::SeqCst);
+    }
+}
+
+/// The maximum time to wait for the wrapped source before checking for signals.
+#[cfg(all(unix, feature = "signals"))]
+const SIGNAL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[cfg(all(unix, feature = "signals"))]
+impl<S> SignalEvents<S> {
+    /// Creates a source that adds the signals sent to the process to the events of the given
+    /// source.
+    ///
+    /// This installs handlers for `SIGWINCH`, `SIGTERM`, `SIGHUP`, `SIGTSTP` and `SIGCONT`. When
+    /// the source is dropped, the signals are no longer delivered, and the default actions of
+    /// `SIGTERM`, `SIGHUP` and `SIGTSTP` are restored unless another source exists.
+    pub fn new(source: S) -> io::Result<Self> {
+        let signals = Signals::new([SIGWINCH, SIGTERM, SIGHUP, SIGTSTP, SIGCONT])?;
+        DefaultActions::get()?.acquire();
+        Ok(Self {
+            source,
+            registration: Registration { signals },
+            pending: VecDeque::new(),
+            restore_on_terminate: true,
+        })
+    }
+
+    /// Sets whether the terminal is restored before a [`Signal::Terminate`] is delivered.
+    ///
+    /// This is enabled by default.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn restore_on_terminate(mut self, restore_on_terminate: bool) -> Self {
+        self.restore_on_terminate = restore_on_terminate;
+        self
+    }
+
+    /// Consumes the source and returns the wrapped source.
+    ///
+    /// The signals are no longer delivered, as when the source is dropped.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    /// Returns the next signal received by the process, if any.
+    fn next_signal(&mut self) -> Option<Event> {
+        self.pending
+            .extend(
+                self.registration
+                    .signals
+                    .pending()
+                    .filter_map(|signal| match signal {
+                        SIGWINCH => Some(Signal::Resize),
+                        SIGTERM | SIGHUP => Some(Signal::Terminate),
+                        SIGTSTP => Some(Signal::Suspend),
+                        SIGCONT => Some(Signal::Continue),
+                        _ => None,
+                    }),
+            );
+        let signal = self.pending.pop_front()?;
+        if signal == Signal::Terminate && self.restore_on_terminate {
+            crate::restore();
+        }
+        Some(Event::Signal(signal))
+    }
+}
+
+#[cfg(all(unix, feature = "signals"))]
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.signals.handle().close();
+        if let Ok(actions) = DefaultActions::get() {
+            actions.release();
+        }
+    }
+}
+
+#[cfg(all(unix, feature = "signals"))]
+impl<S: EventSource> EventSource for SignalEvents<S> {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let end = Instant::now() + timeout;
+        loop {
+            if let Some(event) = self.next_signal() {
+                return Ok(Some(event));
+            }
+            // signals do not interrupt the wrapped source, so wait for it in short intervals
+            let start = Instant::now();
+            let wait = end
+                .saturating_duration_since(start)
+                .min(SIGNAL_POLL_INTERVAL);
+            if let Some(event) = self.source.next_event(wait)? {
+                return Ok(Some(event));
+            }
+            // stop when the timeout expired, or when the source returned early without an event
+            if Instant::now() >= end || start.elapsed() < wait {
+                return Ok(self.next_signal());
+            }
+        }
+    }
+}
+
+/// Restores the terminal and suspends the process, like the default action of `Ctrl+Z`.
+///
+/// Call this when receiving a [`Signal::Suspend`]. This function returns once the process is
+/// continued (e.g. with `fg` in the shell), after which the application must initialize the
+/// terminal again and redraw the whole screen, as other programs may have used the terminal in the
+/// meantime.
+#[cfg(all(unix, feature = "signals"))]
+pub fn suspend() -> io::Result<()> {
+    crate::try_restore()?;
+    signal_hook::low_level::emulate_default_handler(SIGTSTP)
+}
+
+#[cfg(all(test, unix, feature = "signals"))]
+mod tests {
+    use signal_hook::low_level::raise;
+
+    use super::*;
+    use crate::event::EventPlayer;
+
+    #[test]
+    fn delivers_signals() {
+        let mut events = SignalEvents::new(EventPlayer::new([])).unwrap();
+        raise(SIGCONT).unwrap();
+        raise(SIGWINCH).unwrap();
+        let mut received = Vec::new();
+        while let Some(event) = events.next_event(Duration::ZERO).unwrap() {
+            received.push(event);
+        }
+        assert_eq!(
+            received,
+            [
+                Event::Signal(Signal::Continue),
+                Event::Signal(Signal::Resize)
+            ]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+use super::{Event, EventSource};
+
+/// The identifier of a one-shot timer started with [`Ticker::add_timer`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerId(u64);
+
+/// An [`EventSource`] that adds timer events to the events of another source.
+///
+/// The ticker delivers an [`Event::Tick`] at a fixed rate, set with [`Ticker::tick_rate`], which
+/// is useful to drive animations. It also delivers an [`Event::Timer`] when a one-shot timer
+/// started with [`Ticker::add_timer`] expires, which is useful for timeouts such as hiding a
+/// notification after a few seconds. Between the timer events, the events of the wrapped source
+/// are passed through unchanged.
+///
+/// If the application falls behind, missed ticks are not delivered in a burst: the next tick is
+/// scheduled one tick rate after the late one.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use ratatui::event::{Event, EventSource, TerminalEvents, Ticker};
+///
+/// let mut events = Ticker::new(TerminalEvents).tick_rate(Duration::from_millis(100));
+/// let timeout = events.add_timer(Duration::from_secs(5));
+/// loop {
+///     match events.next_event(Duration::from_secs(1))? {
+///         Some(Event::Tick) => { /* advance the animations */ }
+///         Some(Event::Timer(id)) if id == timeout => break,
+///         Some(_event) => { /* handle the terminal events */ }
+///         None => {}
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ticker<S> {
+    source: S,
+    tick_rate: Option<Duration>,
+    next_tick: Option<Instant>,
+    timers: Vec<(Instant, TimerId)>,
+    next_id: u64,
+}
+
+impl<S> Ticker<S> {
+    /// Creates a ticker that adds timer events to the events of the given source.
+    ///
+    /// No ticks are delivered until a tick rate is set with [`Ticker::tick_rate`].
+    pub const fn new(source: S) -> Self {
+        Self {
+            source,
+            tick_rate: None,
+            next_tick: None,
+            timers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Sets the interval between two [`Event::Tick`]s.
+    ///
+    /// The first tick is delivered one interval after this call. A zero interval disables the
+    /// ticks.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tick_rate(mut self, tick_rate: Duration) -> Self {
+        self.set_tick_rate(tick_rate);
+        self
+    }
+
+    /// Changes the interval between two [`Event::Tick`]s.
+    ///
+    /// The next tick is delivered one interval after this call. A zero interval disables the
+    /// ticks.
+    pub fn set_tick_rate(&mut self, tick_rate: Duration) {
+        self.tick_rate = (!tick_rate.is_zero()).then_some(tick_rate);
+        self.next_tick = self.tick_rate.map(|rate| Instant::now() + rate);
+    }
+
+    /// Starts a one-shot timer that delivers an [`Event::Timer`] with the returned identifier once
+    /// the delay has elapsed.
+    pub fn add_timer(&mut self, delay: Duration) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        self.timers.push((Instant::now() + delay, id));
+        id
+    }
+
+    /// Cancels a timer that has not expired yet.
+    ///
+    /// Returns whether the timer was pending.
+    pub fn cancel_timer(&mut self, id: TimerId) -> bool {
+        let pending = self.timers.len();
+        self.timers.retain(|&(_, timer)| timer != id);
+        self.timers.len() != pending
+    }
+
+    /// Consumes the ticker and returns the wrapped source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    /// Returns the earliest time at which a tick or a timer is due.
+    fn next_deadline(&self) -> Option<Instant> {
+        let timer = self.timers.iter().map(|&(deadline, _)| deadline).min();
+        match (self.next_tick, timer) {
+            (Some(tick), Some(timer)) => Some(tick.min(timer)),
+            (tick, timer) => tick.or(timer),
+        }
+    }
+
+    /// Returns the event of the tick or timer that is due at `now`, if any.
+    fn due_event(&mut self, now: Instant) -> Option<Event> {
+        let timer = self
+            .timers
+            .iter()
+            .enumerate()
+            .filter(|(_, &(deadline, _))| deadline <= now)
+            .min_by_key(|(_, &(deadline, _))| deadline)
+            .map(|(index, &(deadline, _))| (index, deadline));
+        match (self.next_tick, timer) {
+            (Some(tick), timer) if tick <= now && timer.map_or(true, |(_, at)| tick <= at) => {
+                let rate = self.tick_rate.unwrap_or_default();
+                let next_tick = tick + rate;
+                self.next_tick = Some(if next_tick <= now {
+                    now + rate
+                } else {
+                    next_tick
+                });
+                Some(Event::Tick)
+            }
+            (_, Some((index, _))) => Some(Event::Timer(self.timers.swap_remove(index).1)),
+            _ => None,
+        }
+    }
+}
+
+impl<S: EventSource> EventSource for Ticker<S> {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let now = Instant::now();
+        if let Some(event) = self.due_event(now) {
+            return Ok(Some(event));
+        }
+        let wait = self
+            .next_deadline()
+            .map_or(timeout, |deadline| (deadline - now).min(timeout));
+        if let Some(event) = self.source.next_event(wait)? {
+            return Ok(Some(event));
+        }
+        Ok(self.due_event(Instant::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::event::EventPlayer;
+
+    fn ticker() -> Ticker<EventPlayer> {
+        Ticker::new(EventPlayer::new([]))
+    }
+
+    #[test]
+    fn ticks() {
+        let mut ticker = ticker().tick_rate(Duration::from_millis(10));
+        assert_eq!(ticker.next_event(Duration::ZERO).unwrap(), None);
+        thread::sleep(Duration::from_millis(15));
+        assert_eq!(
+            ticker.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Tick)
+        );
+        assert_eq!(ticker.next_event(Duration::ZERO).unwrap(), None);
+    }
+
+    #[test]
+    fn missed_ticks_are_not_delivered_in_a_burst() {
+        let mut ticker = ticker().tick_rate(Duration::from_millis(5));
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(
+            ticker.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Tick)
+        );
+        assert_eq!(ticker.next_event(Duration::ZERO).unwrap(), None);
+    }
+
+    #[test]
+    fn timers() {
+        let mut ticker = ticker();
+        let first = ticker.add_timer(Duration::ZERO);
+        let second = ticker.add_timer(Duration::ZERO);
+        let cancelled = ticker.add_timer(Duration::ZERO);
+        let later = ticker.add_timer(Duration::from_secs(60));
+        assert!(ticker.cancel_timer(cancelled));
+        assert!(!ticker.cancel_timer(cancelled));
+        assert_eq!(
+            ticker.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Timer(first))
+        );
+        assert_eq!(
+            ticker.next_event(Duration::ZERO).unwrap(),
+            Some(Event::Timer(second))
+        );
+        assert_eq!(ticker.next_event(Duration::ZERO).unwrap(), None);
+        assert!(ticker.cancel_timer(later));
+    }
+}
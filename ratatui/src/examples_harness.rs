@@ -0,0 +1,284 @@
+//! Golden-file tests for whole user interfaces.
+//!
+//! The [`SnapshotHarness`] renders the draw function of an application (the function passed to
+//! [`Terminal::draw`]) into a [`TestBackend`] at several sizes, and compares each rendered buffer
+//! with a snapshot stored in a directory. The snapshots contain the [`Debug`] representation of
+//! the buffers, so both the content and the styles of the cells are checked.
+//!
+//! Missing or outdated snapshots are written instead of being compared when the
+//! [`UPDATE_ENV_VAR`] environment variable is set to `1`, e.g. after an intended change:
+//!
+//! ```text
+//! RATATUI_UPDATE_SNAPSHOTS=1 cargo test
+//! ```
+//!
+//! The examples of the repository are tested with this harness, so that changes to the widgets
+//! can be validated against real user interfaces. Applications can use it the same way, by
+//! enabling the `examples-harness` feature for their tests:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! ratatui = { version = "*", features = ["examples-harness"] }
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use ratatui::{examples_harness::SnapshotHarness, layout::Size, widgets::Paragraph, Frame};
+//!
+//! fn draw(frame: &mut Frame) {
+//!     frame.render_widget(Paragraph::new("Hello World!"), frame.area());
+//! }
+//!
+//! #[test]
+//! fn snapshots() {
+//!     SnapshotHarness::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots"))
+//!         .sizes([Size::new(20, 2), Size::new(80, 24)])
+//!         .assert("hello_world", draw);
+//! }
+//! ```
+//!
+//! [`Terminal::draw`]: crate::Terminal::draw
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{backend::TestBackend, buffer::Buffer, layout::Size, Frame, Terminal};
+
+/// The environment variable that makes a [`SnapshotHarness`] write the snapshots instead of
+/// comparing them when it is set to `1`.
+pub const UPDATE_ENV_VAR: &str = "RATATUI_UPDATE_SNAPSHOTS";
+
+/// The sizes at which a [`SnapshotHarness`] renders by default: a small terminal, the classic
+/// 80x24 terminal and a large terminal.
+pub const DEFAULT_SIZES: [Size; 3] = [Size::new(40, 10), Size::new(80, 24), Size::new(120, 40)];
+
+/// Renders a user interface at several sizes and compares it with stored snapshots.
+///
+/// Each size is stored in its own file, named `<name>@<width>x<height>.snap`, in the directory of
+/// the harness.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SnapshotHarness {
+    dir: PathBuf,
+    sizes: Vec<Size>,
+    update: bool,
+}
+
+/// An error returned by [`SnapshotHarness::check`].
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The snapshot file does not exist.
+    Missing {
+        /// The path of the missing snapshot.
+        path: PathBuf,
+    },
+    /// The rendered buffer does not match the snapshot.
+    Mismatch {
+        /// The path of the snapshot.
+        path: PathBuf,
+        /// The content of the snapshot.
+        expected: String,
+        /// The snapshot of the rendered buffer.
+        actual: String,
+    },
+    /// The snapshot could not be read or written, or the user interface could not be drawn.
+    Io(io::Error),
+}
+
+impl SnapshotHarness {
+    /// Creates a harness that stores its snapshots in the given directory and renders at the
+    /// [`DEFAULT_SIZES`].
+    ///
+    /// The snapshots are updated if the [`UPDATE_ENV_VAR`] environment variable is set to `1`.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            sizes: DEFAULT_SIZES.to_vec(),
+            update: env::var(UPDATE_ENV_VAR).is_ok_and(|value| value == "1"),
+        }
+    }
+
+    /// Sets the sizes at which the user interface is rendered.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sizes<I: IntoIterator<Item = Size>>(mut self, sizes: I) -> Self {
+        self.sizes = sizes.into_iter().collect();
+        self
+    }
+
+    /// Sets whether the snapshots are written instead of being compared.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Renders the user interface at each size and compares it with its snapshot.
+    ///
+    /// Returns the first error found. When updating, the snapshots that are missing or that do
+    /// not match are written instead.
+    pub fn check<F: FnMut(&mut Frame)>(
+        &self,
+        name: &str,
+        mut draw: F,
+    ) -> Result<(), SnapshotError> {
+        for &size in &self.sizes {
+            let actual = format!("{:?}\n", render(size, &mut draw)?);
+            let path = self.snapshot_path(name, size);
+            let expected = match fs::read_to_string(&path) {
+                Ok(expected) => Some(expected),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+                Err(err) => return Err(SnapshotError::Io(err)),
+            };
+            if expected.as_deref() == Some(actual.as_str()) {
+                continue;
+            }
+            if self.update {
+                fs::create_dir_all(&self.dir)?;
+                fs::write(&path, actual)?;
+                continue;
+            }
+            return Err(match expected {
+                Some(expected) => SnapshotError::Mismatch {
+                    path,
+                    expected,
+                    actual,
+                },
+                None => SnapshotError::Missing { path },
+            });
+        }
+        Ok(())
+    }
+
+    /// Renders the user interface at each size and compares it with its snapshot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a snapshot does not match, with the expected and actual buffers in the message.
+    #[track_caller]
+    pub fn assert<F: FnMut(&mut Frame)>(&self, name: &str, draw: F) {
+        if let Err(err) = self.check(name, draw) {
+            panic!("{err}");
+        }
+    }
+
+    /// Returns the path of the snapshot of the named user interface at the given size.
+    pub fn snapshot_path(&self, name: &str, size: Size) -> PathBuf {
+        self.dir
+            .join(format!("{name}@{}x{}.snap", size.width, size.height))
+    }
+
+    /// Returns the directory in which the snapshots are stored.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// Renders a user interface into a [`TestBackend`] of the given size and returns its buffer.
+pub fn render<F: FnMut(&mut Frame)>(size: Size, draw: F) -> io::Result<Buffer> {
+    let mut terminal = Terminal::new(TestBackend::new(size.width, size.height))?;
+    terminal.draw(draw)?;
+    Ok(terminal.backend().buffer().clone())
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { path } => write!(
+                f,
+                "snapshot {} is missing, run the tests with {UPDATE_ENV_VAR}=1 to create it",
+                path.display()
+            ),
+            Self::Mismatch {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "snapshot {} does not match, run the tests with {UPDATE_ENV_VAR}=1 to update \
+                 it\nexpected:\n{expected}actual:\n{actual}",
+                path.display()
+            ),
+            Self::Io(err) => write!(f, "failed to check snapshot: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::Paragraph;
+
+    fn draw(frame: &mut Frame) {
+        frame.render_widget(Paragraph::new("Hello"), frame.area());
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("ratatui-snapshots-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn render_buffer() {
+        let buffer = render(Size::new(7, 1), draw).unwrap();
+        assert_eq!(buffer, Buffer::with_lines(["Hello  "]));
+    }
+
+    #[test]
+    fn missing_then_update() {
+        let dir = temp_dir("missing");
+        let harness = SnapshotHarness::new(&dir).sizes([Size::new(5, 1), Size::new(6, 2)]);
+        let err = harness
+            .clone()
+            .update(false)
+            .check("hello", draw)
+            .unwrap_err();
+        assert!(matches!(err, SnapshotError::Missing { .. }));
+
+        harness.clone().update(true).check("hello", draw).unwrap();
+        assert!(harness.snapshot_path("hello", Size::new(6, 2)).exists());
+        harness.update(false).check("hello", draw).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn mismatch() {
+        let dir = temp_dir("mismatch");
+        let harness = SnapshotHarness::new(&dir).sizes([Size::new(5, 1)]);
+        harness.clone().update(true).check("hello", draw).unwrap();
+
+        let err = harness
+            .update(false)
+            .check("hello", |frame: &mut Frame| {
+                frame.render_widget(Paragraph::new("World"), frame.area());
+            })
+            .unwrap_err();
+        let SnapshotError::Mismatch {
+            expected, actual, ..
+        } = err
+        else {
+            panic!("expected a mismatch, got {err:?}");
+        };
+        assert!(expected.contains("\"Hello\""));
+        assert!(actual.contains("\"World\""));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
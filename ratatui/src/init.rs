@@ -1,10 +1,29 @@
-use std::io::{self, stdout, Stdout};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, stderr, stdout, IsTerminal, Stderr, Stdout, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU8, Ordering},
+        Mutex, PoisonError,
+    },
+};
 
-use ratatui_core::terminal::{Terminal, TerminalOptions};
+use ratatui_core::{
+    backend::{Backend, ClearType, MouseCapture, NotificationProtocol, NullBackend, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+    terminal::{Terminal, TerminalOptions, Viewport},
+};
 use ratatui_crossterm::{
     crossterm::{
+        event::{
+            DisableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+            PushKeyboardEnhancementFlags,
+        },
         execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+        terminal::{
+            disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+            LeaveAlternateScreen,
+        },
     },
     CrosstermBackend,
 };
@@ -16,6 +35,33 @@ use ratatui_crossterm::{
 /// use [`Terminal`] and a [backend][`crate::backend`] of your choice directly.
 pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
 
+/// A type alias for a terminal that keeps the standard output free for data.
+///
+/// This is a [`Terminal`] using the [`CrosstermBackend`] which writes to a [`TerminalOutput`], or
+/// a [`NullBackend`] when no terminal is available. See [`TerminalExt::init_dual_output`].
+pub type DualOutputTerminal = Terminal<HeadlessFallback<CrosstermBackend<TerminalOutput>>>;
+
+/// The output that [`restore`] writes to, which is the output used by the last initialization.
+static RESTORE_OUTPUT: AtomicU8 = AtomicU8::new(OutputKind::Stdout as u8);
+
+/// The controlling terminal opened by the last initialization, kept for [`restore`] so that it
+/// doesn't have to be opened again.
+static RESTORE_TTY: Mutex<Option<File>> = Mutex::new(None);
+
+/// Whether [`restore`] must leave the alternate screen.
+///
+/// This is only cleared when the last initialization did not enter the alternate screen, so that
+/// [`restore`] still leaves an alternate screen that the application entered by itself.
+static ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(true);
+
+/// Whether mouse capture may have been enabled since the last initialization, and must be disabled
+/// by [`restore`].
+static MOUSE_CAPTURE: AtomicBool = AtomicBool::new(false);
+
+/// Whether keyboard enhancement flags were pushed by the last initialization, and must be popped
+/// by [`restore`].
+static KEYBOARD_ENHANCED: AtomicBool = AtomicBool::new(false);
+
 /// Initialize a terminal with reasonable defaults for most applications.
 ///
 /// This will create a new [`DefaultTerminal`] and initialize it with the following defaults:
@@ -27,8 +73,8 @@ pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
 ///   is called after any other panic hooks that may be installed to ensure that the terminal is
 ///   restored before those hooks are called.
 ///
-/// For more control over the terminal initialization, use [`Terminal::new`] or
-/// [`Terminal::with_options`].
+/// To choose which of these steps are performed, use [`InitOptions`]. For more control over the
+/// terminal initialization, use [`Terminal::new`] or [`Terminal::with_options`].
 ///
 /// Ensure that this method is called *after* your app installs any other panic hooks to ensure the
 /// terminal is restored before the other hooks are called.
@@ -45,6 +91,10 @@ pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
 /// - Entering the alternate screen buffer
 /// - Creating the terminal fails due to being unable to calculate the terminal size
 ///
+/// This means that it panics when no terminal is available, e.g. when the application runs under
+/// cron or in CI. Use [`init_dual_output`] instead for applications that must also run without a
+/// terminal, as it falls back to a headless terminal that discards its output.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -64,7 +114,10 @@ pub fn init() -> DefaultTerminal {
 /// - A panic hook is installed that restores the terminal before panicking.
 /// - A [`Terminal`] is created using [`CrosstermBackend`] writing to [`Stdout`]
 ///
-/// If any of these steps fail, the error is returned.
+/// If any of these steps fail, the error is returned. This is the case when no terminal is
+/// available (e.g. under cron or in CI), as enabling raw mode and querying the size of the terminal
+/// both require one. [`try_init_dual_output`] falls back to a headless terminal instead, see
+/// [`HeadlessFallback`].
 ///
 /// Ensure that this method is called *after* your app installs any other panic hooks to ensure the
 /// terminal is restored before the other hooks are called.
@@ -80,6 +133,9 @@ pub fn init() -> DefaultTerminal {
 /// # Ok::<(), std::io::Error>(())
 /// ```
 pub fn try_init() -> io::Result<DefaultTerminal> {
+    set_restore_output(OutputKind::Stdout, None);
+    // mouse capture is often enabled by the application after the initialization
+    set_restore_modes(true, true);
     set_panic_hook();
     enable_raw_mode()?;
     execute!(stdout(), EnterAlternateScreen)?;
@@ -169,12 +225,505 @@ pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
 /// # Ok::<(), std::io::Error>(())
 /// ```
 pub fn try_init_with_options(options: TerminalOptions) -> io::Result<DefaultTerminal> {
+    set_restore_output(OutputKind::Stdout, None);
+    // the alternate screen and mouse capture are often enabled by the application after the
+    // initialization
+    set_restore_modes(true, true);
     set_panic_hook();
     enable_raw_mode()?;
     let backend = CrosstermBackend::new(stdout());
     Terminal::with_options(backend, options)
 }
 
+/// Options for initializing a [`DefaultTerminal`].
+///
+/// This is a builder for the steps performed by [`init`], for applications that need a different
+/// combination of terminal modes. The defaults match [`init`]:
+///
+/// - Raw mode is enabled
+/// - Alternate screen buffer enabled
+/// - Mouse capture disabled
+/// - Keyboard enhancement disabled
+/// - A panic hook is installed that restores the terminal before panicking
+/// - A fullscreen [`Viewport`]
+///
+/// Call [`restore`] before the program exits to undo the changes made to the terminal. Only the
+/// modes enabled by these options are restored, e.g. the alternate screen is not left if it was
+/// not entered.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use ratatui::InitOptions;
+///
+/// let terminal = InitOptions::new().mouse_capture(true).init();
+/// ```
+///
+/// ```rust,no_run
+/// use ratatui::{backend::MouseCapture, InitOptions};
+///
+/// // only capture clicks, so that moving the mouse does not send any events
+/// let terminal = InitOptions::new()
+///     .mouse_capture_mode(MouseCapture::Click)
+///     .init();
+/// ```
+///
+/// ```rust,no_run
+/// use ratatui::{InitOptions, Viewport};
+///
+/// let terminal = InitOptions::new()
+///     .alternate_screen(false)
+///     .viewport(Viewport::Inline(5))
+///     .try_init()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)] // each flag is an independent terminal mode
+pub struct InitOptions {
+    raw_mode: bool,
+    alternate_screen: bool,
+    mouse_capture: MouseCapture,
+    keyboard_enhancement: bool,
+    panic_hook: bool,
+    viewport: Viewport,
+}
+
+impl Default for InitOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InitOptions {
+    /// Creates new options with the same defaults as [`init`].
+    pub const fn new() -> Self {
+        Self {
+            raw_mode: true,
+            alternate_screen: true,
+            mouse_capture: MouseCapture::Disabled,
+            keyboard_enhancement: false,
+            panic_hook: true,
+            viewport: Viewport::Fullscreen,
+        }
+    }
+
+    /// Sets whether raw mode is enabled.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn raw_mode(mut self, raw_mode: bool) -> Self {
+        self.raw_mode = raw_mode;
+        self
+    }
+
+    /// Sets whether the alternate screen buffer is entered.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alternate_screen(mut self, alternate_screen: bool) -> Self {
+        self.alternate_screen = alternate_screen;
+        self
+    }
+
+    /// Sets whether mouse events are captured.
+    ///
+    /// When enabled, all mouse events are captured, including mouse movement. Use
+    /// [`InitOptions::mouse_capture_mode`] to capture fewer events.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.mouse_capture = if mouse_capture {
+            MouseCapture::AllMotion
+        } else {
+            MouseCapture::Disabled
+        };
+        self
+    }
+
+    /// Sets which mouse events are captured.
+    ///
+    /// See [`MouseCapture`] for the available modes.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mouse_capture_mode(mut self, mouse_capture: MouseCapture) -> Self {
+        self.mouse_capture = mouse_capture;
+        self
+    }
+
+    /// Sets whether the keyboard enhancement of the [kitty keyboard protocol] is enabled.
+    ///
+    /// When enabled, the terminal reports key release and repeat events, distinguishes keys that
+    /// otherwise send the same escape codes (e.g. `Enter` and `Ctrl+M`, `Tab` and `Ctrl+I`, `Esc`
+    /// and `Ctrl+[`), and reports the modifiers of all keys, including function keys.
+    ///
+    /// The protocol is only enabled if the terminal supports it, so the application must still
+    /// handle the legacy key events. Use [`is_keyboard_enhanced`] after the initialization to check
+    /// whether it was enabled, e.g. to only rely on key release events when they are reported.
+    ///
+    /// [kitty keyboard protocol]: https://sw.kovidgoyal.net/kitty/keyboard-protocol/
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn keyboard_enhancement(mut self, keyboard_enhancement: bool) -> Self {
+        self.keyboard_enhancement = keyboard_enhancement;
+        self
+    }
+
+    /// Sets whether a panic hook that restores the terminal is installed.
+    ///
+    /// Disable this if the application installs its own panic hook that calls [`restore`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn panic_hook(mut self, panic_hook: bool) -> Self {
+        self.panic_hook = panic_hook;
+        self
+    }
+
+    /// Sets the [`Viewport`] of the terminal.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Initializes a terminal with these options.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the initialization steps fail. See [`init`] for details.
+    pub fn init(self) -> DefaultTerminal {
+        self.try_init().expect("failed to initialize terminal")
+    }
+
+    /// Tries to initialize a terminal with these options.
+    ///
+    /// If any of the initialization steps fail, the error is returned. Like [`try_init`], this
+    /// fails when no terminal is available.
+    pub fn try_init(self) -> io::Result<DefaultTerminal> {
+        set_restore_output(OutputKind::Stdout, None);
+        set_restore_modes(self.alternate_screen, self.mouse_capture.is_enabled());
+        if self.panic_hook {
+            set_panic_hook();
+        }
+        if self.raw_mode {
+            enable_raw_mode()?;
+        }
+        if self.alternate_screen {
+            execute!(stdout(), EnterAlternateScreen)?;
+        }
+        // the flags are pushed after entering the alternate screen, as terminals keep separate
+        // flags for the main and alternate screens
+        if self.keyboard_enhancement && supports_keyboard_enhancement().unwrap_or(false) {
+            let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                | KeyboardEnhancementFlags::REPORT_EVENT_TYPES;
+            execute!(stdout(), PushKeyboardEnhancementFlags(flags))?;
+            KEYBOARD_ENHANCED.store(true, Ordering::Relaxed);
+        }
+        let mut backend = CrosstermBackend::new(stdout());
+        if self.mouse_capture.is_enabled() {
+            backend.set_mouse_capture(self.mouse_capture)?;
+        }
+        Terminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: self.viewport,
+            },
+        )
+    }
+}
+
+/// Initialize a terminal that draws the UI without writing to the standard output.
+///
+/// This is the same as [`Terminal::init_dual_output`](TerminalExt::init_dual_output). See it for
+/// details.
+///
+/// # Panics
+///
+/// This function will panic if any of the initialization steps fail. See [`init`] for details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// let mut terminal = ratatui::init_dual_output();
+/// // draw the UI and let the user pick an item...
+/// ratatui::restore();
+/// println!("picked item");
+/// ```
+pub fn init_dual_output() -> DualOutputTerminal {
+    DualOutputTerminal::init_dual_output()
+}
+
+/// Try to initialize a terminal that draws the UI without writing to the standard output.
+///
+/// This is the same as [`Terminal::try_init_dual_output`](TerminalExt::try_init_dual_output). See
+/// [`TerminalExt::init_dual_output`] for details. If any of the initialization steps fail, the
+/// error is returned.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// let terminal = ratatui::try_init_dual_output()?;
+/// if terminal.backend().is_headless() {
+///     eprintln!("no terminal available, running without a UI");
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn try_init_dual_output() -> io::Result<DualOutputTerminal> {
+    DualOutputTerminal::try_init_dual_output()
+}
+
+/// Extension trait for [`Terminal`] that provides constructors for a [`DualOutputTerminal`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use ratatui::{Terminal, TerminalExt};
+///
+/// let mut terminal = Terminal::try_init_dual_output()?;
+/// // draw the UI and let the user pick an item...
+/// ratatui::restore();
+/// println!("picked item");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait TerminalExt: Sized {
+    /// Initialize a terminal that draws the UI without writing to the standard output.
+    ///
+    /// This is for command line tools that show an interactive UI while writing their result to
+    /// the standard output, so that it can be piped to another program (e.g. a fuzzy finder used
+    /// as `files=$(picker | sort)`). The UI is written to the first [`TerminalOutput`] that is
+    /// available:
+    ///
+    /// 1. the standard error, if it is a terminal
+    /// 2. the controlling terminal (`/dev/tty` on Unix, `CONOUT$` on Windows)
+    /// 3. a [`NullBackend`], if no terminal is available (e.g. under cron or in CI). In this
+    ///    headless mode, the output is discarded and the terminal has a fixed 80x24 size, so that
+    ///    the application can still run without a UI. Raw mode and the alternate screen are not
+    ///    enabled.
+    ///
+    /// Otherwise, the terminal is initialized like [`init`]: raw mode is enabled, the alternate
+    /// screen is entered and a panic hook that restores the terminal is installed. [`restore`]
+    /// then restores the output used by this function.
+    ///
+    /// Use [`HeadlessFallback::is_headless`] on the backend of the terminal to check whether a UI
+    /// is displayed, and [`is_stdout_piped`] to check whether the standard output is redirected.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the initialization steps fail. See [`init`] for details.
+    fn init_dual_output() -> Self {
+        Self::try_init_dual_output().expect("failed to initialize terminal")
+    }
+
+    /// Try to initialize a terminal that draws the UI without writing to the standard output.
+    ///
+    /// See [`TerminalExt::init_dual_output`] for details. If any of the initialization steps fail,
+    /// the error is returned.
+    fn try_init_dual_output() -> io::Result<Self>;
+}
+
+impl TerminalExt for DualOutputTerminal {
+    fn try_init_dual_output() -> io::Result<Self> {
+        try_init_dual_output_to(TerminalOutput::detect())
+    }
+}
+
+/// Initializes a [`DualOutputTerminal`] that draws to the given output, or a headless terminal if
+/// there is none.
+fn try_init_dual_output_to(output: Option<TerminalOutput>) -> io::Result<DualOutputTerminal> {
+    let Some(mut output) = output else {
+        set_restore_output(OutputKind::Headless, None);
+        set_restore_modes(false, false);
+        return DualOutputTerminal::new(HeadlessFallback::Headless(NullBackend::default()));
+    };
+    let tty = match &output {
+        TerminalOutput::Tty(tty) => Some(tty.try_clone()?),
+        _ => None,
+    };
+    set_restore_output(output.kind(), tty);
+    set_restore_modes(true, false);
+    set_panic_hook();
+    enable_raw_mode()?;
+    execute!(output, EnterAlternateScreen)?;
+    DualOutputTerminal::new(HeadlessFallback::Terminal(CrosstermBackend::new(output)))
+}
+
+/// Returns whether the standard output is redirected to a file or a pipe instead of a terminal.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// if ratatui::is_stdout_piped() {
+///     // write machine-readable output
+/// }
+/// ```
+pub fn is_stdout_piped() -> bool {
+    !stdout().is_terminal()
+}
+
+/// The output a [`DualOutputTerminal`] draws the UI to.
+///
+/// See [`TerminalExt::init_dual_output`] for how the output is chosen.
+#[derive(Debug)]
+pub enum TerminalOutput {
+    /// The standard output.
+    Stdout(Stdout),
+    /// The standard error.
+    Stderr(Stderr),
+    /// The controlling terminal of the process, opened directly.
+    Tty(File),
+}
+
+/// The kinds of [`TerminalOutput`], or no output, stored for [`restore`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(u8)]
+enum OutputKind {
+    Stdout,
+    Stderr,
+    Tty,
+    Headless,
+}
+
+impl TerminalOutput {
+    /// Detects the output to draw the UI to without writing to the standard output.
+    ///
+    /// Returns the standard error if it is a terminal, otherwise the controlling terminal if it
+    /// can be opened, otherwise `None`.
+    pub fn detect() -> Option<Self> {
+        let stderr = stderr();
+        if stderr.is_terminal() {
+            return Some(Self::Stderr(stderr));
+        }
+        Self::open_tty().ok().map(Self::Tty)
+    }
+
+    /// Opens the controlling terminal of the process.
+    fn open_tty() -> io::Result<File> {
+        let path = if cfg!(windows) { "CONOUT$" } else { "/dev/tty" };
+        OpenOptions::new().read(true).write(true).open(path)
+    }
+
+    const fn kind(&self) -> OutputKind {
+        match self {
+            Self::Stdout(_) => OutputKind::Stdout,
+            Self::Stderr(_) => OutputKind::Stderr,
+            Self::Tty(_) => OutputKind::Tty,
+        }
+    }
+}
+
+impl Write for TerminalOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Stdout(stdout) => stdout.write(buf),
+            Self::Stderr(stderr) => stderr.write(buf),
+            Self::Tty(tty) => tty.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Stdout(stdout) => stdout.flush(),
+            Self::Stderr(stderr) => stderr.flush(),
+            Self::Tty(tty) => tty.flush(),
+        }
+    }
+}
+
+/// A backend that falls back to a [`NullBackend`] when no terminal is available.
+///
+/// See [`TerminalExt::init_dual_output`].
+#[derive(Debug)]
+pub enum HeadlessFallback<B> {
+    /// A terminal is available.
+    Terminal(B),
+    /// No terminal is available, and the output is discarded.
+    Headless(NullBackend),
+}
+
+impl<B> HeadlessFallback<B> {
+    /// Returns whether the output is discarded because no terminal is available.
+    pub const fn is_headless(&self) -> bool {
+        matches!(self, Self::Headless(_))
+    }
+}
+
+/// Calls the same method on the backend of either variant of a [`HeadlessFallback`].
+macro_rules! delegate {
+    ($self:ident, $backend:ident => $call:expr) => {
+        match $self {
+            HeadlessFallback::Terminal($backend) => $call,
+            HeadlessFallback::Headless($backend) => $call,
+        }
+    };
+}
+
+impl<B: Backend> Backend for HeadlessFallback<B> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        delegate!(self, backend => backend.draw(content))
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        delegate!(self, backend => backend.append_lines(n))
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        delegate!(self, backend => backend.hide_cursor())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        delegate!(self, backend => backend.show_cursor())
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        delegate!(self, backend => backend.get_cursor_position())
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        delegate!(self, backend => backend.set_cursor_position(position))
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        delegate!(self, backend => backend.clear())
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        delegate!(self, backend => backend.clear_region(clear_type))
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        delegate!(self, backend => backend.size())
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        delegate!(self, backend => backend.window_size())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        delegate!(self, backend => Backend::flush(backend))
+    }
+
+    fn bell(&mut self) -> io::Result<()> {
+        delegate!(self, backend => backend.bell())
+    }
+
+    fn notify(
+        &mut self,
+        protocol: NotificationProtocol,
+        title: &str,
+        body: &str,
+    ) -> io::Result<()> {
+        delegate!(self, backend => backend.notify(protocol, title, body))
+    }
+
+    fn set_mouse_capture(&mut self, capture: MouseCapture) -> io::Result<()> {
+        delegate!(self, backend => backend.set_mouse_capture(capture))
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
+        delegate!(self, backend => backend.scroll_region_up(region, amount))
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
+        delegate!(self, backend => backend.scroll_region_down(region, amount))
+    }
+}
+
 /// Restores the terminal to its original state.
 ///
 /// This function should be called before the program exits to ensure that the terminal is
@@ -184,9 +733,13 @@ pub fn try_init_with_options(options: TerminalOptions) -> io::Result<DefaultTerm
 /// following steps:
 ///
 /// 1. Raw mode is disabled.
-/// 2. The alternate screen buffer is left.
+/// 2. Keyboard enhancement is disabled, if it was enabled by [`InitOptions::keyboard_enhancement`].
+/// 3. The alternate screen buffer is left, unless the last initialization disabled it with
+///    [`InitOptions::alternate_screen`].
+/// 4. Mouse capture is disabled, unless it was not enabled by [`InitOptions`] or
+///    [`TerminalExt::init_dual_output`].
 ///
-/// If either of these steps fail, the error is printed to stderr and ignored.
+/// If any of these steps fail, the error is printed to stderr and ignored.
 ///
 /// Use this function over [`try_restore`] when you don't need to handle the error yourself, as
 /// ignoring the error is generally the correct behavior when cleaning up before exiting. If you
@@ -210,9 +763,16 @@ pub fn restore() {
 /// following steps:
 ///
 /// 1. Raw mode is disabled.
-/// 2. The alternate screen buffer is left.
+/// 2. Keyboard enhancement is disabled, if it was enabled by [`InitOptions::keyboard_enhancement`].
+/// 3. The alternate screen buffer is left, unless the last initialization disabled it with
+///    [`InitOptions::alternate_screen`].
+/// 4. Mouse capture is disabled, unless it was not enabled by [`InitOptions`] or
+///    [`TerminalExt::init_dual_output`].
+///
+/// If any of these steps fail, the error is returned.
 ///
-/// If either of these steps fail, the error is returned.
+/// The escape sequences are written to the output used by the last initialization, which is the
+/// standard output unless the terminal was initialized with [`TerminalExt::init_dual_output`].
 ///
 /// Use [`restore`] instead of this function when you don't need to handle the error yourself, as
 /// ignoring the error is generally the correct behavior when cleaning up before exiting. If you
@@ -225,13 +785,70 @@ pub fn restore() {
 /// # Ok::<(), std::io::Error>(())
 /// ```
 pub fn try_restore() -> io::Result<()> {
+    let kind = RESTORE_OUTPUT.load(Ordering::Relaxed);
+    if kind == OutputKind::Headless as u8 {
+        // the terminal was not modified
+        return Ok(());
+    }
     // disabling raw mode first is important as it has more side effects than leaving the alternate
     // screen buffer
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen)?;
+    let tty = RESTORE_TTY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    let mut output = match (kind, tty) {
+        (kind, _) if kind == OutputKind::Stderr as u8 => TerminalOutput::Stderr(stderr()),
+        (kind, Some(tty)) if kind == OutputKind::Tty as u8 => TerminalOutput::Tty(tty),
+        // the terminal was already restored
+        (kind, None) if kind == OutputKind::Tty as u8 => return Ok(()),
+        _ => TerminalOutput::Stdout(stdout()),
+    };
+    if KEYBOARD_ENHANCED.swap(false, Ordering::Relaxed) {
+        execute!(output, PopKeyboardEnhancementFlags)?;
+    }
+    if ALTERNATE_SCREEN.load(Ordering::Relaxed) {
+        execute!(output, LeaveAlternateScreen)?;
+    }
+    if MOUSE_CAPTURE.swap(false, Ordering::Relaxed) {
+        execute!(output, DisableMouseCapture)?;
+    }
     Ok(())
 }
 
+/// Returns whether the keyboard enhancement of the kitty keyboard protocol is enabled.
+///
+/// This is only the case after initializing the terminal with
+/// [`InitOptions::keyboard_enhancement`] on a terminal that supports the protocol, and until the
+/// terminal is restored. When it returns `false`, key release events are not reported and keys
+/// such as `Enter` and `Ctrl+M` cannot be distinguished.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use ratatui::InitOptions;
+///
+/// let terminal = InitOptions::new().keyboard_enhancement(true).init();
+/// if !ratatui::is_keyboard_enhanced() {
+///     // fall back to toggling on key presses, as no release events will be reported
+/// }
+/// ```
+pub fn is_keyboard_enhanced() -> bool {
+    KEYBOARD_ENHANCED.load(Ordering::Relaxed)
+}
+
+/// Stores the output that [`restore`] writes to, with the controlling terminal if it is used.
+fn set_restore_output(kind: OutputKind, tty: Option<File>) {
+    RESTORE_OUTPUT.store(kind as u8, Ordering::Relaxed);
+    *RESTORE_TTY.lock().unwrap_or_else(PoisonError::into_inner) = tty;
+}
+
+/// Stores whether [`restore`] must leave the alternate screen and disable mouse capture.
+fn set_restore_modes(alternate_screen: bool, mouse_capture: bool) {
+    ALTERNATE_SCREEN.store(alternate_screen, Ordering::Relaxed);
+    MOUSE_CAPTURE.store(mouse_capture, Ordering::Relaxed);
+}
+
 /// Sets a panic hook that restores the terminal before panicking.
 ///
 /// Replaces the panic hook with a one that will restore the terminal state before calling the
@@ -243,3 +860,23 @@ fn set_panic_hook() {
         hook(info);
     }));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Rect, widgets::Paragraph};
+
+    #[test]
+    fn dual_output_without_terminal_is_headless() -> io::Result<()> {
+        let mut terminal = try_init_dual_output_to(None)?;
+        assert!(terminal.backend().is_headless());
+        assert_eq!(terminal.size()?, Size::new(80, 24));
+
+        let frame =
+            terminal.draw(|frame| frame.render_widget(Paragraph::new("hi"), frame.area()))?;
+        assert_eq!(frame.area, Rect::new(0, 0, 80, 24));
+
+        // the terminal was not modified, so there is nothing to restore
+        try_restore()
+    }
+}
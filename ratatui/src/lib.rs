@@ -120,6 +120,11 @@
 //! # fn run(terminal: &mut ratatui::DefaultTerminal) -> std::io::Result<()> { Ok(()) }
 //! ```
 //!
+//! Command line tools that write data to the standard output while showing a UI can use
+//! [`Terminal::init_dual_output`](TerminalExt::init_dual_output) instead, which draws the UI on the
+//! standard error or the controlling terminal, and falls back to running headless when no terminal
+//! is available.
+//!
 //! See the [`backend` module] and the [Backends] section of the [Ratatui Website] for more info on
 //! the alternate screen and raw mode.
 //!
@@ -328,9 +333,15 @@
 /// re-export the `palette` crate so that users don't have to add it as a dependency
 #[cfg(feature = "palette")]
 pub use palette;
+/// re-export the `portable-pty` crate so that users don't have to add it as a dependency
+#[cfg(feature = "portable-pty")]
+pub use portable_pty;
 pub use ratatui_core::{
     buffer, layout,
-    terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport},
+    terminal::{
+        CompletedFrame, CursorState, Frame, StateStore, Terminal, TerminalOptions, Tooltip,
+        Viewport, WidgetId,
+    },
 };
 /// re-export the `crossterm` crate so that users don't have to add it as a dependency
 #[cfg(feature = "crossterm")]
@@ -344,22 +355,36 @@ pub use ratatui_termwiz::termwiz;
 
 #[cfg(feature = "crossterm")]
 pub use crate::init::{
-    init, init_with_options, restore, try_init, try_init_with_options, try_restore, DefaultTerminal,
+    init, init_dual_output, init_with_options, is_keyboard_enhanced, is_stdout_piped, restore,
+    try_init, try_init_dual_output, try_init_with_options, try_restore, DefaultTerminal,
+    DualOutputTerminal, HeadlessFallback, InitOptions, TerminalExt, TerminalOutput,
 };
 
 /// Re-exports for the backend implementations.
 pub mod backend {
-    pub use ratatui_core::backend::{Backend, ClearType, TestBackend, WindowSize};
+    pub use ratatui_core::backend::{
+        Backend, ClearType, MouseCapture, NotificationProtocol, NullBackend, TestBackend,
+        WindowSize,
+    };
     #[cfg(feature = "crossterm")]
-    pub use ratatui_crossterm::{CrosstermBackend, FromCrossterm, IntoCrossterm};
+    pub use ratatui_crossterm::{CrosstermBackend, FlushStats, FromCrossterm, IntoCrossterm};
     #[cfg(all(not(windows), feature = "termion"))]
     pub use ratatui_termion::{FromTermion, IntoTermion, TermionBackend};
     #[cfg(feature = "termwiz")]
     pub use ratatui_termwiz::{FromTermwiz, IntoTermwiz, TermwizBackend};
 }
 
+pub mod ansi;
+#[cfg(feature = "serde")]
+pub mod declarative;
+#[cfg(feature = "crossterm")]
+pub mod event;
+#[cfg(feature = "examples-harness")]
+pub mod examples_harness;
 pub mod prelude;
 pub use ratatui_core::{style, symbols, text};
+#[cfg(feature = "serde")]
+pub mod theme;
 pub mod widgets;
 pub use ratatui_widgets::border;
 #[cfg(feature = "crossterm")]
@@ -31,6 +31,9 @@ pub use ratatui_core::backend::{self, Backend};
 #[cfg(feature = "crossterm")]
 pub use ratatui_crossterm::{CrosstermBackend, FromCrossterm, IntoCrossterm};
 
+#[cfg(feature = "crossterm")]
+pub use crate::TerminalExt;
+
 #[cfg(all(not(windows), feature = "termion"))]
 pub use crate::backend::{FromTermion, IntoTermion, TermionBackend};
 #[cfg(feature = "termwiz")]
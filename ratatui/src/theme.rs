@@ -0,0 +1,262 @@
+//! Named styles that can be loaded from a configuration file.
+//!
+//! A [`Theme`] maps names such as `"title"` or `"selected"` to [`Style`]s, so that the colors of
+//! an application can be customized without recompiling. It can be deserialized with [`serde`]
+//! from any format, where it is represented as a map from names to styles.
+//!
+//! With the `notify` feature, [`Theme::watch`] loads a theme from a JSON file and reloads it
+//! every time the file changes, so that the application can redraw with the new styles while the
+//! user edits them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{
+//!     style::{Color, Style, Stylize},
+//!     theme::Theme,
+//! };
+//!
+//! let theme: Theme = serde_json::from_str(r#"{ "title": { "fg": "Yellow" } }"#)?;
+//! assert_eq!(theme.get("title"), Style::new().yellow());
+//! assert_eq!(theme.get("missing"), Style::new());
+//!
+//! let theme = theme.style("selected", Style::new().on_blue());
+//! assert_eq!(theme.get("selected").bg, Some(Color::Blue));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+use std::collections::BTreeMap;
+#[cfg(feature = "notify")]
+use std::{
+    fs, io,
+    path::Path,
+    sync::{mpsc, Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+#[cfg(feature = "notify")]
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+
+use crate::style::Style;
+
+/// A set of named styles.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Theme {
+    styles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    /// Creates a theme without any styles.
+    pub const fn new() -> Self {
+        Self {
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the style with the given name, replacing any previous style with that name.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<N: Into<String>, S: Into<Style>>(mut self, name: N, style: S) -> Self {
+        self.styles.insert(name.into(), style.into());
+        self
+    }
+
+    /// Returns the style with the given name, or the default style if the theme does not have
+    /// one.
+    pub fn get(&self, name: &str) -> Style {
+        self.styles.get(name).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the names and styles of the theme, in the order of the names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> {
+        self.styles
+            .iter()
+            .map(|(name, style)| (name.as_str(), *style))
+    }
+
+    /// Loads a theme from a JSON file.
+    #[cfg(feature = "notify")]
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Loads a theme from a JSON file and reloads it every time the file changes.
+    ///
+    /// The returned [`ThemeWatcher`] holds the current theme. An application redraws with the
+    /// new styles when [`ThemeWatcher::reloaded`] returns `true`, e.g. after each event or tick
+    /// of its update loop. Changes that leave the file in a state that cannot be loaded (e.g.
+    /// while an editor is saving it) are ignored and the previous theme is kept.
+    ///
+    /// The directory of the file is watched rather than the file itself, so that the theme is
+    /// also reloaded when an editor saves the file by replacing it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui::{theme::Theme, widgets::Paragraph};
+    ///
+    /// let watcher = Theme::watch("theme.json")?;
+    /// let mut terminal = ratatui::init();
+    /// loop {
+    ///     let theme = watcher.theme();
+    ///     terminal.draw(|frame| {
+    ///         frame.render_widget(Paragraph::new("Hello").style(theme.get("text")), frame.area());
+    ///     })?;
+    ///     // blocks until the file changes, an application would also handle its events here
+    ///     while !watcher.wait_reload(std::time::Duration::from_millis(250)) {}
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "notify")]
+    pub fn watch<P: AsRef<Path>>(path: P) -> io::Result<ThemeWatcher> {
+        let path = path.as_ref().canonicalize()?;
+        let theme = Arc::new(Mutex::new(Self::load(&path)?));
+        let (sender, reloads) = mpsc::channel();
+
+        let directory = path.parent().unwrap_or(&path).to_path_buf();
+        let shared = Arc::clone(&theme);
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+                let is_theme_file = event
+                    .paths
+                    .iter()
+                    .any(|changed| changed.file_name() == path.file_name());
+                if !is_theme_file || !(event.kind.is_create() || event.kind.is_modify()) {
+                    return;
+                }
+                let Ok(reloaded) = Self::load(&path) else {
+                    return;
+                };
+                let mut current = shared.lock().unwrap_or_else(PoisonError::into_inner);
+                if *current != reloaded {
+                    *current = reloaded;
+                    // the watcher may have been dropped, in which case there is no one to notify
+                    let _ = sender.send(());
+                }
+            })
+            .map_err(io::Error::other)?;
+        watcher
+            .watch(&directory, RecursiveMode::NonRecursive)
+            .map_err(io::Error::other)?;
+
+        Ok(ThemeWatcher {
+            theme,
+            reloads,
+            _watcher: watcher,
+        })
+    }
+}
+
+impl<N: Into<String>, S: Into<Style>> FromIterator<(N, S)> for Theme {
+    fn from_iter<I: IntoIterator<Item = (N, S)>>(iter: I) -> Self {
+        let styles = iter
+            .into_iter()
+            .map(|(name, style)| (name.into(), style.into()))
+            .collect();
+        Self { styles }
+    }
+}
+
+/// A [`Theme`] that is reloaded from a file every time the file changes.
+///
+/// This is returned by [`Theme::watch`]. The file stops being watched when the watcher is
+/// dropped.
+#[cfg(feature = "notify")]
+#[derive(Debug)]
+pub struct ThemeWatcher {
+    theme: Arc<Mutex<Theme>>,
+    reloads: mpsc::Receiver<()>,
+    _watcher: RecommendedWatcher,
+}
+
+#[cfg(feature = "notify")]
+impl ThemeWatcher {
+    /// Returns the current theme.
+    pub fn theme(&self) -> Theme {
+        self.theme
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns whether the theme was reloaded since the previous call, in which case the
+    /// application should redraw.
+    pub fn reloaded(&self) -> bool {
+        self.reloads.try_iter().count() > 0
+    }
+
+    /// Waits up to `timeout` for the theme to be reloaded and returns whether it was.
+    ///
+    /// Like [`ThemeWatcher::reloaded`], this returns `true` once for all the reloads since the
+    /// previous call.
+    pub fn wait_reload(&self, timeout: Duration) -> bool {
+        self.reloaded()
+            || self.reloads.recv_timeout(timeout).is_ok() && {
+                // consume the reloads that happened while the first one was being received
+                self.reloaded();
+                true
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Stylize;
+
+    #[test]
+    fn get() {
+        let theme = Theme::new().style("title", Style::new().bold());
+        assert_eq!(theme.get("title"), Style::new().bold());
+        assert_eq!(theme.get("body"), Style::new());
+    }
+
+    #[test]
+    fn from_iter() {
+        let theme = Theme::from_iter([("a", Style::new().red()), ("b", Style::new().blue())]);
+        assert_eq!(
+            theme.iter().collect::<Vec<_>>(),
+            [("a", Style::new().red()), ("b", Style::new().blue())]
+        );
+    }
+
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let theme = Theme::new()
+            .style("title", Style::new().yellow().bold())
+            .style("selected", Style::new().on_blue());
+        let json = serde_json::to_string(&theme)?;
+        assert_eq!(serde_json::from_str::<Theme>(&json)?, theme);
+        Ok(())
+    }
+
+    #[cfg(feature = "notify")]
+    #[test]
+    fn watch_reloads_changed_file() -> io::Result<()> {
+        let directory = std::env::temp_dir().join(format!("ratatui-theme-{}", std::process::id()));
+        fs::create_dir_all(&directory)?;
+        let path = directory.join("theme.json");
+        fs::write(&path, r#"{ "title": { "fg": "Red" } }"#)?;
+
+        let watcher = Theme::watch(&path)?;
+        assert_eq!(watcher.theme().get("title"), Style::new().red());
+        assert!(!watcher.reloaded());
+
+        fs::write(&path, r#"{ "title": { "fg": "Green" } }"#)?;
+        assert!(watcher.wait_reload(Duration::from_secs(5)));
+        assert_eq!(watcher.theme().get("title"), Style::new().green());
+
+        // a file that cannot be loaded keeps the previous theme
+        fs::write(&path, "{")?;
+        assert!(!watcher.wait_reload(Duration::from_millis(200)));
+        assert_eq!(watcher.theme().get("title"), Style::new().green());
+
+        fs::remove_dir_all(&directory)
+    }
+}
@@ -11,50 +11,115 @@
 //! stored but used as *commands* to draw common figures in the UI.
 //!
 //! The available widgets are:
+//! - [`Align`]: positions another widget of a given size within a larger area.
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
+//! - [`CardGrid`]: lays out cards of different heights in columns, like a masonry wall.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
+//! - [`ChatView`]: displays a conversation as message bubbles.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`CpuBars`]: displays the usage of each CPU core as a bar, like `htop`.
+//! - [`DataView`]: displays a JSON or YAML value as a collapsible tree.
+//! - [`Either`]: renders one of two widgets of different types.
+//! - [`Focusable`]: a block around another widget whose borders change when it is focused.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`HintBar`]: shows the actions that are available in the current context.
+//! - [`Keycast`]: shows the keys that were recently pressed, fading them out.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`MemoryGauge`]: displays the memory in use as a gauge.
+//! - [`NetworkSparklines`]: displays the received and transmitted network traffic as sparklines.
+//! - [`Padded`]: renders another widget inside a padded area.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PtyView`]: hosts a program running in a pseudo terminal (requires the `portable-pty`
+//!   feature).
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`Scrollbars`]: displays a vertical and a horizontal scrollbar sharing a corner.
+//! - [`SearchBar`]: an input field to search the content of other widgets.
+//! - [`Skeleton`]: displays shimmering placeholder lines while content is loading.
+//! - [`Spacer`]: fills the space between other widgets, optionally drawing a divider.
 //! - [`Sparkline`]: display a single data set as a sparkline.
+//! - [`Stack`]: renders several widgets on top of each other in the same area.
+//! - [`Switch`]: renders one of several widgets of the same type, chosen by index.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Transition`]: animates the change from one widget to another.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
+//! [`CpuBars`]: crate::widgets::metrics::CpuBars
+//! [`MemoryGauge`]: crate::widgets::metrics::MemoryGauge
+//! [`NetworkSparklines`]: crate::widgets::metrics::NetworkSparklines
 
-pub use ratatui_core::widgets::{StatefulWidget, Widget};
+pub use ratatui_core::widgets::{HasCursor, SliceWidget, StatefulWidget, Widget};
+#[cfg(feature = "widget-barchart")]
+pub use ratatui_widgets::barchart::{Bar, BarChart, BarGroup};
 // TODO remove this module once title etc. are gone
+#[cfg(feature = "portable-pty")]
+pub use pty_view::PtyView;
 pub use ratatui_widgets::block;
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
+#[cfg(feature = "widget-canvas")]
+pub use ratatui_widgets::canvas;
+#[cfg(feature = "widget-chart")]
+pub use ratatui_widgets::chart::{self, Axis, Chart, Dataset, GraphType, LegendPosition};
+#[cfg(feature = "widget-data-view")]
+pub use ratatui_widgets::data_view::{DataView, DataViewState};
+#[cfg(feature = "widget-gauge")]
+pub use ratatui_widgets::gauge::{Gauge, LineGauge};
+#[cfg(feature = "widget-histogram")]
+pub use ratatui_widgets::histogram;
+#[cfg(feature = "widget-metrics")]
+pub use ratatui_widgets::metrics;
+#[cfg(feature = "widget-sparkline")]
+pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar};
+#[cfg(feature = "widget-table-csv")]
+pub use ratatui_widgets::table::TableData;
+#[cfg(feature = "widget-table")]
+pub use ratatui_widgets::table::{Cell, CellEdit, CellEditor, Row, Table, TableState};
+#[cfg(feature = "widget-tabs")]
+pub use ratatui_widgets::tabs::Tabs;
 pub use ratatui_widgets::{
-    barchart::{Bar, BarChart, BarGroup},
+    align::Align,
     block::{Block, Padding},
     borders::{BorderType, Borders},
-    canvas,
-    chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    card_grid::{Card, CardGrid, CardGridState},
+    chat_view::{ChatHistory, ChatMessage, ChatSide, ChatView, ChatViewState},
     clear::Clear,
-    gauge::{Gauge, LineGauge},
-    list::{List, ListDirection, ListItem, ListState},
+    dialog::{ConfirmDialog, ConfirmState, DialogButton, DialogResult, PromptDialog, PromptState},
+    either::Either,
+    hint_bar::{Hint, HintBar},
+    interact::{Button, Focusable, InputSnapshot, InteractiveWidget, Link, Response, Toggle},
+    keycast::{Keycast, KeycastState},
+    list::{HighlightSpacing, List, ListDirection, ListItem, ListState},
     logo::{RatatuiLogo, Size as RatatuiLogoSize},
     mascot::{MascotEyeColor, RatatuiMascot},
-    paragraph::{Paragraph, Wrap},
-    scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
-    sparkline::{RenderDirection, Sparkline, SparklineBar},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
-    tabs::Tabs,
+    padded::Padded,
+    paragraph::{Gutter, Paragraph, ParagraphState, ScrollableParagraph, Wrap},
+    scrollbar::{
+        ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState, Scrollbars,
+        ScrollbarsState,
+    },
+    search_bar::{SearchBar, SearchControl, SearchState},
+    skeleton::Skeleton,
+    spacer::Spacer,
+    stack::{Stack, StackLayer},
+    switch::Switch,
+    transition::{Transition, TransitionDirection, TransitionEffect},
 };
+#[cfg(feature = "unstable-widget-ref")]
+pub use registry::{IntoWidgetRef, RegistryError, WidgetRegistry};
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
 
+#[cfg(feature = "portable-pty")]
+mod pty_view;
+#[cfg(feature = "unstable-widget-ref")]
+mod registry;
 mod stateful_widget_ref;
 mod widget_ref;
 
@@ -131,6 +196,7 @@ pub trait FrameExt {
 impl FrameExt for ratatui_core::terminal::Frame<'_> {
     #[allow(clippy::needless_pass_by_value)]
     fn render_widget_ref<W: WidgetRef>(&mut self, widget: W, area: Rect) {
+        apply_ambient_style(self, area);
         widget.render_ref(area, self.buffer_mut());
     }
 
@@ -139,6 +205,16 @@ impl FrameExt for ratatui_core::terminal::Frame<'_> {
     where
         W: StatefulWidgetRef,
     {
+        apply_ambient_style(self, area);
         widget.render_ref(area, self.buffer_mut(), state);
     }
 }
+
+/// Styles the area with the ambient style of the frame, like `Frame::render_widget` does.
+#[cfg(feature = "unstable-widget-ref")]
+fn apply_ambient_style(frame: &mut ratatui_core::terminal::Frame<'_>, area: Rect) {
+    let style = frame.ambient_style();
+    if style != ratatui_core::style::Style::new() {
+        frame.buffer_mut().set_style(area, style);
+    }
+}
@@ -0,0 +1,375 @@
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    thread,
+};
+
+use portable_pty::{native_pty_system, Child, CommandBuilder, ExitStatus, MasterPty, PtySize};
+#[cfg(feature = "crossterm")]
+use ratatui_crossterm::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::{
+    ansi::Screen,
+    buffer::Buffer,
+    layout::{Position, Rect, Size},
+    widgets::{HasCursor, Widget},
+};
+
+/// A widget that hosts a program running in a pseudo terminal.
+///
+/// The output of the program is parsed by an [`ansi::Screen`](crate::ansi::Screen) on a
+/// background thread, and rendering the view copies the screen into the area of the widget.
+/// The pty is resized to the area it is rendered in, so the program always lays out its output
+/// for the space it has. Input is forwarded to the program with [`PtyView::write_input`], or with
+/// [`PtyView::send_key`] for the key events read from the terminal.
+///
+/// The program is killed when the view is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::{
+///     crossterm::event::{self, Event},
+///     portable_pty::CommandBuilder,
+///     widgets::PtyView,
+///     layout::Size,
+/// };
+///
+/// let mut view = PtyView::spawn(CommandBuilder::new("htop"), Size::new(80, 24))?;
+/// let mut terminal = ratatui::init();
+/// while view.try_wait()?.is_none() {
+///     terminal.draw(|frame| {
+///         frame.render_widget(&mut view, frame.area());
+///         frame.set_cursor_from(&view);
+///     })?;
+///     if event::poll(std::time::Duration::from_millis(16))? {
+///         if let Event::Key(key) = event::read()? {
+///             view.send_key(key)?;
+///         }
+///     }
+/// }
+/// ratatui::restore();
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct PtyView {
+    screen: Arc<Mutex<Screen>>,
+    updated: Arc<AtomicBool>,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    area: Option<Rect>,
+}
+
+impl fmt::Debug for PtyView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PtyView")
+            .field("screen", &self.screen)
+            .field("child", &self.child)
+            .field("area", &self.area)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PtyView {
+    /// Runs the command in a new pseudo terminal of the given size.
+    ///
+    /// The size is only used until the view is first rendered, as rendering resizes the pty to
+    /// the area of the widget.
+    pub fn spawn(command: CommandBuilder, size: Size) -> io::Result<Self> {
+        let pair = native_pty_system()
+            .openpty(pty_size(size))
+            .map_err(io::Error::other)?;
+        let child = pair
+            .slave
+            .spawn_command(command)
+            .map_err(io::Error::other)?;
+        // the program holds its own handle to the slave side, which closes the output of the
+        // master when the program exits
+        drop(pair.slave);
+        let mut reader = pair.master.try_clone_reader().map_err(io::Error::other)?;
+        let writer = pair.master.take_writer().map_err(io::Error::other)?;
+
+        let screen = Arc::new(Mutex::new(Screen::new(size.width, size.height)));
+        let updated = Arc::new(AtomicBool::new(false));
+        let (shared_screen, shared_updated) = (Arc::clone(&screen), Arc::clone(&updated));
+        thread::Builder::new()
+            .name("pty-view".to_string())
+            .spawn(move || {
+                let mut bytes = [0; 4096];
+                loop {
+                    match reader.read(&mut bytes) {
+                        Ok(0) => break,
+                        Ok(len) => {
+                            lock(&shared_screen).process(&bytes[..len]);
+                            shared_updated.store(true, Ordering::Release);
+                        }
+                        Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                        // reading fails instead of returning 0 on some systems once the program
+                        // exited
+                        Err(_) => break,
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            screen,
+            updated,
+            master: pair.master,
+            writer,
+            child,
+            area: None,
+        })
+    }
+
+    /// Returns a copy of the screen of the program.
+    pub fn screen(&self) -> Screen {
+        lock(&self.screen).clone()
+    }
+
+    /// Returns whether the program wrote output since the previous call, in which case the
+    /// application should redraw.
+    pub fn updated(&self) -> bool {
+        self.updated.swap(false, Ordering::Acquire)
+    }
+
+    /// Writes bytes to the input of the program, as if they were typed in the pseudo terminal.
+    pub fn write_input(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.writer.write_all(bytes)?;
+        self.writer.flush()
+    }
+
+    /// Forwards a key event to the program, encoded as the control sequence a terminal sends for
+    /// that key.
+    ///
+    /// Key releases and keys that have no encoding (e.g. media keys) are ignored.
+    #[cfg(feature = "crossterm")]
+    pub fn send_key(&mut self, key: KeyEvent) -> io::Result<()> {
+        if key.kind == KeyEventKind::Release {
+            return Ok(());
+        }
+        match encode_key(key) {
+            Some(bytes) => self.write_input(&bytes),
+            None => Ok(()),
+        }
+    }
+
+    /// Resizes the pseudo terminal and the screen, and lets the program know about the new size.
+    ///
+    /// This is done automatically when the view is rendered in an area of a different size.
+    pub fn resize(&self, size: Size) -> io::Result<()> {
+        lock(&self.screen).resize(size.width, size.height);
+        self.master.resize(pty_size(size)).map_err(io::Error::other)
+    }
+
+    /// Returns the exit status of the program if it exited, without waiting for it.
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.child.try_wait()
+    }
+
+    /// Kills the program.
+    pub fn kill(&mut self) -> io::Result<()> {
+        self.child.kill()
+    }
+}
+
+impl Drop for PtyView {
+    fn drop(&mut self) {
+        if matches!(self.child.try_wait(), Ok(None)) {
+            // the program may exit between the two calls, which is fine
+            let _ = self.child.kill();
+        }
+        // reap the program so that it does not linger as a zombie process
+        let _ = self.child.wait();
+    }
+}
+
+impl Widget for &mut PtyView {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if lock(&self.screen).size() != area.as_size() {
+            // the program keeps running with the previous size if the pty cannot be resized
+            let _ = self.resize(area.as_size());
+        }
+        Widget::render(&*lock(&self.screen), area, buf);
+        self.area = Some(area);
+    }
+}
+
+impl HasCursor for PtyView {
+    /// Returns the position of the cursor of the program in the area where the view was last
+    /// rendered.
+    fn cursor_position(&self) -> Option<Position> {
+        let area = self.area?;
+        let cursor = lock(&self.screen).cursor_position();
+        let position = Position::new(
+            area.x.saturating_add(cursor.x),
+            area.y.saturating_add(cursor.y),
+        );
+        area.contains(position).then_some(position)
+    }
+}
+
+/// Locks the screen, ignoring poisoning as the screen stays consistent if a panic happens while
+/// the lock is held.
+fn lock(screen: &Mutex<Screen>) -> MutexGuard<'_, Screen> {
+    screen.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+const fn pty_size(size: Size) -> PtySize {
+    PtySize {
+        rows: size.height,
+        cols: size.width,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+/// Encodes a key the way xterm does in its default mode.
+#[cfg(feature = "crossterm")]
+fn encode_key(key: KeyEvent) -> Option<Vec<u8>> {
+    let mut bytes = match key.code {
+        KeyCode::Char(ch) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match ch.to_ascii_lowercase() {
+                ch @ 'a'..='z' => vec![ch as u8 - b'a' + 1],
+                ' ' | '@' | '2' => vec![0],
+                '[' | '3' => vec![0x1b],
+                '\\' | '4' => vec![0x1c],
+                ']' | '5' => vec![0x1d],
+                '^' | '6' => vec![0x1e],
+                '_' | '/' | '7' => vec![0x1f],
+                ch => ch.to_string().into_bytes(),
+            }
+        }
+        KeyCode::Char(ch) => ch.to_string().into_bytes(),
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => b"\x1b[A".to_vec(),
+        KeyCode::Down => b"\x1b[B".to_vec(),
+        KeyCode::Right => b"\x1b[C".to_vec(),
+        KeyCode::Left => b"\x1b[D".to_vec(),
+        KeyCode::Home => b"\x1b[H".to_vec(),
+        KeyCode::End => b"\x1b[F".to_vec(),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::F(n @ 1..=4) => vec![0x1b, b'O', b'P' + n - 1],
+        KeyCode::F(n @ 5..=12) => {
+            let code = [15, 17, 18, 19, 20, 21, 23, 24][usize::from(n - 5)];
+            format!("\x1b[{code}~").into_bytes()
+        }
+        _ => return None,
+    };
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        bytes.insert(0, 0x1b);
+    }
+    Some(bytes)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::style::{Style, Stylize};
+
+    /// Waits until the text of the screen of the view satisfies the predicate.
+    ///
+    /// The programs of the tests block on their input until the test has done what they need to
+    /// wait for, so the output that the predicate waits for always arrives eventually.
+    fn wait_for(view: &PtyView, predicate: impl Fn(&str) -> bool) -> Screen {
+        loop {
+            let screen = view.screen();
+            if predicate(&screen.to_text().to_string()) {
+                return screen;
+            }
+            thread::yield_now();
+        }
+    }
+
+    fn shell(script: &str) -> CommandBuilder {
+        let mut command = CommandBuilder::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn renders_output() -> io::Result<()> {
+        let mut view =
+            PtyView::spawn(shell(r"printf '\033[31mred\033[0m text'"), Size::new(10, 2))?;
+        wait_for(&view, |text| text.contains("red text"));
+        assert!(view.updated());
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        view.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["red text  ", "          "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().red());
+        assert_eq!(buf, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn forwards_input() -> io::Result<()> {
+        let mut view = PtyView::spawn(shell("read line; echo \"got $line\""), Size::new(20, 3))?;
+        view.write_input(b"abc\r")?;
+        let screen = wait_for(&view, |text| text.contains("got abc"));
+        assert_eq!(screen.to_text().lines[1].to_string(), "got abc");
+        Ok(())
+    }
+
+    #[test]
+    fn resizes_to_rendered_area() -> io::Result<()> {
+        // the program only reads the size once the view was rendered and the input was written
+        let mut view = PtyView::spawn(shell("read line; stty size"), Size::new(10, 2))?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        view.render(Rect::new(1, 1, 11, 2), &mut buf);
+        assert_eq!(view.screen().size(), Size::new(11, 2));
+        view.write_input(b"\r")?;
+        let screen = wait_for(&view, |text| text.contains("2 11"));
+        assert_eq!(screen.to_text().lines[0].to_string(), "2 11");
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_position() -> io::Result<()> {
+        let mut view = PtyView::spawn(shell("printf ab; read line"), Size::new(5, 2))?;
+        wait_for(&view, |text| text.contains("ab"));
+        assert_eq!(view.cursor_position(), None);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        view.render(Rect::new(2, 3, 5, 2), &mut buf);
+        assert_eq!(view.cursor_position(), Some(Position::new(4, 3)));
+        Ok(())
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn encode_keys() {
+        let key = |code, modifiers| encode_key(KeyEvent::new(code, modifiers));
+        assert_eq!(
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Some(vec![3])
+        );
+        assert_eq!(
+            key(KeyCode::Char('x'), KeyModifiers::ALT),
+            Some(b"\x1bx".to_vec())
+        );
+        assert_eq!(
+            key(KeyCode::Up, KeyModifiers::NONE),
+            Some(b"\x1b[A".to_vec())
+        );
+        assert_eq!(
+            key(KeyCode::F(2), KeyModifiers::NONE),
+            Some(b"\x1bOQ".to_vec())
+        );
+        assert_eq!(
+            key(KeyCode::F(5), KeyModifiers::NONE),
+            Some(b"\x1b[15~".to_vec())
+        );
+        assert_eq!(key(KeyCode::Null, KeyModifiers::NONE), None);
+    }
+}
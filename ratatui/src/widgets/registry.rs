@@ -0,0 +1,259 @@
+use std::{collections::BTreeMap, fmt};
+
+use super::{Widget, WidgetRef};
+use crate::{buffer::Buffer, layout::Rect};
+
+/// A registry of widget constructors that can be looked up by name.
+///
+/// A `WidgetRegistry` maps names to factories that build a boxed [`WidgetRef`] from a
+/// configuration value of type `C`. This makes it possible to build dashboards whose panes are
+/// declared in a configuration file (e.g. a list of widget names and their options in a TOML
+/// file) and to let third-party crates provide their own widgets.
+///
+/// The configuration type is chosen by the application, usually a dynamically typed value such as
+/// `toml::Value` or `serde_json::Value`. When the `serde` feature is enabled,
+/// [`register_deserialize`](Self::register_deserialize) registers a widget type that is
+/// deserialized directly from the configuration value.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::{Block, Paragraph, WidgetRegistry},
+/// };
+///
+/// let mut registry = WidgetRegistry::<String>::new();
+/// registry.register("paragraph", |text: &String| {
+///     Ok::<_, &str>(Paragraph::new(text.clone()))
+/// });
+/// registry.register("block", |title: &String| {
+///     Ok::<_, &str>(Block::bordered().title(title.clone()))
+/// });
+///
+/// let widget = registry.build("paragraph", &"Hello".to_string()).unwrap();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+/// widget.render_ref(buf.area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["Hello"]));
+/// ```
+pub struct WidgetRegistry<C> {
+    factories: BTreeMap<String, Factory<C>>,
+}
+
+type Factory<C> = Box<dyn Fn(&C) -> Result<Box<dyn WidgetRef>, String>>;
+
+/// An error returned when a [`WidgetRegistry`] fails to build a widget.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RegistryError {
+    /// No widget is registered with the given name.
+    UnknownWidget(String),
+    /// The factory of the widget rejected the configuration.
+    InvalidConfig {
+        /// The name of the widget.
+        name: String,
+        /// The error reported by the factory.
+        message: String,
+    },
+}
+
+impl<C> WidgetRegistry<C> {
+    /// Creates a new empty registry.
+    pub const fn new() -> Self {
+        Self {
+            factories: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a widget factory under the given name.
+    ///
+    /// The factory builds a widget from a configuration value, or returns an error describing why
+    /// the configuration is invalid. Registering a name that is already registered replaces the
+    /// previous factory.
+    pub fn register<F, W, E>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn(&C) -> Result<W, E> + 'static,
+        W: IntoWidgetRef,
+        E: fmt::Display,
+    {
+        let factory = move |config: &C| match factory(config) {
+            Ok(widget) => Ok(widget.into_widget_ref()),
+            Err(err) => Err(err.to_string()),
+        };
+        self.factories.insert(name.into(), Box::new(factory));
+    }
+
+    /// Registers a widget type that is deserialized from the configuration value.
+    #[cfg(feature = "serde")]
+    pub fn register_deserialize<W>(&mut self, name: impl Into<String>)
+    where
+        W: serde::de::DeserializeOwned + IntoWidgetRef,
+        C: Clone + for<'de> serde::Deserializer<'de>,
+    {
+        self.register(name, |config: &C| W::deserialize(config.clone()));
+    }
+
+    /// Returns `true` if a widget is registered with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Returns an iterator over the registered names, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+
+    /// Builds the widget registered with the given name from a configuration value.
+    pub fn build(&self, name: &str, config: &C) -> Result<Box<dyn WidgetRef>, RegistryError> {
+        let factory = self
+            .factories
+            .get(name)
+            .ok_or_else(|| RegistryError::UnknownWidget(name.to_string()))?;
+        factory(config).map_err(|message| RegistryError::InvalidConfig {
+            name: name.to_string(),
+            message,
+        })
+    }
+}
+
+/// Conversion of an owned widget into a boxed [`WidgetRef`] trait object.
+///
+/// This is implemented for all `'static` widgets that implement [`Widget`] for a reference to
+/// themselves, which is the case for all the widgets provided by Ratatui.
+pub trait IntoWidgetRef {
+    /// Boxes the widget as a [`WidgetRef`] trait object.
+    fn into_widget_ref(self) -> Box<dyn WidgetRef>;
+}
+
+impl<W: 'static> IntoWidgetRef for W
+where
+    for<'a> &'a W: Widget,
+{
+    fn into_widget_ref(self) -> Box<dyn WidgetRef> {
+        Box::new(Owned(self))
+    }
+}
+
+/// Renders an owned widget that implements [`Widget`] for a reference to itself.
+struct Owned<W>(W);
+
+impl<W> WidgetRef for Owned<W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.0.render(area, buf);
+    }
+}
+
+impl<C> Default for WidgetRegistry<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> fmt::Debug for WidgetRegistry<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WidgetRegistry")
+            .field("names", &self.factories.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownWidget(name) => write!(f, "unknown widget `{name}`"),
+            Self::InvalidConfig { name, message } => {
+                write!(f, "invalid configuration for widget `{name}`: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Line;
+
+    fn registry() -> WidgetRegistry<u16> {
+        let mut registry = WidgetRegistry::new();
+        registry.register("count", |count: &u16| {
+            if *count > 9 {
+                return Err("too large");
+            }
+            Ok(Line::from("*".repeat(usize::from(*count))))
+        });
+        registry
+    }
+
+    #[test]
+    fn build() {
+        let widget = registry().build("count", &3).unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        widget.render_ref(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["***  "]));
+    }
+
+    #[test]
+    fn build_unknown_widget() {
+        let err = registry().build("missing", &3).err().unwrap();
+        assert_eq!(err, RegistryError::UnknownWidget("missing".to_string()));
+        assert_eq!(err.to_string(), "unknown widget `missing`");
+    }
+
+    #[test]
+    fn build_invalid_config() {
+        let err = registry().build("count", &10).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid configuration for widget `count`: too large"
+        );
+    }
+
+    #[test]
+    fn names() {
+        let mut registry = registry();
+        registry.register("block", |_: &u16| {
+            Ok::<_, &str>(crate::widgets::Block::new())
+        });
+        assert!(registry.contains("block"));
+        assert!(!registry.contains("missing"));
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["block", "count"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn register_deserialize() {
+        #[derive(serde::Deserialize)]
+        struct Label {
+            text: String,
+        }
+
+        impl Widget for &Label {
+            fn render(self, area: Rect, buf: &mut Buffer) {
+                Line::from(self.text.as_str()).render(area, buf);
+            }
+        }
+
+        let mut registry = WidgetRegistry::<serde_json::Value>::new();
+        registry.register_deserialize::<Label>("label");
+
+        let config = serde_json::json!({ "text": "hi" });
+        let widget = registry.build("label", &config).unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        widget.render_ref(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["hi "]));
+
+        let err = registry
+            .build("label", &serde_json::json!({}))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid configuration for widget `label`: missing field `text`"
+        );
+    }
+}
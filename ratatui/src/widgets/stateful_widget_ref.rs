@@ -80,6 +80,27 @@ where
     }
 }
 
+/// Renders a boxed stateful widget by reference.
+impl<W: StatefulWidgetRef + ?Sized> StatefulWidgetRef for Box<W> {
+    type State = W::State;
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.as_ref().render_ref(area, buf, state);
+    }
+}
+
+/// Renders a boxed stateful widget trait object as a [`StatefulWidget`].
+///
+/// This makes it possible to store stateful widgets of different types that share the same state
+/// type in a single collection and render them with [`Frame::render_stateful_widget`].
+///
+/// [`Frame::render_stateful_widget`]: crate::Frame::render_stateful_widget
+impl<State: ?Sized> StatefulWidget for Box<dyn StatefulWidgetRef<State = State> + '_> {
+    type State = State;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::{fixture, rstest};
@@ -120,6 +141,15 @@ mod tests {
         assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
     }
 
+    #[rstest]
+    fn render_box_dyn(mut buf: Buffer, mut state: String) {
+        let widget: Box<dyn StatefulWidgetRef<State = String>> = Box::new(&PersonalGreeting);
+        widget.render_ref(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
+        StatefulWidget::render(widget, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
+    }
+
     #[rstest]
     fn render_stateful_widget_ref_with_unsized_state(mut buf: Buffer) {
         struct Bytes;
@@ -90,6 +90,37 @@ where
     }
 }
 
+/// Renders a boxed widget by reference.
+///
+/// This allows a collection of `Box<dyn WidgetRef>` to be passed to functions that accept any
+/// `WidgetRef`, such as [`FrameExt::render_widget_ref`].
+///
+/// [`FrameExt::render_widget_ref`]: super::FrameExt::render_widget_ref
+impl<W: WidgetRef + ?Sized> WidgetRef for Box<W> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.as_ref().render_ref(area, buf);
+    }
+}
+
+/// Renders a boxed widget trait object as a [`Widget`].
+///
+/// This makes it possible to store widgets of different types (e.g. panes provided by plugins) in
+/// a single collection and render them with [`Frame::render_widget`].
+///
+/// [`Frame::render_widget`]: crate::Frame::render_widget
+impl Widget for Box<dyn WidgetRef + '_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+/// Renders a borrowed widget trait object as a [`Widget`].
+impl Widget for &dyn WidgetRef {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
 /// Provides the ability to render a string slice by reference.
 ///
 /// This trait implementation ensures that a string slice, which is an immutable view over a
@@ -209,6 +240,30 @@ mod tests {
         assert_eq!(buf, Buffer::with_lines(["Hello        Goodbye"]));
     }
 
+    #[rstest]
+    fn render_box_widget(mut buf: Buffer) {
+        let widgets: Vec<Box<dyn WidgetRef>> = vec![Box::new(&Greeting), Box::new(&Farewell)];
+        for widget in &widgets {
+            widget.as_ref().render(buf.area, &mut buf);
+        }
+        assert_eq!(buf, Buffer::with_lines(["Hello        Goodbye"]));
+        for widget in widgets {
+            widget.render(buf.area, &mut buf);
+        }
+        assert_eq!(buf, Buffer::with_lines(["Hello        Goodbye"]));
+    }
+
+    #[rstest]
+    fn render_ref_box_generic(mut buf: Buffer) {
+        fn render_twice<W: WidgetRef>(widget: &W, area: Rect, buf: &mut Buffer) {
+            widget.render_ref(area, buf);
+            widget.render_ref(area, buf);
+        }
+        let widget: Box<dyn WidgetRef> = Box::new(&Greeting);
+        render_twice(&widget, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["Hello               "]));
+    }
+
     #[rstest]
     fn render_ref_some(mut buf: Buffer) {
         let widget = Some(&Greeting);
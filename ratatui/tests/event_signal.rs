@@ -0,0 +1,28 @@
+#![cfg(unix)]
+
+use std::{os::unix::process::ExitStatusExt, process::Command};
+
+use ratatui::event::{EventPlayer, SignalEvents};
+use signal_hook::{consts::SIGTERM, low_level::raise};
+
+/// Set in the child process spawned by [`terminates_after_drop`].
+///
+/// The child runs this test binary again, so this must remain its only test.
+const CHILD_VAR: &str = "RATATUI_SIGNAL_EVENTS_CHILD";
+
+#[test]
+fn terminates_after_drop() {
+    if std::env::var_os(CHILD_VAR).is_some() {
+        // in the child: raising SIGTERM terminates the process once the sources are dropped
+        drop(SignalEvents::new(EventPlayer::new([])).unwrap());
+        let events = SignalEvents::new(EventPlayer::new([])).unwrap();
+        let _ = events.into_inner();
+        raise(SIGTERM).unwrap();
+        std::process::exit(0);
+    }
+    let status = Command::new(std::env::current_exe().unwrap())
+        .env(CHILD_VAR, "1")
+        .status()
+        .unwrap();
+    assert_eq!(status.signal(), Some(SIGTERM));
+}
@@ -1,10 +1,16 @@
-use std::error::Error;
+use std::{
+    error::Error,
+    io::ErrorKind,
+    sync::{Arc, Mutex},
+};
 
 use ratatui::{
-    backend::TestBackend,
-    layout::Rect,
-    widgets::{Block, Paragraph, Widget},
-    Terminal, TerminalOptions, Viewport,
+    backend::{Backend, NotificationProtocol, TestBackend},
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect, Size},
+    style::{Modifier, Style, Stylize},
+    widgets::{Block, List, ListState, Paragraph, PromptDialog, PromptState, Widget},
+    CursorState, Terminal, TerminalOptions, Tooltip, Viewport, WidgetId,
 };
 
 #[test]
@@ -39,6 +45,77 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_size_changed() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 10))?;
+    terminal.draw(|_| {})?;
+    assert_eq!(terminal.size_changed(), None);
+
+    terminal.backend_mut().resize(8, 6);
+    terminal.draw(|_| {})?;
+    assert_eq!(terminal.size_changed(), Some(Size::new(10, 10)));
+
+    terminal.draw(|_| {})?;
+    assert_eq!(terminal.size_changed(), None);
+    Ok(())
+}
+
+#[test]
+fn terminal_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Terminal<TestBackend>>();
+}
+
+#[test]
+fn terminal_calls_resize_hook() -> Result<(), Box<dyn Error>> {
+    let resizes = Arc::new(Mutex::new(Vec::new()));
+    let mut terminal = Terminal::new(TestBackend::new(10, 10))?;
+    let hook_resizes = Arc::clone(&resizes);
+    terminal.set_resize_hook(move |previous, size| {
+        hook_resizes.lock().unwrap().push((previous, size));
+    });
+
+    terminal.draw(|_| {})?;
+    terminal.backend_mut().resize(8, 6);
+    terminal.draw(|frame| assert_eq!(frame.area(), Rect::new(0, 0, 8, 6)))?;
+    terminal.clear_resize_hook();
+    terminal.backend_mut().resize(4, 4);
+    terminal.draw(|_| {})?;
+
+    assert_eq!(
+        *resizes.lock().unwrap(),
+        [(Size::new(10, 10), Size::new(8, 6))]
+    );
+    Ok(())
+}
+
+#[test]
+fn frame_set_cursor_in() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(20, 10))?;
+    terminal.draw(|frame| frame.set_cursor_in(Rect::new(2, 3, 10, 2), 4, 1))?;
+    terminal.backend_mut().assert_cursor_position((6, 4));
+
+    // the cursor stays in the area
+    terminal.draw(|frame| frame.set_cursor_in(Rect::new(2, 3, 10, 2), 15, 5))?;
+    terminal.backend_mut().assert_cursor_position((11, 4));
+    terminal.draw(|frame| frame.set_cursor_in(Rect::new(2, 3, 0, 0), 1, 1))?;
+    terminal.backend_mut().assert_cursor_position((2, 3));
+    Ok(())
+}
+
+#[test]
+fn frame_set_cursor_from() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(30, 8))?;
+    let mut state = PromptState::default().with_value("abc");
+    terminal.draw(|frame| {
+        frame.render_stateful_widget(PromptDialog::new("Name:"), frame.area(), &mut state);
+        frame.set_cursor_from(&state);
+    })?;
+    let position = state.cursor_position().expect("the input field is focused");
+    terminal.backend_mut().assert_cursor_position(position);
+    Ok(())
+}
+
 #[test]
 fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(10, 10);
@@ -64,6 +141,102 @@ fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_bell_unsupported_by_backend() {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let err = terminal.bell().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    let err = terminal
+        .notify_with(NotificationProtocol::Osc9, "title", "body")
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn terminal_cursor_state_stack() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.set_cursor_position((1, 2))?;
+    terminal.show_cursor()?;
+
+    terminal.push_cursor_state()?;
+    terminal.set_cursor_position((3, 4))?;
+    terminal.hide_cursor()?;
+    terminal.push_cursor_state()?;
+    terminal.set_cursor_position((5, 6))?;
+
+    let state = terminal.pop_cursor_state()?;
+    assert_eq!(
+        state,
+        Some(CursorState {
+            hidden: true,
+            position: Position::new(3, 4),
+        })
+    );
+    terminal.backend_mut().assert_cursor_position((3, 4));
+
+    let state = terminal.pop_cursor_state()?;
+    assert_eq!(
+        state,
+        Some(CursorState {
+            hidden: false,
+            position: Position::new(1, 2),
+        })
+    );
+    terminal.backend_mut().assert_cursor_position((1, 2));
+
+    assert_eq!(terminal.pop_cursor_state()?, None);
+    Ok(())
+}
+
+#[test]
+fn terminal_with_cursor_hidden_restores_cursor() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.set_cursor_position((1, 2))?;
+    let value = terminal.with_cursor_hidden(|terminal| {
+        terminal.set_cursor_position((7, 8)).unwrap();
+        42
+    })?;
+    assert_eq!(value, 42);
+    terminal.backend_mut().assert_cursor_position((1, 2));
+    Ok(())
+}
+
+#[test]
+fn terminal_reserved_region_is_not_drawn_or_cleared() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(4, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let region = Rect::new(1, 0, 2, 1);
+    terminal.reserve_region(region);
+    terminal.reserve_region(region);
+    assert_eq!(terminal.reserved_regions(), [region]);
+
+    // simulate an external program drawing into the reserved region
+    let external = Cell::new("E");
+    terminal
+        .backend_mut()
+        .draw([(1, 0, &external), (2, 0, &external)].into_iter())?;
+
+    terminal.draw(|frame| frame.render_widget(Paragraph::new("bbbb\nbbbb"), frame.area()))?;
+    terminal.backend().assert_buffer_lines(["bEEb", "bbbb"]);
+
+    terminal.clear()?;
+    terminal.backend().assert_buffer_lines([" EE ", "    "]);
+
+    terminal.draw(|frame| frame.render_widget(Paragraph::new("cccc\ncccc"), frame.area()))?;
+    terminal.backend().assert_buffer_lines(["cEEc", "cccc"]);
+
+    assert!(terminal.release_region(region)?);
+    assert!(!terminal.release_region(region)?);
+    terminal.backend().assert_buffer_lines(["c  c", "cccc"]);
+
+    terminal.draw(|frame| frame.render_widget(Paragraph::new("cccc\ncccc"), frame.area()))?;
+    terminal.backend().assert_buffer_lines(["cccc", "cccc"]);
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a
@@ -482,3 +655,126 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "scrolling-regions")]
+fn terminal_draw_scrolls_moved_rows() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(6, 5))?;
+    let draw = |terminal: &mut Terminal<TestBackend>, offset: u16| {
+        terminal
+            .draw(|f| {
+                let paragraph = Paragraph::new("a\nb\nc\nd\ne")
+                    .scroll((offset, 0))
+                    .block(Block::bordered());
+                f.render_widget(paragraph, f.area());
+            })
+            .map(|_| ())
+    };
+    draw(&mut terminal, 0)?;
+
+    // mark a cell directly on the screen, so that we can tell whether its row was scrolled or
+    // redrawn
+    let marker = Cell::new("X");
+    terminal
+        .backend_mut()
+        .draw(std::iter::once((2, 2, &marker)))?;
+
+    draw(&mut terminal, 1)?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["┌────┐", "│bX  │", "│c   │", "│d   │", "└────┘"]);
+
+    draw(&mut terminal, 0)?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["┌────┐", "│a   │", "│bX  │", "│c   │", "└────┘"]);
+    terminal.backend().assert_scrollback_empty();
+
+    Ok(())
+}
+
+#[test]
+fn terminal_keeps_widget_states_across_frames() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let id = WidgetId::from("menu");
+    let draw = |terminal: &mut Terminal<TestBackend>| {
+        terminal
+            .draw(|f| {
+                let list = List::new(["Item 1", "Item 2", "Item 3"]).highlight_symbol(">");
+                f.render_stateful_widget_with_id(list, f.area(), id);
+            })
+            .map(|_| ())
+    };
+    draw(&mut terminal)?;
+    assert_eq!(
+        terminal.states().get::<ListState>(id),
+        Some(&ListState::default())
+    );
+
+    terminal
+        .states_mut()
+        .get_mut::<ListState>(id)
+        .unwrap()
+        .select(Some(2));
+    draw(&mut terminal)?;
+    terminal.backend().assert_buffer_lines([" Item ", ">Item "]);
+    assert_eq!(terminal.states().get::<ListState>(id).unwrap().offset(), 1);
+    Ok(())
+}
+
+#[test]
+fn terminal_draws_tooltip_of_hovered_region() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend)?;
+    let draw = |terminal: &mut Terminal<TestBackend>| {
+        terminal
+            .draw(|f| {
+                let open = Rect::new(0, 0, 5, 1);
+                let quit = Rect::new(5, 0, 5, 1);
+                f.render_widget("Open", open);
+                f.render_widget("Quit", quit);
+                f.set_tooltip(open, Tooltip::new("Ctrl+O").focused(true));
+                f.set_tooltip(quit, Tooltip::new("q").style(Style::new()));
+            })
+            .map(|_| ())
+    };
+    let mut focused = Buffer::with_lines(["Open Quit ", " Ctrl+O   ", "          "]);
+    focused.set_style(Rect::new(0, 1, 8, 1), Modifier::REVERSED);
+
+    draw(&mut terminal)?;
+    terminal.backend().assert_buffer(&focused);
+
+    terminal.set_pointer_position(Some(Position::new(9, 0)));
+    draw(&mut terminal)?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["Open Quit ", "        q ", "          "]);
+
+    terminal.set_pointer_position(Some(Position::new(2, 2)));
+    draw(&mut terminal)?;
+    terminal.backend().assert_buffer(&focused);
+    Ok(())
+}
+
+#[test]
+fn terminal_applies_ambient_style() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 1);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.push_style(Modifier::DIM);
+        f.push_style(Style::new().red());
+        assert_eq!(f.ambient_style(), Style::new().red().dim());
+        f.render_widget("ab", Rect::new(0, 0, 2, 1));
+        f.render_widget("cd".blue(), Rect::new(2, 0, 2, 1));
+        assert_eq!(f.pop_style(), Some(Style::new().red()));
+        assert_eq!(f.pop_style(), Some(Style::new().dim()));
+        assert_eq!(f.pop_style(), None);
+        f.render_widget("ef", Rect::new(4, 0, 2, 1));
+    })?;
+    let mut expected = Buffer::with_lines(["abcdef"]);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red().dim());
+    expected.set_style(Rect::new(2, 0, 2, 1), Style::new().blue().dim());
+    terminal.backend().assert_buffer(&expected);
+    Ok(())
+}
@@ -1,3 +1,4 @@
+#![cfg(feature = "widget-barchart")]
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
@@ -1,3 +1,4 @@
+#![cfg(feature = "widget-canvas")]
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
@@ -1,3 +1,4 @@
+#![cfg(feature = "widget-chart")]
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
@@ -1,3 +1,4 @@
+#![cfg(feature = "widget-gauge")]
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
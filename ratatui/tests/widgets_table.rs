@@ -1,3 +1,4 @@
+#![cfg(feature = "widget-table")]
 #![allow(deprecated)]
 
 use ratatui::{
@@ -1,3 +1,4 @@
+#![cfg(feature = "widget-tabs")]
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
@@ -85,6 +85,10 @@ pub enum Command {
     #[command(visible_alias = "tl")]
     TestLibs,
 
+    /// Run the snapshot tests of the examples
+    #[command(visible_alias = "te")]
+    TestExamples,
+
     /// Run cargo hack to test each feature in isolation
     #[command(visible_alias = "h")]
     Hack,
@@ -109,6 +113,7 @@ impl Run for Command {
             Command::TestBackend(command) => command.run(),
             Command::TestDocs => test_docs(),
             Command::TestLibs => test_libs(),
+            Command::TestExamples => test_examples(),
             Command::Hack => hack(),
         }
     }
@@ -148,6 +153,7 @@ fn lint_markdown() -> Result<()> {
 /// Run tests for libs, backends, and docs
 fn test() -> Result<()> {
     test_libs()?;
+    test_examples()?;
     for backend in [Backend::Crossterm, Backend::Termion, Backend::Termwiz] {
         TestBackend { backend }.run()?;
     }
@@ -165,6 +171,18 @@ fn test_libs() -> Result<()> {
     run_cargo(vec!["test", "--lib", "--all-targets", "--all-features"])
 }
 
+/// Run the snapshot tests of the examples with all features, so that the examples which require a
+/// feature are covered
+fn test_examples() -> Result<()> {
+    run_cargo(vec![
+        "test",
+        "--package",
+        "ratatui",
+        "--examples",
+        "--all-features",
+    ])
+}
+
 /// Run cargo hack to test each feature in isolation
 fn hack() -> Result<()> {
     run_cargo(vec![